@@ -108,6 +108,575 @@ mod wasm_export;
 /// }
 /// ```
 ///
+/// ### Partial Success With Warnings
+/// Functions and methods can also return `Diagnosed<T, E>` instead of [Result<T, E>],
+/// which carries a list of non-fatal warnings alongside the result, in that case the
+/// exporting function returns `WasmEncodedDiagnosedResult<T>` which is the same as
+/// `WasmEncodedResult<T>` but with an extra `warnings` field, this also works with
+/// `preserve_js_class` attribute.
+///
+/// example:
+/// ```ignore
+/// #[wasm_export(js_name = "parse")]
+/// pub fn parse(input: String) -> Diagnosed<Config, Error> {
+///     let mut warnings = vec![];
+///     // collect warnings while parsing
+///     Diagnosed::ok(config).with_warnings(warnings)
+/// }
+/// ```
+///
+/// and we will get the following on JS/TS:
+/// ```ts
+/// const result = parse(input);
+/// for (const warning of result.warnings) {
+///     console.warn(warning.readableMsg);
+/// }
+/// ```
+///
+/// ### Batch Results
+/// Bulk operations can return `Vec<Result<T, E>>` so that one failing item doesn't
+/// fail the whole call, in that case each item is encoded separately and the exporting
+/// function returns `WasmEncodedResults<T>` which holds the encoded `items` along with
+/// `successCount` and `errorCount` summary counters, this also works with `preserve_js_class`
+/// attribute. If the whole call fails before producing any items, such as failing to decode
+/// its arguments or being aborted, `items` is empty and the error is held in the top level
+/// `error` field instead, so it is not mistaken for the error of a single item.
+///
+/// example:
+/// ```ignore
+/// #[wasm_export(js_name = "validateOrders")]
+/// pub fn validate_orders(orders: Vec<Order>) -> Vec<Result<Order, Error>> {
+///     orders.into_iter().map(validate).collect()
+/// }
+/// ```
+///
+/// and we will get the following on JS/TS:
+/// ```ts
+/// const results = validateOrders(orders);
+/// if (results.error) {
+///     // handle the failed call
+/// } else if (results.errorCount > 0) {
+///     const failed = results.items.filter((item) => item.error);
+///     // handle failed items
+/// }
+/// ```
+///
+/// ### Error Context
+/// The `error_context` attribute makes the exporting function attach the context of
+/// the call site to the `context` field of its returning errors, that includes the rust
+/// path of the function, its js name and a redacted summary of its arguments (only their
+/// names and types and not their values), it can be used for standalone functions, impl
+/// block methods or the whole impl block in which case it applies to all of its methods.
+///
+/// example:
+/// ```ignore
+/// #[wasm_export(js_name = "getOrder", error_context)]
+/// pub async fn get_order(#[wasm_export(js_name = "orderHash")] order_hash: String) -> Result<Order, Error> {
+///     // body
+/// }
+/// ```
+///
+/// and we will get the following on JS/TS:
+/// ```ts
+/// const result = await getOrder(orderHash);
+/// if (result.error) {
+///     // "some_crate::get_order", "getOrder", ["orderHash: String"]
+///     const { rustPath, jsName, args } = result.error.context;
+/// }
+/// ```
+///
+/// ### Doc Comments
+/// Doc comments of functions and methods are forwarded to the exporting function, where
+/// the conventional rustdoc sections are converted to their TSDoc counterparts, that is,
+/// items of `# Arguments` section become `param_description` of the corresponding params,
+/// `# Returns` section becomes `return_description` and `# Examples` section becomes an
+/// `@example` block, explicitly specified `param_description` and `return_description`
+/// attributes take precedence over the doc comments.
+///
+/// example:
+/// ```ignore
+/// /// Multiply two numbers together
+/// ///
+/// /// # Arguments
+/// /// * `x` - The first number
+/// /// * `y` - The second number
+/// ///
+/// /// # Returns
+/// /// The product of x and y
+/// #[wasm_export(js_name = "multiply")]
+/// pub fn mul(x: u32, y: u32) -> Result<u32, Error> {
+///     Ok(x * y)
+/// }
+/// ```
+///
+/// ### Optional Parameters
+/// Trailing `Option<T>` parameters are declared as omittable `arg?: T` in TS by `wasm_bindgen`,
+/// but not when their TS type is overridden by `unchecked_param_type` attribute, in that case
+/// the `optional` parameter attribute can be used to declare them as `arg: T | undefined`, so
+/// they can still be omitted by JS callers, it can also be used for non `Option<T>` parameters
+/// that have `unchecked_param_type` attribute such as `JsValue` which will be `undefined` if
+/// omitted. Optional parameters must be trailing, i.e. they can only be followed by other
+/// optional or `Option<T>` parameters.
+///
+/// example:
+/// ```ignore
+/// #[wasm_export(js_name = "getOrders")]
+/// pub async fn get_orders(
+///     owner: String,
+///     #[wasm_export(optional, unchecked_param_type = "OrdersFilter")] filter: JsValue,
+/// ) -> Result<Vec<Order>, Error> {
+///     // body
+/// }
+/// ```
+///
+/// and we will get the following on JS/TS:
+/// ```ts
+/// export function getOrders(owner: string, filter: OrdersFilter | undefined): Promise<WasmEncodedResult<Order[]>>;
+/// ```
+///
+/// ### Default Parameter Values
+/// The `default = <expr>` parameter attribute gives a parameter a default value, the exporting
+/// function accepts it as `Option<T>` so that it can be omitted (or passed as `null`/`undefined`)
+/// in JS/TS and the given rust expression is evaluated and passed to the original function in
+/// that case, the default value is also noted in the param description of the TS declaration.
+/// Same as `Option<T>` parameters, they are only omittable in TS if they are trailing.
+///
+/// example:
+/// ```ignore
+/// #[wasm_export(js_name = "getOrders")]
+/// pub async fn get_orders(
+///     owner: String,
+///     #[wasm_export(param_description = "Max number of orders", default = 100)] limit: u32,
+/// ) -> Result<Vec<Order>, Error> {
+///     // body
+/// }
+/// ```
+///
+/// and we will get the following on JS/TS:
+/// ```ts
+/// /**
+///  * @param owner
+///  * @param limit - Max number of orders. Defaults to `100`.
+///  */
+/// export function getOrders(owner: string, limit?: number | null): Promise<WasmEncodedResult<Order[]>>;
+/// ```
+///
+/// ### Variadic Parameters
+/// The `variadic` parameter attribute makes the last `Vec<T>` parameter receive the JS rest
+/// arguments, the items are decoded one by one (through `serde_wasm_bindgen`) and if any of
+/// them fails, the original function is not called and an error listing the index of each of
+/// the failed items is returned instead, the items are typed as `T[]` in TS for primitives,
+/// strings and plain named types (assuming they are exported to TS with the same name), for
+/// other types `unchecked_param_type` attribute can be used to specify it.
+///
+/// example:
+/// ```ignore
+/// #[wasm_export(js_name = "concat")]
+/// pub fn concat(#[wasm_export(variadic)] bytecodes: Vec<Bytecode>) -> Result<Bytecode, Error> {
+///     // body
+/// }
+/// ```
+///
+/// and we will get the following on JS/TS:
+/// ```ts
+/// export function concat(...bytecodes: Bytecode[]): WasmEncodedResult<Bytecode>;
+/// ```
+///
+/// ### Parameter Conversions
+/// The `from_js = path::to::fn` parameter attribute makes the exporting function accept a
+/// JS value for the parameter (typed in TS by the `js_type` attribute) which is decoded into
+/// the input type of the given function (through `serde_wasm_bindgen`) and converted by it
+/// before calling the original function, the conversion function should return a `Result<T, E>`
+/// where `E` is convertible into `WasmEncodedError`, if either of decoding or conversion fails
+/// the original function is not called and an error naming the parameter is returned instead.
+///
+/// example:
+/// ```ignore
+/// fn parse_address(value: String) -> Result<Address, Error> {
+///     Address::from_str(&value).map_err(Error::from)
+/// }
+///
+/// #[wasm_export(js_name = "getBalance")]
+/// pub async fn get_balance(
+///     #[wasm_export(from_js = parse_address, js_type = "string")] owner: Address,
+/// ) -> Result<String, Error> {
+///     // body
+/// }
+/// ```
+///
+/// and we will get the following on JS/TS:
+/// ```ts
+/// export function getBalance(owner: string): Promise<WasmEncodedResult<string>>;
+/// ```
+///
+/// ### Return Value Mapping
+/// The `map_ok = path::to::fn` attribute maps the success values of the original function
+/// (after awaiting it if it is async) by the given function before they are returned to
+/// JS, which is useful for converting internal types into their JS friendly counterparts,
+/// since the mapped type is not known to the macro, the exporting function returns `JsValue`
+/// and `unchecked_return_type` attribute is required to specify its TS type, it works for
+/// all of the returning kinds and with `preserve_js_class` attribute as well.
+///
+/// example:
+/// ```ignore
+/// fn to_js_order(order: InternalOrder) -> Order {
+///     Order::from(order)
+/// }
+///
+/// #[wasm_export(js_name = "getOrder", map_ok = to_js_order, unchecked_return_type = "Order")]
+/// pub async fn get_order(order_hash: String) -> Result<InternalOrder, Error> {
+///     // body
+/// }
+/// ```
+///
+/// and we will get the following on JS/TS:
+/// ```ts
+/// export function getOrder(orderHash: string): Promise<WasmEncodedResult<Order>>;
+/// ```
+///
+/// ### Union Types
+/// Params and returning values of `JsUnion2`, `JsUnion3` and `JsUnion4` types are declared
+/// as the TS union of their branches, if the TS types of all of the branches are known, i.e.
+/// primitives, strings and plain named types, otherwise `unchecked_param_type` or
+/// `unchecked_return_type` attributes can be used to specify them, at runtime the JS value
+/// is dispatched to the branch that its JS type matches before falling back to serde.
+///
+/// example:
+/// ```ignore
+/// #[wasm_export(js_name = "getBalance")]
+/// pub async fn get_balance(
+///     owner: JsUnion2<String, Address>,
+///     block: JsUnion2<u32, u64>,
+/// ) -> Result<JsUnion2<String, u64>, Error> {
+///     // body
+/// }
+/// ```
+///
+/// and we will get the following on JS/TS:
+/// ```ts
+/// export function getBalance(
+///   owner: string | Address,
+///   block: number | bigint,
+/// ): Promise<WasmEncodedResult<string | bigint>>;
+/// ```
+///
+/// ### Typed Promises
+/// Params and returning values of `TypedPromise<T>` type are declared as `Promise<T>` in TS
+/// (`Promise<any>` if the TS type of `T` is not known), a `TypedPromise` param awaits as the
+/// decoded value of the promise in rust, and a `TypedPromise` can be created from a rust future
+/// to be returned from a non async function.
+///
+/// example:
+/// ```ignore
+/// #[wasm_export(js_name = "startSync")]
+/// pub fn start_sync(client: Client) -> Result<TypedPromise<u32>, Error> {
+///     Ok(TypedPromise::from_future(async move { client.sync().await }))
+/// }
+///
+/// #[wasm_export(js_name = "waitForOrder")]
+/// pub async fn wait_for_order(order: TypedPromise<Order>) -> Result<String, Error> {
+///     Ok(order.await?.id)
+/// }
+/// ```
+///
+/// and we will get the following on JS/TS:
+/// ```ts
+/// export function startSync(client: Client): WasmEncodedResult<Promise<number>>;
+/// export function waitForOrder(order: Promise<Order>): Promise<WasmEncodedResult<string>>;
+/// ```
+///
+/// ### Typed Callbacks
+/// Params of `JsCallback<(A, B), R>` and `JsAsyncCallback<(A, B), R>` types are declared as
+/// `(arg0: A, arg1: B) => R` and `(arg0: A, arg1: B) => R | Promise<R>` TS function types
+/// respectively (unknown TS types are `any`), calling them from rust encodes the args and
+/// decodes the returned value, where thrown JS errors, promise rejections and invalid returned
+/// values become `Err` values through `WasmEncodedError`, the returned value of the callbacks
+/// that return `()` (`void` in TS) is ignored.
+///
+/// example:
+/// ```ignore
+/// #[wasm_export(js_name = "syncOrders")]
+/// pub async fn sync_orders(
+///     on_progress: JsCallback<(u32, u32), ()>,
+///     resolver: JsAsyncCallback<(String,), Order>,
+/// ) -> Result<u32, Error> {
+///     let order = resolver.call(("0x1234".to_string(),)).await?;
+///     on_progress.call((1, 1))?;
+///     Ok(1)
+/// }
+/// ```
+///
+/// and we will get the following on JS/TS:
+/// ```ts
+/// export function syncOrders(
+///   on_progress: (arg0: number, arg1: number) => void,
+///   resolver: (arg0: string) => Order | Promise<Order>,
+/// ): Promise<WasmEncodedResult<number>>;
+/// ```
+///
+/// ### Async Streams
+/// Functions whose returning type is `impl Stream<Item = Result<T, E>>` (the `Stream` trait of
+/// `futures-core`, re-exported in the prelude) return a JS async iterator of the results, i.e.
+/// `AsyncIterableIterator<WasmEncodedResult<T>>` in TS, whose items are encoded as the stream
+/// yields them, so they can be consumed with `for await...of`. The stream is dropped once it
+/// ends or the consumer stops early by calling `return()` (e.g. by breaking out of the loop),
+/// and the export hooks track the call until then. The stream must be `'static`, so methods
+/// should return streams that do not borrow `self`.
+///
+/// example:
+/// ```ignore
+/// #[wasm_export(js_name = "scanLogs")]
+/// pub fn scan_logs(from: u64) -> impl Stream<Item = Result<Log, Error>> {
+///     LogScanner::new(from)
+/// }
+/// ```
+///
+/// and we will get the following on JS/TS:
+/// ```ts
+/// export function scanLogs(from: bigint): AsyncIterableIterator<WasmEncodedResult<Log>>;
+///
+/// for await (const { value, error } of scanLogs(0n)) {
+///   // ...
+/// }
+/// ```
+///
+/// ### Lazy Iterators
+/// Functions whose returning type is `impl Iterator<Item = T>` or `Box<dyn Iterator<Item = T>>`
+/// return a JS iterator that is backed by the rust iterator, i.e. `IterableIterator<T>` in TS,
+/// so large results are not collected and encoded up front, each `next()` call converts a single
+/// item into JS through its wasm traits instead. The rust iterator is dropped once it is finished,
+/// the consumer stops early by calling `return()` (e.g. by breaking out of a `for...of` loop) or
+/// the JS iterator is garbage collected. Errors that occur before the function is called, such
+/// as failed param conversions, are thrown by the first `next()` call. Same as async streams, the
+/// iterator must be `'static`.
+///
+/// example:
+/// ```ignore
+/// #[wasm_export(js_name = "tokenize", unchecked_return_type = "Token")]
+/// pub fn tokenize(text: String) -> impl Iterator<Item = Token> {
+///     Tokenizer::new(text)
+/// }
+/// ```
+///
+/// and we will get the following on JS/TS:
+/// ```ts
+/// export function tokenize(text: string): IterableIterator<Token>;
+///
+/// for (const token of tokenize("some text")) {
+///   // ...
+/// }
+/// ```
+///
+/// ### Cancellation
+/// Async functions can be cancelled from JS by an `AbortSignal` param, i.e. a param of
+/// `AbortSignal` (`wasm_bindgen_utils::abort::AbortSignal` or `web_sys::AbortSignal`) or
+/// `Option<AbortSignal>` type or any JS value param marked with `abort_signal` attribute, which
+/// is declared as `AbortSignal | undefined` in TS, the call is raced against the signal and once
+/// it is aborted the rust future is dropped and the call resolves with an error of `aborted`
+/// kind, a value that is not an `AbortSignal` resolves with an invalid argument error. Rather than declaring the param, `cancellable`
+/// attribute appends an optional trailing `signal` param to the exporting function, and
+/// `timeout_ms` attribute aborts the call the same way once the given ms elapse. Both of them
+/// can also be specified for an impl block, in which case they apply to all of its async
+/// methods, except that the methods with their own abort signal param or a variadic param are
+/// not given the `signal` param. They are not available for streams and iterators.
+///
+/// example:
+/// ```ignore
+/// #[wasm_export(js_name = "fetchOrders", cancellable, timeout_ms = 5000)]
+/// pub async fn fetch_orders(owner: String) -> Result<Vec<Order>, Error> {
+///     // body
+/// }
+/// ```
+///
+/// and we will get the following on JS/TS:
+/// ```ts
+/// export function fetchOrders(owner: string, signal?: AbortSignal | null): Promise<WasmEncodedResult<Order[]>>;
+///
+/// const controller = new AbortController();
+/// const result = await fetchOrders("0x123", controller.signal);
+/// if (result.error?.kind === "aborted") {
+///   // aborted or timed out
+/// }
+/// ```
+///
+/// ### Generic Functions
+/// Generic functions and methods cannot be exported as is, their concrete instances are
+/// exported instead, each specified by an `instantiate` attribute with the type arguments
+/// and the JS name of the instance, which are exported as separate functions. Since
+/// wasm_bindgen classes cannot be generic either, each instance of a generic impl block is
+/// exported as a separate class, specified by `js_class` key, that only contains the static
+/// methods of the impl block. Generic return types are not known to TS, so they should be
+/// specified with `unchecked_return_type` attribute.
+///
+/// example:
+/// ```ignore
+/// #[wasm_export(
+///     instantiate(T = Json, js_name = "decodeJson"),
+///     instantiate(T = Cbor, js_name = "decodeCbor"),
+///     unchecked_return_type = "Order"
+/// )]
+/// pub fn decode<T: Format>(data: Vec<u8>) -> Result<T::Output, Error> {
+///     // body
+/// }
+///
+/// #[wasm_export(
+///     instantiate(T = Json, js_class = "JsonCodec"),
+///     instantiate(T = Cbor, js_class = "CborCodec")
+/// )]
+/// impl<T: Format> Codec<T> {
+///     #[wasm_export(js_name = "name", unchecked_return_type = "string")]
+///     pub fn name() -> Result<String, Error> {
+///         // body
+///     }
+/// }
+/// ```
+///
+/// and we will get the following on JS/TS:
+/// ```ts
+/// export function decodeJson(data: Uint8Array): WasmEncodedResult<Order>;
+/// export function decodeCbor(data: Uint8Array): WasmEncodedResult<Order>;
+/// export class JsonCodec {
+///   static name(): WasmEncodedResult<string>;
+/// }
+/// export class CborCodec {
+///   static name(): WasmEncodedResult<string>;
+/// }
+/// ```
+///
+/// ### Deprecation
+/// The `deprecated` attribute marks the exporting function as deprecated with a TSDoc
+/// `@deprecated` tag in its doc comments, it can be used as `deprecated`, `deprecated = "note"`
+/// or `deprecated(note = "...", since = "...", use_instead = "...", warn)` where all of
+/// the keys are optional and `warn` makes the exporting function log a warning with
+/// `console.warn` the first time it is called, it can be used for standalone functions,
+/// impl block methods or the whole impl block in which case it applies to all of its methods.
+///
+/// example:
+/// ```ignore
+/// #[wasm_export(js_name = "getOrder", deprecated(since = "0.2.0", use_instead = "getOrderV2", warn))]
+/// pub async fn get_order(order_hash: String) -> Result<Order, Error> {
+///     // body
+/// }
+/// ```
+///
+/// ### Export Hooks
+/// Every exporting function calls the hooks registered by `set_export_hooks()` with
+/// its js name, `before` and `after` the call (along with its elapsed time in ms) and
+/// `on_error` for each of the errors it fails with, but not for the warnings of `Diagnosed`,
+/// which is useful for uniform logging, timing and error reporting, the hooks are compiled out if `hooks` feature of
+/// `wasm-bindgen-utils` is disabled and are skipped if there are none registered.
+///
+/// example:
+/// ```ignore
+/// set_export_hooks(
+///     |name| log(&format!("calling {name}")),
+///     |name, elapsed_ms| log(&format!("{name} took {elapsed_ms}ms")),
+///     |name, error| report(name, error),
+/// );
+/// ```
+///
+/// ### Crate Path
+/// The generated code refers to the items of `wasm-bindgen-utils` (and its re-exported
+/// `wasm_bindgen`) by their fully qualified paths, so it does not depend on the imports
+/// of the calling module, and expects the crate to be available as `::wasm_bindgen_utils`.
+/// If the crate is renamed or re-exported from another crate, its path can be specified
+/// with `crate` attribute on standalone functions or impl blocks.
+///
+/// example:
+/// ```ignore
+/// #[wasm_export(crate = "::my_sdk::wasm_utils")]
+/// impl TestStruct {
+///     #[wasm_export(js_name = "getOrder")]
+///     pub async fn get_order(order_hash: String) -> Result<Order, Error> {
+///         // body
+///     }
+/// }
+/// ```
+///
+/// ### Native Builds
+/// The exporting functions and impl blocks are only compiled for wasm targets, i.e. under
+/// `#[cfg(target_family = "wasm")]`, while the original items are always compiled with
+/// their `wasm_export` parameter attributes removed, so the same annotated code builds for
+/// native targets as well. The configuration predicate can be changed with `cfg` attribute
+/// on standalone functions or impl blocks, for example `cfg(all())` always exports them.
+///
+/// example:
+/// ```ignore
+/// #[wasm_export(cfg(all(target_family = "wasm", feature = "js-api")))]
+/// impl TestStruct {
+///     #[wasm_export(js_name = "getOrder")]
+///     pub async fn get_order(order_hash: String) -> Result<Order, Error> {
+///         // body
+///     }
+/// }
+/// ```
+///
+/// ### Trait Impls
+/// Since wasm_bindgen cannot export trait impl blocks, using this macro on `impl Trait for
+/// Struct` keeps the trait impl unchanged and exports all of its methods through an inherent
+/// impl block whose methods call `<Struct as Trait>::method`, with all of the attributes above
+/// available for them. The associated types of the trait impl block that are used as
+/// `Self::Name` in the method signatures are resolved to their types for exporting.
+///
+/// example:
+/// ```ignore
+/// #[wasm_export]
+/// impl Parser for OrderParser {
+///     type Output = Order;
+///
+///     #[wasm_export(js_name = "parse", preserve_js_class)]
+///     fn parse(&self, #[wasm_export(js_name = "rawOrder")] raw: String) -> Result<Self::Output, Error> {
+///         // body
+///     }
+/// }
+/// ```
+///
+/// ### JS-implementable Traits
+/// Using this macro on a trait definition lets JS objects implement it, it keeps the trait
+/// unchanged and generates a `Js{Trait}` extern type that such JS objects are passed to rust
+/// as, declared as a TS `interface` with the typed methods of the trait (named `js_name` if
+/// specified at the top, otherwise the trait name), and a `Js{Trait}Adapter` struct that
+/// implements the trait by calling into the wrapped JS object. Args are converted to JS and
+/// returned values from JS the same way as the types that use `impl_wasm_traits!` are, async
+/// methods accept either a value or a `Promise` from JS, and JS exceptions, promise rejections
+/// and invalid returned values become `Err` values through `WasmEncodedError`, so the error type
+/// of the methods should impl `From<WasmEncodedError>`. All of the methods should take `self`
+/// and return a `Result`, methods with a default impl can be left out of the JS interface with
+/// `skip` attribute, and methods and params accept `js_name`, `unchecked_return_type`,
+/// `return_description`, `js_type`, `unchecked_param_type` and `param_description` attributes
+/// for their TS declaration. The trait cannot be generic or have supertraits or associated items.
+///
+/// example:
+/// ```ignore
+/// #[wasm_export(js_name = "KeyValueStorage")]
+/// pub trait Storage {
+///     #[wasm_export(js_name = "getItem")]
+///     fn get(&self, key: &str) -> Result<Option<String>, Error>;
+///     async fn set(&self, key: String, value: String) -> Result<(), Error>;
+/// }
+///
+/// #[wasm_export(js_name = "sync")]
+/// pub async fn sync(storage: JsStorage) -> Result<(), Error> {
+///     let storage = JsStorageAdapter::from(storage);
+///     storage.set("key".to_string(), "value".to_string()).await
+/// }
+/// ```
+/// which generates the following TS interface:
+/// ```ts
+/// export interface KeyValueStorage {
+///     getItem(key: string): string | undefined;
+///     set(key: string, value: string): void | Promise<void>;
+/// }
+/// ```
+///
+/// ### Diagnostics
+/// The errors of all of the methods of an impl block and of all of the parameters are
+/// reported at once rather than stopping at the first one. Parameter attributes only accept
+/// `param_description`, `unchecked_param_type`, `js_name`, `js_type`, `optional`, `default`,
+/// `variadic`, `from_js` and `abort_signal` keys, any other key is rejected with a suggestion of the closest
+/// one if it is likely a misspelling, e.g. "unexpected `optinal` parameter attribute, did
+/// you mean `optional`?".
+///
 #[proc_macro_attribute]
 pub fn wasm_export(attr: TokenStream, item: TokenStream) -> TokenStream {
     match wasm_export::expand(attr.into(), item.into()) {
@@ -115,3 +684,40 @@ pub fn wasm_export(attr: TokenStream, item: TokenStream) -> TokenStream {
         Err(e) => e.into_compile_error().into(),
     }
 }
+
+/// A proc macro that generates the wasm_bindgen bindings of the functions of an extern
+/// block along with wrappers that call into them with rich types, the inverse of
+/// [macro@wasm_export].
+///
+/// Each function must return `Result<T, E>`, where `E: From<WasmEncodedError>`. Its args are
+/// converted to JS and its returned value from JS the same way as the types that use
+/// `impl_wasm_traits!` are (`JsValue` args and returns are passed as they are), async functions
+/// accept either a value or a `Promise` from JS, and JS exceptions, promise rejections, returned
+/// `WasmEncodedResult` errors and invalid returned values become `Err` values, a returned
+/// `WasmEncodedResult` success is unwrapped to its `value`.
+///
+/// The top attributes other than `crate` and `cfg` are forwarded to wasm_bindgen for the
+/// extern block, and the `#[wasm_import(...)]` or `#[wasm_bindgen(...)]` attributes of a
+/// function are forwarded to wasm_bindgen for its raw binding, such as `js_name` (defaults to
+/// the function name), `js_namespace`, `method` (the first param is the JS object, passed as
+/// is) or `static_method_of`. Params accept `js_name` attribute that names them in the errors.
+/// Like the exporting items, the generated items are only compiled for wasm targets unless
+/// `cfg` attribute is specified, see [macro@wasm_export].
+///
+/// Example:
+/// ```ignore
+/// #[wasm_import(module = "/js/api.js")]
+/// extern "C" {
+///     #[wasm_import(js_name = "fetchOrder")]
+///     pub async fn fetch_order(id: u64) -> Result<Order, WasmEncodedError>;
+///
+///     pub fn log(msg: &str) -> Result<(), WasmEncodedError>;
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn wasm_import(attr: TokenStream, item: TokenStream) -> TokenStream {
+    match wasm_export::expand_import(attr.into(), item.into()) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
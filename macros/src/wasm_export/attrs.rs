@@ -1,14 +1,17 @@
-use std::ops::Deref;
-
 use quote::ToTokens;
 use proc_macro2::Span;
-use super::{error::extend_err_msg};
+use super::{
+    error::{extend_err_msg, Errors},
+    docs::RustDocs,
+    builder::WasmExportFunctionBuilder,
+};
 use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     spanned::Spanned,
     token::Comma,
-    Error, ImplItemFn, Meta, Path, PathSegment, ReturnType, Token, Type, TypePath,
+    Attribute, Error, Ident, ImplItemFn, Meta, Path, PathSegment, ReturnType, Token, Type,
+    TypeParamBound, TypePath,
 };
 
 /// Contains list of wasm_export macro attribute keys
@@ -16,11 +19,279 @@ pub struct AttrKeys;
 impl AttrKeys {
     pub const SKIP: &'static str = "skip";
     pub const WASM_EXPORT: &'static str = "wasm_export";
+    pub const WASM_IMPORT: &'static str = "wasm_import";
     pub const PRESERVE_JS_CLASS: &'static str = "preserve_js_class";
     pub const UNCHECKED_RETURN_TYPE: &'static str = "unchecked_return_type";
     pub const RETURN_DESCRIPTION: &'static str = "return_description";
     pub const UNCHECKED_PARAM_TYPE: &'static str = "unchecked_param_type";
+    pub const PARAM_DESCRIPTION: &'static str = "param_description";
+    pub const OPTIONAL: &'static str = "optional";
+    pub const DEFAULT: &'static str = "default";
+    pub const VARIADIC: &'static str = "variadic";
+    pub const FROM_JS: &'static str = "from_js";
+    pub const ABORT_SIGNAL: &'static str = "abort_signal";
+    pub const JS_TYPE: &'static str = "js_type";
     pub const JS_NAME: &'static str = "js_name";
+    pub const ERROR_CONTEXT: &'static str = "error_context";
+    pub const CANCELLABLE: &'static str = "cancellable";
+    pub const TIMEOUT_MS: &'static str = "timeout_ms";
+    pub const DEPRECATED: &'static str = "deprecated";
+    pub const MAP_OK: &'static str = "map_ok";
+    pub const INSTANTIATE: &'static str = "instantiate";
+    pub const JS_CLASS: &'static str = "js_class";
+    pub const CRATE: &'static str = "crate";
+    pub const CFG: &'static str = "cfg";
+}
+
+/// Specifies the shape of the returning type of an exporting function/method
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReturnKind {
+    /// `Result<T, E>` which is exported as `WasmEncodedResult<T>`
+    #[default]
+    Result,
+    /// `Diagnosed<T, E>` which is exported as `WasmEncodedDiagnosedResult<T>`
+    Diagnosed,
+    /// `Vec<Result<T, E>>` which is exported as `WasmEncodedResults<T>`
+    Batch,
+    /// `impl Stream<Item = Result<T, E>>` which is exported as a `JsAsyncIterator`, i.e.
+    /// `AsyncIterableIterator<WasmEncodedResult<T>>` in TS
+    Stream,
+    /// `impl Iterator<Item = T>` or `Box<dyn Iterator<Item = T>>` which is exported as a
+    /// `JsIterator`, i.e. `IterableIterator<T>` in TS
+    Iterator,
+}
+
+impl ReturnKind {
+    /// Returns the name of the type that this kind of returning type is exported as
+    pub fn encoded_type_name(&self) -> &'static str {
+        match self {
+            ReturnKind::Result => "WasmEncodedResult",
+            ReturnKind::Diagnosed => "WasmEncodedDiagnosedResult",
+            ReturnKind::Batch => "WasmEncodedResults",
+            ReturnKind::Stream => "JsAsyncIterator",
+            ReturnKind::Iterator => "JsIterator",
+        }
+    }
+
+    /// Returns the TS type that this kind of returning type is exported as for the given
+    /// TS type of its inner type, e.g. `WasmEncodedResult<T>` for `Result<T, E>`
+    pub fn encoded_ts_type(&self, inner: &str) -> String {
+        match self {
+            ReturnKind::Stream => format!("AsyncIterableIterator<WasmEncodedResult<{}>>", inner),
+            ReturnKind::Iterator => format!("IterableIterator<{}>", inner),
+            _ => format!("{}<{}>", self.encoded_type_name(), inner),
+        }
+    }
+
+    /// Whether the calls of async functions with this kind of returning type can be raced
+    /// against an abort signal or a timeout, that is, the ones whose whole result is awaited
+    /// before returning, as opposed to streams and iterators whose items are produced later
+    pub fn is_cancellable(&self) -> bool {
+        !matches!(self, ReturnKind::Stream | ReturnKind::Iterator)
+    }
+}
+
+/// Struct that holds the parsed `deprecated` attribute details, which can be specified as
+/// `deprecated`, `deprecated = "note"` or `deprecated(note = "...", since = "...", use_instead = "...", warn)`
+#[derive(Debug, Clone)]
+pub struct DeprecatedAttr {
+    pub note: Option<String>,
+    pub since: Option<String>,
+    pub use_instead: Option<String>,
+    /// Whether to log a one-time runtime warning the first time the exporting function is called
+    pub warn: Option<Span>,
+    /// The span of the attribute
+    pub span: Span,
+}
+
+impl Parse for DeprecatedAttr {
+    fn parse(input: ParseStream) -> Result<Self, Error> {
+        Self::parse_meta(&input.parse()?)
+    }
+}
+
+impl DeprecatedAttr {
+    pub const NOTE: &'static str = "note";
+    pub const SINCE: &'static str = "since";
+    pub const USE_INSTEAD: &'static str = "use_instead";
+    pub const WARN: &'static str = "warn";
+
+    /// Parses the given `deprecated` attribute meta
+    pub fn parse_meta(meta: &Meta) -> Result<Self, Error> {
+        let mut deprecated = DeprecatedAttr {
+            note: None,
+            since: None,
+            use_instead: None,
+            warn: None,
+            span: meta.span(),
+        };
+        match meta {
+            Meta::Path(_) => {}
+            Meta::NameValue(name_value) => {
+                deprecated.note = Some(Self::parse_str(meta, &name_value.value)?);
+            }
+            Meta::List(list) => {
+                let nested = list
+                    .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                    .map_err(extend_err_msg(
+                        " as deprecated attribute keys must be delimited by comma",
+                    ))?;
+                for meta in nested {
+                    let key = meta.path().get_ident().map(ToString::to_string);
+                    let field = match key.as_deref() {
+                        Some(Self::NOTE) => &mut deprecated.note,
+                        Some(Self::SINCE) => &mut deprecated.since,
+                        Some(Self::USE_INSTEAD) => &mut deprecated.use_instead,
+                        Some(Self::WARN) => {
+                            if deprecated.warn.is_some() {
+                                return Err(Error::new_spanned(meta, "duplicate `warn` key"));
+                            }
+                            meta.require_path_only().map_err(extend_err_msg(
+                                ", `warn` key does not take any extra tokens or arguments",
+                            ))?;
+                            deprecated.warn = Some(meta.span());
+                            continue;
+                        }
+                        _ => {
+                            return Err(Error::new_spanned(
+                                meta.path(),
+                                "unexpected deprecated attribute key, expected one of `note`, `since`, `use_instead` or `warn`",
+                            ));
+                        }
+                    };
+                    if field.is_some() {
+                        let msg = format!("duplicate `{}` key", key.unwrap_or_default());
+                        return Err(Error::new_spanned(meta, msg));
+                    }
+                    let value = &meta
+                        .require_name_value()
+                        .map_err(extend_err_msg(" and it must be a string literal"))?
+                        .value;
+                    *field = Some(Self::parse_str(&meta, value)?);
+                }
+            }
+        }
+        Ok(deprecated)
+    }
+
+    /// Builds the deprecation message from the note, since and use_instead details
+    pub fn message(&self) -> String {
+        let mut parts = vec![];
+        if let Some(note) = &self.note {
+            parts.push(note.clone());
+        }
+        if let Some(since) = &self.since {
+            parts.push(format!("Since {since}."));
+        }
+        if let Some(use_instead) = &self.use_instead {
+            parts.push(format!("Use `{use_instead}` instead."));
+        }
+        parts.join(" ")
+    }
+
+    fn parse_str(meta: &Meta, value: &syn::Expr) -> Result<String, Error> {
+        if let syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(str),
+            ..
+        }) = value
+        {
+            Ok(str.value())
+        } else {
+            Err(Error::new_spanned(meta, "expected string literal"))
+        }
+    }
+}
+
+/// Struct that holds the parsed `instantiate` attribute details, which is specified as
+/// `instantiate(T = Type, js_name = "...")` for generic functions/methods and as
+/// `instantiate(T = Type, js_class = "...")` for generic impl blocks, each of them
+/// exports a concrete instance of the generic item with the given type arguments
+#[derive(Debug, Clone)]
+pub struct InstantiateAttr {
+    pub type_args: Vec<(Ident, Type)>,
+    pub js_name: Option<String>,
+    pub js_class: Option<String>,
+    pub span: Span,
+}
+
+impl InstantiateAttr {
+    /// Parses the given `instantiate` attribute meta
+    pub fn parse_meta(meta: &Meta) -> Result<Self, Error> {
+        let list = meta.require_list().map_err(extend_err_msg(
+            " and it must contain the type arguments, e.g. `instantiate(T = SomeType, js_name = \"...\")`",
+        ))?;
+        let args = list
+            .parse_args_with(Punctuated::<InstantiateArg, Token![,]>::parse_terminated)
+            .map_err(extend_err_msg(
+                " as instantiate attribute keys must be delimited by comma",
+            ))?;
+        let mut instantiate = InstantiateAttr {
+            type_args: vec![],
+            js_name: None,
+            js_class: None,
+            span: meta.span(),
+        };
+        for arg in args {
+            let key = arg.key.to_string();
+            match arg.value {
+                InstantiateArgValue::Str(value) => {
+                    let field = if key == AttrKeys::JS_NAME {
+                        &mut instantiate.js_name
+                    } else {
+                        &mut instantiate.js_class
+                    };
+                    if field.is_some() {
+                        let msg = format!("duplicate `{}` key", key);
+                        return Err(Error::new_spanned(arg.key, msg));
+                    }
+                    *field = Some(value.value());
+                }
+                InstantiateArgValue::Type(ty) => {
+                    if instantiate
+                        .type_args
+                        .iter()
+                        .any(|(ident, _)| *ident == arg.key)
+                    {
+                        let msg = format!("duplicate `{}` type argument", key);
+                        return Err(Error::new_spanned(arg.key, msg));
+                    }
+                    instantiate.type_args.push((arg.key, *ty));
+                }
+            }
+        }
+        if instantiate.type_args.is_empty() {
+            return Err(Error::new_spanned(
+                meta,
+                "expected at least one type argument, e.g. `T = SomeType`",
+            ));
+        }
+        Ok(instantiate)
+    }
+}
+
+/// A single `key = value` item of `instantiate` attribute, where the value is a string
+/// literal for `js_name` and `js_class` keys and a type for the type arguments
+struct InstantiateArg {
+    key: Ident,
+    value: InstantiateArgValue,
+}
+
+enum InstantiateArgValue {
+    Str(syn::LitStr),
+    Type(Box<Type>),
+}
+
+impl Parse for InstantiateArg {
+    fn parse(input: ParseStream) -> Result<Self, Error> {
+        let key: Ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let value = if key == AttrKeys::JS_NAME || key == AttrKeys::JS_CLASS {
+            InstantiateArgValue::Str(input.parse()?)
+        } else {
+            InstantiateArgValue::Type(Box::new(input.parse()?))
+        };
+        Ok(InstantiateArg { key, value })
+    }
 }
 
 /// Struct that holds the parsed wasm_export attributes details
@@ -31,6 +302,14 @@ pub struct WasmExportAttrs {
     pub should_skip: Option<Span>,
     pub preserve_js_class: Option<Span>,
     pub return_description: Option<(String, Span)>,
+    pub error_context: Option<Span>,
+    pub cancellable: Option<Span>,
+    pub timeout_ms: Option<(u32, Span)>,
+    pub deprecated: Option<DeprecatedAttr>,
+    pub map_ok: Option<syn::Path>,
+    pub instantiate: Vec<InstantiateAttr>,
+    pub krate: Option<syn::Path>,
+    pub cfg: Option<Meta>,
 }
 
 impl Parse for WasmExportAttrs {
@@ -64,11 +343,17 @@ impl WasmExportAttrs {
     /// Processes the return type for the exporting function/method from the specified
     /// `unchecked_return_type` attr, falls back to original return inner type if not
     /// provided by `unchecked_return_type` attribute
-    pub fn handle_return_type(&mut self, output: &ReturnType) -> Option<Type> {
-        let return_type = Self::try_extract_result_inner_type(output).cloned();
-        let as_str = return_type
-            .as_ref()
-            .map(|v| format!("{}", v.to_token_stream()));
+    pub fn handle_return_type(&mut self, output: &ReturnType) -> Option<(Type, ReturnKind)> {
+        let (return_type, return_kind) = match Self::try_extract_return_inner_type(output) {
+            Some((ty, kind)) => (Some(ty.clone()), kind),
+            None => (None, ReturnKind::default()),
+        };
+        let as_str = return_type.as_ref().map(|v| {
+            WasmExportFunctionBuilder::union_ts_type(v)
+                .or_else(|| WasmExportFunctionBuilder::promise_ts_type(v))
+                .or_else(|| WasmExportFunctionBuilder::callback_ts_type(v))
+                .unwrap_or_else(|| format!("{}", v.to_token_stream()))
+        });
 
         // handle return type attr for exporting item's wasm_bindgen macro invocation
         if let Some(v) = self
@@ -77,7 +362,7 @@ impl WasmExportAttrs {
             .map(|v| &v.0)
             .or(as_str.as_ref())
         {
-            let return_type = format!("WasmEncodedResult<{}>", v);
+            let return_type = return_kind.encoded_ts_type(v);
             self.forward_attrs.push(syn::parse_quote!(
                 unchecked_return_type = #return_type
             ));
@@ -90,96 +375,293 @@ impl WasmExportAttrs {
             ));
         }
 
-        return_type
+        return_type.map(|ty| (ty, return_kind))
+    }
+
+    /// Returns the keys and spans of the specified attributes that only apply to exporting
+    /// functions/methods, that is, the ones that are not `crate`, `cfg` or `skip` attributes
+    /// or forwarded to wasm_bindgen
+    pub fn export_attr_spans(&self) -> Vec<(&'static str, Span)> {
+        let mut spans = vec![];
+        if let Some((_, span)) = &self.unchecked_return_type {
+            spans.push((AttrKeys::UNCHECKED_RETURN_TYPE, *span));
+        }
+        if let Some((_, span)) = &self.return_description {
+            spans.push((AttrKeys::RETURN_DESCRIPTION, *span));
+        }
+        if let Some(span) = self.preserve_js_class {
+            spans.push((AttrKeys::PRESERVE_JS_CLASS, span));
+        }
+        if let Some(span) = self.error_context {
+            spans.push((AttrKeys::ERROR_CONTEXT, span));
+        }
+        if let Some(span) = self.cancellable {
+            spans.push((AttrKeys::CANCELLABLE, span));
+        }
+        if let Some((_, span)) = &self.timeout_ms {
+            spans.push((AttrKeys::TIMEOUT_MS, *span));
+        }
+        if let Some(deprecated) = &self.deprecated {
+            spans.push((AttrKeys::DEPRECATED, deprecated.span));
+        }
+        if let Some(map_ok) = &self.map_ok {
+            spans.push((AttrKeys::MAP_OK, map_ok.span()));
+        }
+        for instantiate in &self.instantiate {
+            spans.push((AttrKeys::INSTANTIATE, instantiate.span));
+        }
+        spans
+    }
+
+    /// Validates that `unchecked_return_type` is specified if `map_ok` is, since the
+    /// returning type is not known after the mapping
+    pub fn validate_map_ok(&self) -> Result<(), Error> {
+        match &self.map_ok {
+            Some(map_ok) if self.unchecked_return_type.is_none() => Err(Error::new_spanned(
+                map_ok,
+                "`map_ok` attribute requires `unchecked_return_type` attribute",
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the path of the wasm_bindgen_utils crate that the generated code refers
+    /// to, that is the value of `crate` attribute if specified, otherwise the default path
+    pub fn crate_path(&self) -> syn::Path {
+        self.krate.clone().unwrap_or_else(Self::default_crate_path)
+    }
+
+    /// Returns the default path of the wasm_bindgen_utils crate in the generated code
+    pub fn default_crate_path() -> syn::Path {
+        syn::parse_quote!(::wasm_bindgen_utils)
+    }
+
+    /// Returns the configuration predicate that the generated exporting items are compiled
+    /// under, that is the value of `cfg` attribute if specified, otherwise the default one
+    pub fn cfg_predicate(&self) -> Meta {
+        self.cfg.clone().unwrap_or_else(Self::default_cfg_predicate)
+    }
+
+    /// Returns the default configuration predicate of the generated exporting items, which
+    /// only compiles them for wasm targets so that the original items can be built natively
+    pub fn default_cfg_predicate() -> Meta {
+        syn::parse_quote!(target_family = "wasm")
+    }
+
+    /// Falls back to the `# Returns` section of the given rustdoc for the return
+    /// description if it is not specified by `return_description` attribute
+    pub fn handle_doc_returns(&mut self, docs: &RustDocs) {
+        if self.return_description.is_none() {
+            self.return_description = docs.returns.clone().map(|v| (v, Span::call_site()));
+        }
     }
 
     /// Handles wasm_export specified sequence of attributes delimited by comma
     pub fn handle_attrs_sequence(&mut self, metas: Punctuated<Meta, Comma>) -> Result<(), Error> {
+        let mut errors = Errors::default();
         for meta in metas {
-            match meta.path().get_ident().map(ToString::to_string).as_deref() {
-                Some(AttrKeys::UNCHECKED_RETURN_TYPE) => {
-                    if self.unchecked_return_type.is_some() {
-                        return Err(Error::new_spanned(
-                            meta,
-                            "duplicate `unchecked_return_type` attribute",
-                        ));
-                    } else if let syn::Expr::Lit(syn::ExprLit {
-                        lit: syn::Lit::Str(str),
-                        ..
-                    }) = &meta
-                        .require_name_value()
-                        .map_err(extend_err_msg(" and it must be a string literal"))?
-                        .value
-                    {
-                        self.unchecked_return_type = Some((str.value(), meta.span()));
-                    } else {
-                        return Err(Error::new_spanned(meta, "expected string literal"));
-                    }
+            errors.collect(self.handle_attr(meta));
+        }
+        errors.finish()
+    }
+
+    /// Handles a single wasm_export attribute
+    fn handle_attr(&mut self, meta: Meta) -> Result<(), Error> {
+        match meta.path().get_ident().map(ToString::to_string).as_deref() {
+            Some(AttrKeys::UNCHECKED_RETURN_TYPE) => {
+                if self.unchecked_return_type.is_some() {
+                    return Err(Error::new_spanned(
+                        meta,
+                        "duplicate `unchecked_return_type` attribute",
+                    ));
+                } else if let syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(str),
+                    ..
+                }) = &meta
+                    .require_name_value()
+                    .map_err(extend_err_msg(" and it must be a string literal"))?
+                    .value
+                {
+                    self.unchecked_return_type = Some((str.value(), meta.span()));
+                } else {
+                    return Err(Error::new_spanned(meta, "expected string literal"));
                 }
-                Some(AttrKeys::RETURN_DESCRIPTION) => {
-                    if self.return_description.is_some() {
-                        return Err(Error::new_spanned(
-                            meta,
-                            "duplicate `return_description` attribute",
-                        ));
-                    } else if let syn::Expr::Lit(syn::ExprLit {
-                        lit: syn::Lit::Str(str),
-                        ..
-                    }) = &meta
-                        .require_name_value()
-                        .map_err(extend_err_msg(" and it must be a string literal"))?
-                        .value
-                    {
-                        self.return_description = Some((str.value(), meta.span()));
-                    } else {
-                        return Err(Error::new_spanned(meta, "expected string literal"));
-                    }
+            }
+            Some(AttrKeys::RETURN_DESCRIPTION) => {
+                if self.return_description.is_some() {
+                    return Err(Error::new_spanned(
+                        meta,
+                        "duplicate `return_description` attribute",
+                    ));
+                } else if let syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(str),
+                    ..
+                }) = &meta
+                    .require_name_value()
+                    .map_err(extend_err_msg(" and it must be a string literal"))?
+                    .value
+                {
+                    self.return_description = Some((str.value(), meta.span()));
+                } else {
+                    return Err(Error::new_spanned(meta, "expected string literal"));
                 }
-                Some(AttrKeys::SKIP) => {
-                    if self.should_skip.is_some() {
-                        return Err(Error::new_spanned(meta, "duplicate `skip` attribute"));
-                    }
-                    meta.require_path_only().map_err(extend_err_msg(
-                        ", `skip` attribute does not take any extra tokens or arguments",
-                    ))?;
-                    self.should_skip = Some(meta.span());
+            }
+            Some(AttrKeys::SKIP) => {
+                if self.should_skip.is_some() {
+                    return Err(Error::new_spanned(meta, "duplicate `skip` attribute"));
                 }
-                Some(AttrKeys::PRESERVE_JS_CLASS) => {
-                    if self.preserve_js_class.is_some() {
-                        return Err(Error::new_spanned(
-                            meta,
-                            "duplicate `preserve_js_class` attribute",
-                        ));
-                    }
-                    meta.require_path_only().map_err(extend_err_msg(
-                        ", `preserve_js_class` attribute does not take any extra tokens or arguments",
-                    ))?;
-                    self.preserve_js_class = Some(meta.span());
+                meta.require_path_only().map_err(extend_err_msg(
+                    ", `skip` attribute does not take any extra tokens or arguments",
+                ))?;
+                self.should_skip = Some(meta.span());
+            }
+            Some(AttrKeys::PRESERVE_JS_CLASS) => {
+                if self.preserve_js_class.is_some() {
+                    return Err(Error::new_spanned(
+                        meta,
+                        "duplicate `preserve_js_class` attribute",
+                    ));
+                }
+                meta.require_path_only().map_err(extend_err_msg(
+                    ", `preserve_js_class` attribute does not take any extra tokens or arguments",
+                ))?;
+                self.preserve_js_class = Some(meta.span());
+            }
+            Some(AttrKeys::ERROR_CONTEXT) => {
+                if self.error_context.is_some() {
+                    return Err(Error::new_spanned(
+                        meta,
+                        "duplicate `error_context` attribute",
+                    ));
+                }
+                meta.require_path_only().map_err(extend_err_msg(
+                    ", `error_context` attribute does not take any extra tokens or arguments",
+                ))?;
+                self.error_context = Some(meta.span());
+            }
+            Some(AttrKeys::CANCELLABLE) => {
+                if self.cancellable.is_some() {
+                    return Err(Error::new_spanned(
+                        meta,
+                        "duplicate `cancellable` attribute",
+                    ));
                 }
-                _ => {
-                    // include unchanged to be forwarded to the corresponding export item
-                    self.forward_attrs.push(meta);
+                meta.require_path_only().map_err(extend_err_msg(
+                    ", `cancellable` attribute does not take any extra tokens or arguments",
+                ))?;
+                self.cancellable = Some(meta.span());
+            }
+            Some(AttrKeys::TIMEOUT_MS) => {
+                if self.timeout_ms.is_some() {
+                    return Err(Error::new_spanned(meta, "duplicate `timeout_ms` attribute"));
+                } else if let syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(int),
+                    ..
+                }) = &meta
+                    .require_name_value()
+                    .map_err(extend_err_msg(" and it must be an integer literal"))?
+                    .value
+                {
+                    let timeout_ms = int.base10_parse().map_err(|_| {
+                        Error::new_spanned(
+                            int,
+                            "expected an integer of milliseconds that fits in `u32`",
+                        )
+                    })?;
+                    self.timeout_ms = Some((timeout_ms, meta.span()));
+                } else {
+                    return Err(Error::new_spanned(meta, "expected integer literal"));
                 }
             }
+            Some(AttrKeys::INSTANTIATE) => {
+                self.instantiate.push(InstantiateAttr::parse_meta(&meta)?);
+            }
+            Some(AttrKeys::MAP_OK) => {
+                if self.map_ok.is_some() {
+                    return Err(Error::new_spanned(meta, "duplicate `map_ok` attribute"));
+                } else if let syn::Expr::Path(expr_path) = &meta
+                    .require_name_value()
+                    .map_err(extend_err_msg(" and it must be a path to a function"))?
+                    .value
+                {
+                    self.map_ok = Some(expr_path.path.clone());
+                } else {
+                    return Err(Error::new_spanned(meta, "expected a path to a function"));
+                }
+            }
+            Some(AttrKeys::CRATE) => {
+                if self.krate.is_some() {
+                    return Err(Error::new_spanned(meta, "duplicate `crate` attribute"));
+                } else if let syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(str),
+                    ..
+                }) = &meta
+                    .require_name_value()
+                    .map_err(extend_err_msg(" and it must be a string literal"))?
+                    .value
+                {
+                    self.krate = Some(str.parse().map_err(|_| {
+                        Error::new_spanned(
+                            str,
+                            "expected a path, e.g. `crate = \"path::to::wasm_bindgen_utils\"`",
+                        )
+                    })?);
+                } else {
+                    return Err(Error::new_spanned(meta, "expected string literal"));
+                }
+            }
+            Some(AttrKeys::CFG) => {
+                if self.cfg.is_some() {
+                    return Err(Error::new_spanned(meta, "duplicate `cfg` attribute"));
+                }
+                let list = meta.require_list().map_err(extend_err_msg(
+                    " and it must contain a configuration predicate, e.g. `cfg(target_family = \"wasm\")`",
+                ))?;
+                self.cfg = Some(list.parse_args::<Meta>().map_err(|_| {
+                    Error::new_spanned(
+                        list,
+                        "expected a single configuration predicate, e.g. `cfg(target_family = \"wasm\")`",
+                    )
+                })?);
+            }
+            Some(AttrKeys::DEPRECATED) => {
+                if self.deprecated.is_some() {
+                    return Err(Error::new_spanned(meta, "duplicate `deprecated` attribute"));
+                }
+                self.deprecated = Some(DeprecatedAttr::parse_meta(&meta)?);
+            }
+            _ => {
+                // include unchanged to be forwarded to the corresponding export item
+                self.forward_attrs.push(meta);
+            }
         }
         Ok(())
     }
 
     // Handles wasm_export macro attributes for a given impl method
     pub fn handle_method_attrs(method: &mut ImplItemFn) -> Result<Self, Error> {
-        // start parsing nested attributes of this method
+        Self::handle_item_attrs(&mut method.attrs, AttrKeys::WASM_EXPORT)
+    }
+
+    /// Handles the macro attributes of the given key of an item, such as a method of an
+    /// impl block or a trait or an imported function, and cleans them from the given attributes
+    pub fn handle_item_attrs(attrs: &mut Vec<Attribute>, key: &str) -> Result<Self, Error> {
+        // start parsing nested attributes of this item
         let mut keep = Vec::new();
+        let mut errors = Errors::default();
         let mut wasm_export_attrs = Self::default();
-        for attr in method.attrs.iter_mut() {
-            if attr.path().is_ident(AttrKeys::WASM_EXPORT) {
+        for attr in attrs.iter_mut() {
+            if attr.path().is_ident(key) {
                 // skip parsing by delimited comma if there are no nested attrs
                 if !matches!(attr.meta, Meta::Path(_)) {
                     let nested_seq = attr
                         .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
                         .map_err(extend_err_msg(
                             " as wasm_export attributes must be delimited by comma",
-                        ))?;
-                    wasm_export_attrs.handle_attrs_sequence(nested_seq)?;
+                        ));
+                    if let Some(nested_seq) = errors.collect(nested_seq) {
+                        errors.collect(wasm_export_attrs.handle_attrs_sequence(nested_seq));
+                    }
                 }
                 keep.push(false);
             } else {
@@ -187,32 +669,90 @@ impl WasmExportAttrs {
             }
         }
 
-        // extract wasm_export attrs from this method input
+        // extract wasm_export attrs from this item input
         let mut keep = keep.into_iter();
-        method.attrs.retain(|_| keep.next().unwrap_or(true));
+        attrs.retain(|_| keep.next().unwrap_or(true));
 
-        Ok(wasm_export_attrs)
+        errors.finish_with(wasm_export_attrs)
     }
 
-    /// Tries to extract the inner type T from a Result<T, E> type, returning None if not a Result
-    pub fn try_extract_result_inner_type(output: &ReturnType) -> Option<&Type> {
-        if let ReturnType::Type(_, return_type) = output {
-            if let Type::Path(TypePath {
-                path: Path { segments, .. },
-                ..
-            }) = return_type.deref()
-            {
-                if let Some(PathSegment {
-                    ident, arguments, ..
-                }) = segments.last()
-                {
-                    if *ident == "Result" {
-                        if let syn::PathArguments::AngleBracketed(args) = arguments {
-                            if let Some(syn::GenericArgument::Type(t)) = args.args.first() {
-                                return Some(t);
-                            }
-                        }
+    /// Tries to extract the inner type T from a Result<T, E>, Diagnosed<T, E>, Vec<Result<T, E>>,
+    /// impl Stream<Item = Result<T, E>>, impl Iterator<Item = T> or Box<dyn Iterator<Item = T>>
+    /// type along with the kind of the returning type, returning None if not any of them
+    pub fn try_extract_return_inner_type(output: &ReturnType) -> Option<(&Type, ReturnKind)> {
+        let ReturnType::Type(_, return_type) = output else {
+            return None;
+        };
+        if let Type::ImplTrait(impl_trait) = return_type.as_ref() {
+            if let Some(item_type) = Self::try_extract_item_type(&impl_trait.bounds, "Stream") {
+                return match Self::try_extract_first_generic_type(item_type)? {
+                    (ident, inner_type) if ident == "Result" => {
+                        Some((inner_type, ReturnKind::Stream))
                     }
+                    _ => None,
+                };
+            }
+            return Self::try_extract_item_type(&impl_trait.bounds, "Iterator")
+                .map(|inner_type| (inner_type, ReturnKind::Iterator));
+        }
+        let (ident, inner_type) = Self::try_extract_first_generic_type(return_type)?;
+        match ident.to_string().as_str() {
+            "Result" => Some((inner_type, ReturnKind::Result)),
+            "Diagnosed" => Some((inner_type, ReturnKind::Diagnosed)),
+            "Vec" => match Self::try_extract_first_generic_type(inner_type)? {
+                (ident, inner_type) if ident == "Result" => Some((inner_type, ReturnKind::Batch)),
+                _ => None,
+            },
+            "Box" => match inner_type {
+                Type::TraitObject(trait_object) => {
+                    Self::try_extract_item_type(&trait_object.bounds, "Iterator")
+                        .map(|inner_type| (inner_type, ReturnKind::Iterator))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Tries to extract the `Item` type from the bound of the given trait (by its name) of
+    /// the given bounds, for example `T` from the `Iterator<Item = T>` bound
+    fn try_extract_item_type<'a>(
+        bounds: &'a Punctuated<TypeParamBound, Token![+]>,
+        trait_name: &str,
+    ) -> Option<&'a Type> {
+        bounds.iter().find_map(|bound| {
+            let TypeParamBound::Trait(bound) = bound else {
+                return None;
+            };
+            let segment = bound.path.segments.last()?;
+            let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+                return None;
+            };
+            if segment.ident != trait_name {
+                return None;
+            }
+            args.args.iter().find_map(|arg| match arg {
+                syn::GenericArgument::AssocType(assoc) if assoc.ident == "Item" => Some(&assoc.ty),
+                _ => None,
+            })
+        })
+    }
+
+    /// Tries to extract the last path segment ident of the given type along with its
+    /// first generic type argument, for example `Result` and `T` from `Result<T, E>`
+    fn try_extract_first_generic_type(ty: &Type) -> Option<(&Ident, &Type)> {
+        if let Type::Path(TypePath {
+            path: Path { segments, .. },
+            ..
+        }) = ty
+        {
+            if let Some(PathSegment {
+                ident,
+                arguments: syn::PathArguments::AngleBracketed(args),
+            }) = segments.last()
+            {
+                if let Some(syn::GenericArgument::Type(t)) = args.args.first() {
+                    return Some((ident, t));
                 }
             }
         }
@@ -246,7 +786,7 @@ mod tests {
 
         // mixed
         let stream = TokenStream::from_str(
-            "some_top_attr, some_other_top_attr = something, preserve_js_class",
+            "some_top_attr, some_other_top_attr = something, preserve_js_class, error_context",
         )
         .unwrap();
         let result: WasmExportAttrs = syn::parse2(stream).unwrap();
@@ -257,6 +797,7 @@ mod tests {
         assert!(result.should_skip.is_none());
         assert!(result.unchecked_return_type.is_none());
         assert!(result.preserve_js_class.is_some());
+        assert!(result.error_context.is_some());
         assert_eq!(result.forward_attrs, expected_forward_attrs);
     }
 
@@ -268,12 +809,21 @@ mod tests {
             should_skip: None,
             unchecked_return_type: Some(("SomeOverrideType".to_string(), Span::call_site())),
             preserve_js_class: None,
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
             return_description: None,
+            map_ok: None,
+            instantiate: vec![],
+            krate: None,
+            cfg: None,
         };
-        let result = wasm_export_attrs.handle_return_type(&ret_type).unwrap();
+        let (result, kind) = wasm_export_attrs.handle_return_type(&ret_type).unwrap();
 
         let expected_type: Type = parse_quote!(SomeType);
         assert_eq!(result, expected_type);
+        assert_eq!(kind, ReturnKind::Result);
 
         let expected_wasm_export_attrs = WasmExportAttrs {
             forward_attrs: vec![parse_quote!(
@@ -282,7 +832,15 @@ mod tests {
             should_skip: None,
             unchecked_return_type: Some(("SomeOverrideType".to_string(), Span::call_site())),
             preserve_js_class: None,
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
             return_description: None,
+            map_ok: None,
+            instantiate: vec![],
+            krate: None,
+            cfg: None,
         };
         assert!(wasm_export_attrs.should_skip.is_none());
         assert_eq!(
@@ -303,12 +861,21 @@ mod tests {
             should_skip: None,
             unchecked_return_type: None,
             preserve_js_class: None,
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
             return_description: None,
+            map_ok: None,
+            instantiate: vec![],
+            krate: None,
+            cfg: None,
         };
-        let result = wasm_export_attrs.handle_return_type(&ret_type).unwrap();
+        let (result, kind) = wasm_export_attrs.handle_return_type(&ret_type).unwrap();
 
         let expected_type: Type = parse_quote!(SomeType);
         assert_eq!(result, expected_type);
+        assert_eq!(kind, ReturnKind::Result);
 
         let expected_wasm_export_attrs = WasmExportAttrs {
             forward_attrs: vec![parse_quote!(
@@ -317,7 +884,15 @@ mod tests {
             should_skip: None,
             unchecked_return_type: None,
             preserve_js_class: None,
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
             return_description: None,
+            map_ok: None,
+            instantiate: vec![],
+            krate: None,
+            cfg: None,
         };
         assert!(wasm_export_attrs.should_skip.is_none());
         assert!(wasm_export_attrs.unchecked_return_type.is_none());
@@ -327,6 +902,121 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_wasm_export_ret_type_union() {
+        let ret_type: ReturnType = parse_quote!(-> Result<JsUnion2<String, u64>, Error>);
+        let mut wasm_export_attrs = WasmExportAttrs::default();
+        let (result, _) = wasm_export_attrs.handle_return_type(&ret_type).unwrap();
+
+        let expected_type: Type = parse_quote!(JsUnion2<String, u64>);
+        assert_eq!(result, expected_type);
+        assert_eq!(
+            wasm_export_attrs.forward_attrs,
+            vec![parse_quote!(
+                unchecked_return_type = "WasmEncodedResult<string | bigint>"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_wasm_export_ret_type_promise() {
+        let ret_type: ReturnType = parse_quote!(-> Result<TypedPromise<SomeType>, Error>);
+        let mut wasm_export_attrs = WasmExportAttrs::default();
+        let (result, _) = wasm_export_attrs.handle_return_type(&ret_type).unwrap();
+
+        let expected_type: Type = parse_quote!(TypedPromise<SomeType>);
+        assert_eq!(result, expected_type);
+        assert_eq!(
+            wasm_export_attrs.forward_attrs,
+            vec![parse_quote!(
+                unchecked_return_type = "WasmEncodedResult<Promise<SomeType>>"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_wasm_export_ret_type_stream() {
+        let ret_type: ReturnType = parse_quote!(-> impl Stream<Item = Result<SomeType, Error>>);
+        let mut wasm_export_attrs = WasmExportAttrs::default();
+        let (result, kind) = wasm_export_attrs.handle_return_type(&ret_type).unwrap();
+
+        let expected_type: Type = parse_quote!(SomeType);
+        assert_eq!(result, expected_type);
+        assert_eq!(kind, ReturnKind::Stream);
+        assert_eq!(
+            wasm_export_attrs.forward_attrs,
+            vec![parse_quote!(
+                unchecked_return_type = "AsyncIterableIterator<WasmEncodedResult<SomeType>>"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_wasm_export_ret_type_iterator() {
+        let ret_type: ReturnType = parse_quote!(-> Box<dyn Iterator<Item = SomeType>>);
+        let mut wasm_export_attrs = WasmExportAttrs::default();
+        let (result, kind) = wasm_export_attrs.handle_return_type(&ret_type).unwrap();
+
+        let expected_type: Type = parse_quote!(SomeType);
+        assert_eq!(result, expected_type);
+        assert_eq!(kind, ReturnKind::Iterator);
+        assert_eq!(
+            wasm_export_attrs.forward_attrs,
+            vec![parse_quote!(
+                unchecked_return_type = "IterableIterator<SomeType>"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_wasm_export_ret_type_batch() {
+        let ret_type: ReturnType = parse_quote!(-> Vec<Result<SomeType, Error>>);
+        let mut wasm_export_attrs = WasmExportAttrs::default();
+        let (result, kind) = wasm_export_attrs.handle_return_type(&ret_type).unwrap();
+
+        let expected_type: Type = parse_quote!(SomeType);
+        assert_eq!(result, expected_type);
+        assert_eq!(kind, ReturnKind::Batch);
+        assert_eq!(
+            wasm_export_attrs.forward_attrs,
+            vec![parse_quote!(
+                unchecked_return_type = "WasmEncodedResults<SomeType>"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_wasm_export_ret_type_diagnosed() {
+        let ret_type: ReturnType = parse_quote!(-> Diagnosed<SomeType, Error>);
+        let mut wasm_export_attrs = WasmExportAttrs::default();
+        let (result, kind) = wasm_export_attrs.handle_return_type(&ret_type).unwrap();
+
+        let expected_type: Type = parse_quote!(SomeType);
+        assert_eq!(result, expected_type);
+        assert_eq!(kind, ReturnKind::Diagnosed);
+        assert_eq!(
+            wasm_export_attrs.forward_attrs,
+            vec![parse_quote!(
+                unchecked_return_type = "WasmEncodedDiagnosedResult<SomeType>"
+            )]
+        );
+
+        // with override
+        let mut wasm_export_attrs = WasmExportAttrs {
+            unchecked_return_type: Some(("SomeOverrideType".to_string(), Span::call_site())),
+            ..Default::default()
+        };
+        let (result, kind) = wasm_export_attrs.handle_return_type(&ret_type).unwrap();
+        assert_eq!(result, expected_type);
+        assert_eq!(kind, ReturnKind::Diagnosed);
+        assert_eq!(
+            wasm_export_attrs.forward_attrs,
+            vec![parse_quote!(
+                unchecked_return_type = "WasmEncodedDiagnosedResult<SomeOverrideType>"
+            )]
+        );
+    }
+
     #[test]
     fn test_handle_attrs_sequence_happy() {
         // parse a mixed seq of attrs
@@ -420,6 +1110,45 @@ mod tests {
         let mut wasm_export_attrs = WasmExportAttrs::default();
         let err = wasm_export_attrs.handle_attrs_sequence(seq).unwrap_err();
         assert_eq!(err.to_string(), "unexpected token in attribute, `preserve_js_class` attribute does not take any extra tokens or arguments");
+
+        // dup error_context
+        let input = TokenStream::from_str(r#"error_context, error_context"#).unwrap();
+        let seq = Punctuated::<Meta, Token![,]>::parse_terminated
+            .parse2(input)
+            .unwrap();
+        let mut wasm_export_attrs = WasmExportAttrs::default();
+        let err = wasm_export_attrs.handle_attrs_sequence(seq).unwrap_err();
+        assert_eq!(err.to_string(), "duplicate `error_context` attribute");
+
+        // invalid error_context
+        let input = TokenStream::from_str(r#"error_context = something"#).unwrap();
+        let seq = Punctuated::<Meta, Token![,]>::parse_terminated
+            .parse2(input)
+            .unwrap();
+        let mut wasm_export_attrs = WasmExportAttrs::default();
+        let err = wasm_export_attrs.handle_attrs_sequence(seq).unwrap_err();
+        assert_eq!(err.to_string(), "unexpected token in attribute, `error_context` attribute does not take any extra tokens or arguments");
+
+        // all errors are collected
+        let input = TokenStream::from_str(
+            r#"return_description = 1, js_name = "someName", skip, skip, map_ok = "func""#,
+        )
+        .unwrap();
+        let seq = Punctuated::<Meta, Token![,]>::parse_terminated
+            .parse2(input)
+            .unwrap();
+        let mut wasm_export_attrs = WasmExportAttrs::default();
+        let err = wasm_export_attrs.handle_attrs_sequence(seq).unwrap_err();
+        let msgs = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            msgs,
+            vec![
+                "expected string literal",
+                "duplicate `skip` attribute",
+                "expected a path to a function",
+            ]
+        );
+        assert_eq!(wasm_export_attrs.forward_attrs.len(), 1);
     }
 
     #[test]
@@ -481,34 +1210,113 @@ mod tests {
             err.to_string(),
             "expected `,` as wasm_export attributes must be delimited by comma"
         );
+
+        // errors of all attrs are collected
+        let mut method: ImplItemFn = parse_quote!(
+            #[wasm_export(some_forward_attr; skip)]
+            #[wasm_export(return_description = 1)]
+            pub fn some_fn(arg1: String) -> Result<SomeType, Error> {
+                Ok(SomeType::new())
+            }
+        );
+        let err = WasmExportAttrs::handle_method_attrs(&mut method).unwrap_err();
+        let msgs = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            msgs,
+            vec![
+                "expected `,` as wasm_export attributes must be delimited by comma",
+                "expected string literal",
+            ]
+        );
     }
 
     #[test]
-    fn test_try_extract_result_inner_type_happy() {
+    fn test_try_extract_return_inner_type_happy() {
         let output: ReturnType = parse_quote!(-> Result<SomeType, Error>);
-        let result = WasmExportAttrs::try_extract_result_inner_type(&output).unwrap();
+        let (result, kind) = WasmExportAttrs::try_extract_return_inner_type(&output).unwrap();
         let expected: Type = parse_quote!(SomeType);
         assert_eq!(*result, expected);
+        assert_eq!(kind, ReturnKind::Result);
 
         let output: ReturnType = parse_quote!(-> Result<(), Error>);
-        let result = WasmExportAttrs::try_extract_result_inner_type(&output).unwrap();
+        let (result, kind) = WasmExportAttrs::try_extract_return_inner_type(&output).unwrap();
         let expected: Type = parse_quote!(());
         assert_eq!(*result, expected);
+        assert_eq!(kind, ReturnKind::Result);
+
+        let output: ReturnType = parse_quote!(-> wasm_bindgen_utils::result::Diagnosed<u8, Error>);
+        let (result, kind) = WasmExportAttrs::try_extract_return_inner_type(&output).unwrap();
+        let expected: Type = parse_quote!(u8);
+        assert_eq!(*result, expected);
+        assert_eq!(kind, ReturnKind::Diagnosed);
+
+        let output: ReturnType = parse_quote!(-> Vec<std::result::Result<u8, Error>>);
+        let (result, kind) = WasmExportAttrs::try_extract_return_inner_type(&output).unwrap();
+        let expected: Type = parse_quote!(u8);
+        assert_eq!(*result, expected);
+        assert_eq!(kind, ReturnKind::Batch);
+
+        let output: ReturnType =
+            parse_quote!(-> impl Stream<Item = Result<u8, Error>> + Unpin + 'static);
+        let (result, kind) = WasmExportAttrs::try_extract_return_inner_type(&output).unwrap();
+        let expected: Type = parse_quote!(u8);
+        assert_eq!(*result, expected);
+        assert_eq!(kind, ReturnKind::Stream);
+
+        let output: ReturnType =
+            parse_quote!(-> impl 'static + futures::Stream<Item = std::result::Result<u8, E>>);
+        let (result, kind) = WasmExportAttrs::try_extract_return_inner_type(&output).unwrap();
+        let expected: Type = parse_quote!(u8);
+        assert_eq!(*result, expected);
+        assert_eq!(kind, ReturnKind::Stream);
+
+        let output: ReturnType = parse_quote!(-> impl Iterator<Item = SomeType> + 'static);
+        let (result, kind) = WasmExportAttrs::try_extract_return_inner_type(&output).unwrap();
+        let expected: Type = parse_quote!(SomeType);
+        assert_eq!(*result, expected);
+        assert_eq!(kind, ReturnKind::Iterator);
+
+        let output: ReturnType = parse_quote!(-> Box<dyn std::iter::Iterator<Item = u8>>);
+        let (result, kind) = WasmExportAttrs::try_extract_return_inner_type(&output).unwrap();
+        let expected: Type = parse_quote!(u8);
+        assert_eq!(*result, expected);
+        assert_eq!(kind, ReturnKind::Iterator);
     }
 
     #[test]
-    fn test_try_extract_result_inner_type_unhappy() {
+    fn test_try_extract_return_inner_type_unhappy() {
         let output: ReturnType = parse_quote!(-> SomeType);
-        assert!(WasmExportAttrs::try_extract_result_inner_type(&output).is_none());
+        assert!(WasmExportAttrs::try_extract_return_inner_type(&output).is_none());
 
         let output: ReturnType = parse_quote!(-> Option<SomeType>);
-        assert!(WasmExportAttrs::try_extract_result_inner_type(&output).is_none());
+        assert!(WasmExportAttrs::try_extract_return_inner_type(&output).is_none());
 
         let output: ReturnType = parse_quote!(-> ());
-        assert!(WasmExportAttrs::try_extract_result_inner_type(&output).is_none());
+        assert!(WasmExportAttrs::try_extract_return_inner_type(&output).is_none());
 
         let output: ReturnType = parse_quote!();
-        assert!(WasmExportAttrs::try_extract_result_inner_type(&output).is_none());
+        assert!(WasmExportAttrs::try_extract_return_inner_type(&output).is_none());
+
+        let output: ReturnType = parse_quote!(-> Diagnosed);
+        assert!(WasmExportAttrs::try_extract_return_inner_type(&output).is_none());
+
+        let output: ReturnType = parse_quote!(-> Vec<SomeType>);
+        assert!(WasmExportAttrs::try_extract_return_inner_type(&output).is_none());
+
+        let output: ReturnType = parse_quote!(-> Vec<Diagnosed<SomeType, Error>>);
+        assert!(WasmExportAttrs::try_extract_return_inner_type(&output).is_none());
+
+        let output: ReturnType = parse_quote!(-> impl Stream<Item = SomeType>);
+        assert!(WasmExportAttrs::try_extract_return_inner_type(&output).is_none());
+
+        let output: ReturnType = parse_quote!(-> impl Display);
+        assert!(WasmExportAttrs::try_extract_return_inner_type(&output).is_none());
+
+        let output: ReturnType = parse_quote!(-> Box<dyn Stream<Item = Result<u8, Error>>>);
+        assert!(WasmExportAttrs::try_extract_return_inner_type(&output).is_none());
+
+        let output: ReturnType = parse_quote!(-> Box<SomeType>);
+        assert!(WasmExportAttrs::try_extract_return_inner_type(&output).is_none());
     }
 
     #[test]
@@ -566,12 +1374,20 @@ mod tests {
             should_skip: None,
             unchecked_return_type: None,
             preserve_js_class: None,
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
             return_description: Some((
                 "returns the calculated result".to_string(),
                 Span::call_site(),
             )),
+            map_ok: None,
+            instantiate: vec![],
+            krate: None,
+            cfg: None,
         };
-        let result = wasm_export_attrs.handle_return_type(&ret_type).unwrap();
+        let (result, _) = wasm_export_attrs.handle_return_type(&ret_type).unwrap();
 
         let expected_type: Type = parse_quote!(u32);
         assert_eq!(result, expected_type);
@@ -587,6 +1403,322 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_handle_doc_returns() {
+        let docs = RustDocs {
+            returns: Some("returns from docs".to_string()),
+            ..Default::default()
+        };
+
+        // falls back to docs
+        let mut wasm_export_attrs = WasmExportAttrs::default();
+        wasm_export_attrs.handle_doc_returns(&docs);
+        assert_eq!(
+            wasm_export_attrs.return_description.unwrap().0,
+            "returns from docs"
+        );
+
+        // explicit attribute wins
+        let mut wasm_export_attrs: WasmExportAttrs =
+            syn::parse_quote!(return_description = "explicit");
+        wasm_export_attrs.handle_doc_returns(&docs);
+        assert_eq!(wasm_export_attrs.return_description.unwrap().0, "explicit");
+
+        // no returns section in docs
+        let mut wasm_export_attrs = WasmExportAttrs::default();
+        wasm_export_attrs.handle_doc_returns(&RustDocs::default());
+        assert!(wasm_export_attrs.return_description.is_none());
+    }
+
+    #[test]
+    fn test_deprecated_parsing() {
+        // path only
+        let result: WasmExportAttrs = syn::parse_quote!(deprecated);
+        let deprecated = result.deprecated.unwrap();
+        assert!(deprecated.note.is_none());
+        assert!(deprecated.since.is_none());
+        assert!(deprecated.use_instead.is_none());
+        assert!(deprecated.warn.is_none());
+        assert_eq!(deprecated.message(), "");
+
+        // note only
+        let result: WasmExportAttrs =
+            syn::parse_quote!(js_name = "someFn", deprecated = "some note");
+        let deprecated = result.deprecated.unwrap();
+        assert_eq!(deprecated.note.as_deref(), Some("some note"));
+        assert_eq!(deprecated.message(), "some note");
+        assert_eq!(result.forward_attrs, vec![parse_quote!(js_name = "someFn")]);
+
+        // list
+        let result: WasmExportAttrs = syn::parse_quote!(deprecated(
+            note = "some note.",
+            since = "1.0.0",
+            use_instead = "otherFn",
+            warn
+        ));
+        let deprecated = result.deprecated.unwrap();
+        assert!(deprecated.warn.is_some());
+        assert_eq!(
+            deprecated.message(),
+            "some note. Since 1.0.0. Use `otherFn` instead."
+        );
+
+        // duplicate deprecated attr
+        let input = TokenStream::from_str(r#"deprecated, deprecated = "some note""#).unwrap();
+        let err = syn::parse2::<WasmExportAttrs>(input).unwrap_err();
+        assert_eq!(err.to_string(), "duplicate `deprecated` attribute");
+
+        // duplicate key
+        let input = TokenStream::from_str(r#"deprecated(since = "1", since = "2")"#).unwrap();
+        let err = syn::parse2::<WasmExportAttrs>(input).unwrap_err();
+        assert_eq!(err.to_string(), "duplicate `since` key");
+
+        let input = TokenStream::from_str(r#"deprecated(warn, warn)"#).unwrap();
+        let err = syn::parse2::<WasmExportAttrs>(input).unwrap_err();
+        assert_eq!(err.to_string(), "duplicate `warn` key");
+
+        // unknown key
+        let input = TokenStream::from_str(r#"deprecated(replacement = "otherFn")"#).unwrap();
+        let err = syn::parse2::<WasmExportAttrs>(input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unexpected deprecated attribute key, expected one of `note`, `since`, `use_instead` or `warn`"
+        );
+
+        // invalid values
+        let input = TokenStream::from_str(r#"deprecated = 1"#).unwrap();
+        let err = syn::parse2::<WasmExportAttrs>(input).unwrap_err();
+        assert_eq!(err.to_string(), "expected string literal");
+
+        let input = TokenStream::from_str(r#"deprecated(since)"#).unwrap();
+        let err = syn::parse2::<WasmExportAttrs>(input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected a value for this attribute: `since = ...` and it must be a string literal"
+        );
+
+        let input = TokenStream::from_str(r#"deprecated(warn = "yes")"#).unwrap();
+        let err = syn::parse2::<WasmExportAttrs>(input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unexpected token in attribute, `warn` key does not take any extra tokens or arguments"
+        );
+    }
+
+    #[test]
+    fn test_cancellation_parsing() {
+        let result: WasmExportAttrs = syn::parse_quote!(cancellable, timeout_ms = 5000);
+        assert!(result.cancellable.is_some());
+        assert_eq!(result.timeout_ms.map(|(v, _)| v), Some(5000));
+        assert!(result.forward_attrs.is_empty());
+
+        let cases = [
+            (
+                "cancellable, cancellable",
+                "duplicate `cancellable` attribute",
+            ),
+            (
+                "cancellable = true",
+                "unexpected token in attribute, `cancellable` attribute does not take any extra tokens or arguments",
+            ),
+            (
+                "timeout_ms = 1, timeout_ms = 2",
+                "duplicate `timeout_ms` attribute",
+            ),
+            (
+                r#"timeout_ms = "1000""#,
+                "expected integer literal",
+            ),
+            (
+                "timeout_ms = -1",
+                "expected an integer of milliseconds that fits in `u32`",
+            ),
+            (
+                "timeout_ms = 5000000000",
+                "expected an integer of milliseconds that fits in `u32`",
+            ),
+            (
+                "timeout_ms",
+                "expected a value for this attribute: `timeout_ms = ...` and it must be an integer literal",
+            ),
+        ];
+        for (input, msg) in cases {
+            let input = TokenStream::from_str(input).unwrap();
+            let err = syn::parse2::<WasmExportAttrs>(input).unwrap_err();
+            assert_eq!(err.to_string(), msg);
+        }
+    }
+
+    #[test]
+    fn test_return_kind_is_cancellable() {
+        assert!(ReturnKind::Result.is_cancellable());
+        assert!(ReturnKind::Diagnosed.is_cancellable());
+        assert!(ReturnKind::Batch.is_cancellable());
+        assert!(!ReturnKind::Stream.is_cancellable());
+        assert!(!ReturnKind::Iterator.is_cancellable());
+    }
+
+    #[test]
+    fn test_map_ok_parsing() {
+        let result: WasmExportAttrs = syn::parse_quote!(
+            map_ok = some_mod::to_js_type,
+            unchecked_return_type = "JsType"
+        );
+        assert_eq!(result.map_ok, Some(parse_quote!(some_mod::to_js_type)));
+        assert!(result.validate_map_ok().is_ok());
+
+        // missing unchecked_return_type
+        let result: WasmExportAttrs = syn::parse_quote!(map_ok = to_js_type);
+        let err = result.validate_map_ok().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`map_ok` attribute requires `unchecked_return_type` attribute"
+        );
+
+        // duplicate map_ok attr
+        let input = TokenStream::from_str(r#"map_ok = to_js_type, map_ok = other"#).unwrap();
+        let err = syn::parse2::<WasmExportAttrs>(input).unwrap_err();
+        assert_eq!(err.to_string(), "duplicate `map_ok` attribute");
+
+        // invalid values
+        let input = TokenStream::from_str(r#"map_ok = "to_js_type""#).unwrap();
+        let err = syn::parse2::<WasmExportAttrs>(input).unwrap_err();
+        assert_eq!(err.to_string(), "expected a path to a function");
+
+        let input = TokenStream::from_str(r#"map_ok"#).unwrap();
+        let err = syn::parse2::<WasmExportAttrs>(input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected a value for this attribute: `map_ok = ...` and it must be a path to a function"
+        );
+    }
+
+    #[test]
+    fn test_crate_parsing() {
+        let result: WasmExportAttrs = syn::parse_quote!(crate = "::my_crate::wbg_utils");
+        assert_eq!(result.krate, Some(parse_quote!(::my_crate::wbg_utils)));
+        assert_eq!(result.crate_path(), parse_quote!(::my_crate::wbg_utils));
+        assert!(result.forward_attrs.is_empty());
+
+        // defaults to the crate itself
+        let result = WasmExportAttrs::default();
+        assert_eq!(result.crate_path(), parse_quote!(::wasm_bindgen_utils));
+
+        let cases = [
+            (
+                r#"crate = "a", crate = "b""#,
+                "duplicate `crate` attribute",
+            ),
+            (
+                r#"crate = "not a path""#,
+                "expected a path, e.g. `crate = \"path::to::wasm_bindgen_utils\"`",
+            ),
+            (r#"crate = some_path"#, "expected string literal"),
+            (
+                r#"crate"#,
+                "expected a value for this attribute: `crate = ...` and it must be a string literal",
+            ),
+        ];
+        for (input, expected) in cases {
+            let input = TokenStream::from_str(input).unwrap();
+            let err = syn::parse2::<WasmExportAttrs>(input).unwrap_err();
+            assert_eq!(err.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_cfg_parsing() {
+        let result: WasmExportAttrs =
+            syn::parse_quote!(cfg(all(target_family = "wasm", feature = "js")));
+        assert_eq!(
+            result.cfg,
+            Some(parse_quote!(all(target_family = "wasm", feature = "js")))
+        );
+        assert_eq!(
+            result.cfg_predicate(),
+            parse_quote!(all(target_family = "wasm", feature = "js"))
+        );
+        assert!(result.forward_attrs.is_empty());
+
+        // defaults to wasm targets
+        let result = WasmExportAttrs::default();
+        assert_eq!(result.cfg_predicate(), parse_quote!(target_family = "wasm"));
+
+        let cases = [
+            (r#"cfg(unix), cfg(windows)"#, "duplicate `cfg` attribute"),
+            (
+                r#"cfg(unix, windows)"#,
+                "expected a single configuration predicate, e.g. `cfg(target_family = \"wasm\")`",
+            ),
+            (
+                r#"cfg()"#,
+                "expected a single configuration predicate, e.g. `cfg(target_family = \"wasm\")`",
+            ),
+            (
+                r#"cfg = "unix""#,
+                "expected `(` and it must contain a configuration predicate, e.g. `cfg(target_family = \"wasm\")`",
+            ),
+        ];
+        for (input, expected) in cases {
+            let input = TokenStream::from_str(input).unwrap();
+            let err = syn::parse2::<WasmExportAttrs>(input).unwrap_err();
+            assert_eq!(err.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_instantiate_parsing() {
+        let result: WasmExportAttrs = syn::parse_quote!(
+            instantiate(T = Json, js_name = "decodeJson"),
+            instantiate(T = Vec<u8>, U = u8, js_class = "Bytes")
+        );
+        assert_eq!(result.instantiate.len(), 2);
+        assert_eq!(
+            result.instantiate[0].type_args,
+            vec![(parse_quote!(T), parse_quote!(Json))]
+        );
+        assert_eq!(result.instantiate[0].js_name.as_deref(), Some("decodeJson"));
+        assert_eq!(result.instantiate[0].js_class, None);
+        assert_eq!(
+            result.instantiate[1].type_args,
+            vec![
+                (parse_quote!(T), parse_quote!(Vec<u8>)),
+                (parse_quote!(U), parse_quote!(u8))
+            ]
+        );
+        assert_eq!(result.instantiate[1].js_name, None);
+        assert_eq!(result.instantiate[1].js_class.as_deref(), Some("Bytes"));
+        assert!(result.forward_attrs.is_empty());
+
+        let cases = [
+            (
+                r#"instantiate"#,
+                "expected attribute arguments in parentheses: `instantiate(...)` and it must contain the type arguments, e.g. `instantiate(T = SomeType, js_name = \"...\")`",
+            ),
+            (
+                r#"instantiate(js_name = "a")"#,
+                "expected at least one type argument, e.g. `T = SomeType`",
+            ),
+            (
+                r#"instantiate(T = A, T = B, js_name = "a")"#,
+                "duplicate `T` type argument",
+            ),
+            (
+                r#"instantiate(T = A, js_name = "a", js_name = "b")"#,
+                "duplicate `js_name` key",
+            ),
+            (
+                r#"instantiate(T = A, js_class = "A", js_class = "B")"#,
+                "duplicate `js_class` key",
+            ),
+        ];
+        for (input, expected) in cases {
+            let input = TokenStream::from_str(input).unwrap();
+            let err = syn::parse2::<WasmExportAttrs>(input).unwrap_err();
+            assert_eq!(err.to_string(), expected);
+        }
+    }
+
     #[test]
     fn test_return_description_with_mixed_attrs() {
         // Test return_description mixed with other attributes
@@ -1,7 +1,16 @@
-use quote::quote;
 use std::ops::Deref;
+use quote::{quote, ToTokens};
+use super::{
+    docs::RustDocs,
+    error::Errors,
+    generics::ExportInstance,
+    attrs::{AttrKeys, DeprecatedAttr, ReturnKind, WasmExportAttrs},
+};
 use proc_macro2::{Span, TokenStream};
-use syn::{punctuated::Punctuated, token::Comma, Block, FnArg, Ident, ImplItemFn, ItemFn, Meta, Type};
+use syn::{
+    punctuated::Punctuated, token::Comma, Block, FnArg, Ident, ImplItemFn, ItemFn, Meta, PatType,
+    ReturnType, Signature, Type,
+};
 
 /// Enum to specify whether a function has a self receiver parameter
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -12,6 +21,30 @@ pub enum SelfReceiver {
     Static,
 }
 
+/// List of the keys that are accepted by wasm_export attribute of a parameter
+const PARAM_ATTR_KEYS: [&str; 9] = [
+    AttrKeys::PARAM_DESCRIPTION,
+    AttrKeys::UNCHECKED_PARAM_TYPE,
+    AttrKeys::JS_NAME,
+    AttrKeys::JS_TYPE,
+    AttrKeys::OPTIONAL,
+    AttrKeys::DEFAULT,
+    AttrKeys::VARIADIC,
+    AttrKeys::FROM_JS,
+    AttrKeys::ABORT_SIGNAL,
+];
+
+/// The param of an async function that its calls are raced against, i.e. the param that
+/// is marked with `abort_signal` attribute or is of `AbortSignal` or `Option<AbortSignal>` type
+pub struct AbortSignalParam {
+    pub ident: Ident,
+    /// The JS name of the param, used in the error of an invalid signal
+    pub js_name: String,
+    pub is_option: bool,
+    /// The `abort_signal` attribute of the param, if it is marked rather than detected by its type
+    pub marker: Option<Meta>,
+}
+
 /// Type alias for the complex return type of process_function_parameters
 type ProcessFunctionParametersResult = syn::Result<(
     SelfReceiver,
@@ -27,11 +60,49 @@ pub enum FunctionType<'a> {
     Standalone(&'a ItemFn),
 }
 
+impl FunctionType<'_> {
+    /// Returns the signature of the function
+    pub fn sig(&self) -> &Signature {
+        match self {
+            FunctionType::Method(method) => &method.sig,
+            FunctionType::Standalone(function) => &function.sig,
+        }
+    }
+}
+
 /// Configuration for building a wasm export function
 pub struct WasmExportFunctionBuilderConfig {
     pub forward_attrs: Vec<Meta>,
     pub return_type: Type,
+    pub return_kind: ReturnKind,
     pub preserve_js_class: Option<Span>,
+    pub error_context: Option<Span>,
+    pub cancellable: Option<Span>,
+    pub timeout_ms: Option<(u32, Span)>,
+    pub deprecated: Option<DeprecatedAttr>,
+    pub map_ok: Option<syn::Path>,
+    pub instance: Option<ExportInstance>,
+    pub trait_path: Option<syn::Path>,
+    pub krate: syn::Path,
+}
+
+impl Default for WasmExportFunctionBuilderConfig {
+    fn default() -> Self {
+        Self {
+            forward_attrs: vec![],
+            return_type: syn::parse_quote!(()),
+            return_kind: ReturnKind::default(),
+            preserve_js_class: None,
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
+            map_ok: None,
+            instance: None,
+            trait_path: None,
+            krate: WasmExportAttrs::default_crate_path(),
+        }
+    }
 }
 
 /// Provides functionalities to build methods/functions that are going to be exposed to wasm bindgen output
@@ -47,46 +118,64 @@ impl WasmExportFunctionBuilder {
     ) -> syn::Result<ImplItemFn> {
         let WasmExportFunctionBuilderConfig {
             forward_attrs,
-            return_type,
             preserve_js_class,
-        } = config;
+            deprecated,
+            map_ok,
+            krate,
+            ..
+        } = &config;
 
         // create exported method from original
         let mut export_method = method.clone();
 
         // set exported method name, it is appended with __wasm_export
-        export_method.sig.ident = Self::populate_name(&method.sig.ident);
+        export_method.sig.ident = Self::export_ident(&method.sig.ident, &config);
+
+        // extract doc comments from original method and convert
+        // its rustdoc sections to their TSDoc counterparts
+        let docs = RustDocs::parse(&Self::extract_doc_comments(&method.attrs));
 
         // process parameters to handle wasm_export attributes
-        let (_, processed_params, _) = Self::process_function_parameters(&method.sig.inputs)?;
+        let (_, processed_params, _) =
+            Self::process_function_parameters(&method.sig.inputs, &docs, krate)?;
         export_method.sig.inputs = processed_params;
 
-        // extract doc comments from original method
-        let doc_comments = Self::extract_doc_comments(&method.attrs);
+        // append the trailing optional signal param if cancellable attr was detected
+        Self::validate_cancellation(&method.sig, &config)?;
+        if config.cancellable.is_some() {
+            export_method
+                .sig
+                .inputs
+                .push(Self::build_signal_param(krate));
+        }
 
         // forward attributes for exported method + allow none snake_case + doc comments
         export_method.attrs = Vec::new();
-        export_method.attrs.extend(doc_comments);
+        export_method.attrs.extend(docs.attrs);
+        export_method.attrs.extend(Self::build_deprecated_doc(
+            deprecated.as_ref(),
+            !export_method.attrs.is_empty(),
+        ));
         export_method
             .attrs
             .push(syn::parse_quote!(#[allow(non_snake_case)]));
+        let forward_attrs = Self::with_variadic_attr(forward_attrs, &method.sig.inputs);
         if !forward_attrs.is_empty() {
             export_method.attrs.push(syn::parse_quote!(
                 #[wasm_bindgen(#(#forward_attrs),*)]
             ));
         }
 
-        // set exported method return type as JsValue if
-        // preserve_js_class is true else set it to WasmEncodedResult
-        if preserve_js_class.is_some() {
-            export_method.sig.output = syn::parse_quote!(-> JsValue);
+        // set exported method return type as JsValue if preserve_js_class
+        // or map_ok is set else set it to corresponding wasm encoded result type
+        if preserve_js_class.is_some() || map_ok.is_some() {
+            export_method.sig.output = syn::parse_quote!(-> #krate::prelude::JsValue);
         } else {
-            export_method.sig.output = syn::parse_quote!(-> WasmEncodedResult<#return_type>);
+            export_method.sig.output = Self::build_encoded_return_type(&config);
         }
 
         // build the method body by calling the original method
-        export_method.block =
-            Self::build_fn_body_unified(FunctionType::Method(method), preserve_js_class.is_some());
+        export_method.block = Self::build_fn_body_unified(FunctionType::Method(method), &config);
 
         Ok(export_method)
     }
@@ -101,71 +190,140 @@ impl WasmExportFunctionBuilder {
     ) -> syn::Result<ItemFn> {
         let WasmExportFunctionBuilderConfig {
             forward_attrs,
-            return_type,
             preserve_js_class,
-        } = config;
+            deprecated,
+            map_ok,
+            krate,
+            ..
+        } = &config;
 
         // create the export function from original
         let mut export_fn = func.clone();
 
         // set exported function name, it is appended with __wasm_export
-        export_fn.sig.ident = Self::populate_name(&func.sig.ident);
+        export_fn.sig.ident = Self::export_ident(&func.sig.ident, &config);
+
+        // extract doc comments from original function and convert
+        // its rustdoc sections to their TSDoc counterparts
+        let docs = RustDocs::parse(&Self::extract_doc_comments(&func.attrs));
 
         // process parameters to handle wasm_export attributes
-        let (_, processed_params, _) = Self::process_function_parameters(&func.sig.inputs)?;
+        let (_, processed_params, _) =
+            Self::process_function_parameters(&func.sig.inputs, &docs, krate)?;
         export_fn.sig.inputs = processed_params;
 
-        // extract doc comments from original function
-        let doc_comments = Self::extract_doc_comments(&func.attrs);
+        // append the trailing optional signal param if cancellable attr was detected
+        Self::validate_cancellation(&func.sig, &config)?;
+        if config.cancellable.is_some() {
+            export_fn.sig.inputs.push(Self::build_signal_param(krate));
+        }
 
         // forward attributes for exported function + allow none snake_case + doc comments
         export_fn.attrs = Vec::new();
-        export_fn.attrs.extend(doc_comments);
+        export_fn.attrs.extend(docs.attrs);
+        export_fn.attrs.extend(Self::build_deprecated_doc(
+            deprecated.as_ref(),
+            !export_fn.attrs.is_empty(),
+        ));
         export_fn
             .attrs
             .push(syn::parse_quote!(#[allow(non_snake_case)]));
-        if !forward_attrs.is_empty() {
-            export_fn.attrs.push(syn::parse_quote!(
-                #[wasm_bindgen(#(#forward_attrs),*)]
-            ));
-        } else {
-            // Add wasm_bindgen even if no specific attrs were forwarded
-            export_fn.attrs.push(syn::parse_quote!(#[wasm_bindgen]));
-        }
+        // wasm_bindgen is invoked through the crate path, so it is also told to refer to
+        // its own crates through it in its generated code
+        let forward_attrs = Self::with_variadic_attr(forward_attrs, &func.sig.inputs);
+        export_fn.attrs.push(syn::parse_quote!(
+            #[#krate::prelude::wasm_bindgen(
+                wasm_bindgen = #krate::prelude::wasm_bindgen,
+                wasm_bindgen_futures = #krate::prelude::wasm_bindgen_futures
+                #(, #forward_attrs)*
+            )]
+        ));
 
-        // set exported function return type as JsValue if
-        // preserve_js_class is true else set it to WasmEncodedResult
-        if preserve_js_class.is_some() {
-            export_fn.sig.output = syn::parse_quote!(-> JsValue);
+        // set exported function return type as JsValue if preserve_js_class
+        // or map_ok is set else set it to corresponding wasm encoded result type
+        if preserve_js_class.is_some() || map_ok.is_some() {
+            export_fn.sig.output = syn::parse_quote!(-> #krate::prelude::JsValue);
         } else {
-            export_fn.sig.output = syn::parse_quote!(-> WasmEncodedResult<#return_type>);
+            export_fn.sig.output = Self::build_encoded_return_type(&config);
         }
 
         // build the function body by calling the original function
         export_fn.block = Box::new(Self::build_fn_body_unified(
             FunctionType::Standalone(func),
-            preserve_js_class.is_some(),
+            &config,
         ));
 
         Ok(export_fn)
     }
 
+    /// Appends `variadic` attr to the given forwarding attributes if the last param of the
+    /// given params is marked as variadic, so its items are accepted as JS rest params
+    pub fn with_variadic_attr(
+        forward_attrs: &[Meta],
+        inputs: &Punctuated<FnArg, Comma>,
+    ) -> Vec<Meta> {
+        let mut forward_attrs = forward_attrs.to_vec();
+        if Self::find_variadic_param(inputs).is_some() {
+            forward_attrs.push(syn::parse_quote!(variadic));
+        }
+        forward_attrs
+    }
+
+    /// Builds the wasm encoded return type of the exporting function/method based on the
+    /// kind of the original returning type, e.g. `WasmEncodedResult<T>` for `Result<T, E>`
+    pub fn build_encoded_return_type(config: &WasmExportFunctionBuilderConfig) -> ReturnType {
+        let return_type = &config.return_type;
+        let encoded_type = Self::encoded_type_path(config);
+        match config.return_kind {
+            // the items are encoded as they are yielded, so the iterators are not generic
+            ReturnKind::Stream | ReturnKind::Iterator => syn::parse_quote!(-> #encoded_type),
+            _ => syn::parse_quote!(-> #encoded_type<#return_type>),
+        }
+    }
+
+    /// Returns the path of the type that the returning type of the exporting function/method
+    /// is exported as, e.g. `WasmEncodedResult` for `Result<T, E>` or `JsAsyncIterator` for
+    /// `impl Stream<Item = Result<T, E>>`
+    pub fn encoded_type_path(config: &WasmExportFunctionBuilderConfig) -> TokenStream {
+        let krate = &config.krate;
+        let encoded_type = Ident::new(config.return_kind.encoded_type_name(), Span::call_site());
+        match config.return_kind {
+            ReturnKind::Stream => quote!(#krate::stream::#encoded_type),
+            ReturnKind::Iterator => quote!(#krate::iter::#encoded_type),
+            _ => quote!(#krate::result::#encoded_type),
+        }
+    }
+
     /// Creates a function call expression (export function/method body) based on the given context (method or standalone)
-    pub fn build_fn_body_unified(function_type: FunctionType, preserve_js_class: bool) -> Block {
+    pub fn build_fn_body_unified(
+        function_type: FunctionType,
+        config: &WasmExportFunctionBuilderConfig,
+    ) -> Block {
+        // concrete instance of the generic function being exported, if any, its type
+        // arguments are specified as turbofish of the call
+        let krate = &config.krate;
+        let instance = config.instance.clone().unwrap_or_default();
+        let turbofish = instance.turbofish();
+
         // build the base call_expr based on the function type
-        let (call_expr, is_async) = match function_type {
+        let (call_expr, is_async) = match &function_type {
             FunctionType::Method(method) => {
                 let fn_name = &method.sig.ident;
                 let (self_receiver, args) = Self::collect_function_arguments(&method.sig.inputs);
 
+                let self_path = instance.self_path(config.trait_path.as_ref());
                 let call_expr = match self_receiver {
+                    SelfReceiver::Instance if config.trait_path.is_some() => {
+                        // trait method call: <Self as Trait>::method_name(self, ...)
+                        quote! { #self_path::#fn_name #turbofish(self, #(#args),*) }
+                    }
                     SelfReceiver::Instance => {
                         // instance method call: self.method_name(...)
-                        quote! { self.#fn_name(#(#args),*) }
+                        quote! { self.#fn_name #turbofish(#(#args),*) }
                     }
                     SelfReceiver::Static => {
                         // static method call: Self::method_name(...)
-                        quote! { Self::#fn_name(#(#args),*) }
+                        quote! { #self_path::#fn_name #turbofish(#(#args),*) }
                     }
                 };
 
@@ -178,64 +336,473 @@ impl WasmExportFunctionBuilder {
 
                 // return base call expression and asyncness
                 (
-                    quote! { #fn_name(#(#args),*) },
+                    quote! { #fn_name #turbofish(#(#args),*) },
                     function.sig.asyncness.is_some(),
                 )
             }
         };
 
-        // append .await if the function is async, and .into() in
-        // all cases to convert rust Result to WasmEncodedResult
-        let call_expr = if is_async {
-            quote!( #call_expr.await.into() )
+        // race the call against its abort signal and timeout if it is cancellable and has
+        // any of them, the signal of an abort signal param is taken before the call since
+        // the param is moved into the call, while the signal param of cancellable attr is
+        // only used by the exporting function, the signal of the param is taken the same
+        // way that the params are converted, so an invalid signal is returned as an error
+        let is_cancellable = Self::is_cancellable(function_type.sig(), config.return_kind);
+        let abort_signal = Self::find_abort_signal_param(&function_type.sig().inputs)
+            .ok()
+            .flatten()
+            .filter(|_| is_cancellable)
+            .map(
+                |AbortSignalParam {
+                     ident,
+                     js_name,
+                     is_option,
+                     ..
+                 }| {
+                    let signal = if is_option {
+                        quote!(#ident.as_ref())
+                    } else {
+                        quote!(::core::option::Option::Some(&#ident))
+                    };
+                    (
+                        Ident::new("__wasm_export_abort_signal", Span::call_site()),
+                        quote!(#krate::abort::abort_signal_of(#js_name, #signal)),
+                    )
+                },
+            );
+        let signal = if abort_signal.is_some() {
+            Some(quote!(__wasm_export_abort_signal))
         } else {
-            quote!( #call_expr.into() )
+            (config.cancellable.is_some() && is_cancellable).then(|| quote!(__wasm_export_signal))
+        };
+        let timeout_ms = config
+            .timeout_ms
+            .filter(|_| is_cancellable)
+            .map(|(timeout_ms, _)| timeout_ms);
+
+        // append .await if the function is async
+        let call_expr = match (is_async, signal, timeout_ms) {
+            (false, _, _) => call_expr,
+            (true, None, None) => quote!( #call_expr.await ),
+            (true, signal, timeout_ms) => {
+                let signal = signal.unwrap_or_else(|| quote!(::core::option::Option::None));
+                let timeout_ms = match timeout_ms {
+                    Some(timeout_ms) => quote!(::core::option::Option::Some(#timeout_ms)),
+                    None => quote!(::core::option::Option::None),
+                };
+                quote!( #krate::abort::abortable(#call_expr, #signal, #timeout_ms).await )
+            }
         };
 
-        // manually build a js obj that resembles the WasmEncodedResult to preserve
-        // the class if preserve_js_class attr was detected and return it as JsValue
-        // otherwise return the call expression unchanged
-        if preserve_js_class {
-            syn::parse_quote!({
-                // bring necessary items in scope
-                use js_sys::{Reflect, Object};
+        // box the returned stream or iterator, so its items can be mapped and encoded lazily
+        let call_expr = match config.return_kind {
+            ReturnKind::Stream => quote!( #krate::stream::ExportStream::new(#call_expr) ),
+            ReturnKind::Iterator => quote!( #krate::iter::ExportIter::new(#call_expr) ),
+            _ => call_expr,
+        };
 
-                // create empty js obj
-                let obj = Object::new();
+        // map the success values of the call if map_ok attr was detected
+        let call_expr = if let Some(map_ok) = &config.map_ok {
+            quote!( #call_expr.map_ok(#map_ok) )
+        } else {
+            call_expr
+        };
 
-                // call the expression and proceed based on its result
-                //
-                // populate "value" field with class instance and "error" field with undefined if
-                // result is Ok and vice versa if result is Err, this js obj will resemble the
-                // WasmEncodedResult (that normally is serialized through serde_wasm_bindgen which
-                // results in plain js objects for nested types) type in js/ts with preserving the
-                // class instance for value field
-                //
-                // "Reflect::set" can only fail if the obj is sealed or frozen which is not the case
-                // here, so it is safe to use unwrap, "Reflect::set" is similar to "obj[key] = value"
-                // in js, for more info read MDN docs for Reflect
-                let result = #call_expr;
-                match result {
-                    Ok(value) => {
-                        Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
-                        Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED).unwrap();
+        // track the call for the registered export hooks and report the errors of the result
+        // to them, but not its warnings, the call site context is also attached to the errors
+        // and warnings beforehand if error_context attr was detected
+        let js_name = Self::export_js_name(function_type.sig(), &config.forward_attrs);
+
+        // the traits of the called methods are brought in scope anonymously, so the generated
+        // code does not depend on the imports of the user
+        let traits = if config.map_ok.is_some() {
+            quote!({EncodeErrors as _, MapOk as _})
+        } else {
+            quote!(EncodeErrors as _)
+        };
+        let mut track_call = quote! {
+            use #krate::result::#traits;
+            let __wasm_export_call = #krate::hooks::ExportCall::start(#js_name);
+        };
+
+        // log the deprecation warning the first time the function is called if it is
+        // deprecated with warn key
+        if let Some(deprecated) = config.deprecated.as_ref().filter(|v| v.warn.is_some()) {
+            let msg = format!("`{}` is deprecated. {}", js_name, deprecated.message());
+            let msg = msg.trim_end();
+            track_call = quote! {
+                static __WASM_EXPORT_DEPRECATED: ::std::sync::Once = ::std::sync::Once::new();
+                __WASM_EXPORT_DEPRECATED.call_once(|| #krate::deprecation::warn_deprecated(#msg));
+                #track_call
+            };
+        }
+
+        // substitute the default values of the omitted params
+        let param_defaults = Self::build_param_defaults(&function_type.sig().inputs);
+        if !param_defaults.is_empty() {
+            track_call = quote! {
+                #track_call
+                #(#param_defaults)*
+            };
+        }
+
+        // the errors of a stream or iterator are encoded as its items are yielded after the
+        // function has returned, so the call tracker is moved into the closure and the call
+        // is tracked until the stream or iterator is dropped
+        let capture = matches!(
+            config.return_kind,
+            ReturnKind::Stream | ReturnKind::Iterator
+        )
+        .then(|| quote!(move));
+        let call_expr = if config.error_context.is_some() {
+            let context = Self::build_error_context(&function_type, &js_name, config);
+            quote!( #call_expr.encode_errors(
+                #capture |error| {
+                    error.context = ::core::option::Option::Some(#context);
+                    __wasm_export_call.on_error(error);
+                },
+                |warning| warning.context = ::core::option::Option::Some(#context)
+            ) )
+        } else {
+            quote!( #call_expr.encode_errors(#capture |error| __wasm_export_call.on_error(error), |_| {}) )
+        };
+
+        // convert the params that need to be converted before the call, i.e. `from_js` params,
+        // the items of the variadic param and the abort signal param, if any of them fails the
+        // original function is not called and the conversion error is returned instead
+        let mut conversions = Self::build_param_conversions(&function_type.sig().inputs, krate);
+        conversions.extend(abort_signal);
+        let call_expr = if conversions.is_empty() {
+            call_expr
+        } else {
+            let (idents, exprs): (Vec<_>, Vec<_>) = conversions.into_iter().unzip();
+            track_call = quote! {
+                #track_call
+                #(let #idents = #exprs;)*
+            };
+            let (error_pat, set_context) = if config.error_context.is_some() {
+                let context = Self::build_error_context(&function_type, &js_name, config);
+                (
+                    quote!(mut error),
+                    quote!(error.context = ::core::option::Option::Some(#context);),
+                )
+            } else {
+                (quote!(error), quote!())
+            };
+            let (scrutinee, ok_pat, err_pat) = if let [ident] = idents.as_slice() {
+                (
+                    quote!(#ident),
+                    quote!(::core::result::Result::Ok(#ident)),
+                    quote!(::core::result::Result::Err(#error_pat)),
+                )
+            } else {
+                // the error of any of the conversions in the order of the params
+                let err_pats = (0..idents.len()).map(|i| {
+                    let elems = (0..idents.len()).map(|j| {
+                        if i == j {
+                            quote!(::core::result::Result::Err(#error_pat))
+                        } else {
+                            quote!(_)
+                        }
+                    });
+                    quote!((#(#elems),*))
+                });
+                (
+                    quote!((#(#idents),*)),
+                    quote!((#(::core::result::Result::Ok(#idents)),*)),
+                    quote!(#(#err_pats)|*),
+                )
+            };
+            quote! {
+                (match #scrutinee {
+                    #ok_pat => #call_expr,
+                    #err_pat => {
+                        #set_context
+                        __wasm_export_call.on_error(&error);
+                        #krate::result::FromEncodedError::from_encoded_error(error)
+                    }
+                })
+            }
+        };
+
+        // manually build a js obj that resembles the wasm encoded result to preserve
+        // the class if preserve_js_class attr was detected and return it as JsValue
+        // otherwise return the call expression converted with .into() to the wasm
+        // encoded result, e.g. rust Result to WasmEncodedResult
+        if config.preserve_js_class.is_none() {
+            // the mapped type is not known, so the wasm encoded result is returned as JsValue
+            if config.map_ok.is_some() {
+                let encoded_type = Self::encoded_type_path(config);
+                return syn::parse_quote!({
+                    #track_call
+                    #encoded_type::from(#call_expr).into()
+                });
+            }
+            // parses the call expression token stream to syn::Block
+            return syn::parse_quote!({
+                #track_call
+                #call_expr.into()
+            });
+        }
+        // populates "value" field of the given js obj with class instance and "error" field with
+        // undefined if result is Ok and vice versa if result is Err
+        //
+        // "Reflect::set" can only fail if the obj is sealed or frozen which is not the case
+        // here, so it is safe to use unwrap, "Reflect::set" is similar to "obj[key] = value"
+        // in js, for more info read MDN docs for Reflect
+        let populate_obj = |obj: TokenStream, result: TokenStream| {
+            quote! {
+                match #result {
+                    ::core::result::Result::Ok(value) => {
+                        Reflect::set(&#obj, &JsValue::from_str("value"), &value.into()).unwrap();
+                        Reflect::set(&#obj, &JsValue::from_str("error"), &JsValue::UNDEFINED).unwrap();
                     }
-                    Err(error) => {
+                    ::core::result::Result::Err(error) => {
                         let wasm_error: WasmEncodedError = error.into();
-                        Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED).unwrap();
-                        Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into()).unwrap();
+                        Reflect::set(&#obj, &JsValue::from_str("value"), &JsValue::UNDEFINED).unwrap();
+                        Reflect::set(&#obj, &JsValue::from_str("error"), &wasm_error.into()).unwrap();
                     }
                 };
+            }
+        };
+        match config.return_kind {
+            ReturnKind::Result => {
+                let populate_obj = populate_obj(quote!(obj), quote!(result));
+                syn::parse_quote!({
+                    // bring necessary items in scope
+                    use #krate::prelude::JsValue;
+                    use #krate::prelude::js_sys::{Reflect, Object};
+                    use #krate::result::WasmEncodedError;
+
+                    // start tracking the call and create empty js obj
+                    #track_call
+                    let obj = Object::new();
+
+                    // call the expression and populate the js obj based on its result, this js
+                    // obj will resemble the WasmEncodedResult (that normally is serialized through
+                    // serde_wasm_bindgen which results in plain js objects for nested types) type
+                    // in js/ts with preserving the class instance for value field
+                    let result = #call_expr.into();
+                    #populate_obj
+
+                    // return as JsValue
+                    obj.into()
+                })
+            }
+            ReturnKind::Diagnosed => {
+                let populate_obj = populate_obj(quote!(obj), quote!(result));
+                syn::parse_quote!({
+                    // bring necessary items in scope
+                    use #krate::prelude::JsValue;
+                    use #krate::prelude::js_sys::{Reflect, Object};
+                    use #krate::result::WasmEncodedError;
+
+                    // start tracking the call and create empty js obj
+                    #track_call
+                    let obj = Object::new();
+
+                    // same as Result kind, but the "warnings" field is also populated
+                    // with the warnings of the Diagnosed result, this js obj will
+                    // resemble the WasmEncodedDiagnosedResult type in js/ts
+                    let #krate::result::Diagnosed { result, warnings } = #call_expr;
+                    #populate_obj
+                    let warnings: ::std::vec::Vec<WasmEncodedError> = warnings
+                        .into_iter()
+                        .map(::core::convert::Into::into)
+                        .collect();
+                    Reflect::set(&obj, &JsValue::from_str("warnings"), &warnings.into()).unwrap();
+
+                    // return as JsValue
+                    obj.into()
+                })
+            }
+            ReturnKind::Batch => {
+                let populate_item = populate_obj(quote!(item), quote!(result));
+                syn::parse_quote!({
+                    // bring necessary items in scope
+                    use #krate::prelude::JsValue;
+                    use #krate::prelude::js_sys::{Reflect, Object, Array};
+                    use #krate::result::WasmEncodedError;
+
+                    // start tracking the call and create empty js obj and items array
+                    #track_call
+                    let obj = Object::new();
+                    let items = Array::new();
+
+                    // populate a js obj for each of the results same as Result kind and
+                    // push it to the items array while counting successes and errors,
+                    // this js obj will resemble the WasmEncodedResults type in js/ts
+                    let batch = #call_expr;
+                    let mut successes: u32 = 0;
+                    let mut failures: u32 = 0;
+                    for result in batch.items {
+                        if result.is_ok() {
+                            successes += 1;
+                        } else {
+                            failures += 1;
+                        }
+                        let item = Object::new();
+                        #populate_item
+                        items.push(&item);
+                    }
+                    Reflect::set(&obj, &JsValue::from_str("items"), &items).unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("successCount"), &successes.into()).unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("errorCount"), &failures.into()).unwrap();
 
-                // return as JsValue
-                obj.into()
-            })
-        } else {
-            // parses the call expression token stream to syn::Block
-            syn::parse_quote!({
-                #call_expr
-            })
+                    // the error of the whole call, if it failed before producing any items
+                    if let ::core::option::Option::Some(error) = batch.error {
+                        Reflect::set(&obj, &JsValue::from_str("error"), &error.into()).unwrap();
+                    }
+
+                    // return as JsValue
+                    obj.into()
+                })
+            }
+            ReturnKind::Stream => syn::parse_quote!({
+                // start tracking the call and return the js async iterator of the stream
+                // whose items resemble the WasmEncodedResult type in js/ts same as Result
+                // kind, with preserving the class instances for their value field
+                #track_call
+                #krate::stream::JsAsyncIterator::from_stream_preserved(#call_expr).into()
+            }),
+            ReturnKind::Iterator => syn::parse_quote!({
+                // the items of js iterator are always converted into js values as they are,
+                // so the class instances are already preserved
+                #track_call
+                #krate::iter::JsIterator::from(#call_expr).into()
+            }),
+        }
+    }
+
+    /// Builds the TSDoc `@deprecated` tag doc comments for the given deprecated attribute,
+    /// separated from the preceding doc comments by an empty line if there are any
+    pub fn build_deprecated_doc(
+        deprecated: Option<&DeprecatedAttr>,
+        has_docs: bool,
+    ) -> Vec<syn::Attribute> {
+        let Some(deprecated) = deprecated else {
+            return vec![];
+        };
+        let mut doc_attrs = vec![];
+        if has_docs {
+            doc_attrs.push(syn::parse_quote!(#[doc = ""]));
         }
+        let tag = format!(" @deprecated {}", deprecated.message());
+        let tag = tag.trim_end();
+        doc_attrs.push(syn::parse_quote!(#[doc = #tag]));
+        doc_attrs
+    }
+
+    /// Builds the expression that creates the boxed error context of the given function,
+    /// which consists of its rust path, its js name and a redacted summary of its arguments
+    pub fn build_error_context(
+        function_type: &FunctionType,
+        js_name: &str,
+        config: &WasmExportFunctionBuilderConfig,
+    ) -> TokenStream {
+        let krate = &config.krate;
+        let instance = config.instance.clone().unwrap_or_default();
+        let turbofish = instance.turbofish();
+        let rust_path = match function_type {
+            FunctionType::Method(method) => {
+                let name = &method.sig.ident;
+                let self_path = instance.self_path(config.trait_path.as_ref());
+                quote!(::std::any::type_name_of_val(&#self_path::#name #turbofish))
+            }
+            FunctionType::Standalone(function) => {
+                let name = &function.sig.ident;
+                quote!(::std::any::type_name_of_val(&#name #turbofish))
+            }
+        };
+        let args = Self::build_args_summary(&function_type.sig().inputs);
+        let context = quote!(#krate::result::WasmEncodedErrorContext::new(#rust_path, #js_name, &[#(#args),*]));
+        quote!(::std::boxed::Box::new(#context))
+    }
+
+    /// Returns the name of the exporting function in js, that is the value of its `js_name`
+    /// attr if specified, otherwise the populated name of the exporting function
+    pub fn export_js_name(sig: &Signature, forward_attrs: &[Meta]) -> String {
+        Self::find_js_name(forward_attrs)
+            .unwrap_or_else(|| Self::populate_name(&sig.ident).to_string())
+    }
+
+    /// Creates the name of the exporting function, that is the populated name of the original
+    /// function, which for the concrete instances of a generic function is also appended by
+    /// their js name, so each instance gets its own exporting function
+    pub fn export_ident(org_fn_ident: &Ident, config: &WasmExportFunctionBuilderConfig) -> Ident {
+        let ident = Self::populate_name(org_fn_ident);
+        let js_name = config
+            .instance
+            .as_ref()
+            .filter(|instance| !instance.type_args.is_empty())
+            .and_then(|_| Self::find_js_name(&config.forward_attrs));
+        let Some(js_name) = js_name else {
+            return ident;
+        };
+        let suffix = js_name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>();
+        Ident::new(&format!("{}_{}", ident, suffix), org_fn_ident.span())
+    }
+
+    /// Builds a redacted summary of the given function parameters, that is, each parameter
+    /// is summarized by its js name (or its pattern if not specified) and its rust type
+    pub fn build_args_summary(inputs: &Punctuated<FnArg, Comma>) -> Vec<String> {
+        inputs
+            .iter()
+            .filter_map(|input| match input {
+                FnArg::Receiver(_) => None,
+                FnArg::Typed(pat_type) => {
+                    let js_name = pat_type
+                        .attrs
+                        .iter()
+                        .filter(|attr| attr.path().is_ident("wasm_export"))
+                        .filter_map(|attr| Self::process_parameter_wasm_export_attr(attr).ok())
+                        .find_map(|metas| Self::find_js_name(&metas));
+                    let name = js_name.unwrap_or_else(|| Self::tokens_to_string(&pat_type.pat));
+                    Some(format!(
+                        "{}: {}",
+                        name,
+                        Self::tokens_to_string(&pat_type.ty)
+                    ))
+                }
+            })
+            .collect()
+    }
+
+    /// Finds the value of `js_name` attr from the given list of attributes
+    pub fn find_js_name(metas: &[Meta]) -> Option<String> {
+        metas.iter().find_map(|meta| match meta {
+            Meta::NameValue(syn::MetaNameValue {
+                path,
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(str),
+                        ..
+                    }),
+                ..
+            }) if path.is_ident("js_name") => Some(str.value()),
+            _ => None,
+        })
+    }
+
+    /// Converts the given tokens to a string that resembles how it is written in rust code
+    fn tokens_to_string(tokens: impl quote::ToTokens) -> String {
+        tokens
+            .to_token_stream()
+            .to_string()
+            .replace(" :: ", "::")
+            .replace(" <", "<")
+            .replace("< ", "<")
+            .replace(" >", ">")
+            .replace(" ,", ",")
+            .replace("& ", "&")
+            .replace("( ", "(")
+            .replace(" )", ")")
+            .replace("[ ", "[")
+            .replace(" ]", "]")
+            .replace(" ;", ";")
     }
 
     /// Collects function arguments and determines if the function has a self receiver
@@ -260,16 +827,21 @@ impl WasmExportFunctionBuilder {
         (self_receiver, args)
     }
 
-    /// Processes function parameters by extracting wasm_export attributes and converting them to wasm_bindgen
+    /// Processes function parameters by extracting wasm_export attributes and converting them to wasm_bindgen,
+    /// params without `param_description` attribute fall back to their description in the given rustdoc
     /// Returns: (self_receiver, processed_inputs_for_wrapper, cleaned_inputs_for_original)
     pub fn process_function_parameters(
         inputs: &Punctuated<FnArg, Comma>,
+        docs: &RustDocs,
+        krate: &syn::Path,
     ) -> ProcessFunctionParametersResult {
         let mut self_receiver = SelfReceiver::Static;
         let mut processed_inputs = Punctuated::new();
         let mut cleaned_inputs = Punctuated::new();
+        let mut params_attrs = Vec::new();
+        let mut errors = Errors::default();
 
-        for input in inputs {
+        for (position, input) in inputs.iter().enumerate() {
             match input {
                 FnArg::Receiver(receiver) => {
                     self_receiver = SelfReceiver::Instance;
@@ -277,7 +849,7 @@ impl WasmExportFunctionBuilder {
                     // Check if receiver has any wasm_export attributes - this should be an error
                     for attr in &receiver.attrs {
                         if attr.path().is_ident("wasm_export") {
-                            return Err(syn::Error::new_spanned(
+                            errors.push(syn::Error::new_spanned(
                                 attr,
                                 "wasm_export parameter attributes cannot be used on receiver parameters (self, &self, &mut self)"
                             ));
@@ -294,26 +866,75 @@ impl WasmExportFunctionBuilder {
                     // Process attributes on this parameter
                     let mut wasm_bindgen_attrs = Vec::new();
                     let mut other_attrs = Vec::new();
+                    let mut is_valid = true;
 
                     for attr in &pat_type.attrs {
                         if attr.path().is_ident("wasm_export") {
                             // Parse wasm_export attribute and convert to wasm_bindgen
-                            let processed_attrs = Self::process_parameter_wasm_export_attr(attr)?;
-                            wasm_bindgen_attrs.extend(processed_attrs);
+                            let processed_attrs = Self::process_parameter_wasm_export_attr(attr);
+                            match errors.collect(processed_attrs) {
+                                Some(processed_attrs) => wasm_bindgen_attrs.extend(processed_attrs),
+                                None => is_valid = false,
+                            }
                             // Don't include wasm_export attrs in cleaned version
                         } else {
                             other_attrs.push(attr.clone());
                         }
                     }
 
-                    // For wrapper function: combine processed wasm_bindgen attrs with other attrs
-                    new_pat_type.attrs.clone_from(&other_attrs);
-                    if !wasm_bindgen_attrs.is_empty() {
-                        new_pat_type
-                            .attrs
-                            .push(syn::parse_quote!(#[wasm_bindgen(#(#wasm_bindgen_attrs),*)]));
+                    // Skip the rest of the checks for this param if its attributes are invalid,
+                    // the errors of the rest of the params are still collected
+                    if !is_valid {
+                        continue;
+                    }
+
+                    // Fall back to the param description from rustdoc `# Arguments` section
+                    if let syn::Pat::Ident(pat_ident) = pat_type.pat.deref() {
+                        let has_description = wasm_bindgen_attrs
+                            .iter()
+                            .any(|meta| meta.path().is_ident(AttrKeys::PARAM_DESCRIPTION));
+                        if let Some(desc) = docs
+                            .param_description(&pat_ident.ident.to_string())
+                            .filter(|_| !has_description)
+                        {
+                            wasm_bindgen_attrs.push(syn::parse_quote!(param_description = #desc));
+                        }
                     }
 
+                    // Accept the param as its JS type if it is converted by a `from_js` function
+                    errors.collect(Self::process_from_js_parameter(
+                        &mut new_pat_type,
+                        &mut wasm_bindgen_attrs,
+                        krate,
+                    ));
+
+                    // Accept the items of the last param as JS rest params if it is variadic
+                    errors.collect(Self::process_variadic_parameter(
+                        &mut new_pat_type,
+                        &mut wasm_bindgen_attrs,
+                        position == inputs.len() - 1,
+                        krate,
+                    ));
+
+                    // Accept the param as optional if it has a default value
+                    errors.collect(Self::process_default_parameter(
+                        &mut new_pat_type,
+                        &mut wasm_bindgen_attrs,
+                    ));
+
+                    // Declare the param as the TS union of its branches if it is a JS union,
+                    // as the TS promise of its value type if it is a typed promise or as the
+                    // TS function type if it is a typed callback
+                    Self::process_union_parameter(&new_pat_type, &mut wasm_bindgen_attrs);
+
+                    // Declare the param as an abort signal in TS if it is marked as one
+                    Self::process_abort_signal_parameter(&new_pat_type, &mut wasm_bindgen_attrs);
+
+                    // For wrapper function: other attrs, processed wasm_bindgen attrs are
+                    // combined after all params are processed
+                    new_pat_type.attrs.clone_from(&other_attrs);
+                    params_attrs.push((processed_inputs.len(), wasm_bindgen_attrs));
+
                     // For original function: only other attrs (wasm_export removed)
                     cleaned_pat_type.attrs = other_attrs;
 
@@ -323,149 +944,891 @@ impl WasmExportFunctionBuilder {
             }
         }
 
+        errors.finish()?;
+
+        // Handle optional params and combine processed wasm_bindgen attrs for wrapper function
+        Self::process_optional_parameters(&processed_inputs, &mut params_attrs)?;
+        for (index, mut wasm_bindgen_attrs) in params_attrs {
+            wasm_bindgen_attrs.retain(|meta| {
+                !meta.path().is_ident(AttrKeys::VARIADIC)
+                    && !meta.path().is_ident(AttrKeys::ABORT_SIGNAL)
+            });
+            if let FnArg::Typed(pat_type) = &mut processed_inputs[index] {
+                if !wasm_bindgen_attrs.is_empty() {
+                    pat_type
+                        .attrs
+                        .push(syn::parse_quote!(#[wasm_bindgen(#(#wasm_bindgen_attrs),*)]));
+                }
+            }
+        }
+
         Ok((self_receiver, processed_inputs, cleaned_inputs))
     }
 
-    /// Processes a single wasm_export attribute on a parameter and converts it to wasm_bindgen format
-    fn process_parameter_wasm_export_attr(attr: &syn::Attribute) -> syn::Result<Vec<syn::Meta>> {
-        use syn::{punctuated::Punctuated, token::Comma, Meta};
-        use super::{error::extend_err_msg, attrs::AttrKeys};
+    /// Declares the param marked with `abort_signal` attribute as `AbortSignal | undefined` in TS,
+    /// unless its TS type is already known, i.e. it is of `AbortSignal` type or it has an
+    /// `unchecked_param_type` attribute, where `| undefined` is left to `optional` attribute
+    fn process_abort_signal_parameter(pat_type: &PatType, wasm_bindgen_attrs: &mut Vec<Meta>) {
+        let has_attr = |key: &str| {
+            wasm_bindgen_attrs
+                .iter()
+                .any(|meta| meta.path().is_ident(key))
+        };
+        if !has_attr(AttrKeys::ABORT_SIGNAL)
+            || has_attr(AttrKeys::UNCHECKED_PARAM_TYPE)
+            || Self::is_abort_signal_type(&pat_type.ty)
+        {
+            return;
+        }
+        let ts_type = if has_attr(AttrKeys::OPTIONAL) {
+            "AbortSignal"
+        } else {
+            "AbortSignal | undefined"
+        };
+        wasm_bindgen_attrs.push(syn::parse_quote!(unchecked_param_type = #ts_type));
+    }
 
-        let mut wasm_bindgen_metas = Vec::new();
-        let mut seen_param_description = false;
-        let mut seen_unchecked_param_type = false;
-        let mut seen_js_name = false;
+    /// Processes the param marked with `from_js` attribute, it is accepted as a JS value by the
+    /// exporting function that is typed in TS by its `js_type` attribute (if specified), so that
+    /// it can be converted by the given function when building the function body
+    fn process_from_js_parameter(
+        pat_type: &mut PatType,
+        wasm_bindgen_attrs: &mut Vec<Meta>,
+        krate: &syn::Path,
+    ) -> syn::Result<()> {
+        use syn::spanned::Spanned;
+
+        let from_js = wasm_bindgen_attrs
+            .iter()
+            .position(|meta| meta.path().is_ident(AttrKeys::FROM_JS))
+            .map(|pos| wasm_bindgen_attrs.remove(pos));
+        let js_type = wasm_bindgen_attrs
+            .iter()
+            .position(|meta| meta.path().is_ident(AttrKeys::JS_TYPE))
+            .map(|pos| wasm_bindgen_attrs.remove(pos));
+        let Some(from_js) = from_js else {
+            return match js_type {
+                Some(js_type) => Err(syn::Error::new_spanned(
+                    js_type,
+                    "`js_type` attribute requires `from_js` attribute",
+                )),
+                None => Ok(()),
+            };
+        };
+        if wasm_bindgen_attrs.iter().any(|meta| {
+            meta.path().is_ident(AttrKeys::DEFAULT) || meta.path().is_ident(AttrKeys::VARIADIC)
+        }) {
+            return Err(syn::Error::new_spanned(
+                from_js,
+                "`from_js` attribute cannot be used together with `default` or `variadic` attributes",
+            ));
+        }
+        if !matches!(pat_type.pat.deref(), syn::Pat::Ident(_)) {
+            return Err(syn::Error::new_spanned(
+                &pat_type.pat,
+                "`from_js` attribute requires the parameter pattern to be an identifier",
+            ));
+        }
+        if let Some(Meta::NameValue(mut name_value)) = js_type {
+            if wasm_bindgen_attrs
+                .iter()
+                .any(|meta| meta.path().is_ident(AttrKeys::UNCHECKED_PARAM_TYPE))
+            {
+                return Err(syn::Error::new_spanned(
+                    name_value,
+                    "`js_type` and `unchecked_param_type` attributes cannot be used together",
+                ));
+            }
+            let key = Ident::new(AttrKeys::UNCHECKED_PARAM_TYPE, name_value.path.span());
+            name_value.path = key.into();
+            wasm_bindgen_attrs.push(Meta::NameValue(name_value));
+        }
+        pat_type.ty = syn::parse_quote!(#krate::prelude::JsValue);
 
-        // Handle empty wasm_export attribute
-        if matches!(attr.meta, Meta::Path(_)) {
-            return Ok(wasm_bindgen_metas);
+        Ok(())
+    }
+
+    /// Processes the param marked with `variadic` attribute, it must be the last param and of
+    /// `Vec<T>` type, its items are accepted as JS values by the exporting function so that
+    /// they can be decoded one by one when building the function body and they are typed in
+    /// TS by the TS type of `T` if not specified by `unchecked_param_type` attribute, its marker
+    /// is kept so that it is not taken into account for the params omittability
+    fn process_variadic_parameter(
+        pat_type: &mut PatType,
+        wasm_bindgen_attrs: &mut Vec<Meta>,
+        is_last: bool,
+        krate: &syn::Path,
+    ) -> syn::Result<()> {
+        let Some(variadic) = wasm_bindgen_attrs
+            .iter()
+            .find(|meta| meta.path().is_ident(AttrKeys::VARIADIC))
+        else {
+            return Ok(());
+        };
+        if !is_last {
+            return Err(syn::Error::new_spanned(
+                variadic,
+                "`variadic` attribute can only be used on the last parameter",
+            ));
+        }
+        if wasm_bindgen_attrs.iter().any(|meta| {
+            meta.path().is_ident(AttrKeys::OPTIONAL) || meta.path().is_ident(AttrKeys::DEFAULT)
+        }) {
+            return Err(syn::Error::new_spanned(
+                variadic,
+                "`variadic` attribute cannot be used together with `optional` or `default` attributes",
+            ));
+        }
+        if !matches!(pat_type.pat.deref(), syn::Pat::Ident(_)) {
+            return Err(syn::Error::new_spanned(
+                &pat_type.pat,
+                "`variadic` attribute requires the parameter pattern to be an identifier",
+            ));
+        }
+        let Some(item_type) = Self::vec_item_type(&pat_type.ty) else {
+            return Err(syn::Error::new_spanned(
+                variadic,
+                "`variadic` attribute requires a `Vec<T>` parameter type",
+            ));
+        };
+        if Self::is_js_value_type(item_type) {
+            return Ok(());
+        }
+        let has_type_override = wasm_bindgen_attrs
+            .iter()
+            .any(|meta| meta.path().is_ident(AttrKeys::UNCHECKED_PARAM_TYPE));
+        if let Some(ts_type) = Self::ts_type_of(item_type).filter(|_| !has_type_override) {
+            let ts_type = if ts_type.contains(" | ") || ts_type.contains("=>") {
+                format!("({})[]", ts_type)
+            } else {
+                format!("{}[]", ts_type)
+            };
+            wasm_bindgen_attrs.push(syn::parse_quote!(unchecked_param_type = #ts_type));
         }
+        pat_type.ty = syn::parse_quote!(::std::vec::Vec<#krate::prelude::JsValue>);
 
-        // Parse the attribute contents
-        let nested_metas = attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)?;
+        Ok(())
+    }
 
-        for meta in nested_metas {
-            if let Some(ident) = meta.path().get_ident() {
-                match ident.to_string().as_str() {
-                    "param_description" => {
-                        // Check for duplicate param_description
-                        if seen_param_description {
-                            return Err(syn::Error::new_spanned(
-                                meta,
-                                "duplicate `param_description` attribute",
-                            ));
-                        }
-                        seen_param_description = true;
-
-                        // Validate that it has a string literal value
-                        if let syn::Expr::Lit(syn::ExprLit {
-                            lit: syn::Lit::Str(_),
-                            ..
-                        }) = &meta
-                            .require_name_value()
-                            .map_err(extend_err_msg(" and it must be a string literal"))?
-                            .value
-                        {
-                            // Convert param_description from wasm_export to wasm_bindgen format
-                            wasm_bindgen_metas.push(meta);
-                        } else {
-                            return Err(syn::Error::new_spanned(meta, "expected string literal"));
-                        }
-                    }
-                    AttrKeys::UNCHECKED_PARAM_TYPE => {
-                        // Check for duplicate unchecked_param_type
-                        if seen_unchecked_param_type {
-                            return Err(syn::Error::new_spanned(
-                                meta,
-                                "duplicate `unchecked_param_type` attribute",
-                            ));
-                        }
-                        seen_unchecked_param_type = true;
-
-                        // Validate that it has a string literal value
-                        if let syn::Expr::Lit(syn::ExprLit {
-                            lit: syn::Lit::Str(_),
-                            ..
-                        }) = &meta
-                            .require_name_value()
-                            .map_err(extend_err_msg(" and it must be a string literal"))?
-                            .value
-                        {
-                            // Convert unchecked_param_type from wasm_export to wasm_bindgen format
-                            wasm_bindgen_metas.push(meta);
-                        } else {
-                            return Err(syn::Error::new_spanned(meta, "expected string literal"));
-                        }
-                    }
-                    AttrKeys::JS_NAME => {
-                        // Check for duplicate js_name
-                        if seen_js_name {
-                            return Err(syn::Error::new_spanned(
-                                meta,
-                                "duplicate `js_name` attribute",
-                            ));
-                        }
-                        seen_js_name = true;
-
-                        // Validate that it has a string literal value
-                        if let syn::Expr::Lit(syn::ExprLit {
-                            lit: syn::Lit::Str(_),
-                            ..
-                        }) = &meta
-                            .require_name_value()
-                            .map_err(extend_err_msg(" and it must be a string literal"))?
-                            .value
-                        {
-                            // Convert js_name from wasm_export to wasm_bindgen format
-                            wasm_bindgen_metas.push(meta);
-                        } else {
-                            return Err(syn::Error::new_spanned(meta, "expected string literal"));
-                        }
-                    }
-                    _ => {
-                        // Unknown attributes are ignored (could be forwarded in the future)
-                    }
+    /// Finds the last param if it is marked with `variadic` attribute and returns its
+    /// ident, the type of its items and its js name (or its ident if not specified)
+    pub fn find_variadic_param(inputs: &Punctuated<FnArg, Comma>) -> Option<(Ident, Type, String)> {
+        let Some(FnArg::Typed(pat_type)) = inputs.last() else {
+            return None;
+        };
+        let syn::Pat::Ident(pat_ident) = pat_type.pat.deref() else {
+            return None;
+        };
+        let metas = Self::parameter_metas(pat_type);
+        if !metas
+            .iter()
+            .any(|meta| meta.path().is_ident(AttrKeys::VARIADIC))
+        {
+            return None;
+        }
+        let item_type = Self::vec_item_type(&pat_type.ty)?.clone();
+        let name = Self::find_js_name(&metas).unwrap_or_else(|| pat_ident.ident.to_string());
+        Some((pat_ident.ident.clone(), item_type, name))
+    }
+
+    /// Finds the abort signal param of the given params, that is, the param marked with
+    /// `abort_signal` attribute or the one of `AbortSignal` or `Option<AbortSignal>` type,
+    /// a function can only have one of them
+    pub fn find_abort_signal_param(
+        inputs: &Punctuated<FnArg, Comma>,
+    ) -> syn::Result<Option<AbortSignalParam>> {
+        let mut abort_signal: Option<AbortSignalParam> = None;
+        for input in inputs {
+            let FnArg::Typed(pat_type) = input else {
+                continue;
+            };
+            let metas = Self::parameter_metas(pat_type);
+            let marker = metas
+                .iter()
+                .find(|meta| meta.path().is_ident(AttrKeys::ABORT_SIGNAL))
+                .cloned();
+            if marker.is_none() && !Self::is_abort_signal_type(&pat_type.ty) {
+                continue;
+            }
+            if let Some(marker) = &marker {
+                if metas.iter().any(|meta| {
+                    meta.path().is_ident(AttrKeys::FROM_JS)
+                        || meta.path().is_ident(AttrKeys::DEFAULT)
+                        || meta.path().is_ident(AttrKeys::VARIADIC)
+                }) {
+                    return Err(syn::Error::new_spanned(
+                        marker,
+                        "`abort_signal` attribute cannot be used together with `from_js`, `default` or `variadic` attributes",
+                    ));
                 }
             }
+            let syn::Pat::Ident(pat_ident) = pat_type.pat.deref() else {
+                return match marker {
+                    Some(_) => Err(syn::Error::new_spanned(
+                        &pat_type.pat,
+                        "`abort_signal` attribute requires the parameter pattern to be an identifier",
+                    )),
+                    None => continue,
+                };
+            };
+            if abort_signal.is_some() {
+                return Err(syn::Error::new_spanned(
+                    pat_type,
+                    "a function can only have one abort signal parameter",
+                ));
+            }
+            abort_signal = Some(AbortSignalParam {
+                ident: pat_ident.ident.clone(),
+                js_name: Self::find_js_name(&metas).unwrap_or_else(|| pat_ident.ident.to_string()),
+                is_option: Self::is_option_type(&pat_type.ty),
+                marker,
+            });
         }
-
-        Ok(wasm_bindgen_metas)
+        Ok(abort_signal)
     }
 
-    /// Cleans wasm_export attributes from function parameters (for cleaning original functions)
-    pub fn clean_parameter_attributes(inputs: &mut Punctuated<FnArg, Comma>) {
-        for input in inputs.iter_mut() {
-            if let FnArg::Typed(pat_type) = input {
-                pat_type
-                    .attrs
-                    .retain(|attr| !attr.path().is_ident("wasm_export"));
+    /// Determines if the given type is `AbortSignal` or `Option<AbortSignal>` by the last
+    /// segment of its path, so `web_sys::AbortSignal` is detected as well
+    pub fn is_abort_signal_type(ty: &Type) -> bool {
+        let Type::Path(type_path) = ty else {
+            return false;
+        };
+        let Some(segment) = type_path.path.segments.last() else {
+            return false;
+        };
+        match &segment.arguments {
+            syn::PathArguments::None => segment.ident == "AbortSignal",
+            syn::PathArguments::AngleBracketed(args) if segment.ident == "Option" => {
+                matches!(
+                    args.args.first(),
+                    Some(syn::GenericArgument::Type(inner)) if args.args.len() == 1
+                        && Self::is_abort_signal_type(inner)
+                        && !Self::is_option_type(inner)
+                )
             }
+            _ => false,
         }
     }
 
-    /// Extracts doc comments (/// ...) from function/method attributes
-    pub fn extract_doc_comments(attrs: &[syn::Attribute]) -> Vec<syn::Attribute> {
-        attrs
-            .iter()
-            .filter(|attr| {
-                // Check if attribute is a doc comment (starts with "doc")
-                attr.path().is_ident("doc")
-            })
-            .cloned()
-            .collect()
+    /// Whether the calls of the given function can be raced against an abort signal or a
+    /// timeout, that is, if it is async and its returning type is not a stream or an iterator
+    pub fn is_cancellable(sig: &Signature, return_kind: ReturnKind) -> bool {
+        sig.asyncness.is_some() && return_kind.is_cancellable()
     }
 
-    /// Creates the function name from the original name, it is appended by __wasm_export
-    pub fn populate_name(org_fn_ident: &Ident) -> Ident {
-        Ident::new(
-            &format!("{}__wasm_export", org_fn_ident),
-            org_fn_ident.span(),
+    /// Whether the trailing `signal` param of `cancellable` attribute can be appended to
+    /// the given params, that is, if they have neither an abort signal nor a variadic param
+    pub fn can_append_signal_param(inputs: &Punctuated<FnArg, Comma>) -> bool {
+        let has_variadic = inputs.last().is_some_and(|input| match input {
+            FnArg::Typed(pat_type) => Self::parameter_metas(pat_type)
+                .iter()
+                .any(|meta| meta.path().is_ident(AttrKeys::VARIADIC)),
+            FnArg::Receiver(_) => false,
+        });
+        !has_variadic && matches!(Self::find_abort_signal_param(inputs), Ok(None))
+    }
+
+    /// Validates the abort signal param and the `cancellable` and `timeout_ms` attributes of
+    /// the given function, they can only be used for functions that are cancellable, and the
+    /// `signal` param of `cancellable` attribute cannot be appended to its params if they
+    /// already have an abort signal or a variadic param
+    pub fn validate_cancellation(
+        sig: &Signature,
+        config: &WasmExportFunctionBuilderConfig,
+    ) -> syn::Result<()> {
+        let msg = |key: &str| {
+            format!("`{key}` attribute can only be used for async functions that do not return a stream or an iterator")
+        };
+        let mut errors = Errors::default();
+        let abort_signal = errors.collect(Self::find_abort_signal_param(&sig.inputs));
+        if !Self::is_cancellable(sig, config.return_kind) {
+            if let Some(marker) = abort_signal
+                .iter()
+                .flatten()
+                .find_map(|v| v.marker.as_ref())
+            {
+                errors.push(syn::Error::new_spanned(marker, msg(AttrKeys::ABORT_SIGNAL)));
+            }
+            if let Some(span) = config.cancellable {
+                errors.push(syn::Error::new(span, msg(AttrKeys::CANCELLABLE)));
+            }
+            if let Some((_, span)) = config.timeout_ms {
+                errors.push(syn::Error::new(span, msg(AttrKeys::TIMEOUT_MS)));
+            }
+        } else if let Some(span) = config.cancellable {
+            if !Self::can_append_signal_param(&sig.inputs) {
+                errors.push(syn::Error::new(
+                    span,
+                    "`cancellable` attribute cannot be used for functions that already have an abort signal or a `variadic` parameter",
+                ));
+            }
+        }
+        errors.finish()
+    }
+
+    /// Builds the trailing optional `signal` param that `cancellable` attribute appends
+    /// to the params of the exporting function
+    pub fn build_signal_param(krate: &syn::Path) -> FnArg {
+        syn::parse_quote!(
+            #[wasm_bindgen(
+                js_name = "signal",
+                param_description = "An optional signal to abort the call with"
+            )]
+            __wasm_export_signal: ::core::option::Option<#krate::abort::AbortSignal>
         )
     }
-}
 
-#[cfg(test)]
-mod tests {
+    /// Returns the type of the items of the given type if it is `Vec<T>`
+    pub fn vec_item_type(ty: &Type) -> Option<&Type> {
+        let Type::Path(type_path) = ty else {
+            return None;
+        };
+        let segment = type_path.path.segments.last()?;
+        if segment.ident != "Vec" {
+            return None;
+        }
+        match &segment.arguments {
+            syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+                match args.args.first()? {
+                    syn::GenericArgument::Type(item_type) => Some(item_type),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the TS type of the given type if it is a primitive, a string or a plain
+    /// named type without generics, which is assumed to be exported to TS with the
+    /// same name, e.g. by `Tsify` or `impl_custom_tsify!`
+    pub fn ts_type_of(ty: &Type) -> Option<String> {
+        let Type::Path(type_path) = ty else {
+            return None;
+        };
+        if type_path.qself.is_some() {
+            return None;
+        }
+        if let Some(ts_type) = Self::union_ts_type(ty)
+            .or_else(|| Self::promise_ts_type(ty))
+            .or_else(|| Self::callback_ts_type(ty))
+        {
+            return Some(ts_type);
+        }
+        let segment = type_path.path.segments.last()?;
+        if !segment.arguments.is_none() {
+            return None;
+        }
+        let name = segment.ident.to_string();
+        let ts_type = match name.as_str() {
+            "u8" | "u16" | "u32" | "usize" | "i8" | "i16" | "i32" | "isize" | "f32" | "f64" => {
+                "number"
+            }
+            "u64" | "u128" | "i64" | "i128" => "bigint",
+            "String" | "char" => "string",
+            "bool" => "boolean",
+            _ => &name,
+        };
+        Some(ts_type.to_string())
+    }
+
+    /// Returns the TS union of the branches of the given type if it is one of `JsUnion2`,
+    /// `JsUnion3` or `JsUnion4` types and the TS types of all of its branches are known
+    pub fn union_ts_type(ty: &Type) -> Option<String> {
+        let Type::Path(type_path) = ty else {
+            return None;
+        };
+        let segment = type_path.path.segments.last()?;
+        if !matches!(
+            segment.ident.to_string().as_str(),
+            "JsUnion2" | "JsUnion3" | "JsUnion4"
+        ) {
+            return None;
+        }
+        let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+            return None;
+        };
+        let branches = args
+            .args
+            .iter()
+            .map(|arg| match arg {
+                syn::GenericArgument::Type(ty) => Self::ts_type_of(ty).map(Self::ts_function_group),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(branches.join(" | "))
+    }
+
+    /// Returns the TS function type of the given type if it is `JsCallback<(A, B), R>` or
+    /// `JsAsyncCallback<(A, B), R>`, i.e. `(arg0: A, arg1: B) => R` or `(arg0: A, arg1: B)
+    /// => R | Promise<R>` respectively, where the TS types that are not known are `any`
+    pub fn callback_ts_type(ty: &Type) -> Option<String> {
+        let Type::Path(type_path) = ty else {
+            return None;
+        };
+        let segment = type_path.path.segments.last()?;
+        let is_async = match segment.ident.to_string().as_str() {
+            "JsCallback" => false,
+            "JsAsyncCallback" => true,
+            _ => return None,
+        };
+        let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+            return None;
+        };
+        let mut args = args.args.iter();
+        let (
+            Some(syn::GenericArgument::Type(Type::Tuple(params))),
+            Some(syn::GenericArgument::Type(return_type)),
+            None,
+        ) = (args.next(), args.next(), args.next())
+        else {
+            return None;
+        };
+        let ts_type_or_any = |ty: &Type| Self::ts_type_of(ty).unwrap_or_else(|| "any".to_string());
+        let params = params
+            .elems
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| format!("arg{i}: {}", ts_type_or_any(ty)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let return_type = match return_type {
+            Type::Tuple(tuple) if tuple.elems.is_empty() => "void".to_string(),
+            ty => ts_type_or_any(ty),
+        };
+        if is_async {
+            Some(format!(
+                "({params}) => {return_type} | Promise<{return_type}>"
+            ))
+        } else {
+            Some(format!("({params}) => {return_type}"))
+        }
+    }
+
+    /// Wraps the given TS type in parentheses if it is a function type, so that it
+    /// can be composed into other types such as unions and arrays
+    pub fn ts_function_group(ts_type: String) -> String {
+        if ts_type.contains("=>") {
+            format!("({ts_type})")
+        } else {
+            ts_type
+        }
+    }
+
+    /// Returns the TS promise type of the given type if it is `TypedPromise<T>`, i.e.
+    /// `Promise<T>`, where `T` is `any` if its TS type is not known
+    pub fn promise_ts_type(ty: &Type) -> Option<String> {
+        let Type::Path(type_path) = ty else {
+            return None;
+        };
+        let segment = type_path.path.segments.last()?;
+        if segment.ident != "TypedPromise" {
+            return None;
+        }
+        let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+            return None;
+        };
+        let inner = match args.args.first() {
+            Some(syn::GenericArgument::Type(ty)) if args.args.len() == 1 => Self::ts_type_of(ty),
+            _ => return None,
+        };
+        Some(format!("Promise<{}>", inner.as_deref().unwrap_or("any")))
+    }
+
+    /// Processes the param that is a JS union, typed promise or typed callback type, it is
+    /// declared as the TS union of its branches, the TS promise of its value type or the TS
+    /// function type of its args and returning type respectively, unless its TS type is
+    /// already specified by `unchecked_param_type` attribute
+    fn process_union_parameter(pat_type: &PatType, wasm_bindgen_attrs: &mut Vec<Meta>) {
+        let has_type_override = wasm_bindgen_attrs
+            .iter()
+            .any(|meta| meta.path().is_ident(AttrKeys::UNCHECKED_PARAM_TYPE));
+        let ts_type = Self::union_ts_type(&pat_type.ty)
+            .or_else(|| Self::promise_ts_type(&pat_type.ty))
+            .or_else(|| Self::callback_ts_type(&pat_type.ty));
+        if let Some(ts_type) = ts_type.filter(|_| !has_type_override) {
+            wasm_bindgen_attrs.push(syn::parse_quote!(unchecked_param_type = #ts_type));
+        }
+    }
+
+    /// Determines if the given type is `JsValue`
+    pub fn is_js_value_type(ty: &Type) -> bool {
+        match ty {
+            Type::Path(type_path) => {
+                type_path.path.segments.last().is_some_and(|segment| {
+                    segment.ident == "JsValue" && segment.arguments.is_none()
+                })
+            }
+            _ => false,
+        }
+    }
+
+    /// Processes the param marked with `default` attribute, it is accepted as `Option<T>` by the
+    /// exporting function so that it can be omitted in JS/TS and its default value is noted in its
+    /// param description, the default value itself is substituted when building the function body
+    fn process_default_parameter(
+        pat_type: &mut PatType,
+        wasm_bindgen_attrs: &mut Vec<Meta>,
+    ) -> syn::Result<()> {
+        let Some(pos) = wasm_bindgen_attrs
+            .iter()
+            .position(|meta| meta.path().is_ident(AttrKeys::DEFAULT))
+        else {
+            return Ok(());
+        };
+        let default = wasm_bindgen_attrs.remove(pos);
+        if !matches!(pat_type.pat.deref(), syn::Pat::Ident(_)) {
+            return Err(syn::Error::new_spanned(
+                &pat_type.pat,
+                "`default` attribute requires the parameter pattern to be an identifier",
+            ));
+        }
+        if Self::is_option_type(&pat_type.ty) {
+            return Err(syn::Error::new_spanned(
+                default,
+                "`default` attribute cannot be used on `Option<T>` parameters",
+            ));
+        }
+        let ty = &pat_type.ty;
+        pat_type.ty = syn::parse_quote!(::core::option::Option<#ty>);
+
+        // note the default value at the end of the param description
+        let value = Self::tokens_to_string(&default.require_name_value()?.value)
+            .replace(" (", "(")
+            .replace("! ", "!");
+        let note = format!("Defaults to `{}`.", value);
+        let description = wasm_bindgen_attrs
+            .iter_mut()
+            .find(|meta| meta.path().is_ident(AttrKeys::PARAM_DESCRIPTION));
+        match description {
+            Some(Meta::NameValue(name_value)) => {
+                if let syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(str),
+                    ..
+                }) = &name_value.value
+                {
+                    let desc = str.value();
+                    let desc = desc.trim_end();
+                    let desc = if desc.ends_with(['.', '!', '?']) {
+                        format!("{} {}", desc, note)
+                    } else {
+                        format!("{}. {}", desc, note)
+                    };
+                    name_value.value = syn::parse_quote!(#desc);
+                }
+            }
+            _ => wasm_bindgen_attrs.push(syn::parse_quote!(param_description = #note)),
+        }
+
+        Ok(())
+    }
+
+    /// Builds the conversion expressions of the params that need to be converted before calling
+    /// the original function along with their idents, that is, params with `from_js` attribute
+    /// that are converted by the given function and the items of the variadic param that are
+    /// decoded one by one, each of the expressions evaluates to a `Result<T, WasmEncodedError>`
+    pub fn build_param_conversions(
+        inputs: &Punctuated<FnArg, Comma>,
+        krate: &syn::Path,
+    ) -> Vec<(Ident, TokenStream)> {
+        let mut conversions = vec![];
+        for input in inputs {
+            let FnArg::Typed(pat_type) = input else {
+                continue;
+            };
+            let syn::Pat::Ident(pat_ident) = pat_type.pat.deref() else {
+                continue;
+            };
+            let ident = &pat_ident.ident;
+            let metas = Self::parameter_metas(pat_type);
+            let name = Self::find_js_name(&metas).unwrap_or_else(|| ident.to_string());
+            for meta in &metas {
+                match meta {
+                    Meta::NameValue(name_value) if name_value.path.is_ident(AttrKeys::FROM_JS) => {
+                        let from_js = &name_value.value;
+                        conversions.push((
+                            ident.clone(),
+                            quote!(#krate::result::convert_from_js(#name, #ident, #from_js)),
+                        ));
+                    }
+                    Meta::Path(path) if path.is_ident(AttrKeys::VARIADIC) => {
+                        let Some(item_type) = Self::vec_item_type(&pat_type.ty)
+                            .filter(|item_type| !Self::is_js_value_type(item_type))
+                        else {
+                            continue;
+                        };
+                        conversions.push((
+                            ident.clone(),
+                            quote!(#krate::result::decode_variadic::<#item_type>(#name, #ident)),
+                        ));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        conversions
+    }
+
+    /// Returns the processed metas of the wasm_export attributes of the given param, the
+    /// invalid attributes are skipped as they are reported when processing the params
+    fn parameter_metas(pat_type: &PatType) -> Vec<Meta> {
+        pat_type
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident(AttrKeys::WASM_EXPORT))
+            .filter_map(|attr| Self::process_parameter_wasm_export_attr(attr).ok())
+            .flatten()
+            .collect()
+    }
+
+    /// Builds the statements that substitute the default values of the params marked
+    /// with `default` attribute if they were omitted, i.e. they are `None`
+    pub fn build_param_defaults(inputs: &Punctuated<FnArg, Comma>) -> Vec<TokenStream> {
+        inputs
+            .iter()
+            .filter_map(|input| match input {
+                FnArg::Typed(pat_type) => Some(pat_type),
+                FnArg::Receiver(_) => None,
+            })
+            .filter_map(|pat_type| {
+                let syn::Pat::Ident(pat_ident) = pat_type.pat.deref() else {
+                    return None;
+                };
+                let default = Self::parameter_metas(pat_type)
+                    .into_iter()
+                    .find_map(|meta| match meta {
+                        Meta::NameValue(name_value)
+                            if name_value.path.is_ident(AttrKeys::DEFAULT) =>
+                        {
+                            Some(name_value.value)
+                        }
+                        _ => None,
+                    })?;
+                let ident = &pat_ident.ident;
+                Some(quote!(let #ident = #ident.unwrap_or_else(|| #default);))
+            })
+            .collect()
+    }
+
+    /// Processes the params marked with `optional` attribute, they must be either `Option<T>` or
+    /// have `unchecked_param_type` attribute and they can only be followed by other optional params
+    /// (either `Option<T>` or marked as optional), their `unchecked_param_type` is extended with
+    /// `| undefined` so that they are declared as `arg: T | undefined` in TS and can be omitted
+    fn process_optional_parameters(
+        inputs: &Punctuated<FnArg, Comma>,
+        params_attrs: &mut [(usize, Vec<Meta>)],
+    ) -> syn::Result<()> {
+        use syn::spanned::Spanned;
+
+        // iterate in reverse to know if the params are followed by required ones
+        let mut trailing = true;
+        for (index, wasm_bindgen_attrs) in params_attrs.iter_mut().rev() {
+            let FnArg::Typed(pat_type) = &inputs[*index] else {
+                continue;
+            };
+
+            // rest params don't affect the omittability of the params before them
+            if wasm_bindgen_attrs
+                .iter()
+                .any(|meta| meta.path().is_ident(AttrKeys::VARIADIC))
+            {
+                continue;
+            }
+            let is_option = Self::is_option_type(&pat_type.ty);
+            let optional = wasm_bindgen_attrs
+                .iter()
+                .position(|meta| meta.path().is_ident(AttrKeys::OPTIONAL))
+                .map(|pos| wasm_bindgen_attrs.remove(pos));
+            trailing &= is_option || optional.is_some();
+
+            let Some(optional) = optional else {
+                continue;
+            };
+            if !trailing {
+                return Err(syn::Error::new_spanned(
+                    optional,
+                    "optional parameters must be trailing, they cannot be followed by required parameters",
+                ));
+            }
+            let type_override = wasm_bindgen_attrs
+                .iter_mut()
+                .find(|meta| meta.path().is_ident(AttrKeys::UNCHECKED_PARAM_TYPE));
+            match type_override {
+                Some(Meta::NameValue(name_value)) => {
+                    if let syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(ts_type),
+                        ..
+                    }) = &mut name_value.value
+                    {
+                        let value = format!("{} | undefined", ts_type.value());
+                        *ts_type = syn::LitStr::new(&value, ts_type.span());
+                    }
+                }
+                _ if !is_option => {
+                    return Err(syn::Error::new_spanned(
+                        optional,
+                        "`optional` attribute requires an `Option<T>` parameter type or `unchecked_param_type` attribute",
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Determines if the given type is `Option<T>`
+    pub fn is_option_type(ty: &Type) -> bool {
+        match ty {
+            Type::Path(type_path) => type_path.path.segments.last().is_some_and(|segment| {
+                segment.ident == "Option"
+                    && matches!(segment.arguments, syn::PathArguments::AngleBracketed(_))
+            }),
+            _ => false,
+        }
+    }
+
+    /// Processes a single wasm_export attribute on a parameter and converts it to wasm_bindgen format
+    pub fn process_parameter_wasm_export_attr(
+        attr: &syn::Attribute,
+    ) -> syn::Result<Vec<syn::Meta>> {
+        use syn::{punctuated::Punctuated, token::Comma, Meta};
+
+        let mut wasm_bindgen_metas = Vec::new();
+
+        // Handle empty wasm_export attribute
+        if matches!(attr.meta, Meta::Path(_)) {
+            return Ok(wasm_bindgen_metas);
+        }
+
+        // Parse the attribute contents
+        let nested_metas = attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)?;
+
+        let mut errors = Errors::default();
+        let mut seen_keys = Vec::new();
+        for meta in nested_metas {
+            if let Some(meta) = errors.collect(Self::process_parameter_meta(meta, &mut seen_keys)) {
+                wasm_bindgen_metas.push(meta);
+            }
+        }
+
+        errors.finish_with(wasm_bindgen_metas)
+    }
+
+    /// Validates a single key of a parameter wasm_export attribute, all of them are kept to be
+    /// either converted to wasm_bindgen format or handled after all of the params are processed
+    fn process_parameter_meta(
+        meta: syn::Meta,
+        seen_keys: &mut Vec<&str>,
+    ) -> syn::Result<syn::Meta> {
+        use super::error::{did_you_mean, extend_err_msg};
+
+        let Some(key) = PARAM_ATTR_KEYS
+            .into_iter()
+            .find(|key| meta.path().is_ident(key))
+        else {
+            let unknown = meta.path().to_token_stream().to_string().replace(' ', "");
+            let msg = match did_you_mean(&unknown, &PARAM_ATTR_KEYS) {
+                Some(suggestion) => format!(
+                    "unexpected `{unknown}` parameter attribute, did you mean `{suggestion}`?"
+                ),
+                None => format!(
+                    "unexpected `{unknown}` parameter attribute, expected one of {}",
+                    PARAM_ATTR_KEYS.map(|key| format!("`{key}`")).join(", ")
+                ),
+            };
+            return Err(syn::Error::new_spanned(meta.path(), msg));
+        };
+
+        // Check for duplicates
+        if seen_keys.contains(&key) {
+            return Err(syn::Error::new_spanned(
+                meta,
+                format!("duplicate `{key}` attribute"),
+            ));
+        }
+        seen_keys.push(key);
+
+        match key {
+            AttrKeys::OPTIONAL | AttrKeys::VARIADIC | AttrKeys::ABORT_SIGNAL => {
+                // Validate that it has no value, it is kept as a marker to be handled
+                // after all of the params are processed
+                let msg =
+                    format!(", `{key}` attribute does not take any extra tokens or arguments");
+                meta.require_path_only().map_err(extend_err_msg(&msg))?;
+            }
+            AttrKeys::DEFAULT => {
+                // Validate that it has a value, it is kept as a marker to be handled
+                // when building the exporting function body
+                meta.require_name_value()
+                    .map_err(extend_err_msg(" and it must be an expression"))?;
+            }
+            AttrKeys::FROM_JS => {
+                // Validate that it has a path value, it is kept as a marker to be
+                // handled when building the exporting function body
+                let syn::Expr::Path(_) = &meta
+                    .require_name_value()
+                    .map_err(extend_err_msg(" and it must be a path to a function"))?
+                    .value
+                else {
+                    return Err(syn::Error::new_spanned(
+                        meta,
+                        "expected a path to a function",
+                    ));
+                };
+            }
+            _ => {
+                // Validate that it has a string literal value, js_type is converted to
+                // unchecked_param_type after all of the param attributes are processed
+                let syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(_),
+                    ..
+                }) = &meta
+                    .require_name_value()
+                    .map_err(extend_err_msg(" and it must be a string literal"))?
+                    .value
+                else {
+                    return Err(syn::Error::new_spanned(meta, "expected string literal"));
+                };
+            }
+        }
+
+        Ok(meta)
+    }
+
+    /// Cleans wasm_export attributes from function parameters (for cleaning original functions)
+    pub fn clean_parameter_attributes(inputs: &mut Punctuated<FnArg, Comma>) {
+        for input in inputs.iter_mut() {
+            if let FnArg::Typed(pat_type) = input {
+                pat_type
+                    .attrs
+                    .retain(|attr| !attr.path().is_ident("wasm_export"));
+            }
+        }
+    }
+
+    /// Extracts doc comments (/// ...) from function/method attributes
+    pub fn extract_doc_comments(attrs: &[syn::Attribute]) -> Vec<syn::Attribute> {
+        attrs
+            .iter()
+            .filter(|attr| {
+                // Check if attribute is a doc comment (starts with "doc")
+                attr.path().is_ident("doc")
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Creates the function name from the original name, it is appended by __wasm_export
+    pub fn populate_name(org_fn_ident: &Ident) -> Ident {
+        Ident::new(
+            &format!("{}__wasm_export", org_fn_ident),
+            org_fn_ident.span(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
     use super::*;
     use std::str::FromStr;
     use proc_macro2::{Span, TokenStream};
@@ -481,15 +1844,32 @@ mod tests {
         let wasm_export_fn_config = WasmExportFunctionBuilderConfig {
             forward_attrs: vec![parse_quote!(some_forward_attr)],
             return_type: parse_quote!(SomeType),
+            return_kind: ReturnKind::Result,
             preserve_js_class: None,
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
+            map_ok: None,
+            instance: None,
+            trait_path: None,
+            krate: WasmExportAttrs::default_crate_path(),
         };
         let result =
             WasmExportFunctionBuilder::build_export_method(&method, wasm_export_fn_config).unwrap();
+        let return_type: Type =
+            parse_quote!(::wasm_bindgen_utils::result::WasmEncodedResult<SomeType>);
         let expected = parse_quote!(
             #[allow(non_snake_case)]
             #[wasm_bindgen(some_forward_attr)]
-            pub async fn some_fn__wasm_export(arg1: String) -> WasmEncodedResult<SomeType> {
-                Self::some_fn(arg1).await.into()
+            pub async fn some_fn__wasm_export(arg1: String) -> #return_type {
+                use ::wasm_bindgen_utils::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    ::wasm_bindgen_utils::hooks::ExportCall::start("some_fn__wasm_export");
+                Self::some_fn(arg1)
+                    .await
+                    .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                    .into()
             }
         );
         assert_eq!(result, expected);
@@ -501,24 +1881,42 @@ mod tests {
         let wasm_export_fn_config = WasmExportFunctionBuilderConfig {
             forward_attrs: vec![parse_quote!(some_forward_attr)],
             return_type: parse_quote!(SomeType),
+            return_kind: ReturnKind::Result,
             preserve_js_class: Some(Span::call_site()),
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
+            map_ok: None,
+            instance: None,
+            trait_path: None,
+            krate: WasmExportAttrs::default_crate_path(),
         };
         let result =
             WasmExportFunctionBuilder::build_export_method(&method, wasm_export_fn_config).unwrap();
+        let return_type: Type = parse_quote!(::wasm_bindgen_utils::prelude::JsValue);
         let expected = parse_quote!(
             #[allow(non_snake_case)]
             #[wasm_bindgen(some_forward_attr)]
-            pub async fn some_fn__wasm_export(arg1: String) -> JsValue {
-                use js_sys::{Reflect, Object};
+            pub async fn some_fn__wasm_export(arg1: String) -> #return_type {
+                use ::wasm_bindgen_utils::prelude::JsValue;
+                use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+                use ::wasm_bindgen_utils::result::WasmEncodedError;
+                use ::wasm_bindgen_utils::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    ::wasm_bindgen_utils::hooks::ExportCall::start("some_fn__wasm_export");
                 let obj = Object::new();
-                let result = Self::some_fn(arg1).await.into();
+                let result = Self::some_fn(arg1)
+                    .await
+                    .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                    .into();
                 match result {
-                    Ok(value) => {
+                    ::core::result::Result::Ok(value) => {
                         Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
                         Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED)
                             .unwrap();
                     }
-                    Err(error) => {
+                    ::core::result::Result::Err(error) => {
                         let wasm_error: WasmEncodedError = error.into();
                         Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED)
                             .unwrap();
@@ -541,15 +1939,32 @@ mod tests {
         let wasm_export_fn_config = WasmExportFunctionBuilderConfig {
             forward_attrs: vec![parse_quote!(some_forward_attr)],
             return_type: parse_quote!(SomeType),
+            return_kind: ReturnKind::Result,
             preserve_js_class: None,
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
+            map_ok: None,
+            instance: None,
+            trait_path: None,
+            krate: WasmExportAttrs::default_crate_path(),
         };
         let result =
             WasmExportFunctionBuilder::build_export_function(&func, wasm_export_fn_config).unwrap();
+        let return_type: Type =
+            parse_quote!(::wasm_bindgen_utils::result::WasmEncodedResult<SomeType>);
         let expected = parse_quote!(
             #[allow(non_snake_case)]
-            #[wasm_bindgen(some_forward_attr)]
-            pub async fn some_fn__wasm_export(arg1: String) -> WasmEncodedResult<SomeType> {
-                some_fn(arg1).await.into()
+            #[::wasm_bindgen_utils::prelude::wasm_bindgen(wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen, wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures, some_forward_attr)]
+            pub async fn some_fn__wasm_export(arg1: String) -> #return_type {
+                use ::wasm_bindgen_utils::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    ::wasm_bindgen_utils::hooks::ExportCall::start("some_fn__wasm_export");
+                some_fn(arg1)
+                    .await
+                    .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                    .into()
             }
         );
         assert_eq!(result, expected);
@@ -561,24 +1976,42 @@ mod tests {
         let wasm_export_fn_config = WasmExportFunctionBuilderConfig {
             forward_attrs: vec![parse_quote!(some_forward_attr)],
             return_type: parse_quote!(SomeType),
+            return_kind: ReturnKind::Result,
             preserve_js_class: Some(Span::call_site()),
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
+            map_ok: None,
+            instance: None,
+            trait_path: None,
+            krate: WasmExportAttrs::default_crate_path(),
         };
         let result =
             WasmExportFunctionBuilder::build_export_function(&func, wasm_export_fn_config).unwrap();
+        let return_type: Type = parse_quote!(::wasm_bindgen_utils::prelude::JsValue);
         let expected = parse_quote!(
             #[allow(non_snake_case)]
-            #[wasm_bindgen(some_forward_attr)]
-            pub async fn some_fn__wasm_export(arg1: String) -> JsValue {
-                use js_sys::{Reflect, Object};
+            #[::wasm_bindgen_utils::prelude::wasm_bindgen(wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen, wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures, some_forward_attr)]
+            pub async fn some_fn__wasm_export(arg1: String) -> #return_type {
+                use ::wasm_bindgen_utils::prelude::JsValue;
+                use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+                use ::wasm_bindgen_utils::result::WasmEncodedError;
+                use ::wasm_bindgen_utils::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    ::wasm_bindgen_utils::hooks::ExportCall::start("some_fn__wasm_export");
                 let obj = Object::new();
-                let result = some_fn(arg1).await.into();
+                let result = some_fn(arg1)
+                    .await
+                    .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                    .into();
                 match result {
-                    Ok(value) => {
+                    ::core::result::Result::Ok(value) => {
                         Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
                         Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED)
                             .unwrap();
                     }
-                    Err(error) => {
+                    ::core::result::Result::Err(error) => {
                         let wasm_error: WasmEncodedError = error.into();
                         Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED)
                             .unwrap();
@@ -593,74 +2026,452 @@ mod tests {
     }
 
     #[test]
-    fn test_build_fn_body_unified_method_async() {
-        // async method static
-        let method: ImplItemFn = parse_quote!(
-            pub async fn some_name((arg1, arg2): (String, u8)) -> Result<SomeType, Error> {
-                Ok(SomeType::new())
-            }
+    fn test_from_standalone_diagnosed() {
+        // without preserve js class
+        let func: ItemFn = parse_quote!(
+            pub fn some_fn(arg1: String) -> Diagnosed<SomeType, Error> {}
         );
+        let wasm_export_fn_config = WasmExportFunctionBuilderConfig {
+            forward_attrs: vec![parse_quote!(some_forward_attr)],
+            return_type: parse_quote!(SomeType),
+            return_kind: ReturnKind::Diagnosed,
+            preserve_js_class: None,
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
+            map_ok: None,
+            instance: None,
+            trait_path: None,
+            krate: WasmExportAttrs::default_crate_path(),
+        };
         let result =
-            WasmExportFunctionBuilder::build_fn_body_unified(FunctionType::Method(&method), false);
-        let expected: Block = parse_quote!({ Self::some_name((arg1, arg2)).await.into() });
-        assert_eq!(result, expected);
-
-        // async method with self
-        let method: ImplItemFn = parse_quote!(
-            pub async fn some_name(&self, (arg1, arg2): (String, u8)) -> Result<SomeType, Error> {
-                Ok(SomeType::new())
+            WasmExportFunctionBuilder::build_export_function(&func, wasm_export_fn_config).unwrap();
+        let return_type: Type =
+            parse_quote!(::wasm_bindgen_utils::result::WasmEncodedDiagnosedResult<SomeType>);
+        let expected = parse_quote!(
+            #[allow(non_snake_case)]
+            #[::wasm_bindgen_utils::prelude::wasm_bindgen(wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen, wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures, some_forward_attr)]
+            pub fn some_fn__wasm_export(arg1: String) -> #return_type {
+                use ::wasm_bindgen_utils::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    ::wasm_bindgen_utils::hooks::ExportCall::start("some_fn__wasm_export");
+                some_fn(arg1)
+                    .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                    .into()
             }
         );
-        let result =
-            WasmExportFunctionBuilder::build_fn_body_unified(FunctionType::Method(&method), false);
-        let expected: Block = parse_quote!({ self.some_name((arg1, arg2)).await.into() });
         assert_eq!(result, expected);
 
-        // async method static with preserve class
-        let method: ImplItemFn = parse_quote!(
-            pub async fn some_name((arg1, arg2): (String, u8)) -> Result<SomeType, Error> {
-                Ok(SomeType::new())
+        // with preserve js class
+        let wasm_export_fn_config = WasmExportFunctionBuilderConfig {
+            forward_attrs: vec![parse_quote!(some_forward_attr)],
+            return_type: parse_quote!(SomeType),
+            return_kind: ReturnKind::Diagnosed,
+            preserve_js_class: Some(Span::call_site()),
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
+            map_ok: None,
+            instance: None,
+            trait_path: None,
+            krate: WasmExportAttrs::default_crate_path(),
+        };
+        let result =
+            WasmExportFunctionBuilder::build_export_function(&func, wasm_export_fn_config).unwrap();
+        let expected = parse_quote!(
+            #[allow(non_snake_case)]
+            #[::wasm_bindgen_utils::prelude::wasm_bindgen(wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen, wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures, some_forward_attr)]
+            pub fn some_fn__wasm_export(arg1: String) -> ::wasm_bindgen_utils::prelude::JsValue {
+                use ::wasm_bindgen_utils::prelude::JsValue;
+                use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+                use ::wasm_bindgen_utils::result::WasmEncodedError;
+                use ::wasm_bindgen_utils::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    ::wasm_bindgen_utils::hooks::ExportCall::start("some_fn__wasm_export");
+                let obj = Object::new();
+                let ::wasm_bindgen_utils::result::Diagnosed { result, warnings } =
+                    some_fn(arg1).encode_errors(|error| __wasm_export_call.on_error(error), |_| {});
+                match result {
+                    ::core::result::Result::Ok(value) => {
+                        Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
+                        Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED)
+                            .unwrap();
+                    }
+                    ::core::result::Result::Err(error) => {
+                        let wasm_error: WasmEncodedError = error.into();
+                        Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED)
+                            .unwrap();
+                        Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into())
+                            .unwrap();
+                    }
+                };
+                let warnings: ::std::vec::Vec<WasmEncodedError> = warnings
+                    .into_iter()
+                    .map(::core::convert::Into::into)
+                    .collect();
+                Reflect::set(&obj, &JsValue::from_str("warnings"), &warnings.into()).unwrap();
+                obj.into()
             }
         );
-        let result =
-            WasmExportFunctionBuilder::build_fn_body_unified(FunctionType::Method(&method), true);
-        let expected: Block = parse_quote!({
-            use js_sys::{Reflect, Object};
-            let obj = Object::new();
-            let result = Self::some_name((arg1, arg2)).await.into();
-            match result {
-                Ok(value) => {
-                    Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
-                    Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED).unwrap();
-                }
-                Err(error) => {
-                    let wasm_error: WasmEncodedError = error.into();
-                    Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED).unwrap();
-                    Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into()).unwrap();
-                }
-            };
-            obj.into()
-        });
         assert_eq!(result, expected);
+    }
 
-        // async method self with preserve class
-        let method: ImplItemFn = parse_quote!(
-            pub async fn some_name(&self, (arg1, arg2): (String, u8)) -> Result<SomeType, Error> {
-                Ok(SomeType::new())
-            }
+    #[test]
+    fn test_from_standalone_batch() {
+        // without preserve js class
+        let func: ItemFn = parse_quote!(
+            pub fn some_fn(arg1: String) -> Vec<Result<SomeType, Error>> {}
         );
-        let result =
-            WasmExportFunctionBuilder::build_fn_body_unified(FunctionType::Method(&method), true);
-        let expected: Block = parse_quote!({
-            use js_sys::{Reflect, Object};
-            let obj = Object::new();
-            let result = self.some_name((arg1, arg2)).await.into();
-            match result {
-                Ok(value) => {
+        let wasm_export_fn_config = WasmExportFunctionBuilderConfig {
+            forward_attrs: vec![parse_quote!(some_forward_attr)],
+            return_type: parse_quote!(SomeType),
+            return_kind: ReturnKind::Batch,
+            preserve_js_class: None,
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
+            map_ok: None,
+            instance: None,
+            trait_path: None,
+            krate: WasmExportAttrs::default_crate_path(),
+        };
+        let result =
+            WasmExportFunctionBuilder::build_export_function(&func, wasm_export_fn_config).unwrap();
+        let return_type: Type =
+            parse_quote!(::wasm_bindgen_utils::result::WasmEncodedResults<SomeType>);
+        let expected = parse_quote!(
+            #[allow(non_snake_case)]
+            #[::wasm_bindgen_utils::prelude::wasm_bindgen(wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen, wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures, some_forward_attr)]
+            pub fn some_fn__wasm_export(arg1: String) -> #return_type {
+                use ::wasm_bindgen_utils::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    ::wasm_bindgen_utils::hooks::ExportCall::start("some_fn__wasm_export");
+                some_fn(arg1)
+                    .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                    .into()
+            }
+        );
+        assert_eq!(result, expected);
+
+        // with preserve js class
+        let wasm_export_fn_config = WasmExportFunctionBuilderConfig {
+            forward_attrs: vec![parse_quote!(some_forward_attr)],
+            return_type: parse_quote!(SomeType),
+            return_kind: ReturnKind::Batch,
+            preserve_js_class: Some(Span::call_site()),
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
+            map_ok: None,
+            instance: None,
+            trait_path: None,
+            krate: WasmExportAttrs::default_crate_path(),
+        };
+        let result =
+            WasmExportFunctionBuilder::build_export_function(&func, wasm_export_fn_config).unwrap();
+        let expected = parse_quote!(
+            #[allow(non_snake_case)]
+            #[::wasm_bindgen_utils::prelude::wasm_bindgen(wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen, wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures, some_forward_attr)]
+            pub fn some_fn__wasm_export(arg1: String) -> ::wasm_bindgen_utils::prelude::JsValue {
+                use ::wasm_bindgen_utils::prelude::JsValue;
+                use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object, Array};
+                use ::wasm_bindgen_utils::result::WasmEncodedError;
+                use ::wasm_bindgen_utils::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    ::wasm_bindgen_utils::hooks::ExportCall::start("some_fn__wasm_export");
+                let obj = Object::new();
+                let items = Array::new();
+                let batch =
+                    some_fn(arg1).encode_errors(|error| __wasm_export_call.on_error(error), |_| {});
+                let mut successes: u32 = 0;
+                let mut failures: u32 = 0;
+                for result in batch.items {
+                    if result.is_ok() {
+                        successes += 1;
+                    } else {
+                        failures += 1;
+                    }
+                    let item = Object::new();
+                    match result {
+                        ::core::result::Result::Ok(value) => {
+                            Reflect::set(&item, &JsValue::from_str("value"), &value.into())
+                                .unwrap();
+                            Reflect::set(&item, &JsValue::from_str("error"), &JsValue::UNDEFINED)
+                                .unwrap();
+                        }
+                        ::core::result::Result::Err(error) => {
+                            let wasm_error: WasmEncodedError = error.into();
+                            Reflect::set(&item, &JsValue::from_str("value"), &JsValue::UNDEFINED)
+                                .unwrap();
+                            Reflect::set(&item, &JsValue::from_str("error"), &wasm_error.into())
+                                .unwrap();
+                        }
+                    };
+                    items.push(&item);
+                }
+                Reflect::set(&obj, &JsValue::from_str("items"), &items).unwrap();
+                Reflect::set(&obj, &JsValue::from_str("successCount"), &successes.into()).unwrap();
+                Reflect::set(&obj, &JsValue::from_str("errorCount"), &failures.into()).unwrap();
+                if let ::core::option::Option::Some(error) = batch.error {
+                    Reflect::set(&obj, &JsValue::from_str("error"), &error.into()).unwrap();
+                }
+                obj.into()
+            }
+        );
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_standalone_stream() {
+        // without preserve js class
+        let func: ItemFn = parse_quote!(
+            pub fn some_fn(arg1: String) -> impl Stream<Item = Result<SomeType, Error>> {}
+        );
+        let wasm_export_fn_config = WasmExportFunctionBuilderConfig {
+            forward_attrs: vec![parse_quote!(some_forward_attr)],
+            return_type: parse_quote!(SomeType),
+            return_kind: ReturnKind::Stream,
+            ..Default::default()
+        };
+        let result =
+            WasmExportFunctionBuilder::build_export_function(&func, wasm_export_fn_config).unwrap();
+        let return_type: Type = parse_quote!(::wasm_bindgen_utils::stream::JsAsyncIterator);
+        let expected = parse_quote!(
+            #[allow(non_snake_case)]
+            #[::wasm_bindgen_utils::prelude::wasm_bindgen(wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen, wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures, some_forward_attr)]
+            pub fn some_fn__wasm_export(arg1: String) -> #return_type {
+                use ::wasm_bindgen_utils::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    ::wasm_bindgen_utils::hooks::ExportCall::start("some_fn__wasm_export");
+                ::wasm_bindgen_utils::stream::ExportStream::new(some_fn(arg1))
+                    .encode_errors(move |error| __wasm_export_call.on_error(error), |_| {})
+                    .into()
+            }
+        );
+        assert_eq!(result, expected);
+
+        // with preserve js class
+        let wasm_export_fn_config = WasmExportFunctionBuilderConfig {
+            forward_attrs: vec![parse_quote!(some_forward_attr)],
+            return_type: parse_quote!(SomeType),
+            return_kind: ReturnKind::Stream,
+            preserve_js_class: Some(Span::call_site()),
+            ..Default::default()
+        };
+        let result =
+            WasmExportFunctionBuilder::build_export_function(&func, wasm_export_fn_config).unwrap();
+        let stream = quote!(
+            ::wasm_bindgen_utils::stream::ExportStream::new(some_fn(arg1))
+                .encode_errors(move |error| __wasm_export_call.on_error(error), |_| {})
+        );
+        let expected = parse_quote!(
+            #[allow(non_snake_case)]
+            #[::wasm_bindgen_utils::prelude::wasm_bindgen(wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen, wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures, some_forward_attr)]
+            pub fn some_fn__wasm_export(arg1: String) -> ::wasm_bindgen_utils::prelude::JsValue {
+                use ::wasm_bindgen_utils::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    ::wasm_bindgen_utils::hooks::ExportCall::start("some_fn__wasm_export");
+                ::wasm_bindgen_utils::stream::JsAsyncIterator::from_stream_preserved(#stream).into()
+            }
+        );
+        assert_eq!(result, expected);
+
+        // with map_ok
+        let stream = quote!(
+            ::wasm_bindgen_utils::stream::ExportStream::new(some_fn(arg1))
+                .map_ok(Into::into)
+                .encode_errors(move |error| __wasm_export_call.on_error(error), |_| {})
+        );
+        let wasm_export_fn_config = WasmExportFunctionBuilderConfig {
+            return_type: parse_quote!(SomeType),
+            return_kind: ReturnKind::Stream,
+            map_ok: Some(parse_quote!(Into::into)),
+            ..Default::default()
+        };
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Standalone(&func),
+            &wasm_export_fn_config,
+        );
+        let expected: Block = parse_quote!({
+            use ::wasm_bindgen_utils::result::{EncodeErrors as _, MapOk as _};
+            let __wasm_export_call =
+                ::wasm_bindgen_utils::hooks::ExportCall::start("some_fn__wasm_export");
+            ::wasm_bindgen_utils::stream::JsAsyncIterator::from(#stream).into()
+        });
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_method_iterator() {
+        let method: ImplItemFn = parse_quote!(
+            pub fn some_fn(&self, arg1: String) -> Box<dyn Iterator<Item = SomeType>> {}
+        );
+        let wasm_export_fn_config = WasmExportFunctionBuilderConfig {
+            forward_attrs: vec![parse_quote!(some_forward_attr)],
+            return_type: parse_quote!(SomeType),
+            return_kind: ReturnKind::Iterator,
+            ..Default::default()
+        };
+        let result =
+            WasmExportFunctionBuilder::build_export_method(&method, wasm_export_fn_config).unwrap();
+        let return_type: Type = parse_quote!(::wasm_bindgen_utils::iter::JsIterator);
+        let expected: ImplItemFn = parse_quote!(
+            #[allow(non_snake_case)]
+            #[wasm_bindgen(some_forward_attr)]
+            pub fn some_fn__wasm_export(&self, arg1: String) -> #return_type {
+                use ::wasm_bindgen_utils::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    ::wasm_bindgen_utils::hooks::ExportCall::start("some_fn__wasm_export");
+                ::wasm_bindgen_utils::iter::ExportIter::new(self.some_fn(arg1))
+                    .encode_errors(move |error| __wasm_export_call.on_error(error), |_| {})
+                    .into()
+            }
+        );
+        assert_eq!(result, expected);
+
+        // with preserve js class
+        let wasm_export_fn_config = WasmExportFunctionBuilderConfig {
+            return_type: parse_quote!(SomeType),
+            return_kind: ReturnKind::Iterator,
+            preserve_js_class: Some(Span::call_site()),
+            ..Default::default()
+        };
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Method(&method),
+            &wasm_export_fn_config,
+        );
+        let iter = quote!(
+            ::wasm_bindgen_utils::iter::ExportIter::new(self.some_fn(arg1))
+                .encode_errors(move |error| __wasm_export_call.on_error(error), |_| {})
+        );
+        let expected: Block = parse_quote!({
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call =
+                ::wasm_bindgen_utils::hooks::ExportCall::start("some_fn__wasm_export");
+            ::wasm_bindgen_utils::iter::JsIterator::from(#iter).into()
+        });
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_build_fn_body_unified_method_async() {
+        let preserve_js_class_config = WasmExportFunctionBuilderConfig {
+            preserve_js_class: Some(Span::call_site()),
+            ..Default::default()
+        };
+
+        // async method static
+        let method: ImplItemFn = parse_quote!(
+            pub async fn some_name((arg1, arg2): (String, u8)) -> Result<SomeType, Error> {
+                Ok(SomeType::new())
+            }
+        );
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Method(&method),
+            &Default::default(),
+        );
+        let expected: Block = parse_quote!({
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call =
+                ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+            Self::some_name((arg1, arg2))
+                .await
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        });
+        assert_eq!(result, expected);
+
+        // async method with self
+        let method: ImplItemFn = parse_quote!(
+            pub async fn some_name(&self, (arg1, arg2): (String, u8)) -> Result<SomeType, Error> {
+                Ok(SomeType::new())
+            }
+        );
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Method(&method),
+            &Default::default(),
+        );
+        let expected: Block = parse_quote!({
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call =
+                ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+            self.some_name((arg1, arg2))
+                .await
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        });
+        assert_eq!(result, expected);
+
+        // async method static with preserve class
+        let method: ImplItemFn = parse_quote!(
+            pub async fn some_name((arg1, arg2): (String, u8)) -> Result<SomeType, Error> {
+                Ok(SomeType::new())
+            }
+        );
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Method(&method),
+            &preserve_js_class_config,
+        );
+        let expected: Block = parse_quote!({
+            use ::wasm_bindgen_utils::prelude::JsValue;
+            use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+            use ::wasm_bindgen_utils::result::WasmEncodedError;
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call =
+                ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+            let obj = Object::new();
+            let result = Self::some_name((arg1, arg2))
+                .await
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into();
+            match result {
+                ::core::result::Result::Ok(value) => {
+                    Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED).unwrap();
+                }
+                ::core::result::Result::Err(error) => {
+                    let wasm_error: WasmEncodedError = error.into();
+                    Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED).unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into()).unwrap();
+                }
+            };
+            obj.into()
+        });
+        assert_eq!(result, expected);
+
+        // async method self with preserve class
+        let method: ImplItemFn = parse_quote!(
+            pub async fn some_name(&self, (arg1, arg2): (String, u8)) -> Result<SomeType, Error> {
+                Ok(SomeType::new())
+            }
+        );
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Method(&method),
+            &preserve_js_class_config,
+        );
+        let expected: Block = parse_quote!({
+            use ::wasm_bindgen_utils::prelude::JsValue;
+            use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+            use ::wasm_bindgen_utils::result::WasmEncodedError;
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call =
+                ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+            let obj = Object::new();
+            let result = self
+                .some_name((arg1, arg2))
+                .await
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into();
+            match result {
+                ::core::result::Result::Ok(value) => {
                     Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
                     Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED).unwrap();
                 }
-                Err(error) => {
+                ::core::result::Result::Err(error) => {
                     let wasm_error: WasmEncodedError = error.into();
                     Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED).unwrap();
                     Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into()).unwrap();
@@ -673,15 +2484,29 @@ mod tests {
 
     #[test]
     fn test_build_fn_body_unified_method_sync() {
+        let preserve_js_class_config = WasmExportFunctionBuilderConfig {
+            preserve_js_class: Some(Span::call_site()),
+            ..Default::default()
+        };
+
         // sync method static
         let method: ImplItemFn = parse_quote!(
             pub fn some_name((arg1, arg2): (String, u8)) -> Result<SomeType, Error> {
                 Ok(SomeType::new())
             }
         );
-        let result =
-            WasmExportFunctionBuilder::build_fn_body_unified(FunctionType::Method(&method), false);
-        let expected: Block = parse_quote!({ Self::some_name((arg1, arg2)).into() });
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Method(&method),
+            &Default::default(),
+        );
+        let expected: Block = parse_quote!({
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call =
+                ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+            Self::some_name((arg1, arg2))
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        });
         assert_eq!(result, expected);
 
         // sync method with self
@@ -690,9 +2515,18 @@ mod tests {
                 Ok(SomeType::new())
             }
         );
-        let result =
-            WasmExportFunctionBuilder::build_fn_body_unified(FunctionType::Method(&method), false);
-        let expected: Block = parse_quote!({ self.some_name((arg1, arg2)).into() });
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Method(&method),
+            &Default::default(),
+        );
+        let expected: Block = parse_quote!({
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call =
+                ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+            self.some_name((arg1, arg2))
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        });
         assert_eq!(result, expected);
 
         // sync method static with preserve class
@@ -701,18 +2535,27 @@ mod tests {
                 Ok(SomeType::new())
             }
         );
-        let result =
-            WasmExportFunctionBuilder::build_fn_body_unified(FunctionType::Method(&method), true);
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Method(&method),
+            &preserve_js_class_config,
+        );
         let expected: Block = parse_quote!({
-            use js_sys::{Reflect, Object};
+            use ::wasm_bindgen_utils::prelude::JsValue;
+            use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+            use ::wasm_bindgen_utils::result::WasmEncodedError;
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call =
+                ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
             let obj = Object::new();
-            let result = Self::some_name((arg1, arg2)).into();
+            let result = Self::some_name((arg1, arg2))
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into();
             match result {
-                Ok(value) => {
+                ::core::result::Result::Ok(value) => {
                     Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
                     Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED).unwrap();
                 }
-                Err(error) => {
+                ::core::result::Result::Err(error) => {
                     let wasm_error: WasmEncodedError = error.into();
                     Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED).unwrap();
                     Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into()).unwrap();
@@ -728,18 +2571,28 @@ mod tests {
                 Ok(SomeType::new())
             }
         );
-        let result =
-            WasmExportFunctionBuilder::build_fn_body_unified(FunctionType::Method(&method), true);
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Method(&method),
+            &preserve_js_class_config,
+        );
         let expected: Block = parse_quote!({
-            use js_sys::{Reflect, Object};
+            use ::wasm_bindgen_utils::prelude::JsValue;
+            use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+            use ::wasm_bindgen_utils::result::WasmEncodedError;
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call =
+                ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
             let obj = Object::new();
-            let result = self.some_name((arg1, arg2)).into();
+            let result = self
+                .some_name((arg1, arg2))
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into();
             match result {
-                Ok(value) => {
+                ::core::result::Result::Ok(value) => {
                     Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
                     Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED).unwrap();
                 }
-                Err(error) => {
+                ::core::result::Result::Err(error) => {
                     let wasm_error: WasmEncodedError = error.into();
                     Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED).unwrap();
                     Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into()).unwrap();
@@ -752,6 +2605,11 @@ mod tests {
 
     #[test]
     fn test_build_fn_body_unified_standalone_function_async() {
+        let preserve_js_class_config = WasmExportFunctionBuilderConfig {
+            preserve_js_class: Some(Span::call_site()),
+            ..Default::default()
+        };
+
         // async function
         let function: ItemFn = parse_quote!(
             pub async fn some_name((arg1, arg2): (String, u8)) -> Result<SomeType, Error> {
@@ -760,9 +2618,17 @@ mod tests {
         );
         let result = WasmExportFunctionBuilder::build_fn_body_unified(
             FunctionType::Standalone(&function),
-            false,
+            &Default::default(),
         );
-        let expected: Block = parse_quote!({ some_name((arg1, arg2)).await.into() });
+        let expected: Block = parse_quote!({
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call =
+                ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+            some_name((arg1, arg2))
+                .await
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        });
         assert_eq!(result, expected);
 
         // async function with preserve class
@@ -773,18 +2639,26 @@ mod tests {
         );
         let result = WasmExportFunctionBuilder::build_fn_body_unified(
             FunctionType::Standalone(&function),
-            true,
+            &preserve_js_class_config,
         );
         let expected: Block = parse_quote!({
-            use js_sys::{Reflect, Object};
+            use ::wasm_bindgen_utils::prelude::JsValue;
+            use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+            use ::wasm_bindgen_utils::result::WasmEncodedError;
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call =
+                ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
             let obj = Object::new();
-            let result = some_name((arg1, arg2)).await.into();
+            let result = some_name((arg1, arg2))
+                .await
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into();
             match result {
-                Ok(value) => {
+                ::core::result::Result::Ok(value) => {
                     Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
                     Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED).unwrap();
                 }
-                Err(error) => {
+                ::core::result::Result::Err(error) => {
                     let wasm_error: WasmEncodedError = error.into();
                     Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED).unwrap();
                     Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into()).unwrap();
@@ -797,6 +2671,11 @@ mod tests {
 
     #[test]
     fn test_build_fn_body_unified_standalone_function_sync() {
+        let preserve_js_class_config = WasmExportFunctionBuilderConfig {
+            preserve_js_class: Some(Span::call_site()),
+            ..Default::default()
+        };
+
         // sync function
         let function: ItemFn = parse_quote!(
             pub fn some_name((arg1, arg2): (String, u8)) -> Result<SomeType, Error> {
@@ -805,148 +2684,1561 @@ mod tests {
         );
         let result = WasmExportFunctionBuilder::build_fn_body_unified(
             FunctionType::Standalone(&function),
-            false,
+            &Default::default(),
+        );
+        let expected: Block = parse_quote!({
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call =
+                ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+            some_name((arg1, arg2))
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        });
+        assert_eq!(result, expected);
+
+        // sync function with preserve class
+        let function: ItemFn = parse_quote!(
+            pub fn some_name((arg1, arg2): (String, u8)) -> Result<SomeType, Error> {
+                Ok(SomeType::new())
+            }
+        );
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Standalone(&function),
+            &preserve_js_class_config,
+        );
+        let expected: Block = parse_quote!({
+            use ::wasm_bindgen_utils::prelude::JsValue;
+            use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+            use ::wasm_bindgen_utils::result::WasmEncodedError;
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call =
+                ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+            let obj = Object::new();
+            let result = some_name((arg1, arg2))
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into();
+            match result {
+                ::core::result::Result::Ok(value) => {
+                    Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED).unwrap();
+                }
+                ::core::result::Result::Err(error) => {
+                    let wasm_error: WasmEncodedError = error.into();
+                    Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED).unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into()).unwrap();
+                }
+            };
+            obj.into()
+        });
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_build_fn_body_unified_error_context() {
+        let config = WasmExportFunctionBuilderConfig {
+            error_context: Some(Span::call_site()),
+            ..Default::default()
+        };
+
+        // method
+        let method: ImplItemFn = parse_quote!(
+            pub async fn some_name(&self, arg1: String) -> Result<SomeType, Error> {}
+        );
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Method(&method),
+            &config,
+        );
+        let context = TokenStream::from_str(
+            r#"::std::boxed::Box::new(::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                ::std::any::type_name_of_val(&Self::some_name),
+                "some_name__wasm_export",
+                &["arg1: String"]
+            ))"#,
+        )
+        .unwrap();
+        let on_error = quote!(|error| {
+            error.context = ::core::option::Option::Some(#context);
+            __wasm_export_call.on_error(error);
+        });
+        let on_warning = quote!(|warning| warning.context = ::core::option::Option::Some(#context));
+        let expected: Block = parse_quote!({
+                    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+        let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+                    self.some_name(arg1).await.encode_errors(#on_error, #on_warning).into()
+                });
+        assert_eq!(result, expected);
+
+        // standalone function with preserve class
+        let function: ItemFn = parse_quote!(
+            pub fn some_name(arg1: String) -> Result<SomeType, Error> {}
+        );
+        let config = WasmExportFunctionBuilderConfig {
+            forward_attrs: vec![parse_quote!(js_name = "someName")],
+            preserve_js_class: Some(Span::call_site()),
+            error_context: Some(Span::call_site()),
+            ..Default::default()
+        };
+        let context = TokenStream::from_str(
+            r#"::std::boxed::Box::new(::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                ::std::any::type_name_of_val(&some_name),
+                "someName",
+                &["arg1: String"]
+            ))"#,
+        )
+        .unwrap();
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Standalone(&function),
+            &config,
+        );
+        let on_error = quote!(|error| {
+            error.context = ::core::option::Option::Some(#context);
+            __wasm_export_call.on_error(error);
+        });
+        let on_warning = quote!(|warning| warning.context = ::core::option::Option::Some(#context));
+        let expected: Block = parse_quote!({
+            use ::wasm_bindgen_utils::prelude::JsValue;
+            use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+            use ::wasm_bindgen_utils::result::WasmEncodedError;
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("someName");
+            let obj = Object::new();
+            let result = some_name(arg1).encode_errors(#on_error, #on_warning).into();
+            match result {
+                ::core::result::Result::Ok(value) => {
+                    Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED).unwrap();
+                }
+                ::core::result::Result::Err(error) => {
+                    let wasm_error: WasmEncodedError = error.into();
+                    Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED).unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into()).unwrap();
+                }
+            };
+            obj.into()
+        });
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_build_fn_body_unified_abort_signal() {
+        // abort signal param and timeout
+        let config = WasmExportFunctionBuilderConfig {
+            timeout_ms: Some((100, Span::call_site())),
+            ..Default::default()
+        };
+        let method: ImplItemFn = parse_quote!(
+            pub async fn some_name(&self, signal: Option<AbortSignal>) -> Result<SomeType, Error> {}
+        );
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Method(&method),
+            &config,
+        );
+        let call = quote!(self.some_name(signal));
+        let expected: Block = parse_quote!({
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+            let __wasm_export_abort_signal = ::wasm_bindgen_utils::abort::abort_signal_of("signal", signal.as_ref());
+            (match __wasm_export_abort_signal {
+                ::core::result::Result::Ok(__wasm_export_abort_signal) => ::wasm_bindgen_utils::abort::abortable(#call, __wasm_export_abort_signal, ::core::option::Option::Some(100u32))
+                    .await
+                    .encode_errors(|error| __wasm_export_call.on_error(error), |_| {}),
+                ::core::result::Result::Err(error) => {
+                    __wasm_export_call.on_error(&error);
+                    ::wasm_bindgen_utils::result::FromEncodedError::from_encoded_error(error)
+                }
+            })
+            .into()
+        });
+        assert_eq!(result, expected);
+
+        // marked abort signal param
+        let function: ItemFn = parse_quote!(
+            pub async fn some_name(
+                #[wasm_export(abort_signal, js_name = "abortSignal")] signal: JsValue,
+            ) -> Result<SomeType, Error> {
+            }
+        );
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Standalone(&function),
+            &WasmExportFunctionBuilderConfig::default(),
+        );
+        let abortable = quote!(::wasm_bindgen_utils::abort::abortable);
+        let expected: Block = parse_quote!({
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call =
+                ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+            let __wasm_export_abort_signal =
+                ::wasm_bindgen_utils::abort::abort_signal_of("abortSignal", ::core::option::Option::Some(&signal));
+            (match __wasm_export_abort_signal {
+                ::core::result::Result::Ok(__wasm_export_abort_signal) => #abortable(some_name(signal), __wasm_export_abort_signal, ::core::option::Option::None)
+                    .await
+                    .encode_errors(|error| __wasm_export_call.on_error(error), |_| {}),
+                ::core::result::Result::Err(error) => {
+                    __wasm_export_call.on_error(&error);
+                    ::wasm_bindgen_utils::result::FromEncodedError::from_encoded_error(error)
+                }
+            })
+            .into()
+        });
+        assert_eq!(result, expected);
+
+        // cancellable
+        let config = WasmExportFunctionBuilderConfig {
+            cancellable: Some(Span::call_site()),
+            ..Default::default()
+        };
+        let function: ItemFn = parse_quote!(
+            pub async fn some_name(arg1: String) -> Result<SomeType, Error> {}
+        );
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Standalone(&function),
+            &config,
+        );
+        let expected: Block = parse_quote!({
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call =
+                ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+            #abortable(some_name(arg1), __wasm_export_signal, ::core::option::Option::None)
+                .await
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        });
+        assert_eq!(result, expected);
+
+        // abort signal params of non async functions are passed as they are
+        let function: ItemFn = parse_quote!(
+            pub fn some_name(signal: AbortSignal) -> Result<SomeType, Error> {}
+        );
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Standalone(&function),
+            &WasmExportFunctionBuilderConfig::default(),
+        );
+        let expected: Block = parse_quote!({
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call =
+                ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+            some_name(signal)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        });
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_build_export_function_cancellable() {
+        let function: ItemFn = parse_quote!(
+            pub async fn some_name(arg1: String) -> Result<SomeType, Error> {}
+        );
+        let config = WasmExportFunctionBuilderConfig {
+            return_type: parse_quote!(SomeType),
+            cancellable: Some(Span::call_site()),
+            ..Default::default()
+        };
+        let result = WasmExportFunctionBuilder::build_export_function(&function, config).unwrap();
+        let expected: Punctuated<FnArg, Comma> = parse_quote!(
+            arg1: String,
+            #[wasm_bindgen(
+                js_name = "signal",
+                param_description = "An optional signal to abort the call with"
+            )]
+            __wasm_export_signal: ::core::option::Option<::wasm_bindgen_utils::abort::AbortSignal>
+        );
+        assert_eq!(result.sig.inputs, expected);
+
+        // not async
+        let function: ItemFn = parse_quote!(
+            pub fn some_name(arg1: String) -> Result<SomeType, Error> {}
+        );
+        let config = WasmExportFunctionBuilderConfig {
+            cancellable: Some(Span::call_site()),
+            ..Default::default()
+        };
+        let err = WasmExportFunctionBuilder::build_export_function(&function, config).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`cancellable` attribute can only be used for async functions that do not return a stream or an iterator"
+        );
+    }
+
+    #[test]
+    fn test_build_fn_body_unified_deprecated() {
+        let function: ItemFn = parse_quote!(
+            pub fn some_name(arg1: String) -> Result<SomeType, Error> {}
+        );
+        let config = WasmExportFunctionBuilderConfig {
+            forward_attrs: vec![parse_quote!(js_name = "someName")],
+            deprecated: Some(parse_quote!(deprecated(use_instead = "otherName", warn))),
+            ..Default::default()
+        };
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Standalone(&function),
+            &config,
+        );
+        let msg = "`someName` is deprecated. Use `otherName` instead.";
+        let expected: Block = parse_quote!({
+                    static __WASM_EXPORT_DEPRECATED: ::std::sync::Once = ::std::sync::Once::new();
+                    __WASM_EXPORT_DEPRECATED.call_once(|| ::wasm_bindgen_utils::deprecation::warn_deprecated(#msg));
+                    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+        let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("someName");
+                    some_name(arg1).encode_errors(|error| __wasm_export_call.on_error(error), |_| {}).into()
+                });
+        assert_eq!(result, expected);
+
+        // without warn key
+        let config = WasmExportFunctionBuilderConfig {
+            deprecated: Some(parse_quote!(deprecated)),
+            ..Default::default()
+        };
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Standalone(&function),
+            &config,
+        );
+        let expected: Block = parse_quote!({
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call =
+                ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+            some_name(arg1)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        });
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_build_fn_body_unified_param_defaults() {
+        let function: ItemFn = parse_quote!(
+            pub fn some_name(
+                arg1: String,
+                #[wasm_export(default = 10)] arg2: u32,
+                #[wasm_export(js_name = "argThree", default = SomeType::new("a"))] arg3: SomeType,
+            ) -> Result<SomeType, Error> {
+            }
+        );
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Standalone(&function),
+            &WasmExportFunctionBuilderConfig::default(),
+        );
+        let on_error = quote!(|error| __wasm_export_call.on_error(error));
+        let expected: Block = parse_quote!({
+                    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+        let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+                    let arg2 = arg2.unwrap_or_else(|| 10);
+                    let arg3 = arg3.unwrap_or_else(|| SomeType::new("a"));
+                    some_name(arg1, arg2, arg3).encode_errors(#on_error, |_| {}).into()
+                });
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_build_fn_body_unified_map_ok() {
+        let function: ItemFn = parse_quote!(
+            pub async fn some_name(arg1: String) -> Result<SomeType, Error> {}
+        );
+        let config = WasmExportFunctionBuilderConfig {
+            map_ok: Some(parse_quote!(some_mod::to_js_type)),
+            ..Default::default()
+        };
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Standalone(&function),
+            &config,
+        );
+        let on_error = quote!(|error| __wasm_export_call.on_error(error));
+        let call_expr = quote!(some_name(arg1).await.map_ok(some_mod::to_js_type).encode_errors(#on_error, |_| {}));
+        let expected: Block = parse_quote!({
+                    use ::wasm_bindgen_utils::result::{EncodeErrors as _, MapOk as _};
+        let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+                    ::wasm_bindgen_utils::result::WasmEncodedResult::from(#call_expr).into()
+                });
+        assert_eq!(result, expected);
+
+        // diagnosed kind
+        let config = WasmExportFunctionBuilderConfig {
+            return_kind: ReturnKind::Diagnosed,
+            map_ok: Some(parse_quote!(to_js_type)),
+            ..Default::default()
+        };
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Standalone(&function),
+            &config,
+        );
+        let call_expr =
+            quote!(some_name(arg1).await.map_ok(to_js_type).encode_errors(#on_error, |_| {}));
+        let expected: Block = parse_quote!({
+                    use ::wasm_bindgen_utils::result::{EncodeErrors as _, MapOk as _};
+        let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+                    ::wasm_bindgen_utils::result::WasmEncodedDiagnosedResult::from(#call_expr).into()
+                });
+        assert_eq!(result, expected);
+
+        // with preserve_js_class
+        let config = WasmExportFunctionBuilderConfig {
+            preserve_js_class: Some(Span::call_site()),
+            map_ok: Some(parse_quote!(to_js_type)),
+            ..Default::default()
+        };
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Standalone(&function),
+            &config,
+        );
+        let call_expr =
+            quote!(some_name(arg1).await.map_ok(to_js_type).encode_errors(#on_error, |_| {}));
+        let expected = quote!(let result = #call_expr.into(););
+        assert!(result
+            .to_token_stream()
+            .to_string()
+            .contains(&expected.to_string()));
+    }
+
+    #[test]
+    fn test_build_fn_body_unified_variadic() {
+        let method: ImplItemFn = parse_quote!(
+            pub async fn some_name(
+                &self,
+                arg1: String,
+                #[wasm_export(js_name = "someItems", variadic)] items: Vec<SomeType>,
+            ) -> Result<SomeType, Error> {
+            }
+        );
+        let config = WasmExportFunctionBuilderConfig {
+            forward_attrs: vec![parse_quote!(js_name = "someName")],
+            ..Default::default()
+        };
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Method(&method),
+            &config,
+        );
+        let on_error = quote!(|error| __wasm_export_call.on_error(error));
+        let expected: Block = parse_quote!({
+                    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+        let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("someName");
+                    let items = ::wasm_bindgen_utils::result::decode_variadic::<SomeType>("someItems", items);
+                    (match items {
+                        ::core::result::Result::Ok(items) => self.some_name(arg1, items).await.encode_errors(#on_error, |_| {}),
+                        ::core::result::Result::Err(error) => {
+                            __wasm_export_call.on_error(&error);
+                            ::wasm_bindgen_utils::result::FromEncodedError::from_encoded_error(error)
+                        }
+                    })
+                    .into()
+                });
+        assert_eq!(result, expected);
+
+        // with error context
+        let function: ItemFn = parse_quote!(
+            pub fn some_name(#[wasm_export(variadic)] items: Vec<u8>) -> Result<SomeType, Error> {}
+        );
+        let config = WasmExportFunctionBuilderConfig {
+            error_context: Some(Span::call_site()),
+            ..Default::default()
+        };
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Standalone(&function),
+            &config,
+        );
+        let context = WasmExportFunctionBuilder::build_error_context(
+            &FunctionType::Standalone(&function),
+            "some_name__wasm_export",
+            &config,
+        );
+        let on_error = quote!(|error| {
+            error.context = ::core::option::Option::Some(#context);
+            __wasm_export_call.on_error(error);
+        });
+        let on_warning = quote!(|warning| warning.context = ::core::option::Option::Some(#context));
+        let expected: Block = parse_quote!({
+                    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+        let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+                    let items = ::wasm_bindgen_utils::result::decode_variadic::<u8>("items", items);
+                    (match items {
+                        ::core::result::Result::Ok(items) => some_name(items).encode_errors(#on_error, #on_warning),
+                        ::core::result::Result::Err(mut error) => {
+                            error.context = ::core::option::Option::Some(#context);
+                            __wasm_export_call.on_error(&error);
+                            ::wasm_bindgen_utils::result::FromEncodedError::from_encoded_error(error)
+                        }
+                    })
+                    .into()
+                });
+        assert_eq!(result, expected);
+
+        // JsValue items are not decoded
+        let function: ItemFn = parse_quote!(
+            pub fn some_name(
+                #[wasm_export(variadic)] items: Vec<JsValue>,
+            ) -> Result<SomeType, Error> {
+            }
+        );
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Standalone(&function),
+            &WasmExportFunctionBuilderConfig::default(),
+        );
+        let on_error = quote!(|error| __wasm_export_call.on_error(error));
+        let expected: Block = parse_quote!({
+                    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+        let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+                    some_name(items).encode_errors(#on_error, |_| {}).into()
+                });
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_build_fn_body_unified_from_js() {
+        let function: ItemFn = parse_quote!(
+            pub fn some_name(
+                #[wasm_export(from_js = Address::from_hex, js_type = "string")] owner: Address,
+                arg2: u8,
+                #[wasm_export(js_name = "amount", from_js = parse_u256)] arg3: U256,
+            ) -> Result<SomeType, Error> {
+            }
+        );
+        let result = WasmExportFunctionBuilder::build_fn_body_unified(
+            FunctionType::Standalone(&function),
+            &WasmExportFunctionBuilderConfig::default(),
+        );
+        let on_error = quote!(|error| __wasm_export_call.on_error(error));
+        let expected: Block = parse_quote!({
+                    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+        let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("some_name__wasm_export");
+                    let owner = ::wasm_bindgen_utils::result::convert_from_js("owner", owner, Address::from_hex);
+                    let arg3 = ::wasm_bindgen_utils::result::convert_from_js("amount", arg3, parse_u256);
+                    (match (owner, arg3) {
+                        (::core::result::Result::Ok(owner), ::core::result::Result::Ok(arg3)) => some_name(owner, arg2, arg3).encode_errors(#on_error, |_| {}),
+                        (::core::result::Result::Err(error), _) | (_, ::core::result::Result::Err(error)) => {
+                            __wasm_export_call.on_error(&error);
+                            ::wasm_bindgen_utils::result::FromEncodedError::from_encoded_error(error)
+                        }
+                    })
+                    .into()
+                });
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_build_param_conversions() {
+        let inputs: Punctuated<FnArg, Comma> = parse_quote!(
+            &self,
+            #[wasm_export(from_js = parse_address)] owner: Address,
+            arg: u8,
+            #[wasm_export(variadic)] items: Vec<SomeType>
+        );
+        let result = WasmExportFunctionBuilder::build_param_conversions(
+            &inputs,
+            &WasmExportAttrs::default_crate_path(),
+        )
+        .into_iter()
+        .map(|(ident, expr)| (ident.to_string(), expr.to_string()))
+        .collect::<Vec<_>>();
+        let expected = vec![
+            (
+                "owner".to_string(),
+                quote!(::wasm_bindgen_utils::result::convert_from_js(
+                    "owner",
+                    owner,
+                    parse_address
+                ))
+                .to_string(),
+            ),
+            (
+                "items".to_string(),
+                quote!(::wasm_bindgen_utils::result::decode_variadic::<SomeType>(
+                    "items", items
+                ))
+                .to_string(),
+            ),
+        ];
+        assert_eq!(result, expected);
+
+        let inputs: Punctuated<FnArg, Comma> =
+            parse_quote!(arg: u8, #[wasm_export(variadic)] items: Vec<JsValue>);
+        assert!(WasmExportFunctionBuilder::build_param_conversions(
+            &inputs,
+            &WasmExportAttrs::default_crate_path()
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_build_deprecated_doc() {
+        let result = WasmExportFunctionBuilder::build_deprecated_doc(None, true);
+        assert!(result.is_empty());
+
+        let deprecated: DeprecatedAttr = parse_quote!(deprecated(since = "1.0.0"));
+        let result = WasmExportFunctionBuilder::build_deprecated_doc(Some(&deprecated), false);
+        let expected: Vec<syn::Attribute> =
+            vec![parse_quote!(#[doc = " @deprecated Since 1.0.0."])];
+        assert_eq!(result, expected);
+
+        let deprecated: DeprecatedAttr = parse_quote!(deprecated);
+        let result = WasmExportFunctionBuilder::build_deprecated_doc(Some(&deprecated), true);
+        let expected: Vec<syn::Attribute> = vec![
+            parse_quote!(#[doc = ""]),
+            parse_quote!(#[doc = " @deprecated"]),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_build_args_summary() {
+        let function: ItemFn = parse_quote!(
+            pub fn some_name(
+                &self,
+                arg1: &'a str,
+                #[wasm_export(js_name = "secondArg")] second_arg: Vec<Option<u8>>,
+                (arg3, arg4): (String, [u8; 32]),
+                mut arg5: HashMap<String, some_mod::SomeType>,
+            ) -> Result<SomeType, Error> {
+            }
+        );
+        let result = WasmExportFunctionBuilder::build_args_summary(&function.sig.inputs);
+        let expected = vec![
+            "arg1: &'a str",
+            "secondArg: Vec<Option<u8>>",
+            "(arg3, arg4): (String, [u8; 32])",
+            "mut arg5: HashMap<String, some_mod::SomeType>",
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_find_js_name() {
+        let metas: Vec<Meta> = vec![
+            parse_quote!(some_attr),
+            parse_quote!(js_name = "someName"),
+            parse_quote!(js_name = "otherName"),
+        ];
+        assert_eq!(
+            WasmExportFunctionBuilder::find_js_name(&metas),
+            Some("someName".to_string())
+        );
+
+        let metas: Vec<Meta> = vec![parse_quote!(some_attr), parse_quote!(js_name)];
+        assert_eq!(WasmExportFunctionBuilder::find_js_name(&metas), None);
+    }
+
+    #[test]
+    fn test_collect_function_arguments() {
+        // without self argument
+        let stream = TokenStream::from_str(r#"arg1: String, arg2: u8"#).unwrap();
+        let inputs = Punctuated::<FnArg, Comma>::parse_terminated
+            .parse2(stream)
+            .unwrap();
+        let result = WasmExportFunctionBuilder::collect_function_arguments(&inputs);
+        let expected = (
+            SelfReceiver::Static,
+            vec![
+                TokenStream::from_str(r#"arg1"#).unwrap(),
+                TokenStream::from_str(r#"arg2"#).unwrap(),
+            ],
+        );
+        assert_eq!(result.0, expected.0);
+        assert_eq!(result.1.len(), expected.1.len());
+        assert!(result
+            .1
+            .iter()
+            .zip(expected.1.iter())
+            .all(|(res, exp)| { res.to_string() == exp.to_string() }));
+
+        // with self argument
+        let stream = TokenStream::from_str(r#"&self, arg1: String"#).unwrap();
+        let inputs = Punctuated::<FnArg, Comma>::parse_terminated
+            .parse2(stream)
+            .unwrap();
+        let result = WasmExportFunctionBuilder::collect_function_arguments(&inputs);
+        let expected = (
+            SelfReceiver::Instance,
+            vec![TokenStream::from_str(r#"arg1"#).unwrap()],
+        );
+        assert_eq!(result.0, expected.0);
+        assert_eq!(result.1.len(), expected.1.len());
+        assert!(result
+            .1
+            .iter()
+            .zip(expected.1.iter())
+            .all(|(res, exp)| { res.to_string() == exp.to_string() }));
+    }
+
+    #[test]
+    fn test_populate_name() {
+        let org_fn_ident = Ident::new("some_name", Span::call_site());
+        let result = WasmExportFunctionBuilder::populate_name(&org_fn_ident);
+        assert_eq!(result.to_string(), "some_name__wasm_export");
+    }
+
+    #[test]
+    fn test_export_ident() {
+        let org_fn_ident = Ident::new("some_name", Span::call_site());
+        let mut config = WasmExportFunctionBuilderConfig {
+            forward_attrs: vec![parse_quote!(js_name = "some-name.json")],
+            ..Default::default()
+        };
+        let result = WasmExportFunctionBuilder::export_ident(&org_fn_ident, &config);
+        assert_eq!(result.to_string(), "some_name__wasm_export");
+
+        // instances of generic functions are appended by their js name
+        config.instance = Some(ExportInstance {
+            type_args: vec![parse_quote!(Json)],
+            self_type: None,
+        });
+        let result = WasmExportFunctionBuilder::export_ident(&org_fn_ident, &config);
+        assert_eq!(result.to_string(), "some_name__wasm_export_some_name_json");
+    }
+
+    #[test]
+    fn test_process_function_parameters_basic() {
+        // Test basic parameter processing without wasm_export attributes
+        let stream = TokenStream::from_str(r#"arg1: String, arg2: u32"#).unwrap();
+        let inputs = Punctuated::<FnArg, Comma>::parse_terminated
+            .parse2(stream)
+            .unwrap();
+        let result = WasmExportFunctionBuilder::process_function_parameters(
+            &inputs,
+            &RustDocs::default(),
+            &WasmExportAttrs::default_crate_path(),
+        )
+        .unwrap();
+
+        assert_eq!(result.0, SelfReceiver::Static); // no self receiver
+        assert_eq!(result.1.len(), 2); // processed inputs
+        assert_eq!(result.2.len(), 2); // cleaned inputs
+
+        // Both processed and cleaned should be identical when no wasm_export attrs
+        assert_eq!(result.1.len(), result.2.len());
+        // Check that first parameter is the same
+        if let (FnArg::Typed(processed), FnArg::Typed(cleaned)) = (&result.1[0], &result.2[0]) {
+            assert_eq!(processed.attrs.len(), cleaned.attrs.len());
+            assert_eq!(
+                processed.pat.to_token_stream().to_string(),
+                cleaned.pat.to_token_stream().to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_process_function_parameters_with_param_description() {
+        // Test parameter processing with param_description
+        let stream = TokenStream::from_str(
+            r#"#[wasm_export(param_description = "first param")] arg1: String, arg2: u32"#,
+        )
+        .unwrap();
+        let inputs = Punctuated::<FnArg, Comma>::parse_terminated
+            .parse2(stream)
+            .unwrap();
+        let result = WasmExportFunctionBuilder::process_function_parameters(
+            &inputs,
+            &RustDocs::default(),
+            &WasmExportAttrs::default_crate_path(),
+        )
+        .unwrap();
+
+        assert_eq!(result.0, SelfReceiver::Static); // no self receiver
+        assert_eq!(result.1.len(), 2); // processed inputs
+        assert_eq!(result.2.len(), 2); // cleaned inputs
+
+        // Processed should have wasm_bindgen attribute, cleaned should not
+        let processed_first = &result.1[0];
+        let cleaned_first = &result.2[0];
+
+        if let (FnArg::Typed(processed_pat), FnArg::Typed(cleaned_pat)) =
+            (processed_first, cleaned_first)
+        {
+            // Processed should have wasm_bindgen attribute
+            assert!(processed_pat
+                .attrs
+                .iter()
+                .any(|attr| attr.path().is_ident("wasm_bindgen")));
+            // Cleaned should not have any attributes
+            assert!(cleaned_pat.attrs.is_empty());
+        } else {
+            panic!("Expected FnArg::Typed");
+        }
+    }
+
+    #[test]
+    fn test_process_function_parameters_with_doc_param_description() {
+        // Test that rustdoc param descriptions are used unless explicitly specified
+        let stream = TokenStream::from_str(
+            r#"#[wasm_export(param_description = "explicit")] arg1: String, arg2: u32, arg3: u8"#,
+        )
+        .unwrap();
+        let inputs = Punctuated::<FnArg, Comma>::parse_terminated
+            .parse2(stream)
+            .unwrap();
+        let docs = RustDocs {
+            params: vec![
+                ("arg1".to_string(), "from docs".to_string()),
+                ("arg2".to_string(), "second from docs".to_string()),
+            ],
+            ..Default::default()
+        };
+        let result = WasmExportFunctionBuilder::process_function_parameters(
+            &inputs,
+            &docs,
+            &WasmExportAttrs::default_crate_path(),
+        )
+        .unwrap();
+
+        let expected: Punctuated<FnArg, Comma> = parse_quote!(
+            #[wasm_bindgen(param_description = "explicit")] arg1: String,
+            #[wasm_bindgen(param_description = "second from docs")] arg2: u32,
+            arg3: u8
+        );
+        assert_eq!(result.1, expected);
+    }
+
+    #[test]
+    fn test_process_function_parameters_optional() {
+        // Test that optional params with unchecked_param_type are converted
+        let stream = TokenStream::from_str(
+            r#"
+            arg1: String,
+            #[wasm_export(optional)] arg2: Option<u32>,
+            #[wasm_export(optional, unchecked_param_type = "SomeType")] arg3: JsValue,
+            arg4: Option<u8>
+            "#,
+        )
+        .unwrap();
+        let inputs = Punctuated::<FnArg, Comma>::parse_terminated
+            .parse2(stream)
+            .unwrap();
+        let result = WasmExportFunctionBuilder::process_function_parameters(
+            &inputs,
+            &RustDocs::default(),
+            &WasmExportAttrs::default_crate_path(),
+        )
+        .unwrap();
+
+        let expected: Punctuated<FnArg, Comma> = parse_quote!(
+            arg1: String,
+            arg2: Option<u32>,
+            #[wasm_bindgen(unchecked_param_type = "SomeType | undefined")] arg3: JsValue,
+            arg4: Option<u8>
+        );
+        assert_eq!(result.1, expected);
+        let expected: Punctuated<FnArg, Comma> = parse_quote!(
+            arg1: String,
+            arg2: Option<u32>,
+            arg3: JsValue,
+            arg4: Option<u8>
+        );
+        assert_eq!(result.2, expected);
+    }
+
+    #[test]
+    fn test_process_function_parameters_abort_signal() {
+        // Test that the params marked as abort signal are declared as such in TS
+        let stream = TokenStream::from_str(
+            r#"
+            #[wasm_export(abort_signal)] arg1: JsValue,
+            #[wasm_export(abort_signal, unchecked_param_type = "SomeSignal")] arg2: JsValue,
+            #[wasm_export(abort_signal)] arg3: Option<AbortSignal>,
+            #[wasm_export(abort_signal, optional)] arg4: JsValue
+            "#,
+        )
+        .unwrap();
+        let inputs = Punctuated::<FnArg, Comma>::parse_terminated
+            .parse2(stream)
+            .unwrap();
+        let result = WasmExportFunctionBuilder::process_function_parameters(
+            &inputs,
+            &RustDocs::default(),
+            &WasmExportAttrs::default_crate_path(),
+        )
+        .unwrap();
+
+        let expected: Punctuated<FnArg, Comma> = parse_quote!(
+            #[wasm_bindgen(unchecked_param_type = "AbortSignal | undefined")] arg1: JsValue,
+            #[wasm_bindgen(unchecked_param_type = "SomeSignal")] arg2: JsValue,
+            arg3: Option<AbortSignal>,
+            #[wasm_bindgen(unchecked_param_type = "AbortSignal | undefined")] arg4: JsValue
+        );
+        assert_eq!(result.1, expected);
+    }
+
+    #[test]
+    fn test_process_function_parameters_optional_errors() {
+        // Test optional param followed by a required param
+        let stream = TokenStream::from_str(
+            r#"#[wasm_export(optional)] arg1: Option<u32>, arg2: Option<u8>, arg3: String"#,
+        )
+        .unwrap();
+        let inputs = Punctuated::<FnArg, Comma>::parse_terminated
+            .parse2(stream)
+            .unwrap();
+        let err = WasmExportFunctionBuilder::process_function_parameters(
+            &inputs,
+            &RustDocs::default(),
+            &WasmExportAttrs::default_crate_path(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "optional parameters must be trailing, they cannot be followed by required parameters"
+        );
+
+        // Test optional param that is not Option<T> without unchecked_param_type
+        let stream = TokenStream::from_str(r#"#[wasm_export(optional)] arg1: u32"#).unwrap();
+        let inputs = Punctuated::<FnArg, Comma>::parse_terminated
+            .parse2(stream)
+            .unwrap();
+        let err = WasmExportFunctionBuilder::process_function_parameters(
+            &inputs,
+            &RustDocs::default(),
+            &WasmExportAttrs::default_crate_path(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`optional` attribute requires an `Option<T>` parameter type or `unchecked_param_type` attribute"
+        );
+
+        // Test duplicate and invalid optional attr
+        let attr: syn::Attribute = syn::parse_quote!(#[wasm_export(optional, optional)]);
+        let err = WasmExportFunctionBuilder::process_parameter_wasm_export_attr(&attr).unwrap_err();
+        assert_eq!(err.to_string(), "duplicate `optional` attribute");
+
+        let attr: syn::Attribute = syn::parse_quote!(#[wasm_export(optional = true)]);
+        let err = WasmExportFunctionBuilder::process_parameter_wasm_export_attr(&attr).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unexpected token in attribute, `optional` attribute does not take any extra tokens or arguments"
+        );
+    }
+
+    #[test]
+    fn test_process_function_parameters_default() {
+        let stream = TokenStream::from_str(
+            r#"
+            arg1: String,
+            #[wasm_export(default = 10)] arg2: u32,
+            #[wasm_export(param_description = "some desc", default = Vec::new())] arg3: Vec<u8>,
+            #[wasm_export(default = SomeType::default())] arg4: SomeType
+            "#,
+        )
+        .unwrap();
+        let inputs = Punctuated::<FnArg, Comma>::parse_terminated
+            .parse2(stream)
+            .unwrap();
+        let result = WasmExportFunctionBuilder::process_function_parameters(
+            &inputs,
+            &RustDocs::default(),
+            &WasmExportAttrs::default_crate_path(),
+        )
+        .unwrap();
+
+        let expected: Punctuated<FnArg, Comma> = parse_quote!(
+            arg1: String,
+            #[wasm_bindgen(param_description = "Defaults to `10`.")] arg2: ::core::option::Option<u32>,
+            #[wasm_bindgen(param_description = "some desc. Defaults to `Vec::new()`.")] arg3: ::core::option::Option<Vec<u8>>,
+            #[wasm_bindgen(param_description = "Defaults to `SomeType::default()`.")] arg4: ::core::option::Option<SomeType>
+        );
+        assert_eq!(result.1, expected);
+        let expected: Punctuated<FnArg, Comma> = parse_quote!(
+            arg1: String,
+            arg2: u32,
+            arg3: Vec<u8>,
+            arg4: SomeType
+        );
+        assert_eq!(result.2, expected);
+    }
+
+    #[test]
+    fn test_process_function_parameters_default_errors() {
+        // Test default on an Option<T> param
+        let stream =
+            TokenStream::from_str(r#"#[wasm_export(default = 1)] arg1: Option<u32>"#).unwrap();
+        let inputs = Punctuated::<FnArg, Comma>::parse_terminated
+            .parse2(stream)
+            .unwrap();
+        let err = WasmExportFunctionBuilder::process_function_parameters(
+            &inputs,
+            &RustDocs::default(),
+            &WasmExportAttrs::default_crate_path(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`default` attribute cannot be used on `Option<T>` parameters"
         );
-        let expected: Block = parse_quote!({ some_name((arg1, arg2)).into() });
-        assert_eq!(result, expected);
 
-        // sync function with preserve class
-        let function: ItemFn = parse_quote!(
-            pub fn some_name((arg1, arg2): (String, u8)) -> Result<SomeType, Error> {
-                Ok(SomeType::new())
-            }
+        // Test default on a destructured param
+        let stream =
+            TokenStream::from_str(r#"#[wasm_export(default = (1, 2))] (a, b): (u8, u8)"#).unwrap();
+        let inputs = Punctuated::<FnArg, Comma>::parse_terminated
+            .parse2(stream)
+            .unwrap();
+        let err = WasmExportFunctionBuilder::process_function_parameters(
+            &inputs,
+            &RustDocs::default(),
+            &WasmExportAttrs::default_crate_path(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`default` attribute requires the parameter pattern to be an identifier"
         );
-        let result = WasmExportFunctionBuilder::build_fn_body_unified(
-            FunctionType::Standalone(&function),
-            true,
+
+        // Test duplicate and invalid default attr
+        let attr: syn::Attribute = syn::parse_quote!(#[wasm_export(default = 1, default = 2)]);
+        let err = WasmExportFunctionBuilder::process_parameter_wasm_export_attr(&attr).unwrap_err();
+        assert_eq!(err.to_string(), "duplicate `default` attribute");
+
+        let attr: syn::Attribute = syn::parse_quote!(#[wasm_export(default)]);
+        let err = WasmExportFunctionBuilder::process_parameter_wasm_export_attr(&attr).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected a value for this attribute: `default = ...` and it must be an expression"
         );
-        let expected: Block = parse_quote!({
-            use js_sys::{Reflect, Object};
-            let obj = Object::new();
-            let result = some_name((arg1, arg2)).into();
-            match result {
-                Ok(value) => {
-                    Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
-                    Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED).unwrap();
-                }
-                Err(error) => {
-                    let wasm_error: WasmEncodedError = error.into();
-                    Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED).unwrap();
-                    Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into()).unwrap();
-                }
-            };
-            obj.into()
-        });
-        assert_eq!(result, expected);
     }
 
     #[test]
-    fn test_collect_function_arguments() {
-        // without self argument
-        let stream = TokenStream::from_str(r#"arg1: String, arg2: u8"#).unwrap();
+    fn test_process_function_parameters_variadic() {
+        let stream = TokenStream::from_str(
+            r#"
+            arg1: String,
+            #[wasm_export(optional, unchecked_param_type = "SomeType")] arg2: JsValue,
+            #[wasm_export(variadic)] items: Vec<SomeItem>
+            "#,
+        )
+        .unwrap();
         let inputs = Punctuated::<FnArg, Comma>::parse_terminated
             .parse2(stream)
             .unwrap();
-        let result = WasmExportFunctionBuilder::collect_function_arguments(&inputs);
-        let expected = (
-            SelfReceiver::Static,
-            vec![
-                TokenStream::from_str(r#"arg1"#).unwrap(),
-                TokenStream::from_str(r#"arg2"#).unwrap(),
-            ],
+        let result = WasmExportFunctionBuilder::process_function_parameters(
+            &inputs,
+            &RustDocs::default(),
+            &WasmExportAttrs::default_crate_path(),
+        )
+        .unwrap();
+
+        let expected: Punctuated<FnArg, Comma> = parse_quote!(
+            arg1: String,
+            #[wasm_bindgen(unchecked_param_type = "SomeType | undefined")] arg2: JsValue,
+            #[wasm_bindgen(unchecked_param_type = "SomeItem[]")] items: ::std::vec::Vec<::wasm_bindgen_utils::prelude::JsValue>
         );
-        assert_eq!(result.0, expected.0);
-        assert_eq!(result.1.len(), expected.1.len());
-        assert!(result
-            .1
-            .iter()
-            .zip(expected.1.iter())
-            .all(|(res, exp)| { res.to_string() == exp.to_string() }));
+        assert_eq!(result.1, expected);
+        let expected: Punctuated<FnArg, Comma> = parse_quote!(
+            arg1: String,
+            arg2: JsValue,
+            items: Vec<SomeItem>
+        );
+        assert_eq!(result.2, expected);
 
-        // with self argument
-        let stream = TokenStream::from_str(r#"&self, arg1: String"#).unwrap();
+        // explicit unchecked_param_type and JsValue items are kept as is
+        let stream = TokenStream::from_str(
+            r#"
+            #[wasm_export(variadic, unchecked_param_type = "Uint8Array[]")] items: Vec<Vec<u8>>
+            "#,
+        )
+        .unwrap();
         let inputs = Punctuated::<FnArg, Comma>::parse_terminated
             .parse2(stream)
             .unwrap();
-        let result = WasmExportFunctionBuilder::collect_function_arguments(&inputs);
-        let expected = (
-            SelfReceiver::Instance,
-            vec![TokenStream::from_str(r#"arg1"#).unwrap()],
+        let result = WasmExportFunctionBuilder::process_function_parameters(
+            &inputs,
+            &RustDocs::default(),
+            &WasmExportAttrs::default_crate_path(),
+        )
+        .unwrap();
+        let expected: Punctuated<FnArg, Comma> = parse_quote!(
+            #[wasm_bindgen(unchecked_param_type = "Uint8Array[]")] items: ::std::vec::Vec<::wasm_bindgen_utils::prelude::JsValue>
         );
-        assert_eq!(result.0, expected.0);
-        assert_eq!(result.1.len(), expected.1.len());
-        assert!(result
-            .1
-            .iter()
-            .zip(expected.1.iter())
-            .all(|(res, exp)| { res.to_string() == exp.to_string() }));
+        assert_eq!(result.1, expected);
+
+        let stream =
+            TokenStream::from_str(r#"#[wasm_export(variadic)] items: Vec<JsValue>"#).unwrap();
+        let inputs = Punctuated::<FnArg, Comma>::parse_terminated
+            .parse2(stream)
+            .unwrap();
+        let result = WasmExportFunctionBuilder::process_function_parameters(
+            &inputs,
+            &RustDocs::default(),
+            &WasmExportAttrs::default_crate_path(),
+        )
+        .unwrap();
+        let expected: Punctuated<FnArg, Comma> = parse_quote!(items: Vec<JsValue>);
+        assert_eq!(result.1, expected);
     }
 
     #[test]
-    fn test_populate_name() {
-        let org_fn_ident = Ident::new("some_name", Span::call_site());
-        let result = WasmExportFunctionBuilder::populate_name(&org_fn_ident);
-        assert_eq!(result.to_string(), "some_name__wasm_export");
+    fn test_process_function_parameters_variadic_errors() {
+        let cases = [
+            (
+                r#"#[wasm_export(variadic)] items: Vec<u8>, arg: String"#,
+                "`variadic` attribute can only be used on the last parameter",
+            ),
+            (
+                r#"#[wasm_export(variadic)] items: u8"#,
+                "`variadic` attribute requires a `Vec<T>` parameter type",
+            ),
+            (
+                r#"#[wasm_export(variadic, default = vec![])] items: Vec<u8>"#,
+                "`variadic` attribute cannot be used together with `optional` or `default` attributes",
+            ),
+            (
+                r#"#[wasm_export(variadic)] (a, b): (Vec<u8>, Vec<u8>)"#,
+                "`variadic` attribute requires the parameter pattern to be an identifier",
+            ),
+        ];
+        for (stream, msg) in cases {
+            let inputs = Punctuated::<FnArg, Comma>::parse_terminated
+                .parse2(TokenStream::from_str(stream).unwrap())
+                .unwrap();
+            let err = WasmExportFunctionBuilder::process_function_parameters(
+                &inputs,
+                &RustDocs::default(),
+                &WasmExportAttrs::default_crate_path(),
+            )
+            .unwrap_err();
+            assert_eq!(err.to_string(), msg);
+        }
+
+        // Test duplicate and invalid variadic attr
+        let attr: syn::Attribute = syn::parse_quote!(#[wasm_export(variadic, variadic)]);
+        let err = WasmExportFunctionBuilder::process_parameter_wasm_export_attr(&attr).unwrap_err();
+        assert_eq!(err.to_string(), "duplicate `variadic` attribute");
+
+        let attr: syn::Attribute = syn::parse_quote!(#[wasm_export(variadic = true)]);
+        let err = WasmExportFunctionBuilder::process_parameter_wasm_export_attr(&attr).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unexpected token in attribute, `variadic` attribute does not take any extra tokens or arguments"
+        );
     }
 
     #[test]
-    fn test_process_function_parameters_basic() {
-        // Test basic parameter processing without wasm_export attributes
-        let stream = TokenStream::from_str(r#"arg1: String, arg2: u32"#).unwrap();
+    fn test_process_function_parameters_from_js() {
+        let stream = TokenStream::from_str(
+            r#"
+            #[wasm_export(from_js = Address::from_hex, js_type = "string")] owner: Address,
+            #[wasm_export(from_js = parse_u256)] amount: U256,
+            #[wasm_export(optional, from_js = parse_u256, js_type = "string")] limit: Option<U256>
+            "#,
+        )
+        .unwrap();
         let inputs = Punctuated::<FnArg, Comma>::parse_terminated
             .parse2(stream)
             .unwrap();
-        let result = WasmExportFunctionBuilder::process_function_parameters(&inputs).unwrap();
+        let result = WasmExportFunctionBuilder::process_function_parameters(
+            &inputs,
+            &RustDocs::default(),
+            &WasmExportAttrs::default_crate_path(),
+        )
+        .unwrap();
 
-        assert_eq!(result.0, SelfReceiver::Static); // no self receiver
-        assert_eq!(result.1.len(), 2); // processed inputs
-        assert_eq!(result.2.len(), 2); // cleaned inputs
+        let expected: Punctuated<FnArg, Comma> = parse_quote!(
+            #[wasm_bindgen(unchecked_param_type = "string")] owner: ::wasm_bindgen_utils::prelude::JsValue,
+            amount: ::wasm_bindgen_utils::prelude::JsValue,
+            #[wasm_bindgen(unchecked_param_type = "string | undefined")] limit: ::wasm_bindgen_utils::prelude::JsValue
+        );
+        assert_eq!(result.1, expected);
+        let expected: Punctuated<FnArg, Comma> = parse_quote!(
+            owner: Address,
+            amount: U256,
+            limit: Option<U256>
+        );
+        assert_eq!(result.2, expected);
+    }
 
-        // Both processed and cleaned should be identical when no wasm_export attrs
-        assert_eq!(result.1.len(), result.2.len());
-        // Check that first parameter is the same
-        if let (FnArg::Typed(processed), FnArg::Typed(cleaned)) = (&result.1[0], &result.2[0]) {
-            assert_eq!(processed.attrs.len(), cleaned.attrs.len());
+    #[test]
+    fn test_process_function_parameters_from_js_errors() {
+        let cases = [
+            (
+                r#"#[wasm_export(js_type = "string")] owner: Address"#,
+                "`js_type` attribute requires `from_js` attribute",
+            ),
+            (
+                r#"#[wasm_export(from_js = parse, default = Address::ZERO)] owner: Address"#,
+                "`from_js` attribute cannot be used together with `default` or `variadic` attributes",
+            ),
+            (
+                r#"#[wasm_export(from_js = parse, js_type = "string", unchecked_param_type = "string")] owner: Address"#,
+                "`js_type` and `unchecked_param_type` attributes cannot be used together",
+            ),
+            (
+                r#"#[wasm_export(from_js = parse)] (a, b): (u8, u8)"#,
+                "`from_js` attribute requires the parameter pattern to be an identifier",
+            ),
+        ];
+        for (stream, msg) in cases {
+            let inputs = Punctuated::<FnArg, Comma>::parse_terminated
+                .parse2(TokenStream::from_str(stream).unwrap())
+                .unwrap();
+            let err = WasmExportFunctionBuilder::process_function_parameters(
+                &inputs,
+                &RustDocs::default(),
+                &WasmExportAttrs::default_crate_path(),
+            )
+            .unwrap_err();
+            assert_eq!(err.to_string(), msg);
+        }
+
+        let cases: [(syn::Attribute, &str); 5] = [
+            (
+                parse_quote!(#[wasm_export(from_js = a, from_js = b)]),
+                "duplicate `from_js` attribute",
+            ),
+            (
+                parse_quote!(#[wasm_export(from_js = "parse")]),
+                "expected a path to a function",
+            ),
+            (
+                parse_quote!(#[wasm_export(from_js)]),
+                "expected a value for this attribute: `from_js = ...` and it must be a path to a function",
+            ),
+            (
+                parse_quote!(#[wasm_export(js_type = "a", js_type = "b")]),
+                "duplicate `js_type` attribute",
+            ),
+            (
+                parse_quote!(#[wasm_export(js_type = string)]),
+                "expected string literal",
+            ),
+        ];
+        for (attr, msg) in cases {
+            let err =
+                WasmExportFunctionBuilder::process_parameter_wasm_export_attr(&attr).unwrap_err();
+            assert_eq!(err.to_string(), msg);
+        }
+    }
+
+    #[test]
+    fn test_find_variadic_param() {
+        let inputs: Punctuated<FnArg, Comma> = parse_quote!(
+            &self, arg: u8, #[wasm_export(js_name = "someItems", variadic)] items: Vec<SomeType>
+        );
+        let (ident, item_type, name) =
+            WasmExportFunctionBuilder::find_variadic_param(&inputs).unwrap();
+        assert_eq!(ident, "items");
+        assert_eq!(item_type, parse_quote!(SomeType));
+        assert_eq!(name, "someItems");
+
+        let inputs: Punctuated<FnArg, Comma> = parse_quote!(arg: u8, items: Vec<SomeType>);
+        assert!(WasmExportFunctionBuilder::find_variadic_param(&inputs).is_none());
+
+        let inputs: Punctuated<FnArg, Comma> = parse_quote!(&self);
+        assert!(WasmExportFunctionBuilder::find_variadic_param(&inputs).is_none());
+    }
+
+    #[test]
+    fn test_find_abort_signal_param() {
+        // detected by type
+        let inputs: Punctuated<FnArg, Comma> = parse_quote!(&self, arg: u8, signal: AbortSignal);
+        let param = WasmExportFunctionBuilder::find_abort_signal_param(&inputs)
+            .unwrap()
+            .unwrap();
+        assert_eq!(param.ident, "signal");
+        assert!(!param.is_option);
+        assert!(param.marker.is_none());
+
+        let inputs: Punctuated<FnArg, Comma> =
+            parse_quote!(signal: Option<web_sys::AbortSignal>, arg: u8);
+        let param = WasmExportFunctionBuilder::find_abort_signal_param(&inputs)
+            .unwrap()
+            .unwrap();
+        assert_eq!(param.ident, "signal");
+        assert!(param.is_option);
+
+        // marked by attribute
+        let inputs: Punctuated<FnArg, Comma> =
+            parse_quote!(arg: u8, #[wasm_export(abort_signal)] signal: JsValue);
+        let param = WasmExportFunctionBuilder::find_abort_signal_param(&inputs)
+            .unwrap()
+            .unwrap();
+        assert_eq!(param.ident, "signal");
+        assert_eq!(param.marker, Some(parse_quote!(abort_signal)));
+
+        let inputs: Punctuated<FnArg, Comma> = parse_quote!(&self, arg: u8, signal: JsValue);
+        assert!(WasmExportFunctionBuilder::find_abort_signal_param(&inputs)
+            .unwrap()
+            .is_none());
+
+        // errors
+        let cases: Vec<(Punctuated<FnArg, Comma>, &str)> = vec![
+            (
+                parse_quote!(a: AbortSignal, b: Option<AbortSignal>),
+                "a function can only have one abort signal parameter",
+            ),
+            (
+                parse_quote!(#[wasm_export(abort_signal, from_js = to_signal)] a: JsValue),
+                "`abort_signal` attribute cannot be used together with `from_js`, `default` or `variadic` attributes",
+            ),
+            (
+                parse_quote!(#[wasm_export(abort_signal)] (a, b): (JsValue, u8)),
+                "`abort_signal` attribute requires the parameter pattern to be an identifier",
+            ),
+        ];
+        for (inputs, msg) in cases {
+            let err = WasmExportFunctionBuilder::find_abort_signal_param(&inputs)
+                .err()
+                .unwrap();
+            assert_eq!(err.to_string(), msg);
+        }
+    }
+
+    #[test]
+    fn test_is_abort_signal_type() {
+        assert!(WasmExportFunctionBuilder::is_abort_signal_type(
+            &parse_quote!(AbortSignal)
+        ));
+        assert!(WasmExportFunctionBuilder::is_abort_signal_type(
+            &parse_quote!(web_sys::AbortSignal)
+        ));
+        assert!(WasmExportFunctionBuilder::is_abort_signal_type(
+            &parse_quote!(Option<AbortSignal>)
+        ));
+        assert!(!WasmExportFunctionBuilder::is_abort_signal_type(
+            &parse_quote!(Option<Option<AbortSignal>>)
+        ));
+        assert!(!WasmExportFunctionBuilder::is_abort_signal_type(
+            &parse_quote!(Vec<AbortSignal>)
+        ));
+        assert!(!WasmExportFunctionBuilder::is_abort_signal_type(
+            &parse_quote!(&AbortSignal)
+        ));
+    }
+
+    #[test]
+    fn test_validate_cancellation() {
+        let config = WasmExportFunctionBuilderConfig {
+            cancellable: Some(Span::call_site()),
+            timeout_ms: Some((100, Span::call_site())),
+            ..Default::default()
+        };
+        let sig: Signature = parse_quote!(async fn some_fn(arg: u8) -> Result<u8, Error>);
+        assert!(WasmExportFunctionBuilder::validate_cancellation(&sig, &config).is_ok());
+
+        // not async
+        let sig: Signature = parse_quote!(
+            fn some_fn(#[wasm_export(abort_signal)] signal: JsValue) -> Result<u8, Error>
+        );
+        let err = WasmExportFunctionBuilder::validate_cancellation(&sig, &config).unwrap_err();
+        let msgs: Vec<_> = err.into_iter().map(|err| err.to_string()).collect();
+        assert_eq!(
+            msgs,
+            vec![
+                "`abort_signal` attribute can only be used for async functions that do not return a stream or an iterator",
+                "`cancellable` attribute can only be used for async functions that do not return a stream or an iterator",
+                "`timeout_ms` attribute can only be used for async functions that do not return a stream or an iterator",
+            ]
+        );
+
+        // detected abort signal params of non cancellable functions are ignored
+        let config = WasmExportFunctionBuilderConfig {
+            return_kind: ReturnKind::Stream,
+            ..Default::default()
+        };
+        let sig: Signature = parse_quote!(async fn some_fn(signal: AbortSignal) -> impl Stream<Item = Result<u8, Error>>);
+        assert!(WasmExportFunctionBuilder::validate_cancellation(&sig, &config).is_ok());
+
+        // cancellable with abort signal or variadic params
+        let config = WasmExportFunctionBuilderConfig {
+            cancellable: Some(Span::call_site()),
+            ..Default::default()
+        };
+        let sigs: Vec<Signature> = vec![
+            parse_quote!(async fn some_fn(signal: AbortSignal) -> Result<u8, Error>),
+            parse_quote!(async fn some_fn(#[wasm_export(variadic)] items: Vec<u8>) -> Result<u8, Error>),
+        ];
+        for sig in sigs {
+            let err = WasmExportFunctionBuilder::validate_cancellation(&sig, &config).unwrap_err();
             assert_eq!(
-                processed.pat.to_token_stream().to_string(),
-                cleaned.pat.to_token_stream().to_string()
+                err.to_string(),
+                "`cancellable` attribute cannot be used for functions that already have an abort signal or a `variadic` parameter"
             );
         }
     }
 
     #[test]
-    fn test_process_function_parameters_with_param_description() {
-        // Test parameter processing with param_description
+    fn test_with_variadic_attr() {
+        let forward_attrs: Vec<Meta> = vec![parse_quote!(js_name = "someName")];
+        let inputs: Punctuated<FnArg, Comma> =
+            parse_quote!(#[wasm_export(variadic)] items: Vec<u8>);
+        let result = WasmExportFunctionBuilder::with_variadic_attr(&forward_attrs, &inputs);
+        let expected: Vec<Meta> = vec![parse_quote!(js_name = "someName"), parse_quote!(variadic)];
+        assert_eq!(result, expected);
+
+        let inputs: Punctuated<FnArg, Comma> = parse_quote!(items: Vec<u8>);
+        let result = WasmExportFunctionBuilder::with_variadic_attr(&forward_attrs, &inputs);
+        assert_eq!(result, forward_attrs);
+    }
+
+    #[test]
+    fn test_ts_type_of() {
+        let cases: [(Type, Option<&str>); 9] = [
+            (parse_quote!(u32), Some("number")),
+            (parse_quote!(f64), Some("number")),
+            (parse_quote!(u64), Some("bigint")),
+            (parse_quote!(String), Some("string")),
+            (parse_quote!(bool), Some("boolean")),
+            (parse_quote!(some_mod::SomeType), Some("SomeType")),
+            (parse_quote!(SomeType<u8>), None),
+            (parse_quote!(&str), None),
+            (parse_quote!(TypedPromise<String>), Some("Promise<string>")),
+        ];
+        for (ty, expected) in cases {
+            assert_eq!(
+                WasmExportFunctionBuilder::ts_type_of(&ty).as_deref(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_union_ts_type() {
+        let cases: [(Type, Option<&str>); 6] = [
+            (parse_quote!(JsUnion2<String, u32>), Some("string | number")),
+            (
+                parse_quote!(wasm_bindgen_utils::prelude::JsUnion3<u64, bool, SomeType>),
+                Some("bigint | boolean | SomeType"),
+            ),
+            (
+                parse_quote!(JsUnion4<JsUnion2<String, u8>, f64, i128, char>),
+                Some("string | number | number | bigint | string"),
+            ),
+            (parse_quote!(JsUnion2<String, Vec<u8>>), None),
+            (parse_quote!(JsUnion2), None),
+            (parse_quote!(SomeType<String, u32>), None),
+        ];
+        for (ty, expected) in cases {
+            assert_eq!(
+                WasmExportFunctionBuilder::union_ts_type(&ty).as_deref(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_promise_ts_type() {
+        let cases: [(Type, Option<&str>); 6] = [
+            (parse_quote!(TypedPromise<u32>), Some("Promise<number>")),
+            (
+                parse_quote!(wasm_bindgen_utils::prelude::TypedPromise<SomeType>),
+                Some("Promise<SomeType>"),
+            ),
+            (
+                parse_quote!(TypedPromise<JsUnion2<String, u64>>),
+                Some("Promise<string | bigint>"),
+            ),
+            (parse_quote!(TypedPromise<Vec<u8>>), Some("Promise<any>")),
+            (parse_quote!(TypedPromise), None),
+            (parse_quote!(Promise<u8>), None),
+        ];
+        for (ty, expected) in cases {
+            assert_eq!(
+                WasmExportFunctionBuilder::promise_ts_type(&ty).as_deref(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_callback_ts_type() {
+        let cases: [(Type, Option<&str>); 8] = [
+            (
+                parse_quote!(JsCallback<(u32, String), bool>),
+                Some("(arg0: number, arg1: string) => boolean"),
+            ),
+            (parse_quote!(JsCallback<(), ()>), Some("() => void")),
+            (
+                parse_quote!(wasm_bindgen_utils::prelude::JsCallback<(SomeType,), Vec<u8>>),
+                Some("(arg0: SomeType) => any"),
+            ),
+            (
+                parse_quote!(JsAsyncCallback<(String,), SomeType>),
+                Some("(arg0: string) => SomeType | Promise<SomeType>"),
+            ),
+            (parse_quote!(JsUnion2<JsCallback<(), u8>, String>), None),
+            (parse_quote!(JsCallback<u8, u8>), None),
+            (parse_quote!(JsCallback<(u8,)>), None),
+            (parse_quote!(Callback<(u8,), u8>), None),
+        ];
+        for (ty, expected) in cases {
+            assert_eq!(
+                WasmExportFunctionBuilder::callback_ts_type(&ty).as_deref(),
+                expected
+            );
+        }
+        assert_eq!(
+            WasmExportFunctionBuilder::union_ts_type(&parse_quote!(
+                JsUnion2<JsCallback<(), u8>, String>
+            ))
+            .as_deref(),
+            Some("(() => number) | string")
+        );
+    }
+
+    #[test]
+    fn test_process_function_parameters_union() {
         let stream = TokenStream::from_str(
-            r#"#[wasm_export(param_description = "first param")] arg1: String, arg2: u32"#,
+            r#"
+            arg1: JsUnion2<String, u32>,
+            #[wasm_export(unchecked_param_type = "Hex")] arg2: JsUnion2<String, u64>,
+            arg3: JsUnion2<String, Vec<u8>>,
+            arg4: TypedPromise<u8>,
+            arg5: JsAsyncCallback<(u8,), String>,
+            #[wasm_export(variadic)] items: Vec<JsUnion2<bool, SomeType>>
+            "#,
         )
         .unwrap();
         let inputs = Punctuated::<FnArg, Comma>::parse_terminated
             .parse2(stream)
             .unwrap();
-        let result = WasmExportFunctionBuilder::process_function_parameters(&inputs).unwrap();
+        let result = WasmExportFunctionBuilder::process_function_parameters(
+            &inputs,
+            &RustDocs::default(),
+            &WasmExportAttrs::default_crate_path(),
+        )
+        .unwrap();
 
-        assert_eq!(result.0, SelfReceiver::Static); // no self receiver
-        assert_eq!(result.1.len(), 2); // processed inputs
-        assert_eq!(result.2.len(), 2); // cleaned inputs
+        let expected: Punctuated<FnArg, Comma> = parse_quote!(
+            #[wasm_bindgen(unchecked_param_type = "string | number")] arg1: JsUnion2<String, u32>,
+            #[wasm_bindgen(unchecked_param_type = "Hex")] arg2: JsUnion2<String, u64>,
+            arg3: JsUnion2<String, Vec<u8>>,
+            #[wasm_bindgen(unchecked_param_type = "Promise<number>")] arg4: TypedPromise<u8>,
+            #[wasm_bindgen(unchecked_param_type = "(arg0: number) => string | Promise<string>")] arg5: JsAsyncCallback<(u8,), String>,
+            #[wasm_bindgen(unchecked_param_type = "(boolean | SomeType)[]")] items: ::std::vec::Vec<::wasm_bindgen_utils::prelude::JsValue>
+        );
+        assert_eq!(result.1, expected);
+    }
 
-        // Processed should have wasm_bindgen attribute, cleaned should not
-        let processed_first = &result.1[0];
-        let cleaned_first = &result.2[0];
+    #[test]
+    fn test_vec_item_type() {
+        let ty: Type = parse_quote!(Vec<u8>);
+        let expected: Type = parse_quote!(u8);
+        assert_eq!(
+            WasmExportFunctionBuilder::vec_item_type(&ty),
+            Some(&expected)
+        );
 
-        if let (FnArg::Typed(processed_pat), FnArg::Typed(cleaned_pat)) =
-            (processed_first, cleaned_first)
-        {
-            // Processed should have wasm_bindgen attribute
-            assert!(processed_pat
-                .attrs
-                .iter()
-                .any(|attr| attr.path().is_ident("wasm_bindgen")));
-            // Cleaned should not have any attributes
-            assert!(cleaned_pat.attrs.is_empty());
-        } else {
-            panic!("Expected FnArg::Typed");
-        }
+        let ty: Type = parse_quote!(std::vec::Vec<Vec<u8>>);
+        let expected: Type = parse_quote!(Vec<u8>);
+        assert_eq!(
+            WasmExportFunctionBuilder::vec_item_type(&ty),
+            Some(&expected)
+        );
+
+        assert!(WasmExportFunctionBuilder::vec_item_type(&parse_quote!(Vec)).is_none());
+        assert!(WasmExportFunctionBuilder::vec_item_type(&parse_quote!([u8; 2])).is_none());
+        assert!(WasmExportFunctionBuilder::vec_item_type(&parse_quote!(Option<u8>)).is_none());
+    }
+
+    #[test]
+    fn test_is_option_type() {
+        assert!(WasmExportFunctionBuilder::is_option_type(&parse_quote!(
+            Option<u8>
+        )));
+        assert!(WasmExportFunctionBuilder::is_option_type(&parse_quote!(
+            std::option::Option<Vec<u8>>
+        )));
+        assert!(!WasmExportFunctionBuilder::is_option_type(&parse_quote!(
+            Option
+        )));
+        assert!(!WasmExportFunctionBuilder::is_option_type(&parse_quote!(
+            Vec<Option<u8>>
+        )));
+        assert!(!WasmExportFunctionBuilder::is_option_type(&parse_quote!(
+            &Option<u8>
+        )));
     }
 
     #[test]
@@ -956,7 +4248,12 @@ mod tests {
         let inputs = Punctuated::<FnArg, Comma>::parse_terminated
             .parse2(stream)
             .unwrap();
-        let result = WasmExportFunctionBuilder::process_function_parameters(&inputs).unwrap();
+        let result = WasmExportFunctionBuilder::process_function_parameters(
+            &inputs,
+            &RustDocs::default(),
+            &WasmExportAttrs::default_crate_path(),
+        )
+        .unwrap();
 
         assert_eq!(result.0, SelfReceiver::Instance); // has self receiver
         assert_eq!(result.1.len(), 2); // processed inputs (self + arg1)
@@ -973,7 +4270,11 @@ mod tests {
         let inputs = Punctuated::<FnArg, Comma>::parse_terminated
             .parse2(stream)
             .unwrap();
-        let result = WasmExportFunctionBuilder::process_function_parameters(&inputs);
+        let result = WasmExportFunctionBuilder::process_function_parameters(
+            &inputs,
+            &RustDocs::default(),
+            &WasmExportAttrs::default_crate_path(),
+        );
 
         assert!(result.is_err());
         let error = result.unwrap_err();
@@ -991,7 +4292,7 @@ mod tests {
 
         assert_eq!(result.len(), 1);
         let meta = &result[0];
-        assert!(meta.path().is_ident("param_description"));
+        assert!(meta.path().is_ident(AttrKeys::PARAM_DESCRIPTION));
     }
 
     #[test]
@@ -1033,6 +4334,82 @@ mod tests {
             .contains("expected a value for this attribute"));
     }
 
+    #[test]
+    fn test_process_parameter_wasm_export_attr_unknown_key() {
+        // Test unknown key error with a suggestion
+        let attr: syn::Attribute = syn::parse_quote!(#[wasm_export(optinal)]);
+        let result = WasmExportFunctionBuilder::process_parameter_wasm_export_attr(&attr);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "unexpected `optinal` parameter attribute, did you mean `optional`?"
+        );
+
+        // Test unknown key error without a suggestion
+        let attr: syn::Attribute = syn::parse_quote!(#[wasm_export(some::key = "value")]);
+        let result = WasmExportFunctionBuilder::process_parameter_wasm_export_attr(&attr);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "unexpected `some::key` parameter attribute, expected one of `param_description`, `unchecked_param_type`, `js_name`, `js_type`, `optional`, `default`, `variadic`, `from_js`, `abort_signal`"
+        );
+    }
+
+    #[test]
+    fn test_process_parameter_wasm_export_attr_collects_errors() {
+        // Test that all of the errors are reported
+        let attr: syn::Attribute = syn::parse_quote!(
+            #[wasm_export(js_nam = "a", optional = 1, param_description = "desc", variadic(x))]
+        );
+        let result = WasmExportFunctionBuilder::process_parameter_wasm_export_attr(&attr);
+        let msgs = result
+            .unwrap_err()
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            msgs,
+            vec![
+                "unexpected `js_nam` parameter attribute, did you mean `js_name`?",
+                "unexpected token in attribute, `optional` attribute does not take any extra tokens or arguments",
+                "unexpected token in attribute, `variadic` attribute does not take any extra tokens or arguments",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_process_function_parameters_collects_errors() {
+        // Test that the errors of all of the params are reported
+        let stream = TokenStream::from_str(
+            r#"
+            #[wasm_export(defualt = 1)] arg1: u8,
+            #[wasm_export(js_name = 1)] arg2: String,
+            arg3: String,
+            #[wasm_export(from_js = "func")] arg4: Vec<u8>
+            "#,
+        )
+        .unwrap();
+        let inputs = Punctuated::<FnArg, Comma>::parse_terminated
+            .parse2(stream)
+            .unwrap();
+        let result = WasmExportFunctionBuilder::process_function_parameters(
+            &inputs,
+            &RustDocs::default(),
+            &WasmExportAttrs::default_crate_path(),
+        );
+        let msgs = result
+            .unwrap_err()
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            msgs,
+            vec![
+                "unexpected `defualt` parameter attribute, did you mean `default`?",
+                "expected string literal",
+                "expected a path to a function",
+            ]
+        );
+    }
+
     #[test]
     fn test_clean_parameter_attributes() {
         // Test cleaning wasm_export attributes from parameters
@@ -1131,7 +4508,16 @@ mod tests {
         let config = WasmExportFunctionBuilderConfig {
             forward_attrs: vec![parse_quote!(js_name = "someMethod")],
             return_type: parse_quote!(String),
+            return_kind: ReturnKind::Result,
             preserve_js_class: None,
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
+            map_ok: None,
+            instance: None,
+            trait_path: None,
+            krate: WasmExportAttrs::default_crate_path(),
         };
 
         let result = WasmExportFunctionBuilder::build_export_method(&method, config).unwrap();
@@ -1167,7 +4553,16 @@ mod tests {
         let config = WasmExportFunctionBuilderConfig {
             forward_attrs: vec![],
             return_type: parse_quote!(String),
+            return_kind: ReturnKind::Result,
             preserve_js_class: None,
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
+            map_ok: None,
+            instance: None,
+            trait_path: None,
+            krate: WasmExportAttrs::default_crate_path(),
         };
 
         let result = WasmExportFunctionBuilder::build_export_method(&method, config).unwrap();
@@ -1203,7 +4598,16 @@ mod tests {
         let config = WasmExportFunctionBuilderConfig {
             forward_attrs: vec![parse_quote!(js_name = "add")],
             return_type: parse_quote!(u32),
+            return_kind: ReturnKind::Result,
             preserve_js_class: None,
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
+            map_ok: None,
+            instance: None,
+            trait_path: None,
+            krate: WasmExportAttrs::default_crate_path(),
         };
 
         let result = WasmExportFunctionBuilder::build_export_function(&func, config).unwrap();
@@ -1214,7 +4618,16 @@ mod tests {
             .iter()
             .filter(|attr| attr.path().is_ident("doc"))
             .count();
-        assert_eq!(doc_count, 5); // 5 lines of doc comments (updated from incorrect 4)
+        assert_eq!(doc_count, 6); // description, separator, @example tag and 3 lines of example
+
+        // Check that examples section is converted to TSDoc @example
+        let doc_attrs = result
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"));
+        let all_docs = quote!(#(#doc_attrs)*).to_string();
+        assert!(all_docs.contains("@example"));
+        assert!(!all_docs.contains("# Examples"));
 
         // Check that other attributes are also present
         assert!(result
@@ -1224,7 +4637,7 @@ mod tests {
         assert!(result
             .attrs
             .iter()
-            .any(|attr| attr.path().is_ident("wasm_bindgen")));
+            .any(|attr| *attr.path() == parse_quote!(::wasm_bindgen_utils::prelude::wasm_bindgen)));
     }
 
     #[test]
@@ -1238,7 +4651,16 @@ mod tests {
         let config = WasmExportFunctionBuilderConfig {
             forward_attrs: vec![],
             return_type: parse_quote!(()),
+            return_kind: ReturnKind::Result,
             preserve_js_class: None,
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
+            map_ok: None,
+            instance: None,
+            trait_path: None,
+            krate: WasmExportAttrs::default_crate_path(),
         };
 
         let result = WasmExportFunctionBuilder::build_export_function(&func, config).unwrap();
@@ -1259,7 +4681,7 @@ mod tests {
         assert!(result
             .attrs
             .iter()
-            .any(|attr| attr.path().is_ident("wasm_bindgen")));
+            .any(|attr| *attr.path() == parse_quote!(::wasm_bindgen_utils::prelude::wasm_bindgen)));
     }
 
     #[test]
@@ -1275,7 +4697,16 @@ mod tests {
         let config = WasmExportFunctionBuilderConfig {
             forward_attrs: vec![],
             return_type: parse_quote!(JsClass),
+            return_kind: ReturnKind::Result,
             preserve_js_class: Some(Span::call_site()),
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
+            map_ok: None,
+            instance: None,
+            trait_path: None,
+            krate: WasmExportAttrs::default_crate_path(),
         };
 
         let result = WasmExportFunctionBuilder::build_export_function(&func, config).unwrap();
@@ -1291,7 +4722,10 @@ mod tests {
         // Check that return type is JsValue for preserve_js_class
         match &result.sig.output {
             syn::ReturnType::Type(_, ty) => {
-                assert_eq!(quote!(#ty).to_string(), "JsValue");
+                assert_eq!(
+                    quote!(#ty).to_string(),
+                    ":: wasm_bindgen_utils :: prelude :: JsValue"
+                );
             }
             _ => panic!("Expected return type"),
         }
@@ -1314,7 +4748,16 @@ mod tests {
                 parse_quote!(return_description = "a magic number"),
             ],
             return_type: parse_quote!(u32),
+            return_kind: ReturnKind::Result,
             preserve_js_class: None,
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
+            map_ok: None,
+            instance: None,
+            trait_path: None,
+            krate: WasmExportAttrs::default_crate_path(),
         };
 
         let result = WasmExportFunctionBuilder::build_export_function(&func, config).unwrap();
@@ -1331,7 +4774,9 @@ mod tests {
         let wasm_bindgen_attrs: Vec<_> = result
             .attrs
             .iter()
-            .filter(|attr| attr.path().is_ident("wasm_bindgen"))
+            .filter(|attr| {
+                *attr.path() == parse_quote!(::wasm_bindgen_utils::prelude::wasm_bindgen)
+            })
             .collect();
         assert_eq!(wasm_bindgen_attrs.len(), 1);
 
@@ -1365,7 +4810,16 @@ mod tests {
         let config = WasmExportFunctionBuilderConfig {
             forward_attrs: vec![],
             return_type: parse_quote!(String),
+            return_kind: ReturnKind::Result,
             preserve_js_class: None,
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
+            map_ok: None,
+            instance: None,
+            trait_path: None,
+            krate: WasmExportAttrs::default_crate_path(),
         };
 
         let result = WasmExportFunctionBuilder::build_export_function(&func, config).unwrap();
@@ -1414,7 +4868,16 @@ mod tests {
         let config = WasmExportFunctionBuilderConfig {
             forward_attrs: vec![parse_quote!(js_name = "advancedMethod")],
             return_type: parse_quote!(ProcessedValue),
+            return_kind: ReturnKind::Result,
             preserve_js_class: None,
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
+            map_ok: None,
+            instance: None,
+            trait_path: None,
+            krate: WasmExportAttrs::default_crate_path(),
         };
 
         let result = WasmExportFunctionBuilder::build_export_method(&method, config).unwrap();
@@ -1425,7 +4888,15 @@ mod tests {
             .iter()
             .filter(|attr| attr.path().is_ident("doc"))
             .count();
-        assert_eq!(doc_count, 11); // Count the doc comment lines
+        assert_eq!(doc_count, 4); // Parameters and Returns sections are not included
+
+        // Check that parameters section is converted to param_description
+        let FnArg::Typed(input) = &result.sig.inputs[1] else {
+            panic!("Expected FnArg::Typed");
+        };
+        let expected: syn::Attribute =
+            parse_quote!(#[wasm_bindgen(param_description = "The input value to process")]);
+        assert_eq!(input.attrs, vec![expected]);
 
         // Check method name transformation
         assert_eq!(result.sig.ident.to_string(), "advanced_method__wasm_export");
@@ -0,0 +1,372 @@
+use proc_macro2::Span;
+use syn::{spanned::Spanned, Attribute, Expr, ExprLit, Lit, LitStr, Meta};
+
+/// Contains the list of conventional rustdoc section headings that are converted to TSDoc
+pub struct DocSections;
+impl DocSections {
+    pub const ARGUMENTS: &'static [&'static str] = &["arguments", "args", "parameters", "params"];
+    pub const RETURNS: &'static [&'static str] = &["returns", "return"];
+    pub const EXAMPLES: &'static [&'static str] = &["examples", "example"];
+}
+
+/// Specifies the rustdoc section that a doc comment line belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Description,
+    Arguments,
+    Returns,
+    Examples,
+}
+
+/// A single doc comment line, either the text of a literal doc comment or a doc
+/// attribute that is not a string literal (such as `#[doc = include_str!(...)]`)
+enum DocLine {
+    Text(String, Span),
+    Raw(Box<Attribute>),
+}
+
+/// Struct that holds the parsed rustdoc comments of a function/method, where the
+/// conventional `# Arguments`, `# Returns` and `# Examples` sections are extracted
+/// so that they can be converted into their TSDoc counterparts
+#[derive(Debug, Clone, Default)]
+pub struct RustDocs {
+    /// Doc comments of the exporting function/method, i.e. the original doc comments
+    /// without the arguments and returns sections and with examples as `@example`
+    pub attrs: Vec<Attribute>,
+    /// Descriptions of the parameters listed in `# Arguments` section by their names
+    pub params: Vec<(String, String)>,
+    /// Description of the returning value given in `# Returns` section
+    pub returns: Option<String>,
+}
+
+impl RustDocs {
+    /// Parses the given doc comment attributes of a function/method
+    pub fn parse(doc_attrs: &[Attribute]) -> Self {
+        let mut description = vec![];
+        let mut examples = vec![];
+        let mut arguments = vec![];
+        let mut returns = vec![];
+        let mut section = Section::Description;
+        let mut in_code_block = false;
+        for line in Self::doc_lines(doc_attrs) {
+            let DocLine::Text(text, _) = &line else {
+                description.push(line);
+                continue;
+            };
+
+            // code blocks can contain lines that look like headings, such as hidden rustdoc lines
+            let trimmed = text.trim();
+            if trimmed.starts_with("```") {
+                in_code_block = !in_code_block;
+            } else if !in_code_block {
+                // any heading ends the current section, but only the conventional
+                // top level headings start the sections that are converted
+                if let Some(heading) = Self::heading_of(trimmed) {
+                    section = Self::section_of(heading);
+                    if section != Section::Description {
+                        continue;
+                    }
+                }
+            }
+
+            match section {
+                Section::Description => description.push(line),
+                Section::Arguments => arguments.push(trimmed.to_string()),
+                Section::Returns => returns.push(trimmed.to_string()),
+                Section::Examples => {
+                    // skip rustdoc hidden lines of the example code blocks
+                    if !in_code_block || !(trimmed == "#" || trimmed.starts_with("# ")) {
+                        examples.push(line);
+                    }
+                }
+            }
+        }
+
+        // build the doc attrs of the exporting function/method, where
+        // the examples are appended as TSDoc `@example` block
+        Self::trim_empty_lines(&mut description);
+        Self::trim_empty_lines(&mut examples);
+        if !examples.is_empty() {
+            if !description.is_empty() {
+                description.push(DocLine::Text(String::new(), Span::call_site()));
+            }
+            description.push(DocLine::Text(" @example".to_string(), Span::call_site()));
+            description.extend(examples);
+        }
+        let attrs = description
+            .into_iter()
+            .map(|line| match line {
+                DocLine::Raw(attr) => *attr,
+                DocLine::Text(text, span) => {
+                    let lit = LitStr::new(&text, span);
+                    syn::parse_quote!(#[doc = #lit])
+                }
+            })
+            .collect();
+
+        let returns = returns
+            .into_iter()
+            .filter(|v| !v.is_empty())
+            .collect::<Vec<_>>();
+        RustDocs {
+            attrs,
+            params: Self::parse_arguments(&arguments),
+            returns: (!returns.is_empty()).then(|| returns.join(" ")),
+        }
+    }
+
+    /// Returns the doc description of the param with the given name if it was listed in the
+    /// `# Arguments` section
+    pub fn param_description(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, desc)| desc.as_str())
+    }
+
+    /// Splits the given doc attributes into their lines
+    fn doc_lines(doc_attrs: &[Attribute]) -> Vec<DocLine> {
+        let mut lines = vec![];
+        for attr in doc_attrs {
+            if let Meta::NameValue(name_value) = &attr.meta {
+                if let Expr::Lit(ExprLit {
+                    lit: Lit::Str(str), ..
+                }) = &name_value.value
+                {
+                    // block doc comments are a single attribute containing multiple lines
+                    let value = str.value();
+                    lines.extend(
+                        value
+                            .split('\n')
+                            .map(|line| line.strip_suffix('\r').unwrap_or(line))
+                            .map(|line| DocLine::Text(line.to_string(), attr.span())),
+                    );
+                    continue;
+                }
+            }
+            lines.push(DocLine::Raw(Box::new(attr.clone())));
+        }
+        lines
+    }
+
+    /// Returns the text of the given doc line if it is a markdown heading along with its level
+    fn heading_of(line: &str) -> Option<(usize, &str)> {
+        let text = line.trim_start_matches('#');
+        let level = line.len() - text.len();
+        (level > 0 && text.starts_with(' ')).then(|| (level, text.trim()))
+    }
+
+    /// Determines the section of the given doc heading
+    fn section_of((level, heading): (usize, &str)) -> Section {
+        if level != 1 {
+            return Section::Description;
+        }
+        let heading = heading.to_lowercase();
+        if DocSections::ARGUMENTS.contains(&heading.as_str()) {
+            Section::Arguments
+        } else if DocSections::RETURNS.contains(&heading.as_str()) {
+            Section::Returns
+        } else if DocSections::EXAMPLES.contains(&heading.as_str()) {
+            Section::Examples
+        } else {
+            Section::Description
+        }
+    }
+
+    /// Parses the list items of the `# Arguments` section, for example:
+    /// `* `name` - description` or `- name: description`, where the lines
+    /// following an item that are not list items are appended to its description
+    fn parse_arguments(lines: &[String]) -> Vec<(String, String)> {
+        let mut params: Vec<(String, String)> = vec![];
+        for line in lines {
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+            let item = trimmed
+                .strip_prefix("* ")
+                .or_else(|| trimmed.strip_prefix("- "))
+                .and_then(Self::parse_argument_item);
+            match item {
+                Some(param) => params.push(param),
+                None => {
+                    if let Some((_, desc)) = params.last_mut() {
+                        if !desc.is_empty() {
+                            desc.push(' ');
+                        }
+                        desc.push_str(trimmed);
+                    }
+                }
+            }
+        }
+        params
+    }
+
+    /// Parses a single list item of the `# Arguments` section into its name and description
+    fn parse_argument_item(item: &str) -> Option<(String, String)> {
+        let item = item.trim();
+        let (name, rest) = if let Some(item) = item.strip_prefix('`') {
+            item.split_once('`')?
+        } else {
+            let end = item
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(item.len());
+            item.split_at(end)
+        };
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return None;
+        }
+        let desc = rest
+            .trim_start()
+            .trim_start_matches(['-', ':', '–', '—'])
+            .trim();
+        Some((name.to_string(), desc.to_string()))
+    }
+
+    /// Removes the leading and trailing empty lines
+    fn trim_empty_lines(lines: &mut Vec<DocLine>) {
+        let is_empty =
+            |line: &DocLine| matches!(line, DocLine::Text(text, _) if text.trim().is_empty());
+        while lines.last().is_some_and(is_empty) {
+            lines.pop();
+        }
+        let leading = lines.iter().take_while(|line| is_empty(line)).count();
+        lines.drain(..leading);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::{parse_quote, ItemFn};
+
+    fn doc_texts(attrs: &[Attribute]) -> Vec<String> {
+        attrs
+            .iter()
+            .map(|attr| match &attr.meta {
+                Meta::NameValue(name_value) => match &name_value.value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(str), ..
+                    }) => str.value(),
+                    _ => panic!("expected string literal"),
+                },
+                _ => panic!("expected doc attribute"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_sections() {
+        let func: ItemFn = parse_quote!(
+            /// Multiply two numbers together
+            ///
+            /// # Arguments
+            /// * `x` - The first number
+            /// * `y` - The second number that
+            ///   spans multiple lines
+            ///
+            /// # Returns
+            /// The product of x and y
+            ///
+            /// # Errors
+            /// Fails on overflow
+            ///
+            /// # Examples
+            /// ```rust
+            /// # use some_crate::mul;
+            /// let result = mul(2, 3);
+            /// ```
+            pub fn mul(x: u32, y: u32) -> Result<u32, Error> {}
+        );
+        let result = RustDocs::parse(&func.attrs);
+        assert_eq!(
+            result.params,
+            vec![
+                ("x".to_string(), "The first number".to_string()),
+                (
+                    "y".to_string(),
+                    "The second number that spans multiple lines".to_string()
+                ),
+            ]
+        );
+        assert_eq!(result.returns.as_deref(), Some("The product of x and y"));
+        assert_eq!(
+            result.param_description("y"),
+            Some("The second number that spans multiple lines")
+        );
+        assert_eq!(result.param_description("z"), None);
+        assert_eq!(
+            doc_texts(&result.attrs),
+            vec![
+                " Multiply two numbers together",
+                "",
+                " # Errors",
+                " Fails on overflow",
+                "",
+                " @example",
+                " ```rust",
+                " let result = mul(2, 3);",
+                " ```",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_no_sections() {
+        let func: ItemFn = parse_quote!(
+            /// Some description
+            ///
+            /// ## Notes
+            /// - `a` - not an argument
+            #[doc = include_str!("some_file.md")]
+            pub fn some_fn(a: u32) -> Result<u32, Error> {}
+        );
+        let result = RustDocs::parse(&func.attrs);
+        assert!(result.params.is_empty());
+        assert!(result.returns.is_none());
+        assert_eq!(result.attrs.len(), func.attrs.len());
+        assert_eq!(doc_texts(&result.attrs[..4]), doc_texts(&func.attrs[..4]));
+        assert_eq!(result.attrs[4], func.attrs[4]);
+
+        let result = RustDocs::parse(&[]);
+        assert!(result.attrs.is_empty());
+        assert!(result.params.is_empty());
+        assert!(result.returns.is_none());
+    }
+
+    #[test]
+    fn test_parse_block_doc_comment() {
+        let func: ItemFn = parse_quote!(
+            /** Some description
+            # Params
+            - a: first param
+            - b: second param
+            */
+            pub fn some_fn(a: u32, b: u32) -> Result<u32, Error> {}
+        );
+        let result = RustDocs::parse(&func.attrs);
+        assert_eq!(
+            result.params,
+            vec![
+                ("a".to_string(), "first param".to_string()),
+                ("b".to_string(), "second param".to_string()),
+            ]
+        );
+        assert_eq!(doc_texts(&result.attrs), vec![" Some description"]);
+    }
+
+    #[test]
+    fn test_parse_argument_item() {
+        let result = RustDocs::parse_argument_item("`some_arg` - some desc").unwrap();
+        assert_eq!(result, ("some_arg".to_string(), "some desc".to_string()));
+
+        let result = RustDocs::parse_argument_item("some_arg: some desc").unwrap();
+        assert_eq!(result, ("some_arg".to_string(), "some desc".to_string()));
+
+        let result = RustDocs::parse_argument_item("`some_arg`").unwrap();
+        assert_eq!(result, ("some_arg".to_string(), String::new()));
+
+        assert!(RustDocs::parse_argument_item("`some arg` - some desc").is_none());
+        assert!(RustDocs::parse_argument_item(": some desc").is_none());
+    }
+}
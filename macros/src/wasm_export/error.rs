@@ -5,6 +5,63 @@ pub fn extend_err_msg(msg: &str) -> impl Fn(Error) -> Error + '_ {
     |err| Error::new(err.span(), err.to_string() + msg)
 }
 
+/// Accumulates the errors of a macro input so they can all be reported at once,
+/// instead of returning on the first one
+#[derive(Debug, Default)]
+pub struct Errors(Option<Error>);
+
+impl Errors {
+    /// Adds the given error to the accumulated errors
+    pub fn push(&mut self, err: Error) {
+        match &mut self.0 {
+            Some(errors) => errors.combine(err),
+            None => self.0 = Some(err),
+        }
+    }
+
+    /// Takes the error of the given result if any, and returns its value otherwise
+    pub fn collect<T>(&mut self, result: Result<T, Error>) -> Option<T> {
+        result.map_err(|err| self.push(err)).ok()
+    }
+
+    /// Returns the accumulated errors combined into a single error if there are any
+    pub fn finish(self) -> Result<(), Error> {
+        self.0.map_or(Ok(()), Err)
+    }
+
+    /// Returns the given value if there are no accumulated errors
+    pub fn finish_with<T>(self, value: T) -> Result<T, Error> {
+        self.finish().map(|_| value)
+    }
+}
+
+/// Returns the closest of the given candidates to the given unknown key, if it is close
+/// enough to be a likely misspelling of it
+pub fn did_you_mean<'a>(unknown: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(unknown, candidate), *candidate))
+        .filter(|(distance, candidate)| *distance <= candidate.len().max(3) / 3)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Calculates the levenshtein distance between the given strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -16,4 +73,40 @@ mod tests {
         let result = extend_err_msg(", extend msg")(err);
         assert_eq!(result.to_string(), "some msg, extend msg");
     }
+
+    #[test]
+    fn test_errors() {
+        let mut errors = Errors::default();
+        assert_eq!(errors.collect(Ok::<_, Error>(1)), Some(1));
+        assert!(errors.0.is_none());
+
+        let err = Error::new(Span::call_site(), "first msg");
+        assert_eq!(errors.collect(Err::<u8, _>(err)), None);
+        errors.push(Error::new(Span::call_site(), "second msg"));
+        let err = errors.finish_with(1).unwrap_err();
+        let msgs = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+        assert_eq!(msgs, vec!["first msg", "second msg"]);
+
+        assert_eq!(Errors::default().finish_with(1).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_did_you_mean() {
+        let candidates = [
+            "optional",
+            "default",
+            "variadic",
+            "js_name",
+            "param_description",
+        ];
+        assert_eq!(did_you_mean("optinal", &candidates), Some("optional"));
+        assert_eq!(did_you_mean("defualt", &candidates), Some("default"));
+        assert_eq!(did_you_mean("jsname", &candidates), Some("js_name"));
+        assert_eq!(
+            did_you_mean("param_descripton", &candidates),
+            Some("param_description")
+        );
+        assert_eq!(did_you_mean("something", &candidates), None);
+        assert_eq!(did_you_mean("x", &candidates), None);
+    }
 }
@@ -0,0 +1,428 @@
+use quote::quote;
+use proc_macro2::TokenStream;
+use quote::format_ident;
+use super::{attrs::*, builder::WasmExportFunctionBuilder, docs::RustDocs, error::Errors, js_call::*};
+use syn::{
+    parse_quote, punctuated::Punctuated, Attribute, Error, Expr, ForeignItem, ForeignItemFn, Ident,
+    ItemForeignMod, Meta, MetaNameValue, Token, Type,
+};
+
+/// An imported JS function of an extern block along with the details of its wrapper
+struct ImportFn {
+    item: ForeignItemFn,
+    /// Name of the raw wasm_bindgen binding that the wrapper calls
+    binding_ident: Ident,
+    /// Path of the raw binding that the wrapper calls it with, which is qualified by the
+    /// JS class that wasm_bindgen generates it for if it is a method or static method
+    binding_path: TokenStream,
+    js_name: String,
+    params: Vec<JsCallParam>,
+    /// The `T` of the returning `Result<T, E>`
+    return_type: Type,
+    /// The attributes that are forwarded to wasm_bindgen for the raw binding
+    forward_attrs: Vec<Meta>,
+}
+
+/// Parses an extern block and generates the raw wasm_bindgen bindings of its functions along
+/// with wrappers that encode the args, decode the returned values and convert the JS errors
+/// into the errors of the functions, the errors of all of the functions are collected to be
+/// reported at once
+pub fn parse(
+    foreign_mod: &mut ItemForeignMod,
+    top_attrs: WasmExportAttrs,
+) -> Result<TokenStream, Error> {
+    // report invalid attributes along with the errors of the functions
+    let mut errors = Errors::default();
+    for (key, span) in top_attrs.export_attr_spans() {
+        errors.push(Error::new(
+            span,
+            format!("unexpected `{key}` attribute, wasm_import only accepts wasm_bindgen, `crate` and `cfg` attributes"),
+        ));
+    }
+
+    // loop over items inside of the extern block and collect the functions to wrap,
+    // the rest of the items, such as types and statics, are kept as they are
+    let mut import_fns = Vec::new();
+    let mut items = Vec::new();
+    for item in std::mem::take(&mut foreign_mod.items) {
+        match item {
+            ForeignItem::Fn(mut item) => {
+                if let Some(import_fn) = errors.collect(parse_fn(&mut item)).flatten() {
+                    import_fns.push(import_fn);
+                }
+            }
+            item => items.push(item),
+        }
+    }
+    errors.finish()?;
+
+    let krate = top_attrs.crate_path();
+    let cfg = top_attrs.cfg_predicate();
+    let forward_attrs = &top_attrs.forward_attrs;
+    let bindings = import_fns
+        .iter()
+        .map(|import_fn| build_binding(import_fn, &krate));
+    let wrappers = import_fns
+        .iter()
+        .map(|import_fn| build_wrapper(import_fn, &krate));
+    foreign_mod.items = items;
+    foreign_mod.items.extend(bindings);
+
+    Ok(quote! {
+        #[cfg(#cfg)]
+        #[#krate::prelude::wasm_bindgen(wasm_bindgen = #krate::prelude::wasm_bindgen #(, #forward_attrs)*)]
+        #foreign_mod
+
+        #(
+            #[cfg(#cfg)]
+            #wrappers
+        )*
+    })
+}
+
+/// Parses the wasm_import and wasm_bindgen attributes of the given function and cleans them
+/// from it, returns the details of its raw binding and wrapper
+fn parse_fn(item: &mut ForeignItemFn) -> Result<Option<ImportFn>, Error> {
+    // process function attributes, if they are invalid the function params are
+    // still processed so that their errors are reported along with them
+    let mut errors = Errors::default();
+    let attrs = errors.collect(WasmExportAttrs::handle_item_attrs(
+        &mut item.attrs,
+        AttrKeys::WASM_IMPORT,
+    ));
+    let bindgen_attrs = errors.collect(take_wasm_bindgen_attrs(&mut item.attrs));
+    let docs = RustDocs::parse(&WasmExportFunctionBuilder::extract_doc_comments(
+        &item.attrs,
+    ));
+    let params = errors.collect(parse_params(
+        &item.sig,
+        &docs,
+        AttrKeys::WASM_IMPORT,
+        "an imported function",
+    ));
+    let mut attrs = attrs.unwrap_or_default();
+    attrs
+        .forward_attrs
+        .extend(bindgen_attrs.unwrap_or_default());
+    let msg = |key: &str| {
+        format!(
+            "unexpected `{key}` attribute, imported functions only accept wasm_bindgen attributes"
+        )
+    };
+    for (key, span) in attrs.export_attr_spans() {
+        errors.push(Error::new(span, msg(key)));
+    }
+    if let Some(span) = attrs.should_skip {
+        errors.push(Error::new(span, msg(AttrKeys::SKIP)));
+    }
+    if let Some(krate) = &attrs.krate {
+        errors.push(Error::new_spanned(krate, msg(AttrKeys::CRATE)));
+    }
+    if let Some(cfg) = &attrs.cfg {
+        errors.push(Error::new_spanned(cfg, msg(AttrKeys::CFG)));
+    }
+    let mut js_name = None;
+    for meta in &attrs.forward_attrs {
+        if meta.path().is_ident(AttrKeys::JS_NAME) {
+            // wasm_bindgen also accepts the js name as an ident
+            js_name = match meta {
+                Meta::NameValue(MetaNameValue {
+                    value: Expr::Path(path),
+                    ..
+                }) if path.path.get_ident().is_some() => {
+                    path.path.get_ident().map(ToString::to_string)
+                }
+                _ => errors.collect(str_value(meta)),
+            };
+        }
+    }
+
+    // the raw binding is a plain wasm_bindgen import, so the function can have no generics
+    if !item.sig.generics.params.is_empty() || item.sig.generics.where_clause.is_some() {
+        errors.push(Error::new_spanned(
+            &item.sig.generics,
+            "unexpected generics, wasm_import is only applicable to non generic functions",
+        ));
+    }
+    if let Some(variadic) = &item.sig.variadic {
+        errors.push(Error::new_spanned(
+            variadic,
+            "unexpected variadic param, wasm_import is only applicable to functions with fixed params",
+        ));
+    }
+
+    // calling into JS can fail, so the function must return a result
+    let Some((return_type, ReturnKind::Result)) =
+        WasmExportAttrs::try_extract_return_inner_type(&item.sig.output)
+    else {
+        errors.push(Error::new_spanned(
+            &item.sig.output,
+            "expected `Result<T, E>` return type, since calling into JS can fail",
+        ));
+        return errors.finish_with(None);
+    };
+    let return_type = return_type.clone();
+    let Some(mut params) = params else {
+        return errors.finish_with(None);
+    };
+    errors.finish()?;
+
+    // wasm_bindgen generates the bindings of methods and static methods as the
+    // methods of their JS class, and the receiver of a method is passed as is,
+    // since it is the JS object itself
+    let binding_ident = populate_name(&item.sig.ident);
+    let mut binding_path = quote!(#binding_ident);
+    if has_attr(&attrs.forward_attrs, "method") {
+        let Some(this) = params.first_mut() else {
+            return Err(Error::new_spanned(
+                &item.sig,
+                "expected the JS object as the first param of the `method` function",
+            ));
+        };
+        this.raw = true;
+        let class = match &this.ty {
+            Type::Reference(reference) => reference.elem.as_ref(),
+            ty => ty,
+        };
+        binding_path = quote!(<#class>::#binding_ident);
+    } else if let Some(Meta::NameValue(meta)) = attrs
+        .forward_attrs
+        .iter()
+        .find(|meta| meta.path().is_ident("static_method_of"))
+    {
+        let class = &meta.value;
+        binding_path = quote!(<#class>::#binding_ident);
+    }
+
+    // name the params that are not named by their patterns
+    clean_params(&mut item.sig.inputs, &params, AttrKeys::WASM_IMPORT);
+
+    // keep the original name of the function for JS, since the binding is renamed
+    let js_name = js_name.unwrap_or_else(|| item.sig.ident.to_string());
+    let mut forward_attrs = attrs.forward_attrs;
+    if !has_attr(&forward_attrs, AttrKeys::JS_NAME) {
+        forward_attrs.push(parse_quote!(js_name = #js_name));
+    }
+
+    Ok(Some(ImportFn {
+        binding_ident,
+        binding_path,
+        item: item.clone(),
+        js_name,
+        params,
+        return_type,
+        forward_attrs,
+    }))
+}
+
+/// Takes the wasm_bindgen attributes of a function out of the given attributes and returns
+/// their nested attributes, so they are forwarded to the raw binding along with the rest
+fn take_wasm_bindgen_attrs(attrs: &mut Vec<Attribute>) -> Result<Vec<Meta>, Error> {
+    let mut errors = Errors::default();
+    let mut metas = Vec::new();
+    attrs.retain(|attr| {
+        if !attr.path().is_ident("wasm_bindgen") {
+            return true;
+        }
+        let nested = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated);
+        metas.extend(errors.collect(nested).into_iter().flatten());
+        false
+    });
+    errors.finish_with(metas)
+}
+
+/// Builds the raw wasm_bindgen binding of the given function, which catches the thrown
+/// JS exceptions and passes the args and returned value as they are
+fn build_binding(import_fn: &ImportFn, krate: &syn::Path) -> ForeignItem {
+    let ImportFn {
+        binding_ident,
+        params,
+        forward_attrs,
+        ..
+    } = import_fn;
+    let inputs = params.iter().map(|param| {
+        let ident = &param.ident;
+        let ty = param.binding_type(krate);
+        quote!(#ident: #ty)
+    });
+
+    parse_quote! {
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(catch, #(#forward_attrs),*)]
+        fn #binding_ident(#(#inputs),*) -> ::core::result::Result<#krate::prelude::JsValue, #krate::prelude::JsValue>;
+    }
+}
+
+/// Builds the wrapper of the given function that calls its raw binding, with the
+/// attributes, visibility and signature of the original function
+fn build_wrapper(import_fn: &ImportFn, krate: &syn::Path) -> TokenStream {
+    let ImportFn {
+        item,
+        binding_path,
+        js_name,
+        params,
+        return_type,
+        ..
+    } = import_fn;
+    let attrs = &item.attrs;
+    let vis = &item.vis;
+    let sig = &item.sig;
+    let body = build_call_body(
+        params,
+        binding_path.clone(),
+        sig.asyncness.is_some(),
+        return_type,
+        js_name,
+        krate,
+    );
+
+    quote! {
+        #(#attrs)*
+        #vis #sig {
+            #body
+        }
+    }
+}
+
+/// Determines if the given forwarded attributes contain the attribute of the given key
+fn has_attr(forward_attrs: &[Meta], key: &str) -> bool {
+    forward_attrs.iter().any(|meta| meta.path().is_ident(key))
+}
+
+/// Creates the raw binding name from the original name, it is appended by __wasm_import
+fn populate_name(org_fn_ident: &Ident) -> Ident {
+    format_ident!("{}__wasm_import", org_fn_ident)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_happy() {
+        let mut foreign_mod: ItemForeignMod = parse_quote!(
+            extern "C" {
+                pub type Client;
+
+                /// Fetches an order
+                #[wasm_import(js_name = "fetchOrder")]
+                pub async fn fetch_order(
+                    #[wasm_import(js_name = "orderId")] id: u64,
+                ) -> Result<Order, Error>;
+                #[wasm_import(method)]
+                pub fn close(this: &Client, _: &JsValue) -> Result<(), Error>;
+            }
+        );
+        let result = parse(&mut foreign_mod, parse_quote!(js_namespace = "api")).unwrap();
+        let krate = WasmExportAttrs::default_crate_path();
+        let expected: TokenStream = parse_quote!(
+            #[cfg(target_family = "wasm")]
+            #[#krate::prelude::wasm_bindgen(wasm_bindgen = #krate::prelude::wasm_bindgen, js_namespace = "api")]
+            extern "C" {
+                pub type Client;
+
+                #[allow(non_snake_case)]
+                #[wasm_bindgen(catch, js_name = "fetchOrder")]
+                fn fetch_order__wasm_import(
+                    id: &#krate::prelude::JsValue
+                ) -> ::core::result::Result<#krate::prelude::JsValue, #krate::prelude::JsValue>;
+                #[allow(non_snake_case)]
+                #[wasm_bindgen(catch, method, js_name = "close")]
+                fn close__wasm_import(
+                    this: &Client,
+                    arg1: &#krate::prelude::JsValue
+                ) -> ::core::result::Result<#krate::prelude::JsValue, #krate::prelude::JsValue>;
+            }
+
+            #[cfg(target_family = "wasm")]
+            /// Fetches an order
+            pub async fn fetch_order(id: u64,) -> Result<Order, Error> {
+                let id = #krate::import::encode_js_arg("orderId", &id)?;
+                let value = fetch_order__wasm_import(&id)
+                    .map_err(#krate::result::WasmEncodedError::from)?;
+                let value = #krate::import::resolve_js_value(value).await?;
+                let value = #krate::import::decode_js_result::<Order>("fetchOrder", value)?;
+                ::core::result::Result::Ok(value)
+            }
+
+            #[cfg(target_family = "wasm")]
+            pub fn close(this: &Client, arg1: &JsValue) -> Result<(), Error> {
+                let value = <Client>::close__wasm_import(this, arg1)
+                    .map_err(#krate::result::WasmEncodedError::from)?;
+                #krate::import::check_js_error(&value)?;
+                ::core::result::Result::Ok(())
+            }
+        );
+        assert_eq!(result.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_parse_attrs() {
+        // crate and cfg attrs are not forwarded to wasm_bindgen
+        let mut foreign_mod: ItemForeignMod = parse_quote!(
+            extern "C" {
+                #[wasm_bindgen(js_namespace = console)]
+                fn log(msg: &str) -> Result<(), Error>;
+            }
+        );
+        let top_attrs = parse_quote!(crate = "::some_crate", cfg(feature = "js"));
+        let result = parse(&mut foreign_mod, top_attrs).unwrap();
+        let js_value = quote!(::some_crate::prelude::JsValue);
+        let expected: TokenStream = parse_quote!(
+            #[cfg(feature = "js")]
+            #[::some_crate::prelude::wasm_bindgen(wasm_bindgen = ::some_crate::prelude::wasm_bindgen)]
+            extern "C" {
+                #[allow(non_snake_case)]
+                #[wasm_bindgen(catch, js_namespace = console, js_name = "log")]
+                fn log__wasm_import(msg: &#js_value) -> ::core::result::Result<#js_value, #js_value>;
+            }
+
+            #[cfg(feature = "js")]
+            fn log(msg: &str) -> Result<(), Error> {
+                let msg = ::some_crate::import::encode_js_arg("msg", &msg)?;
+                let value =
+                    log__wasm_import(&msg).map_err(::some_crate::result::WasmEncodedError::from)?;
+                ::some_crate::import::check_js_error(&value)?;
+                ::core::result::Result::Ok(())
+            }
+        );
+        assert_eq!(result.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_parse_unhappy() {
+        let mut foreign_mod: ItemForeignMod = parse_quote!(
+            extern "C" {
+                #[wasm_import(preserve_js_class, skip)]
+                fn a(
+                    #[wasm_import(param_description = "a", optional)] value: u8,
+                ) -> Result<(), Error>;
+                fn b<T>(value: T) -> Result<(), Error>;
+                fn c() -> u8;
+            }
+        );
+        let top_attrs = parse_quote!(unchecked_return_type = "string");
+        let err = parse(&mut foreign_mod, top_attrs).unwrap_err();
+        let msgs = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            msgs,
+            vec![
+                "unexpected `unchecked_return_type` attribute, wasm_import only accepts wasm_bindgen, `crate` and `cfg` attributes",
+                "unexpected `optional` parameter attribute for an imported function, expected one of `param_description`, `unchecked_param_type`, `js_name`, `js_type`",
+                "unexpected `preserve_js_class` attribute, imported functions only accept wasm_bindgen attributes",
+                "unexpected `skip` attribute, imported functions only accept wasm_bindgen attributes",
+                "unexpected generics, wasm_import is only applicable to non generic functions",
+                "expected `Result<T, E>` return type, since calling into JS can fail",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_populate_name() {
+        let ident: Ident = parse_quote!(fetch_order);
+        assert_eq!(
+            populate_name(&ident).to_string(),
+            "fetch_order__wasm_import"
+        );
+    }
+}
@@ -0,0 +1,656 @@
+use quote::quote;
+use proc_macro2::TokenStream;
+use super::attrs::{AttrKeys, InstantiateAttr};
+use syn::{
+    visit_mut::{self, VisitMut},
+    Error, GenericParam, Generics, Ident, Meta, Path, Signature, Type, TypeParamBound,
+    WherePredicate,
+};
+
+/// Concrete instance of a generic function/method that is being exported
+#[derive(Debug, Clone, Default)]
+pub struct ExportInstance {
+    /// Type arguments of the function/method own generic params in order, used as turbofish
+    pub type_args: Vec<Type>,
+    /// Concrete self type of the methods of a generic impl block, used instead of `Self`
+    pub self_type: Option<Type>,
+}
+
+impl ExportInstance {
+    /// Builds the turbofish of the type arguments, e.g. `::<A, B>`, empty if there are none
+    pub fn turbofish(&self) -> TokenStream {
+        if self.type_args.is_empty() {
+            return quote!();
+        }
+        let type_args = &self.type_args;
+        quote!(::<#(#type_args),*>)
+    }
+
+    /// Builds the path of the self type for calling the static methods, `Self` for the
+    /// methods of non generic impl blocks and the qualified concrete self type otherwise,
+    /// which is also qualified by the given trait for the methods of trait impl blocks
+    pub fn self_path(&self, trait_path: Option<&Path>) -> TokenStream {
+        match (&self.self_type, trait_path) {
+            (Some(self_type), Some(trait_path)) => quote!(<#self_type as #trait_path>),
+            (None, Some(trait_path)) => quote!(<Self as #trait_path>),
+            (Some(self_type), None) => quote!(<#self_type>),
+            (None, None) => quote!(Self),
+        }
+    }
+}
+
+/// A concrete instance of a generic function/method that is specified by `instantiate`
+/// attribute, with its type arguments in the order of the generic params
+#[derive(Debug, Clone)]
+pub struct FunctionInstance {
+    pub js_name: String,
+    pub type_args: Vec<(Ident, Type)>,
+}
+
+/// A concrete instance of a generic impl block that is specified by `instantiate`
+/// attribute, with its type arguments in the order of the generic params
+#[derive(Debug, Clone)]
+pub struct ImplInstance {
+    pub js_class: String,
+    pub type_args: Vec<(Ident, Type)>,
+}
+
+/// Returns the type params of the given generics
+pub fn type_params(generics: &Generics) -> Vec<&Ident> {
+    generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(type_param) => Some(&type_param.ident),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Resolves the concrete instances of a generic function/method from its `instantiate`
+/// attributes, each of them must specify a `js_name` and a type argument for each of
+/// the type params, returns no instances if the function is not generic
+pub fn function_instances(
+    generics: &Generics,
+    attrs: &[InstantiateAttr],
+) -> Result<Vec<FunctionInstance>, Error> {
+    if type_params(generics).is_empty() {
+        return match attrs.first() {
+            Some(attr) => Err(Error::new(
+                attr.span,
+                "`instantiate` attribute requires a generic function",
+            )),
+            None => Ok(vec![]),
+        };
+    }
+    if attrs.is_empty() {
+        return Err(Error::new_spanned(
+            generics,
+            "generic functions cannot be exported as is, use `instantiate` attribute to export their concrete instances",
+        ));
+    }
+    let mut instances: Vec<FunctionInstance> = vec![];
+    for attr in attrs {
+        if attr.js_class.is_some() {
+            return Err(Error::new(
+                attr.span,
+                "`js_class` key can only be used in `instantiate` attribute of impl blocks",
+            ));
+        }
+        let Some(js_name) = attr.js_name.clone() else {
+            return Err(Error::new(
+                attr.span,
+                "`instantiate` attribute requires `js_name` key",
+            ));
+        };
+        if instances.iter().any(|instance| instance.js_name == js_name) {
+            let msg = format!("duplicate `{}` instance js_name", js_name);
+            return Err(Error::new(attr.span, msg));
+        }
+        let type_args = resolve_type_args(generics, attr)?;
+        instances.push(FunctionInstance { js_name, type_args });
+    }
+    Ok(instances)
+}
+
+/// Resolves the concrete instances of a generic impl block from its `instantiate`
+/// attributes, each of them must specify a `js_class` and a type argument for each
+/// of the type params, returns no instances if the impl block is not generic
+pub fn impl_instances(
+    generics: &Generics,
+    attrs: &[InstantiateAttr],
+) -> Result<Vec<ImplInstance>, Error> {
+    if type_params(generics).is_empty() {
+        return match attrs.first() {
+            Some(attr) => Err(Error::new(
+                attr.span,
+                "`instantiate` attribute requires a generic impl block, for generic methods use it on the methods instead",
+            )),
+            None => Ok(vec![]),
+        };
+    }
+    if attrs.is_empty() {
+        return Err(Error::new_spanned(
+            generics,
+            "generic impl blocks cannot be exported as is, use `instantiate` attribute to export their concrete instances",
+        ));
+    }
+    let mut instances: Vec<ImplInstance> = vec![];
+    for attr in attrs {
+        if attr.js_name.is_some() {
+            return Err(Error::new(
+                attr.span,
+                "`js_name` key can only be used in `instantiate` attribute of functions and methods, use `js_class` key for impl blocks",
+            ));
+        }
+        let Some(js_class) = attr.js_class.clone() else {
+            return Err(Error::new(
+                attr.span,
+                "`instantiate` attribute of impl blocks requires `js_class` key",
+            ));
+        };
+        if instances
+            .iter()
+            .any(|instance| instance.js_class == js_class)
+        {
+            let msg = format!("duplicate `{}` instance js_class", js_class);
+            return Err(Error::new(attr.span, msg));
+        }
+        let type_args = resolve_type_args(generics, attr)?;
+        instances.push(ImplInstance {
+            js_class,
+            type_args,
+        });
+    }
+    Ok(instances)
+}
+
+/// Forwards the js name of a concrete instance of a generic function/method to wasm_bindgen,
+/// instances can only be named by the `js_name` key of their `instantiate` attribute
+pub fn forward_instance_js_name(forward_attrs: &mut Vec<Meta>, js_name: &str) -> Result<(), Error> {
+    if let Some(meta) = forward_attrs
+        .iter()
+        .find(|meta| meta.path().is_ident(AttrKeys::JS_NAME))
+    {
+        return Err(Error::new_spanned(
+            meta,
+            "`js_name` attribute cannot be used together with `instantiate` attribute, use its `js_name` key instead",
+        ));
+    }
+    forward_attrs.push(syn::parse_quote!(js_name = #js_name));
+    Ok(())
+}
+
+/// Resolves the type arguments of the given `instantiate` attribute in the order of the
+/// type params of the given generics, all of the type params must have a type argument
+fn resolve_type_args(
+    generics: &Generics,
+    attr: &InstantiateAttr,
+) -> Result<Vec<(Ident, Type)>, Error> {
+    let params = type_params(generics);
+    if let Some((ident, _)) = attr
+        .type_args
+        .iter()
+        .find(|(ident, _)| !params.contains(&ident))
+    {
+        let msg = format!(
+            "unexpected `{}` type argument, it is not a generic param",
+            ident
+        );
+        return Err(Error::new_spanned(ident, msg));
+    }
+    params
+        .into_iter()
+        .map(|param| {
+            attr.type_args
+                .iter()
+                .find(|(ident, _)| ident == param)
+                .cloned()
+                .ok_or_else(|| {
+                    let msg = format!("missing type argument for `{}` generic param", param);
+                    Error::new(attr.span, msg)
+                })
+        })
+        .collect()
+}
+
+/// Builds the concrete signature of the given generic signature by substituting the given
+/// type arguments (and `Self` with the given self type, if any), the generics of the given
+/// signature and the given outer generics (of the impl block) are used for resolving the
+/// traits of associated types, such as `T::Output`, the generics are removed afterwards
+pub fn instantiate_signature(
+    sig: &Signature,
+    outer_generics: Option<&Generics>,
+    type_args: &[(Ident, Type)],
+    self_type: Option<&Type>,
+) -> Signature {
+    let generics = [Some(&sig.generics), outer_generics];
+    let mut substitutor = TypeSubstitutor::new(type_args, &generics, self_type);
+    let mut sig = sig.clone();
+    for input in sig.inputs.iter_mut() {
+        substitutor.visit_fn_arg_mut(input);
+    }
+    substitutor.visit_return_type_mut(&mut sig.output);
+    sig.generics = Generics::default();
+    sig
+}
+
+/// Builds the concrete type of the given generic type by substituting the given type
+/// arguments, the given generics are used for resolving the traits of associated types
+pub fn instantiate_type(ty: &Type, generics: &Generics, type_args: &[(Ident, Type)]) -> Type {
+    let mut substitutor = TypeSubstitutor::new(type_args, &[Some(generics)], None);
+    let mut ty = ty.clone();
+    substitutor.visit_type_mut(&mut ty);
+    ty
+}
+
+/// Substitutes the associated types that are referred to by `Self::Name` in the given
+/// signature with their types defined in the trait impl block, since they are ambiguous
+/// in the inherent impl block of the exporting methods
+pub fn resolve_assoc_types(sig: &mut Signature, assoc_types: &[(Ident, Type)]) {
+    AssocTypeResolver(assoc_types).visit_signature_mut(sig);
+}
+
+/// Builds the concrete path of the given generic path, such as the trait of a generic trait
+/// impl block, by substituting the given type arguments in its generic arguments
+pub fn instantiate_path(path: &Path, generics: &Generics, type_args: &[(Ident, Type)]) -> Path {
+    let mut substitutor = TypeSubstitutor::new(type_args, &[Some(generics)], None);
+    let mut path = path.clone();
+    substitutor.visit_path_mut(&mut path);
+    path
+}
+
+/// Returns the trait bound of the given type param if it has exactly one trait bound
+fn single_trait_bound(generics: &Generics, param: &Ident) -> Option<Path> {
+    let param_bounds = generics
+        .params
+        .iter()
+        .flat_map(|generic_param| match generic_param {
+            GenericParam::Type(type_param) if type_param.ident == *param => {
+                type_param.bounds.iter().collect()
+            }
+            _ => vec![],
+        });
+    let where_bounds = generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| where_clause.predicates.iter())
+        .flat_map(|predicate| match predicate {
+            WherePredicate::Type(predicate_type)
+                if matches!(&predicate_type.bounded_ty, Type::Path(path) if path.path.is_ident(param)) =>
+            {
+                predicate_type.bounds.iter().collect()
+            }
+            _ => vec![],
+        });
+    let mut trait_bounds = param_bounds
+        .chain(where_bounds)
+        .filter_map(|bound| match bound {
+            TypeParamBound::Trait(trait_bound)
+                if matches!(trait_bound.modifier, syn::TraitBoundModifier::None) =>
+            {
+                Some(trait_bound.path.clone())
+            }
+            _ => None,
+        });
+    let bound = trait_bounds.next()?;
+    trait_bounds.next().is_none().then_some(bound)
+}
+
+/// Substitutes the type params with their type arguments and `Self` with the self type,
+/// associated types of the type params are qualified with their trait bound if known,
+/// e.g. `T::Output` becomes `<SomeType as SomeTrait>::Output`
+struct TypeSubstitutor {
+    type_args: Vec<(Ident, Type, Option<Path>)>,
+    self_type: Option<Type>,
+}
+
+impl TypeSubstitutor {
+    /// Creates a new substitutor for the given type arguments, the trait bound of each type
+    /// param is looked up in the given generics in order
+    fn new(
+        type_args: &[(Ident, Type)],
+        generics: &[Option<&Generics>],
+        self_type: Option<&Type>,
+    ) -> Self {
+        Self {
+            type_args: type_args
+                .iter()
+                .map(|(ident, ty)| {
+                    let bound = generics
+                        .iter()
+                        .flatten()
+                        .find_map(|generics| single_trait_bound(generics, ident));
+                    (ident.clone(), ty.clone(), bound)
+                })
+                .collect(),
+            self_type: self_type.cloned(),
+        }
+    }
+}
+
+/// Substitutes `Self::Name` types with the given associated types
+struct AssocTypeResolver<'a>(&'a [(Ident, Type)]);
+
+impl VisitMut for AssocTypeResolver<'_> {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Type::Path(type_path) = ty {
+            let segments = &type_path.path.segments;
+            if type_path.qself.is_none()
+                && segments.len() == 2
+                && segments[0].ident == "Self"
+                && segments[1].arguments.is_none()
+            {
+                let assoc_type = self.0.iter().find(|(ident, _)| segments[1].ident == *ident);
+                if let Some((_, assoc_type)) = assoc_type {
+                    *ty = assoc_type.clone();
+                    return;
+                }
+            }
+        }
+        visit_mut::visit_type_mut(self, ty);
+    }
+}
+
+impl VisitMut for TypeSubstitutor {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Type::Path(type_path) = ty {
+            if type_path.qself.is_none() {
+                let segments = &type_path.path.segments;
+                let first = &segments[0];
+                let substitute = if first.ident == "Self" {
+                    self.self_type.clone().map(|self_type| (self_type, None))
+                } else {
+                    self.type_args
+                        .iter()
+                        .find(|(ident, _, _)| first.ident == *ident)
+                        .map(|(_, ty, bound)| (ty.clone(), bound.clone()))
+                };
+                if let Some((substitute, bound)) = substitute.filter(|_| first.arguments.is_none())
+                {
+                    let rest = segments.iter().skip(1).collect::<Vec<_>>();
+                    *ty = match (rest.is_empty(), bound) {
+                        (true, _) => substitute,
+                        (false, Some(bound)) => {
+                            syn::parse_quote!(<#substitute as #bound>::#(#rest)::*)
+                        }
+                        (false, None) => syn::parse_quote!(<#substitute>::#(#rest)::*),
+                    };
+                    return;
+                }
+            }
+        }
+        visit_mut::visit_type_mut(self, ty);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn instantiate_attr(tokens: TokenStream) -> InstantiateAttr {
+        InstantiateAttr::parse_meta(&parse_quote!(instantiate(#tokens))).unwrap()
+    }
+
+    #[test]
+    fn test_function_instances() {
+        let generics: Generics = parse_quote!(<'a, T: Format, U>);
+        let attrs = vec![
+            instantiate_attr(quote!(U = u8, T = Json, js_name = "decodeJson")),
+            instantiate_attr(quote!(T = Cbor, js_name = "decodeCbor", U = Vec<u8>)),
+        ];
+        let result = function_instances(&generics, &attrs).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].js_name, "decodeJson");
+        assert_eq!(
+            result[0].type_args,
+            vec![
+                (parse_quote!(T), parse_quote!(Json)),
+                (parse_quote!(U), parse_quote!(u8))
+            ]
+        );
+        assert_eq!(result[1].js_name, "decodeCbor");
+        assert_eq!(
+            result[1].type_args,
+            vec![
+                (parse_quote!(T), parse_quote!(Cbor)),
+                (parse_quote!(U), parse_quote!(Vec<u8>))
+            ]
+        );
+
+        // non generic function
+        let result = function_instances(&Generics::default(), &[]).unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_function_instances_errors() {
+        let generics: Generics = parse_quote!(<T: Format>);
+        let cases = [
+            (
+                Generics::default(),
+                vec![instantiate_attr(quote!(T = Json, js_name = "a"))],
+                "`instantiate` attribute requires a generic function",
+            ),
+            (
+                generics.clone(),
+                vec![],
+                "generic functions cannot be exported as is, use `instantiate` attribute to export their concrete instances",
+            ),
+            (
+                generics.clone(),
+                vec![instantiate_attr(quote!(T = Json))],
+                "`instantiate` attribute requires `js_name` key",
+            ),
+            (
+                generics.clone(),
+                vec![instantiate_attr(quote!(T = Json, js_class = "A"))],
+                "`js_class` key can only be used in `instantiate` attribute of impl blocks",
+            ),
+            (
+                generics.clone(),
+                vec![instantiate_attr(quote!(U = Json, js_name = "a"))],
+                "unexpected `U` type argument, it is not a generic param",
+            ),
+            (
+                parse_quote!(<T, U>),
+                vec![instantiate_attr(quote!(T = Json, js_name = "a"))],
+                "missing type argument for `U` generic param",
+            ),
+            (
+                generics.clone(),
+                vec![
+                    instantiate_attr(quote!(T = Json, js_name = "a")),
+                    instantiate_attr(quote!(T = Cbor, js_name = "a")),
+                ],
+                "duplicate `a` instance js_name",
+            ),
+        ];
+        for (generics, attrs, expected) in cases {
+            let err = function_instances(&generics, &attrs).unwrap_err();
+            assert_eq!(err.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_impl_instances() {
+        let generics: Generics = parse_quote!(<T: Format>);
+        let attrs = vec![instantiate_attr(quote!(T = Json, js_class = "JsonCodec"))];
+        let result = impl_instances(&generics, &attrs).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].js_class, "JsonCodec");
+        assert_eq!(
+            result[0].type_args,
+            vec![(parse_quote!(T), parse_quote!(Json))]
+        );
+
+        let cases = [
+            (
+                Generics::default(),
+                vec![instantiate_attr(quote!(T = Json, js_class = "A"))],
+                "`instantiate` attribute requires a generic impl block, for generic methods use it on the methods instead",
+            ),
+            (
+                generics.clone(),
+                vec![],
+                "generic impl blocks cannot be exported as is, use `instantiate` attribute to export their concrete instances",
+            ),
+            (
+                generics.clone(),
+                vec![instantiate_attr(quote!(T = Json))],
+                "`instantiate` attribute of impl blocks requires `js_class` key",
+            ),
+            (
+                generics.clone(),
+                vec![instantiate_attr(quote!(T = Json, js_name = "a"))],
+                "`js_name` key can only be used in `instantiate` attribute of functions and methods, use `js_class` key for impl blocks",
+            ),
+            (
+                generics.clone(),
+                vec![
+                    instantiate_attr(quote!(T = Json, js_class = "A")),
+                    instantiate_attr(quote!(T = Cbor, js_class = "A")),
+                ],
+                "duplicate `A` instance js_class",
+            ),
+        ];
+        for (generics, attrs, expected) in cases {
+            let err = impl_instances(&generics, &attrs).unwrap_err();
+            assert_eq!(err.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_forward_instance_js_name() {
+        let mut forward_attrs: Vec<Meta> = vec![parse_quote!(some_attr)];
+        forward_instance_js_name(&mut forward_attrs, "decodeJson").unwrap();
+        let expected: Vec<Meta> = vec![
+            parse_quote!(some_attr),
+            parse_quote!(js_name = "decodeJson"),
+        ];
+        assert_eq!(forward_attrs, expected);
+
+        let err = forward_instance_js_name(&mut forward_attrs, "decodeCbor").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`js_name` attribute cannot be used together with `instantiate` attribute, use its `js_name` key instead"
+        );
+    }
+
+    #[test]
+    fn test_instantiate_signature() {
+        let sig: Signature = parse_quote!(
+            fn decode<T: Format, U>(data: &[T], other: Option<U>, s: Self) -> Result<T::Output, Error>
+            where
+                U: Into<String>
+        );
+        let type_args = vec![
+            (parse_quote!(T), parse_quote!(Json)),
+            (parse_quote!(U), parse_quote!(Vec<u8>)),
+        ];
+        let result = instantiate_signature(&sig, None, &type_args, None);
+        let expected: Signature = parse_quote!(
+            fn decode(data: &[Json], other: Option<Vec<u8>>, s: Self) -> Result<<Json as Format>::Output, Error>
+        );
+        assert_eq!(result, expected);
+
+        // with outer generics and self type
+        let mut outer_generics: Generics = parse_quote!(<T>);
+        outer_generics.where_clause = Some(parse_quote!(where T: Format));
+        let sig: Signature = parse_quote!(
+            fn new(value: T::Output, other: T::Other<u8>) -> Result<Self, Error>
+        );
+        let type_args = vec![(parse_quote!(T), parse_quote!(Json))];
+        let self_type: Type = parse_quote!(Codec<Json>);
+        let result =
+            instantiate_signature(&sig, Some(&outer_generics), &type_args, Some(&self_type));
+        let expected: Signature = parse_quote!(
+            fn new(value: <Json as Format>::Output, other: <Json as Format>::Other<u8>) -> Result<Codec<Json>, Error>
+        );
+        assert_eq!(result, expected);
+
+        // multiple bounds are not qualified with a trait
+        let sig: Signature = parse_quote!(fn decode<T: Format + Clone>() -> T::Output);
+        let type_args = vec![(parse_quote!(T), parse_quote!(Json))];
+        let result = instantiate_signature(&sig, None, &type_args, None);
+        let expected: Signature = parse_quote!(fn decode() -> <Json>::Output);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_instantiate_type() {
+        let generics: Generics = parse_quote!(<T: Format, U>);
+        let type_args = vec![
+            (parse_quote!(T), parse_quote!(Json)),
+            (parse_quote!(U), parse_quote!(u8)),
+        ];
+        let result = instantiate_type(&parse_quote!(Codec<T, U>), &generics, &type_args);
+        let expected: Type = parse_quote!(Codec<Json, u8>);
+        assert_eq!(result, expected);
+
+        let result = instantiate_type(&parse_quote!(Codec<T::Output>), &generics, &type_args);
+        let expected: Type = parse_quote!(Codec<<Json as Format>::Output>);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_resolve_assoc_types() {
+        let mut sig: Signature = parse_quote!(
+            fn parse(&self, input: Self::Input) -> Result<Vec<Self::Output>, Self::Error>
+        );
+        let assoc_types = vec![
+            (parse_quote!(Input), parse_quote!(String)),
+            (parse_quote!(Output), parse_quote!(Order)),
+        ];
+        resolve_assoc_types(&mut sig, &assoc_types);
+        let expected: Signature = parse_quote!(
+            fn parse(&self, input: String) -> Result<Vec<Order>, Self::Error>
+        );
+        assert_eq!(sig, expected);
+    }
+
+    #[test]
+    fn test_instantiate_path() {
+        let generics: Generics = parse_quote!(<T: Format, U>);
+        let type_args = vec![
+            (parse_quote!(T), parse_quote!(Json)),
+            (parse_quote!(U), parse_quote!(Vec<u8>)),
+        ];
+        let result = instantiate_path(&parse_quote!(Encoder<T, U>), &generics, &type_args);
+        let expected: Path = parse_quote!(Encoder<Json, Vec<u8>>);
+        assert_eq!(result, expected);
+
+        let result = instantiate_path(&parse_quote!(Encoder<T::Output>), &generics, &type_args);
+        let expected: Path = parse_quote!(Encoder<<Json as Format>::Output>);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_export_instance() {
+        let instance = ExportInstance::default();
+        assert_eq!(instance.turbofish().to_string(), "");
+        assert_eq!(instance.self_path(None).to_string(), "Self");
+        let self_path = instance.self_path(Some(&parse_quote!(Parser)));
+        let result: syn::ExprPath = parse_quote!(#self_path::some_fn);
+        let expected: syn::ExprPath = parse_quote!(<Self as Parser>::some_fn);
+        assert_eq!(result, expected);
+
+        let instance = ExportInstance {
+            type_args: vec![parse_quote!(Json), parse_quote!(Vec<u8>)],
+            self_type: Some(parse_quote!(Codec<Json>)),
+        };
+        let turbofish = instance.turbofish();
+        let result: syn::ExprPath = parse_quote!(some_fn #turbofish);
+        let expected: syn::ExprPath = parse_quote!(some_fn::<Json, Vec<u8>>);
+        assert_eq!(result, expected);
+        let self_path = instance.self_path(None);
+        let result: syn::ExprPath = parse_quote!(#self_path::some_fn);
+        let expected: syn::ExprPath = parse_quote!(<Codec<Json>>::some_fn);
+        assert_eq!(result, expected);
+        let self_path = instance.self_path(Some(&parse_quote!(Format<Json>)));
+        let result: syn::ExprPath = parse_quote!(#self_path::some_fn);
+        let expected: syn::ExprPath = parse_quote!(<Codec<Json> as Format<Json>>::some_fn);
+        assert_eq!(result, expected);
+    }
+}
@@ -1,99 +1,395 @@
 use quote::quote;
 use proc_macro2::TokenStream;
-use super::{builder::*, attrs::*};
-use syn::{Error, ImplItem, ItemImpl, ReturnType};
+use quote::format_ident;
+use super::{builder::*, attrs::*, docs::RustDocs, error::Errors, generics::*};
+use syn::{Error, Generics, ImplItem, ImplItemFn, ItemImpl, Path, ReturnType, Type};
 
-/// Parses an entire impl block methods and generates the wasm exported impl block with all the expected methods
+/// A method to export along with its wasm_export attributes and its concrete instances
+type ExportMethod = (ImplItemFn, WasmExportAttrs, Vec<FunctionInstance>);
+
+/// Parses an entire impl block methods and generates the wasm exported impl block with all the expected methods,
+/// the errors of all of the methods are collected to be reported at once
 pub fn parse(impl_block: &mut ItemImpl, top_attrs: WasmExportAttrs) -> Result<TokenStream, Error> {
-    // bail early if invalid attributes were identified
+    // report invalid attributes along with the errors of the methods
+    let mut errors = Errors::default();
     if let Some((_, span)) = top_attrs.unchecked_return_type {
-        return Err(Error::new(
+        errors.push(Error::new(
             span,
             "unexpected `unchecked_return_type` attribute, it can only be used for impl block methods or standalone functions",
         ));
     }
     if let Some(span) = top_attrs.preserve_js_class {
-        return Err(Error::new(
+        errors.push(Error::new(
             span,
             "unexpected `preserve_js_class` attribute, it can only be used for impl block methods or standalone functions",
         ));
     }
     if let Some((_, span)) = top_attrs.return_description {
-        return Err(Error::new(
+        errors.push(Error::new(
             span,
             "unexpected `return_description` attribute, it can only be used for impl block methods or standalone functions",
         ));
     }
+    if let Some(map_ok) = &top_attrs.map_ok {
+        errors.push(Error::new_spanned(
+            map_ok,
+            "unexpected `map_ok` attribute, it can only be used for impl block methods or standalone functions",
+        ));
+    }
 
-    // create vector to store exported items
-    // and loop over items inside of the impl block and process each method
-    let mut export_items = Vec::new();
+    // wasm_bindgen cannot export trait impl blocks, so their methods are exported by an
+    // inherent impl block of the self type that calls them through the trait
+    let trait_path = match &impl_block.trait_ {
+        Some((Some(bang), _, _)) => {
+            errors.push(Error::new_spanned(
+                bang,
+                "unexpected negative trait impl, wasm_export is only applicable to inherent or trait impl blocks",
+            ));
+            None
+        }
+        Some((None, trait_path, _)) => Some(trait_path.clone()),
+        None => None,
+    };
+
+    // loop over items inside of the impl block and collect the methods to export with
+    // their attributes and their concrete instances if they are generic
+    let mut export_methods = Vec::new();
     for item in impl_block.items.iter_mut() {
         if let ImplItem::Fn(method) = item {
-            // process the method only if its visibility is pub
-            if let syn::Visibility::Public(_) = method.vis {
-                // process method attributes
-                let mut wasm_export_attrs = WasmExportAttrs::handle_method_attrs(method)?;
-
-                // skip this method if skip attr was detected
-                if wasm_export_attrs.should_skip.is_some() {
-                    continue;
-                }
-
-                let return_type = wasm_export_attrs.handle_return_type(&method.sig.output);
-                let WasmExportAttrs {
-                    forward_attrs,
-                    preserve_js_class,
-                    ..
-                } = wasm_export_attrs;
-
-                // items included for exporting must all have Result<> return type
-                if let Some(return_type) = return_type {
-                    // create export method with the given configurations
-                    let config = WasmExportFunctionBuilderConfig {
-                        forward_attrs,
-                        return_type,
-                        preserve_js_class,
-                    };
-                    let export_method =
-                        WasmExportFunctionBuilder::build_export_method(method, config)?;
-
-                    // Clean wasm_export attributes from original method parameters
-                    WasmExportFunctionBuilder::clean_parameter_attributes(&mut method.sig.inputs);
-
-                    export_items.push(ImplItem::Fn(export_method));
-                } else {
-                    let msg = "expected Result<T, E> return type";
-                    return match &method.sig.output {
-                        ReturnType::Default => Err(Error::new_spanned(&method.sig, msg)),
-                        ReturnType::Type(_, _) => Err(Error::new_spanned(&method.sig.output, msg)),
-                    };
+            // process the method only if its visibility is pub, trait impl methods
+            // take the visibility of their trait, so they are all processed
+            if trait_path.is_some() || matches!(method.vis, syn::Visibility::Public(_)) {
+                if let Some(export_method) = errors.collect(parse_method(method)).flatten() {
+                    export_methods.push(export_method);
                 }
             }
         }
     }
 
-    let mut export_impl_block = impl_block.clone();
-    export_impl_block.items = export_items;
-    if !top_attrs.forward_attrs.is_empty() {
+    // the associated types of trait impl blocks are resolved in the exporting methods
+    if trait_path.is_some() {
+        let assoc_types = impl_block
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                ImplItem::Type(assoc_type) => {
+                    Some((assoc_type.ident.clone(), assoc_type.ty.clone()))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        for (method, _, _) in export_methods.iter_mut() {
+            resolve_assoc_types(&mut method.sig, &assoc_types);
+        }
+    }
+
+    // wasm_bindgen classes cannot be generic, so each concrete instance of a generic impl
+    // block is exported as a separate class with static methods that call the instance
+    let impl_instances = errors
+        .collect(impl_instances(&impl_block.generics, &top_attrs.instantiate))
+        .unwrap_or_default();
+    if impl_instances.is_empty() {
+        let mut export_impl_block = impl_block.clone();
+        export_impl_block.unsafety = None;
+        export_impl_block.trait_ = None;
+        export_impl_block.items = errors
+            .collect(build_export_methods(
+                &export_methods,
+                &top_attrs,
+                trait_path.as_ref(),
+                None,
+            ))
+            .unwrap_or_default();
+        if !top_attrs.forward_attrs.is_empty() {
+            let forward = &top_attrs.forward_attrs;
+            export_impl_block.attrs = vec![syn::parse_quote!(
+                #[wasm_bindgen(#(#forward),*)]
+            )];
+        } else {
+            export_impl_block.attrs = vec![syn::parse_quote!(
+                #[wasm_bindgen]
+            )];
+        }
+
+        // Create two impl blocks, original and exporting one
+        let export_impl_block = export_scope(&top_attrs, quote!(#export_impl_block));
+        let output = quote! {
+            #impl_block
+
+            #export_impl_block
+        };
+
+        return errors.finish_with(output);
+    }
+    if let Some(meta) = top_attrs
+        .forward_attrs
+        .iter()
+        .find(|meta| meta.path().is_ident(AttrKeys::JS_CLASS))
+    {
+        errors.push(Error::new_spanned(
+            meta,
+            "`js_class` attribute cannot be used together with `instantiate` attribute, use its `js_class` key instead",
+        ));
+    }
+    let mut export_items = Vec::new();
+    for instance in impl_instances {
+        let self_type = instantiate_type(
+            &impl_block.self_ty,
+            &impl_block.generics,
+            &instance.type_args,
+        );
+        let trait_path = trait_path
+            .as_ref()
+            .map(|path| instantiate_path(path, &impl_block.generics, &instance.type_args));
+        // the methods errors are the same for all of the instances, so they are
+        // only reported for the first one
+        let Some(items) = errors.collect(build_export_methods(
+            &export_methods,
+            &top_attrs,
+            trait_path.as_ref(),
+            Some((&impl_block.generics, &instance, &self_type)),
+        )) else {
+            break;
+        };
+        let js_class = &instance.js_class;
+        let class_ident = format_ident!(
+            "{}__wasm_export",
+            js_class
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect::<String>()
+        );
         let forward = &top_attrs.forward_attrs;
-        export_impl_block.attrs = vec![syn::parse_quote!(
-            #[wasm_bindgen(#(#forward),*)]
-        )];
-    } else {
-        export_impl_block.attrs = vec![syn::parse_quote!(
-            #[wasm_bindgen]
-        )];
+        export_items.push(quote! {
+            #[allow(non_camel_case_types)]
+            #[wasm_bindgen(js_name = #js_class)]
+            pub struct #class_ident;
+
+            #[wasm_bindgen(js_class = #js_class #(, #forward)*)]
+            impl #class_ident {
+                #(#items)*
+            }
+        });
     }
 
-    // Create two impl blocks, original and exporting one
+    // Create the original impl block and the exporting classes of its instances
+    let export_items = export_scope(&top_attrs, quote!(#(#export_items)*));
     let output = quote! {
         #impl_block
 
-        #export_impl_block
+        #export_items
     };
 
-    Ok(output)
+    errors.finish_with(output)
+}
+
+/// Parses the wasm_export attributes of the given method and cleans them from it, returns
+/// the method to export with its attributes and its concrete instances if it is generic,
+/// or None if it should be skipped
+fn parse_method(method: &mut ImplItemFn) -> Result<Option<ExportMethod>, Error> {
+    // process method attributes, if they are invalid the method params are
+    // still processed so that their errors are reported along with them
+    let mut errors = Errors::default();
+    let Some(mut wasm_export_attrs) = errors.collect(WasmExportAttrs::handle_method_attrs(method))
+    else {
+        errors.collect(WasmExportFunctionBuilder::process_function_parameters(
+            &method.sig.inputs,
+            &RustDocs::default(),
+            &WasmExportAttrs::default_crate_path(),
+        ));
+        return errors.finish_with(None);
+    };
+
+    // skip this method if skip attr was detected
+    if wasm_export_attrs.should_skip.is_some() {
+        return Ok(None);
+    }
+
+    // fall back to the rustdoc `# Returns` section for the return description
+    let docs = RustDocs::parse(&WasmExportFunctionBuilder::extract_doc_comments(
+        &method.attrs,
+    ));
+    wasm_export_attrs.handle_doc_returns(&docs);
+    errors.collect(wasm_export_attrs.validate_map_ok());
+    if let Some(krate) = &wasm_export_attrs.krate {
+        errors.push(Error::new_spanned(
+            krate,
+            "unexpected `crate` attribute, it can only be used for impl blocks or standalone functions",
+        ));
+    }
+    if let Some(cfg) = &wasm_export_attrs.cfg {
+        errors.push(Error::new_spanned(
+            cfg,
+            "unexpected `cfg` attribute, it can only be used for impl blocks or standalone functions",
+        ));
+    }
+    let instances = errors.collect(function_instances(
+        &method.sig.generics,
+        &wasm_export_attrs.instantiate,
+    ));
+    let export_method = (
+        method.clone(),
+        wasm_export_attrs,
+        instances.unwrap_or_default(),
+    );
+
+    // Clean wasm_export attributes from original method parameters
+    WasmExportFunctionBuilder::clean_parameter_attributes(&mut method.sig.inputs);
+
+    errors.finish_with(Some(export_method))
+}
+
+/// Wraps the given exporting items in an anonymous const block that brings the wasm_bindgen
+/// crates in scope through the crate path, since the code that wasm_bindgen generates for
+/// impl blocks refers to them by their crate names regardless of its crate path options,
+/// the block is only compiled under the `cfg` predicate of the given attributes
+fn export_scope(top_attrs: &WasmExportAttrs, items: TokenStream) -> TokenStream {
+    let krate = top_attrs.crate_path();
+    let cfg = top_attrs.cfg_predicate();
+    quote! {
+        #[cfg(#cfg)]
+        const _: () = {
+            #[allow(unused_imports)]
+            use #krate::prelude::{wasm_bindgen, wasm_bindgen_futures};
+
+            #items
+        };
+    }
+}
+
+/// Builds the wasm export methods of the given methods, each generic method is exported
+/// once per its concrete instances, and if the impl block is generic, the methods are
+/// built for the given concrete instance of it with the given concrete self type, the
+/// methods of trait impl blocks are called through the given trait, the errors of all of
+/// the methods are collected to be reported at once
+fn build_export_methods(
+    methods: &[ExportMethod],
+    top_attrs: &WasmExportAttrs,
+    trait_path: Option<&Path>,
+    impl_instance: Option<(&Generics, &ImplInstance, &Type)>,
+) -> Result<Vec<ImplItem>, Error> {
+    let mut errors = Errors::default();
+    let mut export_items = Vec::new();
+    for method in methods {
+        let items = build_method_instances(method, top_attrs, trait_path, impl_instance);
+        if let Some(items) = errors.collect(items) {
+            export_items.extend(items);
+        }
+    }
+    errors.finish_with(export_items)
+}
+
+/// Builds the wasm export methods of the given method, one per its concrete instances
+fn build_method_instances(
+    (method, wasm_export_attrs, instances): &ExportMethod,
+    top_attrs: &WasmExportAttrs,
+    trait_path: Option<&Path>,
+    impl_instance: Option<(&Generics, &ImplInstance, &Type)>,
+) -> Result<Vec<ImplItem>, Error> {
+    let mut export_items = Vec::new();
+    if let (Some(receiver), Some(_)) = (method.sig.receiver(), impl_instance) {
+        return Err(Error::new_spanned(
+            receiver,
+            "methods with `self` receiver of generic impl blocks cannot be exported, since wasm_bindgen classes cannot be generic",
+        ));
+    }
+
+    // export each concrete instance of the method, or the method itself if it is not generic
+    let method_instances = if instances.is_empty() {
+        vec![None]
+    } else {
+        instances.iter().map(Some).collect()
+    };
+    for instance in method_instances {
+        let mut wasm_export_attrs = wasm_export_attrs.clone();
+        let mut type_args = impl_instance
+            .map(|(_, impl_instance, _)| impl_instance.type_args.clone())
+            .unwrap_or_default();
+        let mut export_instance = None;
+        if let Some(instance) = instance {
+            forward_instance_js_name(&mut wasm_export_attrs.forward_attrs, &instance.js_name)?;
+            type_args.extend(instance.type_args.iter().cloned());
+            export_instance = Some(ExportInstance {
+                type_args: instance
+                    .type_args
+                    .iter()
+                    .map(|(_, ty)| ty.clone())
+                    .collect(),
+                self_type: None,
+            });
+        }
+
+        // build the concrete method by substituting the type arguments of the method
+        // and of the impl block, and `Self` with the concrete self type
+        let mut concrete_method = method.clone();
+        if instance.is_some() || impl_instance.is_some() {
+            let generics = impl_instance.map(|(generics, _, _)| generics);
+            let self_type = impl_instance.map(|(_, _, self_type)| self_type);
+            concrete_method.sig =
+                instantiate_signature(&method.sig, generics, &type_args, self_type);
+            if let Some(self_type) = self_type {
+                export_instance
+                    .get_or_insert_with(ExportInstance::default)
+                    .self_type = Some(self_type.clone());
+            }
+        }
+
+        let return_type = wasm_export_attrs.handle_return_type(&concrete_method.sig.output);
+        let WasmExportAttrs {
+            forward_attrs,
+            preserve_js_class,
+            error_context,
+            cancellable,
+            timeout_ms,
+            deprecated,
+            map_ok,
+            ..
+        } = wasm_export_attrs;
+
+        // items included for exporting must all have Result<> return type
+        if let Some((return_type, return_kind)) = return_type {
+            // cancellable and timeout_ms at impl block level apply to all of its cancellable
+            // methods, except that the methods which already have an abort signal or a
+            // variadic param are not given the signal param of cancellable
+            let is_cancellable =
+                WasmExportFunctionBuilder::is_cancellable(&concrete_method.sig, return_kind);
+            let can_append_signal_param =
+                WasmExportFunctionBuilder::can_append_signal_param(&concrete_method.sig.inputs);
+            // create export method with the given configurations
+            let config = WasmExportFunctionBuilderConfig {
+                forward_attrs,
+                return_type,
+                return_kind,
+                preserve_js_class,
+                // error_context at impl block level applies to all of its methods
+                error_context: error_context.or(top_attrs.error_context),
+                cancellable: cancellable.or(top_attrs
+                    .cancellable
+                    .filter(|_| is_cancellable && can_append_signal_param)),
+                timeout_ms: timeout_ms.or(top_attrs.timeout_ms.filter(|_| is_cancellable)),
+                // deprecated at impl block level applies to all of its methods
+                deprecated: deprecated.or_else(|| top_attrs.deprecated.clone()),
+                map_ok,
+                instance: export_instance,
+                trait_path: trait_path.cloned(),
+                krate: top_attrs.crate_path(),
+            };
+            let mut export_method =
+                WasmExportFunctionBuilder::build_export_method(&concrete_method, config)?;
+            if trait_path.is_some() {
+                // the exporting methods of trait impl methods need their own visibility
+                export_method.vis = syn::parse_quote!(pub);
+            }
+            export_items.push(ImplItem::Fn(export_method));
+        } else {
+            let msg = "expected Result<T, E> return type";
+            return match &method.sig.output {
+                ReturnType::Default => Err(Error::new_spanned(&method.sig, msg)),
+                ReturnType::Type(_, _) => Err(Error::new_spanned(&method.sig.output, msg)),
+            };
+        }
+    }
+    Ok(export_items)
 }
 
 #[cfg(test)]
@@ -119,25 +415,217 @@ mod tests {
         );
         let result = parse(&mut method, WasmExportAttrs::default()).unwrap();
         let expected: TokenStream = parse_quote!(
-            impl SomeStrcut {
-                pub fn some_fn(arg1: String) -> Result<SomeType, Error> {
+                impl SomeStrcut {
+                    pub fn some_fn(arg1: String) -> Result<SomeType, Error> {
+                        Ok(SomeType::new())
+                    }
+                    #[some_external_macro]
+                    pub fn some_skip_fn(arg1: String) -> SomeType {
+                        SomeType::new()
+                    }
+                }
+                #[cfg(target_family = "wasm")]
+                const _: () = {
+                    #[allow(unused_imports)]
+                    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+
+                    #[wasm_bindgen]
+                    impl SomeStrcut {
+                        #[allow(non_snake_case)]
+                        #[wasm_bindgen(some_forward_attr, unchecked_return_type = "WasmEncodedResult<string>")]
+                        pub fn some_fn__wasm_export(arg1: String) -> ::wasm_bindgen_utils::result::WasmEncodedResult<SomeType> {
+                            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+        let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("some_fn__wasm_export");
+                            Self::some_fn(arg1).encode_errors(|error| __wasm_export_call.on_error(error), |_| {}).into()
+                        }
+                    }
+                };
+            );
+        assert_eq!(result.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_parse_trait_impl() {
+        let mut method: ItemImpl = parse_quote!(
+            impl Parser for SomeStrcut {
+                type Output = SomeType;
+                #[wasm_export(js_name = "parse")]
+                fn parse(&self, #[wasm_export(js_name = "rawInput")] input: String) -> Result<Self::Output, Error> {
                     Ok(SomeType::new())
                 }
-                #[some_external_macro]
-                pub fn some_skip_fn(arg1: String) -> SomeType {
-                    SomeType::new()
+                fn name() -> Result<String, Error> {
+                    Ok(String::new())
+                }
+            }
+        );
+        let result = parse(&mut method, WasmExportAttrs::default()).unwrap();
+        let krate = WasmExportAttrs::default_crate_path();
+        let expected: TokenStream = parse_quote!(
+            impl Parser for SomeStrcut {
+                type Output = SomeType;
+                fn parse(&self, input: String) -> Result<Self::Output, Error> {
+                    Ok(SomeType::new())
+                }
+                fn name() -> Result<String, Error> {
+                    Ok(String::new())
                 }
             }
-            #[wasm_bindgen]
+            #[cfg(target_family = "wasm")]
+            const _: () = {
+                #[allow(unused_imports)]
+                use #krate::prelude::{wasm_bindgen, wasm_bindgen_futures};
+
+                #[wasm_bindgen]
+                impl SomeStrcut {
+                    #[allow(non_snake_case)]
+                    #[wasm_bindgen(js_name = "parse", unchecked_return_type = "WasmEncodedResult<SomeType>")]
+                    pub fn parse__wasm_export(&self, #[wasm_bindgen(js_name = "rawInput")] input: String) -> #krate::result::WasmEncodedResult<SomeType> {
+                        use #krate::result::EncodeErrors as _;
+                        let __wasm_export_call = #krate::hooks::ExportCall::start("parse");
+                        <Self as Parser>::parse(self, input).encode_errors(|error| __wasm_export_call.on_error(error), |_| {}).into()
+                    }
+                    #[allow(non_snake_case)]
+                    #[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<String>")]
+                    pub fn name__wasm_export() -> #krate::result::WasmEncodedResult<String> {
+                        use #krate::result::EncodeErrors as _;
+                        let __wasm_export_call = #krate::hooks::ExportCall::start("name__wasm_export");
+                        <Self as Parser>::name().encode_errors(|error| __wasm_export_call.on_error(error), |_| {}).into()
+                    }
+                }
+            };
+        );
+        assert_eq!(result.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_parse_instantiate() {
+        // generic methods of a non generic impl block
+        let mut method: ItemImpl = parse_quote!(
             impl SomeStrcut {
-                #[allow(non_snake_case)]
-                #[wasm_bindgen(some_forward_attr, unchecked_return_type = "WasmEncodedResult<string>")]
-                pub fn some_fn__wasm_export(arg1: String) -> WasmEncodedResult<SomeType> {
-                    Self::some_fn(arg1).into()
+                #[wasm_export(instantiate(T = Json, js_name = "decodeJson"))]
+                pub fn decode<T: Format>(&self, data: Vec<u8>) -> Result<T, Error> {}
+            }
+        );
+        let result = parse(&mut method, WasmExportAttrs::default()).unwrap();
+        let expected: TokenStream = parse_quote!(
+                impl SomeStrcut {
+                    pub fn decode<T: Format>(&self, data: Vec<u8>) -> Result<T, Error> {}
                 }
+                #[cfg(target_family = "wasm")]
+                const _: () = {
+                    #[allow(unused_imports)]
+                    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+
+                    #[wasm_bindgen]
+                    impl SomeStrcut {
+                        #[allow(non_snake_case)]
+                        #[wasm_bindgen(js_name = "decodeJson", unchecked_return_type = "WasmEncodedResult<Json>")]
+                        pub fn decode__wasm_export_decodeJson(&self, data: Vec<u8>) -> ::wasm_bindgen_utils::result::WasmEncodedResult<Json> {
+                            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+        let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("decodeJson");
+                            self.decode::<Json>(data).encode_errors(|error| __wasm_export_call.on_error(error), |_| {}).into()
+                        }
+                    }
+                };
+            );
+        assert_eq!(result.to_string(), expected.to_string());
+
+        // generic impl block with a generic method
+        let mut method: ItemImpl = parse_quote!(
+            impl<T: Format> Codec<T> {
+                pub fn name() -> Result<String, Error> {}
+                #[wasm_export(instantiate(U = u8, js_name = "encodeBytes"))]
+                pub fn encode<U>(value: T::Value, items: Vec<U>) -> Result<Self, Error> {}
             }
         );
+        let top_attrs: WasmExportAttrs =
+            parse_quote!(instantiate(T = Json, js_class = "JsonCodec"));
+        let result = parse(&mut method, top_attrs).unwrap();
+        let self_type: Type = parse_quote!(Codec<Json>);
+        let encode_inputs = quote!(value: <Json as Format>::Value, items: Vec<u8>);
+        let expected: TokenStream = parse_quote!(
+                impl<T: Format> Codec<T> {
+                    pub fn name() -> Result<String, Error> {}
+                    pub fn encode<U>(value: T::Value, items: Vec<U>) -> Result<Self, Error> {}
+                }
+                #[cfg(target_family = "wasm")]
+                const _: () = {
+                    #[allow(unused_imports)]
+                    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+
+                    #[allow(non_camel_case_types)]
+                    #[wasm_bindgen(js_name = "JsonCodec")]
+                    pub struct JsonCodec__wasm_export;
+
+                    #[wasm_bindgen(js_class = "JsonCodec")]
+                    impl JsonCodec__wasm_export {
+                        #[allow(non_snake_case)]
+                        #[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<String>")]
+                        pub fn name__wasm_export() -> ::wasm_bindgen_utils::result::WasmEncodedResult<String> {
+                            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+        let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("name__wasm_export");
+                            <#self_type>::name()
+                                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                                .into()
+                        }
+                        #[allow(non_snake_case)]
+                        #[wasm_bindgen(
+                            js_name = "encodeBytes",
+                            unchecked_return_type = "WasmEncodedResult<Codec < Json >>"
+                        )]
+                        pub fn encode__wasm_export_encodeBytes(#encode_inputs) -> ::wasm_bindgen_utils::result::WasmEncodedResult<#self_type> {
+                            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+        let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("encodeBytes");
+                            <#self_type>::encode::<u8>(value, items)
+                                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                                .into()
+                        }
+                    }
+                };
+            );
         assert_eq!(result.to_string(), expected.to_string());
+
+        // error for self receiver methods of generic impl blocks
+        let mut method: ItemImpl = parse_quote!(
+            impl<T: Format> Codec<T> {
+                pub fn name(&self) -> Result<String, Error> {}
+            }
+        );
+        let top_attrs: WasmExportAttrs =
+            parse_quote!(instantiate(T = Json, js_class = "JsonCodec"));
+        let err = parse(&mut method, top_attrs).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "methods with `self` receiver of generic impl blocks cannot be exported, since wasm_bindgen classes cannot be generic"
+        );
+
+        // error for generic impl block without instantiate attr
+        let mut method: ItemImpl = parse_quote!(
+            impl<T: Format> Codec<T> {
+                pub fn name() -> Result<String, Error> {}
+            }
+        );
+        let err = parse(&mut method, WasmExportAttrs::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "generic impl blocks cannot be exported as is, use `instantiate` attribute to export their concrete instances"
+        );
+
+        // error for js_class attr used with instantiate attr
+        let mut method: ItemImpl = parse_quote!(
+            impl<T: Format> Codec<T> {
+                pub fn name() -> Result<String, Error> {}
+            }
+        );
+        let top_attrs: WasmExportAttrs = parse_quote!(
+            js_class = "Codec",
+            instantiate(T = Json, js_class = "JsonCodec")
+        );
+        let err = parse(&mut method, top_attrs).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`js_class` attribute cannot be used together with `instantiate` attribute, use its `js_class` key instead"
+        );
     }
 
     #[test]
@@ -174,6 +662,45 @@ mod tests {
         let err = parse(&mut method, wasm_export_attr).unwrap_err();
         assert_eq!(err.to_string(), "unexpected `preserve_js_class` attribute, it can only be used for impl block methods or standalone functions");
 
+        // error for top map_ok attr
+        let mut method: ItemImpl = parse_quote!(
+            impl SomeStrcut {
+                pub fn some_fn(arg1: String) -> Result<SomeType, Error> {
+                    Ok(SomeType::new())
+                }
+            }
+        );
+        let wasm_export_attr = WasmExportAttrs {
+            map_ok: Some(parse_quote!(to_js_type)),
+            ..Default::default()
+        };
+        let err = parse(&mut method, wasm_export_attr).unwrap_err();
+        assert_eq!(err.to_string(), "unexpected `map_ok` attribute, it can only be used for impl block methods or standalone functions");
+
+        // error for method level crate attr
+        let mut method: ItemImpl = parse_quote!(
+            impl SomeStrcut {
+                #[wasm_export(crate = "::some_crate::wbg_utils")]
+                pub fn some_fn(arg1: String) -> Result<SomeType, Error> {
+                    Ok(SomeType::new())
+                }
+            }
+        );
+        let err = parse(&mut method, WasmExportAttrs::default()).unwrap_err();
+        assert_eq!(err.to_string(), "unexpected `crate` attribute, it can only be used for impl blocks or standalone functions");
+
+        // error for method level cfg attr
+        let mut method: ItemImpl = parse_quote!(
+            impl SomeStrcut {
+                #[wasm_export(cfg(feature = "js"))]
+                pub fn some_fn(arg1: String) -> Result<SomeType, Error> {
+                    Ok(SomeType::new())
+                }
+            }
+        );
+        let err = parse(&mut method, WasmExportAttrs::default()).unwrap_err();
+        assert_eq!(err.to_string(), "unexpected `cfg` attribute, it can only be used for impl blocks or standalone functions");
+
         // error for method with non result return type
         let mut method: ItemImpl = parse_quote!(
             impl SomeStrcut {
@@ -185,5 +712,36 @@ mod tests {
         );
         let err = parse(&mut method, WasmExportAttrs::default()).unwrap_err();
         assert_eq!(err.to_string(), "expected Result<T, E> return type");
+
+        // errors of all of the methods and their params are collected
+        let mut method: ItemImpl = parse_quote!(
+            impl SomeStrcut {
+                #[wasm_export(skip = true)]
+                pub fn some_fn(arg1: String) -> Result<SomeType, Error> {
+                    Ok(SomeType::new())
+                }
+                pub fn some_other_fn(#[wasm_export(js_nme = "a")] arg1: String) -> Result<SomeType, Error> {
+                    Ok(SomeType::new())
+                }
+                pub fn some_skip_fn(arg1: String) -> SomeType {
+                    SomeType::new()
+                }
+            }
+        );
+        let wasm_export_attr = WasmExportAttrs {
+            map_ok: Some(parse_quote!(to_js_type)),
+            ..Default::default()
+        };
+        let err = parse(&mut method, wasm_export_attr).unwrap_err();
+        let msgs = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            msgs,
+            vec![
+                "unexpected `map_ok` attribute, it can only be used for impl block methods or standalone functions",
+                "unexpected token in attribute, `skip` attribute does not take any extra tokens or arguments",
+                "unexpected `js_nme` parameter attribute, did you mean `js_name`?",
+                "expected Result<T, E> return type",
+            ]
+        );
     }
 }
@@ -0,0 +1,439 @@
+use quote::quote;
+use proc_macro2::TokenStream;
+use quote::format_ident;
+use super::{attrs::*, builder::WasmExportFunctionBuilder, docs::RustDocs, error::Errors};
+use syn::{
+    punctuated::Punctuated, token::Comma, Error, FnArg, Ident, Meta, Pat, PatIdent, Signature, Type,
+};
+
+/// Contains the parameter attribute keys that are valid for the params of functions that call into JS
+const JS_CALL_PARAM_ATTR_KEYS: [&str; 4] = [
+    AttrKeys::PARAM_DESCRIPTION,
+    AttrKeys::UNCHECKED_PARAM_TYPE,
+    AttrKeys::JS_NAME,
+    AttrKeys::JS_TYPE,
+];
+
+/// A param of a function that calls into JS code, such as a method of a JS-implementable
+/// trait adapter or an imported JS function wrapper
+#[derive(Debug, Clone)]
+pub struct JsCallParam {
+    /// Name of the param, either its ident or a generated one if it is not named by its pattern
+    pub ident: Ident,
+    pub ty: Type,
+    pub js_name: String,
+    pub ts_type: String,
+    pub description: Option<String>,
+    /// Whether the param is passed to the JS code as is instead of being encoded as a JS value
+    pub raw: bool,
+}
+
+impl JsCallParam {
+    /// Determines if the param is encoded as a JS value before it is passed to the JS code
+    pub fn is_encoded(&self) -> bool {
+        !self.raw
+            && !WasmExportFunctionBuilder::is_js_value_type(&self.ty)
+            && !is_js_value_ref(&self.ty)
+    }
+
+    /// Returns the type of the param in the raw wasm_bindgen binding of the JS code
+    pub fn binding_type(&self, krate: &syn::Path) -> TokenStream {
+        let ty = &self.ty;
+        if self.raw {
+            quote!(#ty)
+        } else {
+            quote!(&#krate::prelude::JsValue)
+        }
+    }
+
+    /// Returns the arg that the param is passed to the raw wasm_bindgen binding with
+    pub fn call_arg(&self) -> TokenStream {
+        let ident = &self.ident;
+        if self.raw || is_js_value_ref(&self.ty) {
+            quote!(#ident)
+        } else {
+            quote!(&#ident)
+        }
+    }
+}
+
+/// Parses the params of the given signature along with their attributes of the given key,
+/// params without `param_description` attribute fall back to their description in the given
+/// rustdoc, the given kind of the function is used in the error msgs
+pub fn parse_params(
+    sig: &Signature,
+    docs: &RustDocs,
+    attr_key: &str,
+    fn_kind: &str,
+) -> Result<Vec<JsCallParam>, Error> {
+    let mut errors = Errors::default();
+    let mut params = Vec::new();
+    for (i, pat_type) in sig
+        .inputs
+        .iter()
+        .filter_map(|input| match input {
+            FnArg::Typed(pat_type) => Some(pat_type),
+            FnArg::Receiver(_) => None,
+        })
+        .enumerate()
+    {
+        let mut metas = Vec::new();
+        for attr in &pat_type.attrs {
+            if attr.path().is_ident(attr_key) {
+                let attr_metas =
+                    WasmExportFunctionBuilder::process_parameter_wasm_export_attr(attr);
+                metas.extend(errors.collect(attr_metas).unwrap_or_default());
+            }
+        }
+        let mut value_of = |key: &str| {
+            let meta = metas.iter().find(|meta| meta.path().is_ident(key))?;
+            errors.collect(str_value(meta))
+        };
+        let js_name = value_of(AttrKeys::JS_NAME);
+        let ts_type = value_of(AttrKeys::UNCHECKED_PARAM_TYPE).or(value_of(AttrKeys::JS_TYPE));
+        let description = value_of(AttrKeys::PARAM_DESCRIPTION);
+        for meta in metas.iter() {
+            if !JS_CALL_PARAM_ATTR_KEYS
+                .into_iter()
+                .any(|key| meta.path().is_ident(key))
+            {
+                let path = meta.path();
+                let key = quote!(#path).to_string().replace(' ', "");
+                errors.push(Error::new_spanned(
+                    path,
+                    format!(
+                        "unexpected `{key}` parameter attribute for {fn_kind}, expected one of {}",
+                        JS_CALL_PARAM_ATTR_KEYS
+                            .map(|key| format!("`{key}`"))
+                            .join(", ")
+                    ),
+                ));
+            }
+        }
+
+        let ident = match pat_type.pat.as_ref() {
+            Pat::Ident(PatIdent {
+                ident,
+                by_ref: None,
+                subpat: None,
+                ..
+            }) => ident.clone(),
+            _ => format_ident!("arg{}", i),
+        };
+        let js_name = js_name.unwrap_or_else(|| ident.to_string());
+        let description = description.or_else(|| {
+            docs.param_description(&ident.to_string())
+                .map(ToString::to_string)
+        });
+        params.push(JsCallParam {
+            ts_type: ts_type.unwrap_or_else(|| self::ts_type(&pat_type.ty)),
+            ty: pat_type.ty.as_ref().clone(),
+            ident,
+            js_name,
+            description,
+            raw: false,
+        });
+    }
+
+    errors.finish_with(params)
+}
+
+/// Cleans the attributes of the given key from the given params and names the params that
+/// are not named by their patterns with the names of the given parsed params
+pub fn clean_params(inputs: &mut Punctuated<FnArg, Comma>, params: &[JsCallParam], attr_key: &str) {
+    for (pat_type, param) in inputs
+        .iter_mut()
+        .filter_map(|input| match input {
+            FnArg::Typed(pat_type) => Some(pat_type),
+            FnArg::Receiver(_) => None,
+        })
+        .zip(params)
+    {
+        pat_type
+            .attrs
+            .retain(|attr| !attr.path().is_ident(attr_key));
+        let ident = &param.ident;
+        *pat_type.pat = syn::parse_quote!(#ident);
+    }
+}
+
+/// Builds the body of a function that calls into JS code through the given call of its raw
+/// wasm_bindgen binding, the args are encoded as JS values, the returned value is resolved if
+/// the function is async and decoded into the given type, where thrown JS exceptions, promise
+/// rejections, returned `WasmEncodedResult` errors and invalid values are converted into the
+/// error of the function through `WasmEncodedError`, the given name is used in the error msgs
+pub fn build_call_body(
+    params: &[JsCallParam],
+    call: TokenStream,
+    is_async: bool,
+    return_type: &Type,
+    name: &str,
+    krate: &syn::Path,
+) -> TokenStream {
+    let encode_args = params
+        .iter()
+        .filter(|param| param.is_encoded())
+        .map(|param| {
+            let ident = &param.ident;
+            let js_name = &param.js_name;
+            quote! {
+                let #ident = #krate::import::encode_js_arg(#js_name, &#ident)?;
+            }
+        });
+    let call_args = params.iter().map(JsCallParam::call_arg);
+    let resolve = is_async.then(|| {
+        quote! {
+            let value = #krate::import::resolve_js_value(value).await?;
+        }
+    });
+    let decode = if matches!(return_type, Type::Tuple(tuple) if tuple.elems.is_empty()) {
+        quote! {
+            #krate::import::check_js_error(&value)?;
+            ::core::result::Result::Ok(())
+        }
+    } else if WasmExportFunctionBuilder::is_js_value_type(return_type) {
+        quote! {
+            #krate::import::check_js_error(&value)?;
+            ::core::result::Result::Ok(value)
+        }
+    } else {
+        quote! {
+            let value = #krate::import::decode_js_result::<#return_type>(#name, value)?;
+            ::core::result::Result::Ok(value)
+        }
+    };
+
+    quote! {
+        #(#encode_args)*
+        let value = #call(#(#call_args),*)
+            .map_err(#krate::result::WasmEncodedError::from)?;
+        #resolve
+        #decode
+    }
+}
+
+/// Returns the string literal value of the given name-value meta
+pub fn str_value(meta: &Meta) -> Result<String, Error> {
+    match &meta.require_name_value()?.value {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(str),
+            ..
+        }) => Ok(str.value()),
+        _ => Err(Error::new_spanned(meta, "expected string literal")),
+    }
+}
+
+/// Determines if the given type is `&JsValue`
+pub fn is_js_value_ref(ty: &Type) -> bool {
+    matches!(ty, Type::Reference(reference) if WasmExportFunctionBuilder::is_js_value_type(&reference.elem))
+}
+
+/// Returns the TS type of the given type, references are declared as their referenced types,
+/// `Option<T>` as `T | undefined`, `Vec<T>` and slices as arrays and the types that their TS
+/// types are not known as `any`
+pub fn ts_type(ty: &Type) -> String {
+    match ty {
+        Type::Reference(reference) => ts_type(&reference.elem),
+        Type::Paren(paren) => ts_type(&paren.elem),
+        Type::Tuple(tuple) if tuple.elems.is_empty() => "void".to_string(),
+        Type::Slice(slice) => ts_array_type(&slice.elem),
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            if WasmExportFunctionBuilder::is_js_value_type(ty) {
+                return "any".to_string();
+            }
+            if let Some(item_type) = WasmExportFunctionBuilder::vec_item_type(ty) {
+                return ts_array_type(item_type);
+            }
+            let Some(segment) = type_path.path.segments.last() else {
+                return "any".to_string();
+            };
+            if segment.ident == "str" {
+                return "string".to_string();
+            }
+            if WasmExportFunctionBuilder::is_option_type(ty) {
+                if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                    if let Some(syn::GenericArgument::Type(inner)) = args.args.first() {
+                        let inner = WasmExportFunctionBuilder::ts_function_group(ts_type(inner));
+                        return format!("{inner} | undefined");
+                    }
+                }
+            }
+            WasmExportFunctionBuilder::ts_type_of(ty).unwrap_or_else(|| "any".to_string())
+        }
+        _ => "any".to_string(),
+    }
+}
+
+/// Returns the TS array type of the given item type
+fn ts_array_type(item_type: &Type) -> String {
+    let item = ts_type(item_type);
+    if item.contains(' ') {
+        format!("({item})[]")
+    } else {
+        format!("{item}[]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_parse_params() {
+        let sig: Signature = parse_quote!(
+            fn get(
+                &self,
+                #[wasm_export(js_name = "itemKey", param_description = "some key")] key: &str,
+                _: Vec<u8>,
+                #[wasm_export(js_type = "Item")] item: JsValue,
+            ) -> Result<(), Error>
+        );
+        let docs = RustDocs {
+            params: vec![("arg1".to_string(), "some bytes".to_string())],
+            ..Default::default()
+        };
+        let params = parse_params(&sig, &docs, AttrKeys::WASM_EXPORT, "a trait method").unwrap();
+        let result = params
+            .iter()
+            .map(|param| {
+                (
+                    param.ident.to_string(),
+                    param.js_name.as_str(),
+                    param.ts_type.as_str(),
+                    param.description.as_deref(),
+                    param.is_encoded(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            result,
+            vec![
+                (
+                    "key".to_string(),
+                    "itemKey",
+                    "string",
+                    Some("some key"),
+                    true
+                ),
+                (
+                    "arg1".to_string(),
+                    "arg1",
+                    "number[]",
+                    Some("some bytes"),
+                    true
+                ),
+                ("item".to_string(), "item", "Item", None, false),
+            ]
+        );
+
+        let sig: Signature = parse_quote!(
+            fn get(
+                #[wasm_import(optional)] key: String,
+                #[wasm_import(js_name = 1)] other: String,
+            ) -> Result<(), Error>
+        );
+        let err = parse_params(
+            &sig,
+            &RustDocs::default(),
+            "wasm_import",
+            "an imported function",
+        )
+        .unwrap_err();
+        let msgs = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            msgs,
+            vec![
+                "unexpected `optional` parameter attribute for an imported function, expected one of `param_description`, `unchecked_param_type`, `js_name`, `js_type`",
+                "expected string literal",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_call_body() {
+        let krate = WasmExportAttrs::default_crate_path();
+        let params = vec![
+            JsCallParam {
+                ident: parse_quote!(this),
+                ty: parse_quote!(&Storage),
+                js_name: "this".to_string(),
+                ts_type: "any".to_string(),
+                description: None,
+                raw: true,
+            },
+            JsCallParam {
+                ident: parse_quote!(key),
+                ty: parse_quote!(String),
+                js_name: "itemKey".to_string(),
+                ts_type: "string".to_string(),
+                description: None,
+                raw: false,
+            },
+            JsCallParam {
+                ident: parse_quote!(value),
+                ty: parse_quote!(&JsValue),
+                js_name: "value".to_string(),
+                ts_type: "any".to_string(),
+                description: None,
+                raw: false,
+            },
+        ];
+        let result = build_call_body(
+            &params,
+            quote!(set__wasm_import),
+            true,
+            &parse_quote!(Item),
+            "set",
+            &krate,
+        );
+        let expected: TokenStream = parse_quote!(
+            let key = #krate::import::encode_js_arg("itemKey", &key)?;
+            let value = set__wasm_import(this, &key, value)
+                .map_err(#krate::result::WasmEncodedError::from)?;
+            let value = #krate::import::resolve_js_value(value).await?;
+            let value = #krate::import::decode_js_result::<Item>("set", value)?;
+            ::core::result::Result::Ok(value)
+        );
+        assert_eq!(result.to_string(), expected.to_string());
+
+        let result = build_call_body(
+            &[],
+            quote!(clear),
+            false,
+            &parse_quote!(()),
+            "clear",
+            &krate,
+        );
+        let expected: TokenStream = parse_quote!(
+            let value = clear()
+                .map_err(#krate::result::WasmEncodedError::from)?;
+            #krate::import::check_js_error(&value)?;
+            ::core::result::Result::Ok(())
+        );
+        assert_eq!(result.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_ts_type() {
+        assert_eq!(ts_type(&parse_quote!(&str)), "string");
+        assert_eq!(ts_type(&parse_quote!(u64)), "bigint");
+        assert_eq!(ts_type(&parse_quote!(())), "void");
+        assert_eq!(ts_type(&parse_quote!(JsValue)), "any");
+        assert_eq!(ts_type(&parse_quote!(Option<u8>)), "number | undefined");
+        assert_eq!(
+            ts_type(&parse_quote!(Vec<Option<u8>>)),
+            "(number | undefined)[]"
+        );
+        assert_eq!(ts_type(&parse_quote!(&[SomeType])), "SomeType[]");
+        assert_eq!(
+            ts_type(&parse_quote!(JsUnion2<u8, String>)),
+            "number | string"
+        );
+        assert_eq!(ts_type(&parse_quote!(HashMap<String, u8>)), "any");
+        assert_eq!(
+            ts_type(&parse_quote!(Option<JsCallback<(u8,), ()>>)),
+            "((arg0: number) => void) | undefined"
+        );
+    }
+}
@@ -1,25 +1,62 @@
 use syn::{Error, Item};
 use proc_macro2::TokenStream;
+use error::Errors;
 
 mod attrs;
+mod docs;
 mod error;
 mod builder;
+mod extern_block;
+mod generics;
 mod impl_block;
+mod js_call;
 mod standalone_fn;
+mod trait_def;
 
 /// Starts macro parsing and expansion process by routing the parse towards corresponding
 /// parse logic based on input type
 pub fn expand(attr: TokenStream, item: TokenStream) -> Result<TokenStream, Error> {
     let input = syn::parse2(item)?;
-    let top_attrs = syn::parse2(attr)?;
+
+    // the input is still parsed with the default attributes if the top attributes are
+    // invalid, so that the errors of both are reported at once
+    let mut errors = Errors::default();
+    let top_attrs = errors.collect(syn::parse2(attr)).unwrap_or_default();
 
     // parse the input based on its type
-    match input {
+    let output = match input {
         Item::Impl(mut impl_block) => impl_block::parse(&mut impl_block, top_attrs),
         Item::Fn(mut func) => standalone_fn::parse(&mut func, top_attrs),
+        Item::Trait(mut item_trait) => trait_def::parse(&mut item_trait, top_attrs),
         _ => Err(Error::new_spanned(
             &input,
-            "unexpected input, wasm_export macro is only applicable to impl blocks or functions",
+            "unexpected input, wasm_export macro is only applicable to impl blocks, functions or traits",
         )),
-    }
+    };
+    let output = errors.collect(output);
+    errors.finish()?;
+
+    Ok(output.unwrap_or_default())
+}
+
+/// Starts wasm_import macro parsing and expansion process of the given extern block
+pub fn expand_import(attr: TokenStream, item: TokenStream) -> Result<TokenStream, Error> {
+    let input = syn::parse2(item)?;
+
+    // the input is still parsed with the default attributes if the top attributes are
+    // invalid, so that the errors of both are reported at once
+    let mut errors = Errors::default();
+    let top_attrs = errors.collect(syn::parse2(attr)).unwrap_or_default();
+
+    let output = match input {
+        Item::ForeignMod(mut foreign_mod) => extern_block::parse(&mut foreign_mod, top_attrs),
+        _ => Err(Error::new_spanned(
+            &input,
+            "unexpected input, wasm_import macro is only applicable to extern blocks",
+        )),
+    };
+    let output = errors.collect(output);
+    errors.finish()?;
+
+    Ok(output.unwrap_or_default())
 }
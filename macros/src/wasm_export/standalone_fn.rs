@@ -2,7 +2,12 @@ use quote::quote;
 use proc_macro2::TokenStream;
 use syn::{Error, ItemFn, ReturnType, Visibility};
 use super::{
+    docs::RustDocs,
+    error::Errors,
     attrs::WasmExportAttrs,
+    generics::{
+        forward_instance_js_name, function_instances, instantiate_signature, ExportInstance,
+    },
     builder::{WasmExportFunctionBuilder, WasmExportFunctionBuilderConfig},
 };
 
@@ -17,9 +22,67 @@ pub fn parse(func: &mut ItemFn, mut top_attrs: WasmExportAttrs) -> Result<TokenS
         };
     }
 
-    // Validate return type and determine the inner type T for Result<T, E>
-    let return_type = match top_attrs.handle_return_type(&func.sig.output) {
-        Some(ty) => ty,
+    // Fall back to the rustdoc `# Returns` section for the return description
+    let docs = RustDocs::parse(&WasmExportFunctionBuilder::extract_doc_comments(
+        &func.attrs,
+    ));
+    top_attrs.handle_doc_returns(&docs);
+    let mut errors = Errors::default();
+    errors.collect(top_attrs.validate_map_ok());
+
+    // the exporting functions are only compiled under the cfg predicate, which
+    // is wasm targets by default, so the original function can be built natively
+    let cfg = top_attrs.cfg_predicate();
+
+    // Resolve the concrete instances to export if the function is generic
+    let instances = function_instances(&func.sig.generics, &top_attrs.instantiate)?;
+    let export_fns = if instances.is_empty() {
+        build_export_fn(func, top_attrs, None).map(|export_fn| vec![export_fn])
+    } else {
+        instances
+            .into_iter()
+            .map(|instance| {
+                // each instance is exported as a concrete function with the given js name
+                let mut attrs = top_attrs.clone();
+                forward_instance_js_name(&mut attrs.forward_attrs, &instance.js_name)?;
+                let mut concrete_fn = func.clone();
+                concrete_fn.sig = instantiate_signature(&func.sig, None, &instance.type_args, None);
+                let export_instance = ExportInstance {
+                    type_args: instance.type_args.into_iter().map(|(_, ty)| ty).collect(),
+                    self_type: None,
+                };
+                build_export_fn(&concrete_fn, attrs, Some(export_instance))
+            })
+            .collect::<Result<Vec<_>, Error>>()
+    };
+    let export_fns = errors.collect(export_fns).unwrap_or_default();
+
+    // Clean wasm_export attributes from original function parameters
+    WasmExportFunctionBuilder::clean_parameter_attributes(&mut func.sig.inputs);
+
+    // Combine original and exported function tokens
+    let output = quote! {
+        #func // The original function (with wasm_export attr removed)
+
+        #(
+            #[cfg(#cfg)]
+            #export_fns
+        )*
+    };
+
+    errors.finish_with(output)
+}
+
+/// Builds the wasm exported function of the given function, or of the given concrete
+/// instance of it if the function is generic
+fn build_export_fn(
+    func: &ItemFn,
+    mut top_attrs: WasmExportAttrs,
+    instance: Option<ExportInstance>,
+) -> Result<ItemFn, Error> {
+    // Validate return type and determine the inner type T for Result<T, E> or Diagnosed<T, E>
+    let (return_type, return_kind) = match top_attrs.handle_return_type(&func.sig.output) {
+        Some(ret) => ret,
         None => {
             let msg = "expected Result<T, E> return type";
             return match &func.sig.output {
@@ -29,6 +92,9 @@ pub fn parse(func: &mut ItemFn, mut top_attrs: WasmExportAttrs) -> Result<TokenS
         }
     };
 
+    // the generated code refers to the crate items by their fully qualified paths
+    let krate = top_attrs.crate_path();
+
     // Top attrs (WasmExportAttrs) parsing logic already handles 'skip'
     // which is not valid for standalone functions, also standalone
     // functions cannot have function level attrs like impl blocks do,
@@ -37,6 +103,11 @@ pub fn parse(func: &mut ItemFn, mut top_attrs: WasmExportAttrs) -> Result<TokenS
     let WasmExportAttrs {
         forward_attrs,
         preserve_js_class,
+        error_context,
+        cancellable,
+        timeout_ms,
+        deprecated,
+        map_ok,
         ..
     } = top_attrs;
 
@@ -44,21 +115,18 @@ pub fn parse(func: &mut ItemFn, mut top_attrs: WasmExportAttrs) -> Result<TokenS
     let config = WasmExportFunctionBuilderConfig {
         forward_attrs,
         return_type,
+        return_kind,
         preserve_js_class,
+        error_context,
+        cancellable,
+        timeout_ms,
+        deprecated,
+        map_ok,
+        instance,
+        trait_path: None,
+        krate,
     };
-    let export_fn = WasmExportFunctionBuilder::build_export_function(func, config)?;
-
-    // Clean wasm_export attributes from original function parameters
-    WasmExportFunctionBuilder::clean_parameter_attributes(&mut func.sig.inputs);
-
-    // Combine original and exported function tokens
-    let output = quote! {
-        #func // The original function (with wasm_export attr removed)
-
-        #export_fn
-    };
-
-    Ok(output)
+    WasmExportFunctionBuilder::build_export_function(func, config)
 }
 
 #[cfg(test)]
@@ -67,8 +135,19 @@ mod tests {
     use syn::parse_quote;
     use proc_macro2::Span;
 
+    fn wasm_bindgen_attr_parts() -> (TokenStream, TokenStream) {
+        (
+            quote!(::wasm_bindgen_utils::prelude::wasm_bindgen),
+            quote!(
+                wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+                wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures
+            ),
+        )
+    }
+
     #[test]
     fn test_parse_standalone_fn_basic() {
+        let (wasm_bindgen, crate_options) = wasm_bindgen_attr_parts();
         let mut func: ItemFn = parse_quote!(
             pub async fn my_async_func(a: String) -> Result<u32, JsValue> {
                 Ok(a.len() as u32)
@@ -82,10 +161,18 @@ mod tests {
                 Ok(a.len() as u32)
             }
 
+            #[cfg(target_family = "wasm")]
+
             #[allow(non_snake_case)]
-            #[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<u32>")]
-            pub async fn my_async_func__wasm_export(a: String) -> WasmEncodedResult<u32> {
-                my_async_func(a).await.into()
+            #[#wasm_bindgen(#crate_options, unchecked_return_type = "WasmEncodedResult<u32>")]
+            pub async fn my_async_func__wasm_export(a: String) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u32> {
+                use ::wasm_bindgen_utils::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    ::wasm_bindgen_utils::hooks::ExportCall::start("my_async_func__wasm_export");
+                my_async_func(a)
+                    .await
+                    .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                    .into()
             }
         );
         assert_eq!(result.to_string(), expected.to_string());
@@ -93,6 +180,7 @@ mod tests {
 
     #[test]
     fn test_parse_standalone_fn_with_top_attrs() {
+        let (wasm_bindgen, crate_options) = wasm_bindgen_attr_parts();
         let mut func: ItemFn = parse_quote!(
             #[something_else]
             pub fn my_sync_func() -> Result<(), JsValue> {
@@ -109,14 +197,18 @@ mod tests {
                 Ok(())
             }
 
+            #[cfg(target_family = "wasm")]
+
             #[allow(non_snake_case)]
-            #[wasm_bindgen(
-                catch,
-                js_name = "specificName",
-                unchecked_return_type = "WasmEncodedResult<()>"
-            )]
-            pub fn my_sync_func__wasm_export() -> WasmEncodedResult<()> {
-                my_sync_func().into()
+            #[#wasm_bindgen(#crate_options, catch, js_name = "specificName", unchecked_return_type = "WasmEncodedResult<()>")]
+            pub fn my_sync_func__wasm_export() -> ::wasm_bindgen_utils::result::WasmEncodedResult<()>
+            {
+                use ::wasm_bindgen_utils::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    ::wasm_bindgen_utils::hooks::ExportCall::start("specificName");
+                my_sync_func()
+                    .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                    .into()
             }
         );
         assert_eq!(result.to_string(), expected.to_string());
@@ -124,6 +216,7 @@ mod tests {
 
     #[test]
     fn test_parse_standalone_fn_return_override() {
+        let (wasm_bindgen, crate_options) = wasm_bindgen_attr_parts();
         let mut func: ItemFn = parse_quote!(
             pub fn override_func() -> Result<MyRustType, JsValue> {
                 Ok(MyRustType)
@@ -137,10 +230,18 @@ mod tests {
                 Ok(MyRustType)
             }
 
+            #[cfg(target_family = "wasm")]
+
             #[allow(non_snake_case)]
-            #[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<MyJsType>")]
-            pub fn override_func__wasm_export() -> WasmEncodedResult<MyRustType> {
-                override_func().into()
+            #[#wasm_bindgen(#crate_options, unchecked_return_type = "WasmEncodedResult<MyJsType>")]
+            pub fn override_func__wasm_export(
+            ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<MyRustType> {
+                use ::wasm_bindgen_utils::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    ::wasm_bindgen_utils::hooks::ExportCall::start("override_func__wasm_export");
+                override_func()
+                    .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                    .into()
             }
         );
         assert_eq!(result.to_string(), expected.to_string());
@@ -160,6 +261,7 @@ mod tests {
 
     #[test]
     fn test_parse_happy() {
+        let (wasm_bindgen, crate_options) = wasm_bindgen_attr_parts();
         let mut method: ItemFn = parse_quote!(
             pub async fn some_fn(arg1: String) -> Result<SomeType, Error> {}
         );
@@ -169,14 +271,85 @@ mod tests {
             unchecked_return_type: Some(("string".to_string(), Span::call_site())),
             preserve_js_class: None,
             return_description: None,
+            error_context: None,
+            cancellable: None,
+            timeout_ms: None,
+            deprecated: None,
+            map_ok: None,
+            instantiate: vec![],
+            krate: None,
+            cfg: None,
         };
         let result = parse(&mut method, wasm_export_attrs).unwrap();
         let expected: TokenStream = parse_quote!(
             pub async fn some_fn(arg1: String) -> Result<SomeType, Error> {}
+            #[cfg(target_family = "wasm")]
+            #[allow(non_snake_case)]
+            #[#wasm_bindgen(#crate_options, some_forward_attr, unchecked_return_type = "WasmEncodedResult<string>")]
+            pub async fn some_fn__wasm_export(arg1: String) -> ::wasm_bindgen_utils::result::WasmEncodedResult<SomeType> {
+                use ::wasm_bindgen_utils::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    ::wasm_bindgen_utils::hooks::ExportCall::start("some_fn__wasm_export");
+                some_fn(arg1)
+                    .await
+                    .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                    .into()
+            }
+        );
+        assert_eq!(result.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_parse_crate_path() {
+        let mut func: ItemFn = parse_quote!(
+            pub fn some_fn(arg1: String) -> Result<SomeType, Error> {}
+        );
+        let top_attrs: WasmExportAttrs = parse_quote!(crate = "::some_crate::wbg_utils");
+        let result = parse(&mut func, top_attrs).unwrap();
+        let krate = quote!(::some_crate::wbg_utils);
+        let expected: TokenStream = parse_quote!(
+            pub fn some_fn(arg1: String) -> Result<SomeType, Error> {}
+            #[cfg(target_family = "wasm")]
             #[allow(non_snake_case)]
-            #[wasm_bindgen(some_forward_attr, unchecked_return_type = "WasmEncodedResult<string>")]
-            pub async fn some_fn__wasm_export(arg1: String) -> WasmEncodedResult<SomeType> {
-                some_fn(arg1).await.into()
+            #[#krate::prelude::wasm_bindgen(
+                wasm_bindgen = #krate::prelude::wasm_bindgen,
+                wasm_bindgen_futures = #krate::prelude::wasm_bindgen_futures,
+                unchecked_return_type = "WasmEncodedResult<SomeType>"
+            )]
+            pub fn some_fn__wasm_export(arg1: String) -> #krate::result::WasmEncodedResult<SomeType> {
+                use #krate::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    #krate::hooks::ExportCall::start("some_fn__wasm_export");
+                some_fn(arg1)
+                    .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                    .into()
+            }
+        );
+        assert_eq!(result.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_parse_cfg() {
+        let (wasm_bindgen, crate_options) = wasm_bindgen_attr_parts();
+        let mut func: ItemFn = parse_quote!(
+            pub fn some_fn(#[wasm_export(js_name = "someArg")] arg: u8) -> Result<SomeType, Error> {
+            }
+        );
+        let top_attrs: WasmExportAttrs =
+            parse_quote!(cfg(all(target_family = "wasm", feature = "js")));
+        let result = parse(&mut func, top_attrs).unwrap();
+        let expected: TokenStream = parse_quote!(
+            pub fn some_fn(arg: u8) -> Result<SomeType, Error> {}
+            #[cfg(all(target_family = "wasm", feature = "js"))]
+            #[allow(non_snake_case)]
+            #[#wasm_bindgen(#crate_options, unchecked_return_type = "WasmEncodedResult<SomeType>")]
+            pub fn some_fn__wasm_export(#[wasm_bindgen(js_name = "someArg")] arg: u8) -> ::wasm_bindgen_utils::result::WasmEncodedResult<SomeType> {
+                use ::wasm_bindgen_utils::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    ::wasm_bindgen_utils::hooks::ExportCall::start("some_fn__wasm_export");
+                some_fn(arg)
+                    .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                    .into()
             }
         );
         assert_eq!(result.to_string(), expected.to_string());
@@ -201,8 +374,74 @@ mod tests {
         assert_eq!(err.to_string(), "expected Result<T, E> return type");
     }
 
+    #[test]
+    fn test_parse_instantiate() {
+        let (wasm_bindgen, crate_options) = wasm_bindgen_attr_parts();
+        let mut func: ItemFn = parse_quote!(
+            pub fn decode<T: Format>(data: Vec<u8>) -> Result<T, Error> {}
+        );
+        let top_attrs: WasmExportAttrs = parse_quote!(
+            instantiate(T = Json, js_name = "decodeJson"),
+            instantiate(T = Cbor, js_name = "decode-cbor")
+        );
+        let result = parse(&mut func, top_attrs).unwrap();
+        let expected: TokenStream = parse_quote!(
+            pub fn decode<T: Format>(data: Vec<u8>) -> Result<T, Error> {}
+
+            #[cfg(target_family = "wasm")]
+
+            #[allow(non_snake_case)]
+            #[#wasm_bindgen(#crate_options, js_name = "decodeJson", unchecked_return_type = "WasmEncodedResult<Json>")]
+            pub fn decode__wasm_export_decodeJson(data: Vec<u8>) -> ::wasm_bindgen_utils::result::WasmEncodedResult<Json> {
+                use ::wasm_bindgen_utils::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    ::wasm_bindgen_utils::hooks::ExportCall::start("decodeJson");
+                decode::<Json>(data)
+                    .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                    .into()
+            }
+            #[cfg(target_family = "wasm")]
+            #[allow(non_snake_case)]
+            #[#wasm_bindgen(#crate_options, js_name = "decode-cbor", unchecked_return_type = "WasmEncodedResult<Cbor>")]
+            pub fn decode__wasm_export_decode_cbor(data: Vec<u8>) -> ::wasm_bindgen_utils::result::WasmEncodedResult<Cbor> {
+                use ::wasm_bindgen_utils::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    ::wasm_bindgen_utils::hooks::ExportCall::start("decode-cbor");
+                decode::<Cbor>(data)
+                    .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                    .into()
+            }
+        );
+        assert_eq!(result.to_string(), expected.to_string());
+
+        // error for generic function without instantiate attr
+        let mut func: ItemFn = parse_quote!(
+            pub fn decode<T: Format>(data: Vec<u8>) -> Result<T, Error> {}
+        );
+        let err = parse(&mut func, WasmExportAttrs::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "generic functions cannot be exported as is, use `instantiate` attribute to export their concrete instances"
+        );
+
+        // error for js_name attr used with instantiate attr
+        let mut func: ItemFn = parse_quote!(
+            pub fn decode<T: Format>(data: Vec<u8>) -> Result<T, Error> {}
+        );
+        let top_attrs: WasmExportAttrs = parse_quote!(
+            js_name = "decode",
+            instantiate(T = Json, js_name = "decodeJson")
+        );
+        let err = parse(&mut func, top_attrs).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`js_name` attribute cannot be used together with `instantiate` attribute, use its `js_name` key instead"
+        );
+    }
+
     #[test]
     fn test_parse_standalone_fn_with_return_description() {
+        let (wasm_bindgen, crate_options) = wasm_bindgen_attr_parts();
         let mut func: ItemFn = parse_quote!(
             pub fn add(a: u32, b: u32) -> Result<u32, Error> {
                 Ok(a + b)
@@ -217,13 +456,17 @@ mod tests {
                 Ok(a + b)
             }
 
+            #[cfg(target_family = "wasm")]
+
             #[allow(non_snake_case)]
-            #[wasm_bindgen(
-                unchecked_return_type = "WasmEncodedResult<u32>",
-                return_description = "sum of the inputs"
-            )]
-            pub fn add__wasm_export(a: u32, b: u32) -> WasmEncodedResult<u32> {
-                add(a, b).into()
+            #[#wasm_bindgen(#crate_options, unchecked_return_type = "WasmEncodedResult<u32>", return_description = "sum of the inputs")]
+            pub fn add__wasm_export(a: u32, b: u32) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u32> {
+                use ::wasm_bindgen_utils::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    ::wasm_bindgen_utils::hooks::ExportCall::start("add__wasm_export");
+                add(a, b)
+                    .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                    .into()
             }
         );
         assert_eq!(result.to_string(), expected.to_string());
@@ -231,6 +474,7 @@ mod tests {
 
     #[test]
     fn test_parse_standalone_fn_with_return_description_and_other_attrs() {
+        let (wasm_bindgen, crate_options) = wasm_bindgen_attr_parts();
         let mut func: ItemFn = parse_quote!(
             pub async fn complex_calc(input: String) -> Result<i64, Error> {
                 Ok(42)
@@ -248,15 +492,18 @@ mod tests {
                 Ok(42)
             }
 
+            #[cfg(target_family = "wasm")]
+
             #[allow(non_snake_case)]
-            #[wasm_bindgen(
-                js_name = "complexCalculation",
-                catch,
-                unchecked_return_type = "WasmEncodedResult<i64>",
-                return_description = "complex calculation result"
-            )]
-            pub async fn complex_calc__wasm_export(input: String) -> WasmEncodedResult<i64> {
-                complex_calc(input).await.into()
+            #[#wasm_bindgen(#crate_options, js_name = "complexCalculation", catch, unchecked_return_type = "WasmEncodedResult<i64>", return_description = "complex calculation result")]
+            pub async fn complex_calc__wasm_export(input: String) -> ::wasm_bindgen_utils::result::WasmEncodedResult<i64> {
+                use ::wasm_bindgen_utils::result::EncodeErrors as _;
+                let __wasm_export_call =
+                    ::wasm_bindgen_utils::hooks::ExportCall::start("complexCalculation");
+                complex_calc(input)
+                    .await
+                    .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                    .into()
             }
         );
         assert_eq!(result.to_string(), expected.to_string());
@@ -0,0 +1,567 @@
+use quote::quote;
+use proc_macro2::TokenStream;
+use quote::format_ident;
+use super::{attrs::*, builder::WasmExportFunctionBuilder, docs::RustDocs, error::Errors, js_call::*};
+use syn::{Attribute, Error, Ident, ItemTrait, Meta, Signature, TraitItem, TraitItemFn, Type};
+
+/// A method of a JS-implementable trait along with the details of its JS counterpart
+struct ImportMethod {
+    /// Signature of the method that the adapter implements, with its params named
+    sig: Signature,
+    js_name: String,
+    params: Vec<JsCallParam>,
+    /// The `T` of the returning `Result<T, E>`
+    return_type: Type,
+    ts_return_type: String,
+    return_description: Option<String>,
+    docs: Vec<String>,
+}
+
+/// Parses a trait definition and generates a JS interface type that JS objects implementing
+/// the trait are passed as, along with its TS interface declaration and an adapter struct that
+/// implements the trait by calling into the JS object, the errors of all of the methods are
+/// collected to be reported at once
+pub fn parse(item_trait: &mut ItemTrait, top_attrs: WasmExportAttrs) -> Result<TokenStream, Error> {
+    // report invalid attributes along with the errors of the methods
+    let mut errors = Errors::default();
+    let ts_name = validate_trait_attrs(&top_attrs, &mut errors)
+        .unwrap_or_else(|| item_trait.ident.to_string());
+
+    // the adapter can only implement non generic traits that have no supertraits
+    if !item_trait.generics.params.is_empty() || item_trait.generics.where_clause.is_some() {
+        errors.push(Error::new_spanned(
+            &item_trait.generics,
+            "unexpected generics, wasm_export is only applicable to non generic traits",
+        ));
+    }
+    if !item_trait.supertraits.is_empty() {
+        errors.push(Error::new_spanned(
+            &item_trait.supertraits,
+            "unexpected supertraits, JS objects can only implement traits without supertraits",
+        ));
+    }
+
+    // loop over items inside of the trait and collect the methods that JS objects implement
+    let mut import_methods = Vec::new();
+    for item in item_trait.items.iter_mut() {
+        match item {
+            TraitItem::Fn(method) => {
+                if let Some(import_method) = errors.collect(parse_method(method)).flatten() {
+                    import_methods.push(import_method);
+                }
+            }
+            TraitItem::Type(assoc_type) => errors.push(Error::new_spanned(
+                assoc_type,
+                "unexpected associated type, JS objects can only implement traits that only contain methods",
+            )),
+            TraitItem::Const(assoc_const) => errors.push(Error::new_spanned(
+                assoc_const,
+                "unexpected associated const, JS objects can only implement traits that only contain methods",
+            )),
+            _ => {}
+        }
+    }
+    errors.finish()?;
+
+    let krate = top_attrs.crate_path();
+    let cfg = top_attrs.cfg_predicate();
+    let vis = &item_trait.vis;
+    let unsafety = &item_trait.unsafety;
+    let trait_ident = &item_trait.ident;
+    let js_ident = format_ident!("Js{}", trait_ident);
+    let adapter_ident = format_ident!("Js{}Adapter", trait_ident);
+    let js_doc =
+        format!(" A JS object that implements [`{trait_ident}`] trait as `{ts_name}` TS interface");
+    let adapter_doc = format!(
+        " Implements [`{trait_ident}`] trait by calling into the wrapped [`{js_ident}`] JS object"
+    );
+    let ts_interface = build_ts_interface(&ts_name, &item_trait.attrs, &import_methods);
+    let extern_methods = import_methods
+        .iter()
+        .map(|method| build_extern_method(method, &js_ident, &krate));
+    let adapter_methods = import_methods
+        .iter()
+        .map(|method| build_adapter_method(method, &ts_name, &krate));
+
+    Ok(quote! {
+        #item_trait
+
+        #[cfg(#cfg)]
+        #[#krate::prelude::wasm_bindgen(wasm_bindgen = #krate::prelude::wasm_bindgen)]
+        extern "C" {
+            #[doc = #js_doc]
+            #[derive(Debug, Clone)]
+            #[wasm_bindgen(typescript_type = #ts_name)]
+            #vis type #js_ident;
+
+            #(#extern_methods)*
+        }
+
+        #[cfg(#cfg)]
+        const _: () = {
+            use #krate::prelude::wasm_bindgen;
+
+            #[wasm_bindgen(typescript_custom_section)]
+            const TS_INTERFACE: &'static str = #ts_interface;
+        };
+
+        #[cfg(#cfg)]
+        #[doc = #adapter_doc]
+        #[derive(Debug, Clone)]
+        #vis struct #adapter_ident(pub #js_ident);
+
+        #[cfg(#cfg)]
+        impl ::core::convert::From<#js_ident> for #adapter_ident {
+            fn from(value: #js_ident) -> Self {
+                Self(value)
+            }
+        }
+
+        #[cfg(#cfg)]
+        #unsafety impl #trait_ident for #adapter_ident {
+            #(#adapter_methods)*
+        }
+    })
+}
+
+/// Validates the top wasm_export attributes of a trait, only `js_name`, `crate` and `cfg`
+/// attributes are valid for them, returns the value of `js_name` attribute if specified
+fn validate_trait_attrs(top_attrs: &WasmExportAttrs, errors: &mut Errors) -> Option<String> {
+    let msg = |key: &str| {
+        format!("unexpected `{key}` attribute, wasm_export on a trait only accepts `js_name`, `crate` and `cfg` attributes")
+    };
+    for (key, span) in top_attrs.export_attr_spans() {
+        errors.push(Error::new(span, msg(key)));
+    }
+    js_name_attr(&top_attrs.forward_attrs, errors, msg)
+}
+
+/// Returns the value of `js_name` attribute among the given forwarded attributes, the
+/// rest of them are reported as invalid with the given message
+fn js_name_attr(
+    forward_attrs: &[Meta],
+    errors: &mut Errors,
+    msg: impl Fn(&str) -> String,
+) -> Option<String> {
+    let mut js_name = None;
+    for meta in forward_attrs {
+        if !meta.path().is_ident(AttrKeys::JS_NAME) {
+            let path = meta.path();
+            let key = quote!(#path).to_string().replace(' ', "");
+            errors.push(Error::new_spanned(path, msg(&key)));
+        } else if js_name.is_some() {
+            errors.push(Error::new_spanned(meta, "duplicate `js_name` attribute"));
+        } else {
+            js_name = errors.collect(str_value(meta));
+        }
+    }
+    js_name
+}
+
+/// Parses the wasm_export attributes of the given trait method and cleans them from it, returns
+/// the details of its JS counterpart, or None if it should be skipped
+fn parse_method(method: &mut TraitItemFn) -> Result<Option<ImportMethod>, Error> {
+    // process method attributes, if they are invalid the method params are
+    // still processed so that their errors are reported along with them
+    let mut errors = Errors::default();
+    let attrs = errors.collect(WasmExportAttrs::handle_item_attrs(
+        &mut method.attrs,
+        AttrKeys::WASM_EXPORT,
+    ));
+    let docs = RustDocs::parse(&WasmExportFunctionBuilder::extract_doc_comments(
+        &method.attrs,
+    ));
+    let params = errors.collect(parse_params(
+        &method.sig,
+        &docs,
+        AttrKeys::WASM_EXPORT,
+        "a trait method",
+    ));
+    WasmExportFunctionBuilder::clean_parameter_attributes(&mut method.sig.inputs);
+    let Some(attrs) = attrs else {
+        return errors.finish_with(None);
+    };
+
+    // skipped methods are not part of the JS interface, so the adapter uses their default impl
+    if let Some(span) = attrs.should_skip {
+        if method.default.is_none() {
+            errors.push(Error::new(
+                span,
+                "`skip` attribute requires the method to have a default implementation, since the adapter cannot call it on the JS object",
+            ));
+        }
+        return errors.finish_with(None);
+    }
+    let msg = |key: &str| {
+        format!("unexpected `{key}` attribute, trait methods only accept `js_name`, `unchecked_return_type`, `return_description` and `skip` attributes")
+    };
+    for (key, span) in attrs.export_attr_spans() {
+        if key != AttrKeys::UNCHECKED_RETURN_TYPE && key != AttrKeys::RETURN_DESCRIPTION {
+            errors.push(Error::new(span, msg(key)));
+        }
+    }
+    if let Some(krate) = &attrs.krate {
+        errors.push(Error::new_spanned(krate, msg(AttrKeys::CRATE)));
+    }
+    if let Some(cfg) = &attrs.cfg {
+        errors.push(Error::new_spanned(cfg, msg(AttrKeys::CFG)));
+    }
+    let js_name = js_name_attr(&attrs.forward_attrs, &mut errors, msg)
+        .unwrap_or_else(|| method.sig.ident.to_string());
+
+    // the method is called on the JS object, so it must have a self receiver and no generics
+    if method.sig.receiver().is_none() {
+        errors.push(Error::new_spanned(
+            &method.sig,
+            "expected a `self` receiver, trait methods are called on the JS object that implements them",
+        ));
+    }
+    if !method.sig.generics.params.is_empty() || method.sig.generics.where_clause.is_some() {
+        errors.push(Error::new_spanned(
+            &method.sig.generics,
+            "unexpected generics, JS objects can only implement non generic trait methods",
+        ));
+    }
+
+    // calling into JS can fail, so the method must return a result
+    let Some((return_type, ReturnKind::Result)) =
+        WasmExportAttrs::try_extract_return_inner_type(&method.sig.output)
+    else {
+        errors.push(Error::new_spanned(
+            &method.sig.output,
+            "expected `Result<T, E>` return type, since calling into JS can fail",
+        ));
+        return errors.finish_with(None);
+    };
+    let return_type = return_type.clone();
+    let Some(params) = params else {
+        return errors.finish_with(None);
+    };
+    errors.finish()?;
+
+    let mut ts_return_type = match &attrs.unchecked_return_type {
+        Some((ts_type, _)) => ts_type.clone(),
+        None => ts_type(&return_type),
+    };
+    if method.sig.asyncness.is_some() {
+        ts_return_type = format!("{ts_return_type} | Promise<{ts_return_type}>");
+    }
+
+    // name the params that are not named by their patterns
+    let mut sig = method.sig.clone();
+    clean_params(&mut sig.inputs, &params, AttrKeys::WASM_EXPORT);
+
+    Ok(Some(ImportMethod {
+        sig,
+        js_name,
+        params,
+        return_type,
+        ts_return_type,
+        return_description: attrs
+            .return_description
+            .map(|v| v.0)
+            .or(docs.returns.clone()),
+        docs: doc_lines(&docs.attrs),
+    }))
+}
+
+/// Builds the declaration of the given method in the extern block of the JS interface type,
+/// the args are passed to it encoded as JS values and it catches the thrown JS exceptions
+fn build_extern_method(method: &ImportMethod, js_ident: &Ident, krate: &syn::Path) -> TokenStream {
+    let fn_ident = &method.sig.ident;
+    let js_name = &method.js_name;
+    let args = method.params.iter().map(|param| &param.ident);
+    let arg_types = method.params.iter().map(|param| param.binding_type(krate));
+    quote! {
+        #[wasm_bindgen(method, catch, js_name = #js_name)]
+        fn #fn_ident(
+            this: &#js_ident,
+            #(#args: #arg_types),*
+        ) -> ::core::result::Result<#krate::prelude::JsValue, #krate::prelude::JsValue>;
+    }
+}
+
+/// Builds the implementation of the given method for the adapter, which calls the method
+/// of the JS object, any failure is converted into the method's error through [WasmEncodedError]
+fn build_adapter_method(method: &ImportMethod, ts_name: &str, krate: &syn::Path) -> TokenStream {
+    let sig = &method.sig;
+    let fn_ident = &sig.ident;
+    // the JS object is the first field of the adapter
+    let js_object = quote!(self.0);
+    let body = build_call_body(
+        &method.params,
+        quote!(#js_object.#fn_ident),
+        sig.asyncness.is_some(),
+        &method.return_type,
+        &format!("{}.{}", ts_name, method.js_name),
+        krate,
+    );
+    quote! {
+        #sig {
+            #body
+        }
+    }
+}
+
+/// Builds the TS interface declaration of the given methods with their TSDoc comments
+fn build_ts_interface(
+    ts_name: &str,
+    trait_attrs: &[Attribute],
+    methods: &[ImportMethod],
+) -> String {
+    let trait_docs = RustDocs::parse(&WasmExportFunctionBuilder::extract_doc_comments(
+        trait_attrs,
+    ));
+    let mut ts = ts_doc_comment(&doc_lines(&trait_docs.attrs), "");
+    ts.push_str(&format!("export interface {ts_name} {{\n"));
+    for method in methods {
+        let mut lines = method.docs.clone();
+        for param in &method.params {
+            if let Some(desc) = &param.description {
+                lines.push(format!(" @param {} - {}", param.js_name, desc));
+            }
+        }
+        if let Some(desc) = &method.return_description {
+            lines.push(format!(" @returns {desc}"));
+        }
+        ts.push_str(&ts_doc_comment(&lines, "    "));
+        let params = method
+            .params
+            .iter()
+            .map(|param| format!("{}: {}", param.js_name, param.ts_type))
+            .collect::<Vec<_>>()
+            .join(", ");
+        ts.push_str(&format!(
+            "    {}({}): {};\n",
+            method.js_name, params, method.ts_return_type
+        ));
+    }
+    ts.push('}');
+    ts
+}
+
+/// Builds a TSDoc comment block of the given lines with the given indentation
+fn ts_doc_comment(lines: &[String], indent: &str) -> String {
+    if lines.is_empty() {
+        return String::new();
+    }
+    let mut comment = format!("{indent}/**\n");
+    for line in lines {
+        comment.push_str(&format!("{indent} *{}\n", line.replace("*/", "*\\/")));
+    }
+    comment.push_str(&format!("{indent} */\n"));
+    comment
+}
+
+/// Returns the text lines of the given doc attributes, non literal doc attributes are skipped
+fn doc_lines(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter_map(|attr| str_value(&attr.meta).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_parse_happy() {
+        let mut item_trait: ItemTrait = parse_quote!(
+            /// Some storage
+            pub trait Storage {
+                /// Gets an item
+                ///
+                /// # Returns
+                /// the item if it exists
+                #[wasm_export(js_name = "getItem")]
+                fn get(
+                    &self,
+                    #[wasm_export(js_type = "ItemKey")] key: &str,
+                ) -> Result<Option<String>, Error>;
+                async fn set(
+                    &self,
+                    key: String,
+                    #[wasm_export(param_description = "some value")] value: Vec<u8>,
+                ) -> Result<(), Error>;
+                fn raw(&self, value: &JsValue) -> Result<JsValue, Error>;
+                #[wasm_export(skip)]
+                fn name(&self) -> String {
+                    String::new()
+                }
+            }
+        );
+        let result = parse(&mut item_trait, WasmExportAttrs::default()).unwrap();
+        let krate = WasmExportAttrs::default_crate_path();
+        let ts_interface = "/**\n * Some storage\n */\nexport interface Storage {\n    /**\n     * Gets an item\n     * @returns the item if it exists\n     */\n    getItem(key: ItemKey): string | undefined;\n    /**\n     * @param value - some value\n     */\n    set(key: string, value: number[]): void | Promise<void>;\n    raw(value: any): any;\n}";
+        let expected: TokenStream = parse_quote!(
+            /// Some storage
+            pub trait Storage {
+                /// Gets an item
+                ///
+                /// # Returns
+                /// the item if it exists
+                fn get(&self, key: &str,) -> Result<Option<String>, Error>;
+                async fn set(&self, key: String, value: Vec<u8>,) -> Result<(), Error>;
+                fn raw(&self, value: &JsValue) -> Result<JsValue, Error>;
+                fn name(&self) -> String {
+                    String::new()
+                }
+            }
+
+            #[cfg(target_family = "wasm")]
+            #[#krate::prelude::wasm_bindgen(wasm_bindgen = #krate::prelude::wasm_bindgen)]
+            extern "C" {
+                #[doc = " A JS object that implements [`Storage`] trait as `Storage` TS interface"]
+                #[derive(Debug, Clone)]
+                #[wasm_bindgen(typescript_type = "Storage")]
+                pub type JsStorage;
+
+                #[wasm_bindgen(method, catch, js_name = "getItem")]
+                fn get(
+                    this: &JsStorage,
+                    key: &#krate::prelude::JsValue
+                ) -> ::core::result::Result<#krate::prelude::JsValue, #krate::prelude::JsValue>;
+                #[wasm_bindgen(method, catch, js_name = "set")]
+                fn set(
+                    this: &JsStorage,
+                    key: &#krate::prelude::JsValue,
+                    value: &#krate::prelude::JsValue
+                ) -> ::core::result::Result<#krate::prelude::JsValue, #krate::prelude::JsValue>;
+                #[wasm_bindgen(method, catch, js_name = "raw")]
+                fn raw(
+                    this: &JsStorage,
+                    value: &#krate::prelude::JsValue
+                ) -> ::core::result::Result<#krate::prelude::JsValue, #krate::prelude::JsValue>;
+            }
+
+            #[cfg(target_family = "wasm")]
+            const _: () = {
+                use #krate::prelude::wasm_bindgen;
+
+                #[wasm_bindgen(typescript_custom_section)]
+                const TS_INTERFACE: &'static str = #ts_interface;
+            };
+
+            #[cfg(target_family = "wasm")]
+            #[doc = " Implements [`Storage`] trait by calling into the wrapped [`JsStorage`] JS object"]
+            #[derive(Debug, Clone)]
+            pub struct JsStorageAdapter(pub JsStorage);
+
+            #[cfg(target_family = "wasm")]
+            impl ::core::convert::From<JsStorage> for JsStorageAdapter {
+                fn from(value: JsStorage) -> Self {
+                    Self(value)
+                }
+            }
+
+            #[cfg(target_family = "wasm")]
+            impl Storage for JsStorageAdapter {
+                fn get(&self, key: &str,) -> Result<Option<String>, Error> {
+                    let key = #krate::import::encode_js_arg("key", &key)?;
+                    let value = self
+                        .0
+                        .get(&key)
+                        .map_err(#krate::result::WasmEncodedError::from)?;
+                    let value = #krate::import::decode_js_result::<Option<String> >("Storage.getItem", value)?;
+                    ::core::result::Result::Ok(value)
+                }
+                async fn set(&self, key: String, value: Vec<u8>,) -> Result<(), Error> {
+                    let key = #krate::import::encode_js_arg("key", &key)?;
+                    let value = #krate::import::encode_js_arg("value", &value)?;
+                    let value = self
+                        .0
+                        .set(&key, &value)
+                        .map_err(#krate::result::WasmEncodedError::from)?;
+                    let value = #krate::import::resolve_js_value(value).await?;
+                    #krate::import::check_js_error(&value)?;
+                    ::core::result::Result::Ok(())
+                }
+                fn raw(&self, value: &JsValue) -> Result<JsValue, Error> {
+                    let value = self
+                        .0
+                        .raw(value)
+                        .map_err(#krate::result::WasmEncodedError::from)?;
+                    #krate::import::check_js_error(&value)?;
+                    ::core::result::Result::Ok(value)
+                }
+            }
+        );
+        assert_eq!(result.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_parse_attrs() {
+        let mut item_trait: ItemTrait = parse_quote!(
+            trait Storage {
+                #[wasm_export(unchecked_return_type = "Item", return_description = "the item")]
+                fn get(&mut self, _: String) -> Result<Item, Error>;
+            }
+        );
+        let top_attrs: WasmExportAttrs = parse_quote!(
+            js_name = "JsStore",
+            crate = "crate::utils",
+            cfg(feature = "js")
+        );
+        let result = parse(&mut item_trait, top_attrs).unwrap().to_string();
+        assert!(result.contains(
+            &quote!(#[cfg(feature = "js")] #[crate::utils::prelude::wasm_bindgen(wasm_bindgen = crate::utils::prelude::wasm_bindgen)]).to_string()
+        ));
+        assert!(result.contains(
+            &quote!(
+                #[wasm_bindgen(typescript_type = "JsStore")]
+                type JsStorage;
+            )
+            .to_string()
+        ));
+        assert!(result.contains(&quote!("export interface JsStore {\n    /**\n     * @returns the item\n     */\n    get(arg0: string): Item;\n}").to_string()));
+        assert!(result
+            .contains(&quote!(fn get(&mut self, arg0: String) -> Result<Item, Error>).to_string()));
+        assert!(result.contains(
+            &quote!(crate::utils::import::decode_js_result::<Item>(
+                "JsStore.get",
+                value
+            ))
+            .to_string()
+        ));
+    }
+
+    #[test]
+    fn test_parse_unhappy() {
+        let mut item_trait: ItemTrait = parse_quote!(
+            trait Storage<T>: Clone {
+                type Item;
+                const NAME: &'static str;
+                #[wasm_export(map_ok = "something", js_class = "Some")]
+                fn get(&self, #[wasm_export(optional)] key: String) -> Result<Item, Error>;
+                fn new() -> Result<Self, Error>;
+                fn map<U>(&self) -> Result<U, Error>;
+                fn len(&self) -> usize;
+                #[wasm_export(skip)]
+                fn clear(&self) -> Result<(), Error>;
+            }
+        );
+        let top_attrs: WasmExportAttrs =
+            parse_quote!(js_name = "Store", error_context, js_class = "Some");
+        let err = parse(&mut item_trait, top_attrs).unwrap_err();
+        let msgs = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            msgs,
+            vec![
+                "unexpected `error_context` attribute, wasm_export on a trait only accepts `js_name`, `crate` and `cfg` attributes",
+                "unexpected `js_class` attribute, wasm_export on a trait only accepts `js_name`, `crate` and `cfg` attributes",
+                "unexpected generics, wasm_export is only applicable to non generic traits",
+                "unexpected supertraits, JS objects can only implement traits without supertraits",
+                "unexpected associated type, JS objects can only implement traits that only contain methods",
+                "unexpected associated const, JS objects can only implement traits that only contain methods",
+                "expected a path to a function",
+                "unexpected `optional` parameter attribute for a trait method, expected one of `param_description`, `unchecked_param_type`, `js_name`, `js_type`",
+                "expected a `self` receiver, trait methods are called on the JS object that implements them",
+                "unexpected generics, JS objects can only implement non generic trait methods",
+                "expected `Result<T, E>` return type, since calling into JS can fail",
+                "`skip` attribute requires the method to have a default implementation, since the adapter cannot call it on the JS object",
+            ]
+        );
+    }
+}
@@ -0,0 +1,210 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+pub async fn fetch_quote(
+    pair: String,
+    signal: Option<AbortSignal>,
+) -> Result<Quote, Error> {
+    client().quote(pair, signal).await
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "fetchQuote",
+    unchecked_return_type = "WasmEncodedResult<Quote>"
+)]
+pub async fn fetch_quote__wasm_export(
+    pair: String,
+    signal: Option<AbortSignal>,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<Quote> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "fetchQuote",
+    );
+    let __wasm_export_abort_signal = ::wasm_bindgen_utils::abort::abort_signal_of(
+        "signal",
+        signal.as_ref(),
+    );
+    (match __wasm_export_abort_signal {
+        ::core::result::Result::Ok(__wasm_export_abort_signal) => {
+            ::wasm_bindgen_utils::abort::abortable(
+                    fetch_quote(pair, signal),
+                    __wasm_export_abort_signal,
+                    ::core::option::Option::None,
+                )
+                .await
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        }
+        ::core::result::Result::Err(error) => {
+            __wasm_export_call.on_error(&error);
+            ::wasm_bindgen_utils::result::FromEncodedError::from_encoded_error(error)
+        }
+    })
+        .into()
+}
+pub async fn fetch_orders(owner: String) -> Result<Vec<Order>, Error> {
+    client().orders(owner).await
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "fetchOrders",
+    unchecked_return_type = "WasmEncodedResult<Vec < Order >>"
+)]
+pub async fn fetch_orders__wasm_export(
+    owner: String,
+    #[wasm_bindgen(
+        js_name = "signal",
+        param_description = "An optional signal to abort the call with"
+    )]
+    __wasm_export_signal: ::core::option::Option<
+        ::wasm_bindgen_utils::abort::AbortSignal,
+    >,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<Vec<Order>> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "fetchOrders",
+    );
+    ::wasm_bindgen_utils::abort::abortable(
+            fetch_orders(owner),
+            __wasm_export_signal,
+            ::core::option::Option::Some(5000u32),
+        )
+        .await
+        .encode_errors(
+            |error| {
+                error.context = ::core::option::Option::Some(
+                    ::std::boxed::Box::new(
+                        ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                            ::std::any::type_name_of_val(&fetch_orders),
+                            "fetchOrders",
+                            &["owner: String"],
+                        ),
+                    ),
+                );
+                __wasm_export_call.on_error(error);
+            },
+            |warning| {
+                warning.context = ::core::option::Option::Some(
+                    ::std::boxed::Box::new(
+                        ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                            ::std::any::type_name_of_val(&fetch_orders),
+                            "fetchOrders",
+                            &["owner: String"],
+                        ),
+                    ),
+                );
+            },
+        )
+        .into()
+}
+struct Client;
+impl Client {
+    pub async fn fetch_order(&self, id: String) -> Result<Order, Error> {
+        self.order(id).await
+    }
+    pub async fn watch_order(
+        &self,
+        id: String,
+        signal: JsValue,
+    ) -> Result<Order, Error> {
+        self.watch(id, signal).await
+    }
+    pub fn order_count(&self) -> Result<u32, Error> {
+        Ok(self.count())
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[wasm_bindgen]
+    impl Client {
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "fetchOrder",
+            unchecked_return_type = "WasmEncodedResult<Order>"
+        )]
+        pub async fn fetch_order__wasm_export(
+            &self,
+            id: String,
+            #[wasm_bindgen(
+                js_name = "signal",
+                param_description = "An optional signal to abort the call with"
+            )]
+            __wasm_export_signal: ::core::option::Option<
+                ::wasm_bindgen_utils::abort::AbortSignal,
+            >,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<Order> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "fetchOrder",
+            );
+            ::wasm_bindgen_utils::abort::abortable(
+                    self.fetch_order(id),
+                    __wasm_export_signal,
+                    ::core::option::Option::Some(1000u32),
+                )
+                .await
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "watchOrder",
+            unchecked_return_type = "WasmEncodedResult<Order>"
+        )]
+        pub async fn watch_order__wasm_export(
+            &self,
+            id: String,
+            #[wasm_bindgen(unchecked_param_type = "AbortSignal | undefined")]
+            signal: JsValue,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<Order> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "watchOrder",
+            );
+            let __wasm_export_abort_signal = ::wasm_bindgen_utils::abort::abort_signal_of(
+                "signal",
+                ::core::option::Option::Some(&signal),
+            );
+            (match __wasm_export_abort_signal {
+                ::core::result::Result::Ok(__wasm_export_abort_signal) => {
+                    ::wasm_bindgen_utils::abort::abortable(
+                            self.watch_order(id, signal),
+                            __wasm_export_abort_signal,
+                            ::core::option::Option::None,
+                        )
+                        .await
+                        .encode_errors(
+                            |error| __wasm_export_call.on_error(error),
+                            |_| {},
+                        )
+                }
+                ::core::result::Result::Err(error) => {
+                    __wasm_export_call.on_error(&error);
+                    ::wasm_bindgen_utils::result::FromEncodedError::from_encoded_error(
+                        error,
+                    )
+                }
+            })
+                .into()
+        }
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "orderCount",
+            unchecked_return_type = "WasmEncodedResult<u32>"
+        )]
+        pub fn order_count__wasm_export(
+            &self,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u32> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "orderCount",
+            );
+            self.order_count()
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+    }
+};
@@ -0,0 +1,34 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+#[wasm_export(js_name = "fetchQuote")]
+pub async fn fetch_quote(pair: String, signal: Option<AbortSignal>) -> Result<Quote, Error> {
+    client().quote(pair, signal).await
+}
+
+#[wasm_export(js_name = "fetchOrders", cancellable, timeout_ms = 5000, error_context)]
+pub async fn fetch_orders(owner: String) -> Result<Vec<Order>, Error> {
+    client().orders(owner).await
+}
+
+struct Client;
+
+#[wasm_export(cancellable)]
+impl Client {
+    #[wasm_export(js_name = "fetchOrder", timeout_ms = 1000)]
+    pub async fn fetch_order(&self, id: String) -> Result<Order, Error> {
+        self.order(id).await
+    }
+    #[wasm_export(js_name = "watchOrder")]
+    pub async fn watch_order(
+        &self,
+        id: String,
+        #[wasm_export(abort_signal)] signal: JsValue,
+    ) -> Result<Order, Error> {
+        self.watch(id, signal).await
+    }
+    #[wasm_export(js_name = "orderCount")]
+    pub fn order_count(&self) -> Result<u32, Error> {
+        Ok(self.count())
+    }
+}
@@ -0,0 +1,187 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+struct TestStruct;
+pub async fn some_batch_fn(args: Vec<String>) -> Vec<Result<TestStruct, Error>> {
+    args.into_iter().map(|_| Ok(TestStruct)).collect()
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "someBatchFn",
+    unchecked_return_type = "WasmEncodedResults<TestStruct>"
+)]
+pub async fn some_batch_fn__wasm_export(
+    args: Vec<String>,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResults<TestStruct> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "someBatchFn",
+    );
+    some_batch_fn(args)
+        .await
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
+}
+pub fn some_batch_fn_preserve_class(
+    args: Vec<String>,
+) -> Vec<Result<TestStruct, Error>> {
+    args.into_iter().map(|_| Ok(TestStruct)).collect()
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "someBatchFnPreserveClass",
+    unchecked_return_type = "WasmEncodedResults<TestStruct>"
+)]
+pub fn some_batch_fn_preserve_class__wasm_export(
+    args: Vec<String>,
+) -> ::wasm_bindgen_utils::prelude::JsValue {
+    use ::wasm_bindgen_utils::prelude::JsValue;
+    use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object, Array};
+    use ::wasm_bindgen_utils::result::WasmEncodedError;
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "someBatchFnPreserveClass",
+    );
+    let obj = Object::new();
+    let items = Array::new();
+    let batch = some_batch_fn_preserve_class(args)
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {});
+    let mut successes: u32 = 0;
+    let mut failures: u32 = 0;
+    for result in batch.items {
+        if result.is_ok() {
+            successes += 1;
+        } else {
+            failures += 1;
+        }
+        let item = Object::new();
+        match result {
+            ::core::result::Result::Ok(value) => {
+                Reflect::set(&item, &JsValue::from_str("value"), &value.into()).unwrap();
+                Reflect::set(&item, &JsValue::from_str("error"), &JsValue::UNDEFINED)
+                    .unwrap();
+            }
+            ::core::result::Result::Err(error) => {
+                let wasm_error: WasmEncodedError = error.into();
+                Reflect::set(&item, &JsValue::from_str("value"), &JsValue::UNDEFINED)
+                    .unwrap();
+                Reflect::set(&item, &JsValue::from_str("error"), &wasm_error.into())
+                    .unwrap();
+            }
+        };
+        items.push(&item);
+    }
+    Reflect::set(&obj, &JsValue::from_str("items"), &items).unwrap();
+    Reflect::set(&obj, &JsValue::from_str("successCount"), &successes.into()).unwrap();
+    Reflect::set(&obj, &JsValue::from_str("errorCount"), &failures.into()).unwrap();
+    if let ::core::option::Option::Some(error) = batch.error {
+        Reflect::set(&obj, &JsValue::from_str("error"), &error.into()).unwrap();
+    }
+    obj.into()
+}
+impl TestStruct {
+    pub fn some_batch_method(&self, count: u8) -> Vec<Result<u8, Error>> {
+        (0..count).map(Ok).collect()
+    }
+    pub async fn some_batch_method_preserve_class(
+        &self,
+    ) -> Vec<Result<TestStruct, Error>> {
+        std::iter::once(Ok(TestStruct)).collect()
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[wasm_bindgen]
+    impl TestStruct {
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "someBatchMethod",
+            unchecked_return_type = "WasmEncodedResults<u8>"
+        )]
+        pub fn some_batch_method__wasm_export(
+            &self,
+            count: u8,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResults<u8> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "someBatchMethod",
+            );
+            self.some_batch_method(count)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "someBatchMethodPreserveClass",
+            unchecked_return_type = "WasmEncodedResults<TestStruct>"
+        )]
+        pub async fn some_batch_method_preserve_class__wasm_export(
+            &self,
+        ) -> ::wasm_bindgen_utils::prelude::JsValue {
+            use ::wasm_bindgen_utils::prelude::JsValue;
+            use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object, Array};
+            use ::wasm_bindgen_utils::result::WasmEncodedError;
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "someBatchMethodPreserveClass",
+            );
+            let obj = Object::new();
+            let items = Array::new();
+            let batch = self
+                .some_batch_method_preserve_class()
+                .await
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {});
+            let mut successes: u32 = 0;
+            let mut failures: u32 = 0;
+            for result in batch.items {
+                if result.is_ok() {
+                    successes += 1;
+                } else {
+                    failures += 1;
+                }
+                let item = Object::new();
+                match result {
+                    ::core::result::Result::Ok(value) => {
+                        Reflect::set(&item, &JsValue::from_str("value"), &value.into())
+                            .unwrap();
+                        Reflect::set(
+                                &item,
+                                &JsValue::from_str("error"),
+                                &JsValue::UNDEFINED,
+                            )
+                            .unwrap();
+                    }
+                    ::core::result::Result::Err(error) => {
+                        let wasm_error: WasmEncodedError = error.into();
+                        Reflect::set(
+                                &item,
+                                &JsValue::from_str("value"),
+                                &JsValue::UNDEFINED,
+                            )
+                            .unwrap();
+                        Reflect::set(
+                                &item,
+                                &JsValue::from_str("error"),
+                                &wasm_error.into(),
+                            )
+                            .unwrap();
+                    }
+                };
+                items.push(&item);
+            }
+            Reflect::set(&obj, &JsValue::from_str("items"), &items).unwrap();
+            Reflect::set(&obj, &JsValue::from_str("successCount"), &successes.into())
+                .unwrap();
+            Reflect::set(&obj, &JsValue::from_str("errorCount"), &failures.into())
+                .unwrap();
+            if let ::core::option::Option::Some(error) = batch.error {
+                Reflect::set(&obj, &JsValue::from_str("error"), &error.into()).unwrap();
+            }
+            obj.into()
+        }
+    }
+};
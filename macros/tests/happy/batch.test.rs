@@ -0,0 +1,27 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+struct TestStruct;
+
+#[wasm_export(js_name = "someBatchFn")]
+pub async fn some_batch_fn(args: Vec<String>) -> Vec<Result<TestStruct, Error>> {
+    args.into_iter().map(|_| Ok(TestStruct)).collect()
+}
+
+#[wasm_export(js_name = "someBatchFnPreserveClass", preserve_js_class)]
+pub fn some_batch_fn_preserve_class(args: Vec<String>) -> Vec<Result<TestStruct, Error>> {
+    args.into_iter().map(|_| Ok(TestStruct)).collect()
+}
+
+#[wasm_export]
+impl TestStruct {
+    #[wasm_export(js_name = "someBatchMethod")]
+    pub fn some_batch_method(&self, count: u8) -> Vec<Result<u8, Error>> {
+        (0..count).map(Ok).collect()
+    }
+
+    #[wasm_export(js_name = "someBatchMethodPreserveClass", preserve_js_class)]
+    pub async fn some_batch_method_preserve_class(&self) -> Vec<Result<TestStruct, Error>> {
+        std::iter::once(Ok(TestStruct)).collect()
+    }
+}
@@ -0,0 +1,72 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+pub fn sort_orders(
+    orders: Vec<Order>,
+    compare: JsCallback<(Order, Order), i32>,
+    on_done: Vec<JsCallback<(), ()>>,
+) -> Result<Vec<Order>, Error> {
+    Ok(orders)
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "sortOrders",
+    unchecked_return_type = "WasmEncodedResult<Vec < Order >>",
+    variadic
+)]
+pub fn sort_orders__wasm_export(
+    orders: Vec<Order>,
+    #[wasm_bindgen(unchecked_param_type = "(arg0: Order, arg1: Order) => number")]
+    compare: JsCallback<(Order, Order), i32>,
+    #[wasm_bindgen(unchecked_param_type = "(() => void)[]")]
+    on_done: ::std::vec::Vec<::wasm_bindgen_utils::prelude::JsValue>,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<Vec<Order>> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "sortOrders",
+    );
+    let on_done = ::wasm_bindgen_utils::result::decode_variadic::<
+        JsCallback<(), ()>,
+    >("on_done", on_done);
+    (match on_done {
+        ::core::result::Result::Ok(on_done) => {
+            sort_orders(orders, compare, on_done)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        }
+        ::core::result::Result::Err(error) => {
+            __wasm_export_call.on_error(&error);
+            ::wasm_bindgen_utils::result::FromEncodedError::from_encoded_error(error)
+        }
+    })
+        .into()
+}
+pub async fn resolve_order(
+    id: String,
+    resolver: JsAsyncCallback<(String, u64), Order>,
+) -> Result<Order, Error> {
+    Ok(resolver.call((id, 1)).await?)
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "resolveOrder",
+    unchecked_return_type = "WasmEncodedResult<Order>"
+)]
+pub async fn resolve_order__wasm_export(
+    id: String,
+    #[wasm_bindgen(
+        unchecked_param_type = "(arg0: string, arg1: bigint) => Order | Promise<Order>"
+    )]
+    resolver: JsAsyncCallback<(String, u64), Order>,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<Order> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "resolveOrder",
+    );
+    resolve_order(id, resolver)
+        .await
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
+}
@@ -0,0 +1,19 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+#[wasm_export(js_name = "sortOrders")]
+pub fn sort_orders(
+    orders: Vec<Order>,
+    compare: JsCallback<(Order, Order), i32>,
+    #[wasm_export(variadic)] on_done: Vec<JsCallback<(), ()>>,
+) -> Result<Vec<Order>, Error> {
+    Ok(orders)
+}
+
+#[wasm_export(js_name = "resolveOrder")]
+pub async fn resolve_order(
+    id: String,
+    resolver: JsAsyncCallback<(String, u64), Order>,
+) -> Result<Order, Error> {
+    Ok(resolver.call((id, 1)).await?)
+}
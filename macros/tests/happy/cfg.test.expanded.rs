@@ -0,0 +1,28 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+struct TestStruct;
+impl TestStruct {
+    pub fn some_method(&self, some_arg: String) -> Result<u8, Error> {
+        Ok(1)
+    }
+}
+pub async fn some_fn(arg: Option<String>) -> Result<u8, Error> {
+    Ok(1)
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "someFn",
+    unchecked_return_type = "WasmEncodedResult<u8>"
+)]
+pub async fn some_fn__wasm_export(
+    arg: Option<String>,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u8> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("someFn");
+    some_fn(arg)
+        .await
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
+}
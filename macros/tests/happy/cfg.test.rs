@@ -0,0 +1,17 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+struct TestStruct;
+
+#[wasm_export(cfg(all(target_family = "wasm", feature = "js")))]
+impl TestStruct {
+    #[wasm_export(js_name = "someMethod")]
+    pub fn some_method(&self, #[wasm_export(js_name = "someArg")] some_arg: String) -> Result<u8, Error> {
+        Ok(1)
+    }
+}
+
+#[wasm_export(js_name = "someFn", cfg(target_arch = "wasm32"))]
+pub async fn some_fn(#[wasm_export(optional)] arg: Option<String>) -> Result<u8, Error> {
+    Ok(1)
+}
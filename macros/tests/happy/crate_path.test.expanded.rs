@@ -0,0 +1,166 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+struct TestStruct;
+impl TestStruct {
+    pub fn some_method(&self, arg: Option<u8>) -> Result<u8, Error> {
+        Ok(1)
+    }
+    pub async fn some_static_method() -> Result<TestStruct, Error> {
+        Ok(TestStruct)
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::my_crate::wasm_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[wasm_bindgen]
+    impl TestStruct {
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "someMethod",
+            unchecked_return_type = "WasmEncodedResult<u8>"
+        )]
+        pub fn some_method__wasm_export(
+            &self,
+            arg: Option<u8>,
+        ) -> ::my_crate::wasm_utils::result::WasmEncodedResult<u8> {
+            use ::my_crate::wasm_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::my_crate::wasm_utils::hooks::ExportCall::start(
+                "someMethod",
+            );
+            self.some_method(arg)
+                .encode_errors(
+                    |error| {
+                        error.context = ::core::option::Option::Some(
+                            ::std::boxed::Box::new(
+                                ::my_crate::wasm_utils::result::WasmEncodedErrorContext::new(
+                                    ::std::any::type_name_of_val(&Self::some_method),
+                                    "someMethod",
+                                    &["arg: Option<u8>"],
+                                ),
+                            ),
+                        );
+                        __wasm_export_call.on_error(error);
+                    },
+                    |warning| {
+                        warning.context = ::core::option::Option::Some(
+                            ::std::boxed::Box::new(
+                                ::my_crate::wasm_utils::result::WasmEncodedErrorContext::new(
+                                    ::std::any::type_name_of_val(&Self::some_method),
+                                    "someMethod",
+                                    &["arg: Option<u8>"],
+                                ),
+                            ),
+                        );
+                    },
+                )
+                .into()
+        }
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "someStaticMethod",
+            unchecked_return_type = "WasmEncodedResult<TestStruct>"
+        )]
+        pub async fn some_static_method__wasm_export() -> ::my_crate::wasm_utils::prelude::JsValue {
+            use ::my_crate::wasm_utils::prelude::JsValue;
+            use ::my_crate::wasm_utils::prelude::js_sys::{Reflect, Object};
+            use ::my_crate::wasm_utils::result::WasmEncodedError;
+            use ::my_crate::wasm_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::my_crate::wasm_utils::hooks::ExportCall::start(
+                "someStaticMethod",
+            );
+            let obj = Object::new();
+            let result = Self::some_static_method()
+                .await
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into();
+            match result {
+                ::core::result::Result::Ok(value) => {
+                    Reflect::set(&obj, &JsValue::from_str("value"), &value.into())
+                        .unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED)
+                        .unwrap();
+                }
+                ::core::result::Result::Err(error) => {
+                    let wasm_error: WasmEncodedError = error.into();
+                    Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED)
+                        .unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into())
+                        .unwrap();
+                }
+            };
+            obj.into()
+        }
+    }
+};
+pub async fn some_fn(args: Vec<String>) -> Result<TestStruct, Error> {
+    Ok(TestStruct)
+}
+#[allow(non_snake_case)]
+#[my_crate::wasm_utils::prelude::wasm_bindgen(
+    wasm_bindgen = my_crate::wasm_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = my_crate::wasm_utils::prelude::wasm_bindgen_futures,
+    js_name = "someFn",
+    unchecked_return_type = "WasmEncodedResult<TestStruct>",
+    variadic
+)]
+pub async fn some_fn__wasm_export(
+    #[wasm_bindgen(unchecked_param_type = "string[]")]
+    args: ::std::vec::Vec<my_crate::wasm_utils::prelude::JsValue>,
+) -> my_crate::wasm_utils::result::WasmEncodedResult<TestStruct> {
+    use my_crate::wasm_utils::result::EncodeErrors as _;
+    let __wasm_export_call = my_crate::wasm_utils::hooks::ExportCall::start("someFn");
+    let args = my_crate::wasm_utils::result::decode_variadic::<String>("args", args);
+    (match args {
+        ::core::result::Result::Ok(args) => {
+            some_fn(args)
+                .await
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        }
+        ::core::result::Result::Err(error) => {
+            __wasm_export_call.on_error(&error);
+            my_crate::wasm_utils::result::FromEncodedError::from_encoded_error(error)
+        }
+    })
+        .into()
+}
+pub fn some_diagnosed_fn() -> Diagnosed<TestStruct, Error> {
+    Diagnosed::ok(TestStruct)
+}
+#[allow(non_snake_case)]
+#[::my_crate::wasm_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::my_crate::wasm_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::my_crate::wasm_utils::prelude::wasm_bindgen_futures,
+    js_name = "someDiagnosedFn",
+    unchecked_return_type = "WasmEncodedDiagnosedResult<TestStruct>"
+)]
+pub fn some_diagnosed_fn__wasm_export() -> ::my_crate::wasm_utils::prelude::JsValue {
+    use ::my_crate::wasm_utils::prelude::JsValue;
+    use ::my_crate::wasm_utils::prelude::js_sys::{Reflect, Object};
+    use ::my_crate::wasm_utils::result::WasmEncodedError;
+    use ::my_crate::wasm_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::my_crate::wasm_utils::hooks::ExportCall::start(
+        "someDiagnosedFn",
+    );
+    let obj = Object::new();
+    let ::my_crate::wasm_utils::result::Diagnosed { result, warnings } = some_diagnosed_fn()
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {});
+    match result {
+        ::core::result::Result::Ok(value) => {
+            Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
+            Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED)
+                .unwrap();
+        }
+        ::core::result::Result::Err(error) => {
+            let wasm_error: WasmEncodedError = error.into();
+            Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED)
+                .unwrap();
+            Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into()).unwrap();
+        }
+    };
+    let warnings: ::std::vec::Vec<WasmEncodedError> = warnings
+        .into_iter()
+        .map(::core::convert::Into::into)
+        .collect();
+    Reflect::set(&obj, &JsValue::from_str("warnings"), &warnings.into()).unwrap();
+    obj.into()
+}
@@ -0,0 +1,30 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+struct TestStruct;
+
+#[wasm_export(crate = "::my_crate::wasm_utils")]
+impl TestStruct {
+    #[wasm_export(js_name = "someMethod", error_context)]
+    pub fn some_method(&self, #[wasm_export(optional)] arg: Option<u8>) -> Result<u8, Error> {
+        Ok(1)
+    }
+    #[wasm_export(js_name = "someStaticMethod", preserve_js_class)]
+    pub async fn some_static_method() -> Result<TestStruct, Error> {
+        Ok(TestStruct)
+    }
+}
+
+#[wasm_export(js_name = "someFn", crate = "my_crate::wasm_utils")]
+pub async fn some_fn(#[wasm_export(variadic)] args: Vec<String>) -> Result<TestStruct, Error> {
+    Ok(TestStruct)
+}
+
+#[wasm_export(
+    js_name = "someDiagnosedFn",
+    crate = "::my_crate::wasm_utils",
+    preserve_js_class
+)]
+pub fn some_diagnosed_fn() -> Diagnosed<TestStruct, Error> {
+    Diagnosed::ok(TestStruct)
+}
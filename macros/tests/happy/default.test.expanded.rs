@@ -0,0 +1,66 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+struct TestStruct;
+impl TestStruct {
+    pub fn some_method(&self, arg1: String, arg2: u32) -> Result<u32, Error> {
+        Ok(arg2)
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[wasm_bindgen]
+    impl TestStruct {
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "someMethod",
+            unchecked_return_type = "WasmEncodedResult<u32>"
+        )]
+        pub fn some_method__wasm_export(
+            &self,
+            arg1: String,
+            #[wasm_bindgen(param_description = "some number. Defaults to `10`.")]
+            arg2: ::core::option::Option<u32>,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u32> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "someMethod",
+            );
+            let arg2 = arg2.unwrap_or_else(|| 10);
+            self.some_method(arg1, arg2)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+    }
+};
+pub async fn some_fn(
+    arg1: String,
+    arg2: String,
+    arg3: Option<u8>,
+) -> Result<String, Error> {
+    Ok(arg2)
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "someFn",
+    unchecked_return_type = "WasmEncodedResult<String>"
+)]
+pub async fn some_fn__wasm_export(
+    arg1: String,
+    #[wasm_bindgen(
+        js_name = "argTwo",
+        param_description = "Defaults to `String::from(\"some value\")`."
+    )]
+    arg2: ::core::option::Option<String>,
+    arg3: Option<u8>,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<String> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("someFn");
+    let arg2 = arg2.unwrap_or_else(|| String::from("some value"));
+    some_fn(arg1, arg2, arg3)
+        .await
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
+}
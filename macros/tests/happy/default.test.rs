@@ -0,0 +1,25 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+struct TestStruct;
+
+#[wasm_export]
+impl TestStruct {
+    #[wasm_export(js_name = "someMethod")]
+    pub fn some_method(
+        &self,
+        arg1: String,
+        #[wasm_export(param_description = "some number", default = 10)] arg2: u32,
+    ) -> Result<u32, Error> {
+        Ok(arg2)
+    }
+}
+
+#[wasm_export(js_name = "someFn")]
+pub async fn some_fn(
+    arg1: String,
+    #[wasm_export(js_name = "argTwo", default = String::from("some value"))] arg2: String,
+    arg3: Option<u8>,
+) -> Result<String, Error> {
+    Ok(arg2)
+}
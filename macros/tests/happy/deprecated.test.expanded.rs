@@ -0,0 +1,100 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+struct TestStruct;
+impl TestStruct {
+    /// Old method
+    pub fn old_method(&self) -> Result<u8, Error> {
+        Ok(1)
+    }
+    pub fn other_method(&self) -> Result<u8, Error> {
+        Ok(1)
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[wasm_bindgen]
+    impl TestStruct {
+        /// Old method
+        ///
+        /// @deprecated Since 0.1.0. Use `TestStruct.newMethod` instead.
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "oldMethod",
+            unchecked_return_type = "WasmEncodedResult<u8>"
+        )]
+        pub fn old_method__wasm_export(
+            &self,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u8> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "oldMethod",
+            );
+            self.old_method()
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+        /// @deprecated Will be removed.
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "otherMethod",
+            unchecked_return_type = "WasmEncodedResult<u8>"
+        )]
+        pub fn other_method__wasm_export(
+            &self,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u8> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "otherMethod",
+            );
+            self.other_method()
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+    }
+};
+/// Old function
+pub fn old_fn(arg: String) -> Result<String, Error> {
+    Ok(arg)
+}
+/// Old function
+///
+/// @deprecated Use the batch variant. Since 0.2.0. Use `newFn` instead.
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "oldFn",
+    unchecked_return_type = "WasmEncodedResult<String>"
+)]
+pub fn old_fn__wasm_export(
+    arg: String,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<String> {
+    static __WASM_EXPORT_DEPRECATED: ::std::sync::Once = ::std::sync::Once::new();
+    __WASM_EXPORT_DEPRECATED
+        .call_once(|| ::wasm_bindgen_utils::deprecation::warn_deprecated(
+            "`oldFn` is deprecated. Use the batch variant. Since 0.2.0. Use `newFn` instead.",
+        ));
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("oldFn");
+    old_fn(arg).encode_errors(|error| __wasm_export_call.on_error(error), |_| {}).into()
+}
+pub fn some_fn() -> Result<String, Error> {
+    Ok(String::new())
+}
+/// @deprecated
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    unchecked_return_type = "WasmEncodedResult<String>"
+)]
+pub fn some_fn__wasm_export() -> ::wasm_bindgen_utils::result::WasmEncodedResult<
+    String,
+> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "some_fn__wasm_export",
+    );
+    some_fn().encode_errors(|error| __wasm_export_call.on_error(error), |_| {}).into()
+}
@@ -0,0 +1,31 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+struct TestStruct;
+
+#[wasm_export(deprecated(since = "0.1.0", use_instead = "TestStruct.newMethod"))]
+impl TestStruct {
+    /// Old method
+    #[wasm_export(js_name = "oldMethod")]
+    pub fn old_method(&self) -> Result<u8, Error> {
+        Ok(1)
+    }
+    #[wasm_export(js_name = "otherMethod", deprecated = "Will be removed.")]
+    pub fn other_method(&self) -> Result<u8, Error> {
+        Ok(1)
+    }
+}
+
+/// Old function
+#[wasm_export(
+    js_name = "oldFn",
+    deprecated(note = "Use the batch variant.", since = "0.2.0", use_instead = "newFn", warn)
+)]
+pub fn old_fn(arg: String) -> Result<String, Error> {
+    Ok(arg)
+}
+
+#[wasm_export(deprecated)]
+pub fn some_fn() -> Result<String, Error> {
+    Ok(String::new())
+}
@@ -0,0 +1,146 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+struct TestStruct;
+pub async fn some_diagnosed_fn(arg: String) -> Diagnosed<TestStruct, Error> {
+    Diagnosed::ok(TestStruct)
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "someDiagnosedFn",
+    unchecked_return_type = "WasmEncodedDiagnosedResult<TestStruct>"
+)]
+pub async fn some_diagnosed_fn__wasm_export(
+    arg: String,
+) -> ::wasm_bindgen_utils::result::WasmEncodedDiagnosedResult<TestStruct> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "someDiagnosedFn",
+    );
+    some_diagnosed_fn(arg)
+        .await
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
+}
+pub fn some_diagnosed_fn_preserve_class(arg: String) -> Diagnosed<TestStruct, Error> {
+    Diagnosed::ok(TestStruct)
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "someDiagnosedFnPreserveClass",
+    unchecked_return_type = "WasmEncodedDiagnosedResult<TestStruct>"
+)]
+pub fn some_diagnosed_fn_preserve_class__wasm_export(
+    arg: String,
+) -> ::wasm_bindgen_utils::prelude::JsValue {
+    use ::wasm_bindgen_utils::prelude::JsValue;
+    use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+    use ::wasm_bindgen_utils::result::WasmEncodedError;
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "someDiagnosedFnPreserveClass",
+    );
+    let obj = Object::new();
+    let ::wasm_bindgen_utils::result::Diagnosed { result, warnings } = some_diagnosed_fn_preserve_class(
+            arg,
+        )
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {});
+    match result {
+        ::core::result::Result::Ok(value) => {
+            Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
+            Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED)
+                .unwrap();
+        }
+        ::core::result::Result::Err(error) => {
+            let wasm_error: WasmEncodedError = error.into();
+            Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED)
+                .unwrap();
+            Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into()).unwrap();
+        }
+    };
+    let warnings: ::std::vec::Vec<WasmEncodedError> = warnings
+        .into_iter()
+        .map(::core::convert::Into::into)
+        .collect();
+    Reflect::set(&obj, &JsValue::from_str("warnings"), &warnings.into()).unwrap();
+    obj.into()
+}
+impl TestStruct {
+    pub fn some_diagnosed_method(&self) -> Diagnosed<u8, Error> {
+        Diagnosed::ok(1)
+    }
+    pub async fn some_diagnosed_method_preserve_class(
+        &self,
+    ) -> Diagnosed<TestStruct, Error> {
+        Diagnosed::ok(TestStruct)
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[wasm_bindgen]
+    impl TestStruct {
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "someDiagnosedMethod",
+            unchecked_return_type = "WasmEncodedDiagnosedResult<u8>"
+        )]
+        pub fn some_diagnosed_method__wasm_export(
+            &self,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedDiagnosedResult<u8> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "someDiagnosedMethod",
+            );
+            self.some_diagnosed_method()
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "someDiagnosedMethodPreserveClass",
+            unchecked_return_type = "WasmEncodedDiagnosedResult<TestStruct>"
+        )]
+        pub async fn some_diagnosed_method_preserve_class__wasm_export(
+            &self,
+        ) -> ::wasm_bindgen_utils::prelude::JsValue {
+            use ::wasm_bindgen_utils::prelude::JsValue;
+            use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+            use ::wasm_bindgen_utils::result::WasmEncodedError;
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "someDiagnosedMethodPreserveClass",
+            );
+            let obj = Object::new();
+            let ::wasm_bindgen_utils::result::Diagnosed { result, warnings } = self
+                .some_diagnosed_method_preserve_class()
+                .await
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {});
+            match result {
+                ::core::result::Result::Ok(value) => {
+                    Reflect::set(&obj, &JsValue::from_str("value"), &value.into())
+                        .unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED)
+                        .unwrap();
+                }
+                ::core::result::Result::Err(error) => {
+                    let wasm_error: WasmEncodedError = error.into();
+                    Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED)
+                        .unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into())
+                        .unwrap();
+                }
+            };
+            let warnings: ::std::vec::Vec<WasmEncodedError> = warnings
+                .into_iter()
+                .map(::core::convert::Into::into)
+                .collect();
+            Reflect::set(&obj, &JsValue::from_str("warnings"), &warnings.into())
+                .unwrap();
+            obj.into()
+        }
+    }
+};
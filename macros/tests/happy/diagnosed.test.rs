@@ -0,0 +1,27 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+struct TestStruct;
+
+#[wasm_export(js_name = "someDiagnosedFn")]
+pub async fn some_diagnosed_fn(arg: String) -> Diagnosed<TestStruct, Error> {
+    Diagnosed::ok(TestStruct)
+}
+
+#[wasm_export(js_name = "someDiagnosedFnPreserveClass", preserve_js_class)]
+pub fn some_diagnosed_fn_preserve_class(arg: String) -> Diagnosed<TestStruct, Error> {
+    Diagnosed::ok(TestStruct)
+}
+
+#[wasm_export]
+impl TestStruct {
+    #[wasm_export(js_name = "someDiagnosedMethod")]
+    pub fn some_diagnosed_method(&self) -> Diagnosed<u8, Error> {
+        Diagnosed::ok(1)
+    }
+
+    #[wasm_export(js_name = "someDiagnosedMethodPreserveClass", preserve_js_class)]
+    pub async fn some_diagnosed_method_preserve_class(&self) -> Diagnosed<TestStruct, Error> {
+        Diagnosed::ok(TestStruct)
+    }
+}
@@ -42,76 +42,108 @@ impl Calculator {
         Ok(((self.value * factor) + offset) % 1000)
     }
 }
-#[wasm_bindgen]
-impl Calculator {
-    /// Creates a new Calculator with the given initial value
-    ///
-    /// # Arguments
-    /// * `initial` - The starting value for the calculator
-    #[allow(non_snake_case)]
-    #[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<Calculator>")]
-    pub fn new__wasm_export(initial: u32) -> WasmEncodedResult<Calculator> {
-        Self::new(initial).into()
-    }
-    /// Adds a value to the current calculator value
-    /// Returns a new Calculator instance with the updated value
-    #[allow(non_snake_case)]
-    #[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<Calculator>")]
-    pub fn add__wasm_export(&self, other: u32) -> JsValue {
-        use js_sys::{Reflect, Object};
-        let obj = Object::new();
-        let result = self.add(other).into();
-        match result {
-            Ok(value) => {
-                Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
-                Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED)
-                    .unwrap();
-            }
-            Err(error) => {
-                let wasm_error: WasmEncodedError = error.into();
-                Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED)
-                    .unwrap();
-                Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into())
-                    .unwrap();
-            }
-        };
-        obj.into()
-    }
-    /// Gets the current value of the calculator
-    ///
-    /// This method returns the internal value as a u32.
-    /// The value represents the current state of the calculator.
-    #[allow(non_snake_case)]
-    #[wasm_bindgen(
-        js_name = "getValue",
-        unchecked_return_type = "WasmEncodedResult<u32>"
-    )]
-    pub fn get_value__wasm_export(&self) -> WasmEncodedResult<u32> {
-        self.get_value().into()
-    }
-    /// Complex calculation method with detailed documentation
-    ///
-    /// This method performs a complex calculation involving:
-    /// - Multiplication by the input factor
-    /// - Addition of a constant offset
-    /// - Modulo operation for bounds checking
-    ///
-    /// # Arguments
-    /// * `factor` - The multiplication factor
-    /// * `offset` - The offset to add
-    ///
-    /// # Returns
-    /// The result of the complex calculation
-    #[allow(non_snake_case)]
-    #[wasm_bindgen(
-        unchecked_return_type = "WasmEncodedResult<u32>",
-        return_description = "result of complex calculation"
-    )]
-    pub fn complex_calc__wasm_export(
-        &self,
-        factor: u32,
-        offset: u32,
-    ) -> WasmEncodedResult<u32> {
-        self.complex_calc(factor, offset).into()
+const _: () = {
+    #[allow(unused_imports)]
+    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[wasm_bindgen]
+    impl Calculator {
+        /// Creates a new Calculator with the given initial value
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<Calculator>")]
+        pub fn new__wasm_export(
+            #[wasm_bindgen(param_description = "The starting value for the calculator")]
+            initial: u32,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<Calculator> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "new__wasm_export",
+            );
+            Self::new(initial)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+        /// Adds a value to the current calculator value
+        /// Returns a new Calculator instance with the updated value
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<Calculator>")]
+        pub fn add__wasm_export(
+            &self,
+            other: u32,
+        ) -> ::wasm_bindgen_utils::prelude::JsValue {
+            use ::wasm_bindgen_utils::prelude::JsValue;
+            use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+            use ::wasm_bindgen_utils::result::WasmEncodedError;
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "add__wasm_export",
+            );
+            let obj = Object::new();
+            let result = self
+                .add(other)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into();
+            match result {
+                ::core::result::Result::Ok(value) => {
+                    Reflect::set(&obj, &JsValue::from_str("value"), &value.into())
+                        .unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED)
+                        .unwrap();
+                }
+                ::core::result::Result::Err(error) => {
+                    let wasm_error: WasmEncodedError = error.into();
+                    Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED)
+                        .unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into())
+                        .unwrap();
+                }
+            };
+            obj.into()
+        }
+        /// Gets the current value of the calculator
+        ///
+        /// This method returns the internal value as a u32.
+        /// The value represents the current state of the calculator.
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "getValue",
+            unchecked_return_type = "WasmEncodedResult<u32>"
+        )]
+        pub fn get_value__wasm_export(
+            &self,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u32> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "getValue",
+            );
+            self.get_value()
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+        /// Complex calculation method with detailed documentation
+        ///
+        /// This method performs a complex calculation involving:
+        /// - Multiplication by the input factor
+        /// - Addition of a constant offset
+        /// - Modulo operation for bounds checking
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            unchecked_return_type = "WasmEncodedResult<u32>",
+            return_description = "result of complex calculation"
+        )]
+        pub fn complex_calc__wasm_export(
+            &self,
+            #[wasm_bindgen(param_description = "The multiplication factor")]
+            factor: u32,
+            #[wasm_bindgen(param_description = "The offset to add")]
+            offset: u32,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u32> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "complex_calc__wasm_export",
+            );
+            self.complex_calc(factor, offset)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
     }
-}
+};
@@ -9,9 +9,20 @@ pub fn add(a: u32, b: u32) -> Result<u32, Error> {
 /// This function calculates the sum of two numbers
 /// It's a simple addition operation
 #[allow(non_snake_case)]
-#[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<u32>")]
-pub fn add__wasm_export(a: u32, b: u32) -> WasmEncodedResult<u32> {
-    add(a, b).into()
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    unchecked_return_type = "WasmEncodedResult<u32>"
+)]
+pub fn add__wasm_export(
+    a: u32,
+    b: u32,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u32> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "add__wasm_export",
+    );
+    add(a, b).encode_errors(|error| __wasm_export_call.on_error(error), |_| {}).into()
 }
 /// Multiply two numbers together
 ///
@@ -25,17 +36,23 @@ pub fn mul(x: u32, y: u32) -> Result<u32, Error> {
     Ok(x * y)
 }
 /// Multiply two numbers together
-///
-/// # Arguments
-/// * `x` - The first number
-/// * `y` - The second number
-///
-/// # Returns
-/// The product of x and y
 #[allow(non_snake_case)]
-#[wasm_bindgen(js_name = "multiply", unchecked_return_type = "WasmEncodedResult<u32>")]
-pub fn mul__wasm_export(x: u32, y: u32) -> WasmEncodedResult<u32> {
-    mul(x, y).into()
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "multiply",
+    unchecked_return_type = "WasmEncodedResult<u32>",
+    return_description = "The product of x and y"
+)]
+pub fn mul__wasm_export(
+    #[wasm_bindgen(param_description = "The first number")]
+    x: u32,
+    #[wasm_bindgen(param_description = "The second number")]
+    y: u32,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u32> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("multiply");
+    mul(x, y).encode_errors(|error| __wasm_export_call.on_error(error), |_| {}).into()
 }
 /// Creates a new TestStruct instance
 ///
@@ -51,12 +68,22 @@ pub fn create_test_struct() -> Result<TestStruct, Error> {
 /// lines of documentation that should be preserved
 /// in the generated wasm_bindgen function.
 #[allow(non_snake_case)]
-#[wasm_bindgen(
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
     unchecked_return_type = "WasmEncodedResult<TestStruct>",
     return_description = "new TestStruct instance"
 )]
-pub fn create_test_struct__wasm_export() -> WasmEncodedResult<TestStruct> {
-    create_test_struct().into()
+pub fn create_test_struct__wasm_export() -> ::wasm_bindgen_utils::result::WasmEncodedResult<
+    TestStruct,
+> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "create_test_struct__wasm_export",
+    );
+    create_test_struct()
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
 }
 /// Async function with doc comments
 /// This function demonstrates that doc comments work with async functions too
@@ -66,18 +93,33 @@ pub async fn async_with_docs(input: String) -> Result<TestStruct, Error> {
 /// Async function with doc comments
 /// This function demonstrates that doc comments work with async functions too
 #[allow(non_snake_case)]
-#[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<TestStruct>")]
-pub async fn async_with_docs__wasm_export(input: String) -> JsValue {
-    use js_sys::{Reflect, Object};
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    unchecked_return_type = "WasmEncodedResult<TestStruct>"
+)]
+pub async fn async_with_docs__wasm_export(
+    input: String,
+) -> ::wasm_bindgen_utils::prelude::JsValue {
+    use ::wasm_bindgen_utils::prelude::JsValue;
+    use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+    use ::wasm_bindgen_utils::result::WasmEncodedError;
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "async_with_docs__wasm_export",
+    );
     let obj = Object::new();
-    let result = async_with_docs(input).await.into();
+    let result = async_with_docs(input)
+        .await
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into();
     match result {
-        Ok(value) => {
+        ::core::result::Result::Ok(value) => {
             Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
             Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED)
                 .unwrap();
         }
-        Err(error) => {
+        ::core::result::Result::Err(error) => {
             let wasm_error: WasmEncodedError = error.into();
             Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED)
                 .unwrap();
@@ -86,3 +128,44 @@ pub async fn async_with_docs__wasm_export(input: String) -> JsValue {
     };
     obj.into()
 }
+/// Divides two numbers
+///
+/// # Arguments
+/// * `x` - The dividend
+/// * `y` - The divisor
+///
+/// # Returns
+/// The quotient of x and y
+///
+/// # Examples
+/// ```rust
+/// # use some_crate::div;
+/// let result = div(6, 3);
+/// ```
+pub fn div(x: u32, y: u32) -> Result<u32, Error> {
+    Ok(x / y)
+}
+/// Divides two numbers
+///
+/// @example
+/// ```rust
+/// let result = div(6, 3);
+/// ```
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "divide",
+    unchecked_return_type = "WasmEncodedResult<u32>",
+    return_description = "explicit quotient"
+)]
+pub fn div__wasm_export(
+    #[wasm_bindgen(param_description = "The dividend")]
+    x: u32,
+    #[wasm_bindgen(param_description = "explicit divisor")]
+    y: u32,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u32> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("divide");
+    div(x, y).encode_errors(|error| __wasm_export_call.on_error(error), |_| {}).into()
+}
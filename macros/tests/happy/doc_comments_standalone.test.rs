@@ -38,4 +38,22 @@ pub fn create_test_struct() -> Result<TestStruct, Error> {
 #[wasm_export(preserve_js_class)]
 pub async fn async_with_docs(input: String) -> Result<TestStruct, Error> {
     Ok(TestStruct)
-}
\ No newline at end of file
+}
+/// Divides two numbers
+///
+/// # Arguments
+/// * `x` - The dividend
+/// * `y` - The divisor
+///
+/// # Returns
+/// The quotient of x and y
+///
+/// # Examples
+/// ```rust
+/// # use some_crate::div;
+/// let result = div(6, 3);
+/// ```
+#[wasm_export(js_name = "divide", return_description = "explicit quotient")]
+pub fn div(x: u32, #[wasm_export(param_description = "explicit divisor")] y: u32) -> Result<u32, Error> {
+    Ok(x / y)
+}
@@ -0,0 +1,220 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+struct TestStruct;
+pub async fn some_fn(some_arg: String) -> Result<TestStruct, Error> {
+    Ok(TestStruct)
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "someFn",
+    unchecked_return_type = "WasmEncodedResult<TestStruct>"
+)]
+pub async fn some_fn__wasm_export(
+    #[wasm_bindgen(js_name = "someArg")]
+    some_arg: String,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<TestStruct> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("someFn");
+    some_fn(some_arg)
+        .await
+        .encode_errors(
+            |error| {
+                error.context = ::core::option::Option::Some(
+                    ::std::boxed::Box::new(
+                        ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                            ::std::any::type_name_of_val(&some_fn),
+                            "someFn",
+                            &["someArg: String"],
+                        ),
+                    ),
+                );
+                __wasm_export_call.on_error(error);
+            },
+            |warning| {
+                warning.context = ::core::option::Option::Some(
+                    ::std::boxed::Box::new(
+                        ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                            ::std::any::type_name_of_val(&some_fn),
+                            "someFn",
+                            &["someArg: String"],
+                        ),
+                    ),
+                );
+            },
+        )
+        .into()
+}
+impl TestStruct {
+    pub fn some_method(&self, (arg1, arg2): (u8, Vec<u8>)) -> Diagnosed<u8, Error> {
+        Diagnosed::ok(arg1)
+    }
+    pub async fn some_method_preserve_class() -> Result<TestStruct, Error> {
+        Ok(TestStruct)
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[wasm_bindgen]
+    impl TestStruct {
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "someMethod",
+            unchecked_return_type = "WasmEncodedDiagnosedResult<u8>"
+        )]
+        pub fn some_method__wasm_export(
+            &self,
+            (arg1, arg2): (u8, Vec<u8>),
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedDiagnosedResult<u8> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "someMethod",
+            );
+            self.some_method((arg1, arg2))
+                .encode_errors(
+                    |error| {
+                        error.context = ::core::option::Option::Some(
+                            ::std::boxed::Box::new(
+                                ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                                    ::std::any::type_name_of_val(&Self::some_method),
+                                    "someMethod",
+                                    &["(arg1, arg2): (u8, Vec<u8>)"],
+                                ),
+                            ),
+                        );
+                        __wasm_export_call.on_error(error);
+                    },
+                    |warning| {
+                        warning.context = ::core::option::Option::Some(
+                            ::std::boxed::Box::new(
+                                ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                                    ::std::any::type_name_of_val(&Self::some_method),
+                                    "someMethod",
+                                    &["(arg1, arg2): (u8, Vec<u8>)"],
+                                ),
+                            ),
+                        );
+                    },
+                )
+                .into()
+        }
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "someMethodPreserveClass",
+            unchecked_return_type = "WasmEncodedResult<TestStruct>"
+        )]
+        pub async fn some_method_preserve_class__wasm_export() -> ::wasm_bindgen_utils::prelude::JsValue {
+            use ::wasm_bindgen_utils::prelude::JsValue;
+            use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+            use ::wasm_bindgen_utils::result::WasmEncodedError;
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "someMethodPreserveClass",
+            );
+            let obj = Object::new();
+            let result = Self::some_method_preserve_class()
+                .await
+                .encode_errors(
+                    |error| {
+                        error.context = ::core::option::Option::Some(
+                            ::std::boxed::Box::new(
+                                ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                                    ::std::any::type_name_of_val(
+                                        &Self::some_method_preserve_class,
+                                    ),
+                                    "someMethodPreserveClass",
+                                    &[],
+                                ),
+                            ),
+                        );
+                        __wasm_export_call.on_error(error);
+                    },
+                    |warning| {
+                        warning.context = ::core::option::Option::Some(
+                            ::std::boxed::Box::new(
+                                ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                                    ::std::any::type_name_of_val(
+                                        &Self::some_method_preserve_class,
+                                    ),
+                                    "someMethodPreserveClass",
+                                    &[],
+                                ),
+                            ),
+                        );
+                    },
+                )
+                .into();
+            match result {
+                ::core::result::Result::Ok(value) => {
+                    Reflect::set(&obj, &JsValue::from_str("value"), &value.into())
+                        .unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED)
+                        .unwrap();
+                }
+                ::core::result::Result::Err(error) => {
+                    let wasm_error: WasmEncodedError = error.into();
+                    Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED)
+                        .unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into())
+                        .unwrap();
+                }
+            };
+            obj.into()
+        }
+    }
+};
+impl TestStruct {
+    pub fn some_other_method(&self, arg: &str) -> Vec<Result<u8, Error>> {
+        Vec::new()
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[wasm_bindgen]
+    impl TestStruct {
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "someOtherMethod",
+            unchecked_return_type = "WasmEncodedResults<u8>"
+        )]
+        pub fn some_other_method__wasm_export(
+            &self,
+            arg: &str,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResults<u8> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "someOtherMethod",
+            );
+            self.some_other_method(arg)
+                .encode_errors(
+                    |error| {
+                        error.context = ::core::option::Option::Some(
+                            ::std::boxed::Box::new(
+                                ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                                    ::std::any::type_name_of_val(&Self::some_other_method),
+                                    "someOtherMethod",
+                                    &["arg: &str"],
+                                ),
+                            ),
+                        );
+                        __wasm_export_call.on_error(error);
+                    },
+                    |warning| {
+                        warning.context = ::core::option::Option::Some(
+                            ::std::boxed::Box::new(
+                                ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                                    ::std::any::type_name_of_val(&Self::some_other_method),
+                                    "someOtherMethod",
+                                    &["arg: &str"],
+                                ),
+                            ),
+                        );
+                    },
+                )
+                .into()
+        }
+    }
+};
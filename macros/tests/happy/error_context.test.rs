@@ -0,0 +1,30 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+struct TestStruct;
+
+#[wasm_export(js_name = "someFn", error_context)]
+pub async fn some_fn(#[wasm_export(js_name = "someArg")] some_arg: String) -> Result<TestStruct, Error> {
+    Ok(TestStruct)
+}
+
+#[wasm_export(error_context)]
+impl TestStruct {
+    #[wasm_export(js_name = "someMethod")]
+    pub fn some_method(&self, (arg1, arg2): (u8, Vec<u8>)) -> Diagnosed<u8, Error> {
+        Diagnosed::ok(arg1)
+    }
+
+    #[wasm_export(js_name = "someMethodPreserveClass", preserve_js_class)]
+    pub async fn some_method_preserve_class() -> Result<TestStruct, Error> {
+        Ok(TestStruct)
+    }
+}
+
+#[wasm_export]
+impl TestStruct {
+    #[wasm_export(js_name = "someOtherMethod", error_context)]
+    pub fn some_other_method(&self, arg: &str) -> Vec<Result<u8, Error>> {
+        Vec::new()
+    }
+}
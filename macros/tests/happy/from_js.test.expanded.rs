@@ -0,0 +1,119 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+struct TestStruct;
+impl TestStruct {
+    pub fn some_method(&self, owner: Address) -> Result<u32, Error> {
+        Ok(1)
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[wasm_bindgen]
+    impl TestStruct {
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "someMethod",
+            unchecked_return_type = "WasmEncodedResult<u32>"
+        )]
+        pub fn some_method__wasm_export(
+            &self,
+            #[wasm_bindgen(unchecked_param_type = "string")]
+            owner: ::wasm_bindgen_utils::prelude::JsValue,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u32> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "someMethod",
+            );
+            let owner = ::wasm_bindgen_utils::result::convert_from_js(
+                "owner",
+                owner,
+                Address::from_hex,
+            );
+            (match owner {
+                ::core::result::Result::Ok(owner) => {
+                    self.some_method(owner)
+                        .encode_errors(
+                            |error| __wasm_export_call.on_error(error),
+                            |_| {},
+                        )
+                }
+                ::core::result::Result::Err(error) => {
+                    __wasm_export_call.on_error(&error);
+                    ::wasm_bindgen_utils::result::FromEncodedError::from_encoded_error(
+                        error,
+                    )
+                }
+            })
+                .into()
+        }
+    }
+};
+pub async fn some_fn(arg1: String, amount: U256) -> Result<u32, Error> {
+    Ok(1)
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "someFn",
+    unchecked_return_type = "WasmEncodedResult<u32>"
+)]
+pub async fn some_fn__wasm_export(
+    arg1: String,
+    #[wasm_bindgen(js_name = "someAmount", unchecked_param_type = "string")]
+    amount: ::wasm_bindgen_utils::prelude::JsValue,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u32> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("someFn");
+    let amount = ::wasm_bindgen_utils::result::convert_from_js(
+        "someAmount",
+        amount,
+        parse_amount,
+    );
+    (match amount {
+        ::core::result::Result::Ok(amount) => {
+            some_fn(arg1, amount)
+                .await
+                .encode_errors(
+                    |error| {
+                        error.context = ::core::option::Option::Some(
+                            ::std::boxed::Box::new(
+                                ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                                    ::std::any::type_name_of_val(&some_fn),
+                                    "someFn",
+                                    &["arg1: String", "someAmount: U256"],
+                                ),
+                            ),
+                        );
+                        __wasm_export_call.on_error(error);
+                    },
+                    |warning| {
+                        warning.context = ::core::option::Option::Some(
+                            ::std::boxed::Box::new(
+                                ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                                    ::std::any::type_name_of_val(&some_fn),
+                                    "someFn",
+                                    &["arg1: String", "someAmount: U256"],
+                                ),
+                            ),
+                        );
+                    },
+                )
+        }
+        ::core::result::Result::Err(mut error) => {
+            error.context = ::core::option::Option::Some(
+                ::std::boxed::Box::new(
+                    ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                        ::std::any::type_name_of_val(&some_fn),
+                        "someFn",
+                        &["arg1: String", "someAmount: U256"],
+                    ),
+                ),
+            );
+            __wasm_export_call.on_error(&error);
+            ::wasm_bindgen_utils::result::FromEncodedError::from_encoded_error(error)
+        }
+    })
+        .into()
+}
@@ -0,0 +1,23 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+struct TestStruct;
+
+#[wasm_export]
+impl TestStruct {
+    #[wasm_export(js_name = "someMethod")]
+    pub fn some_method(
+        &self,
+        #[wasm_export(from_js = Address::from_hex, js_type = "string")] owner: Address,
+    ) -> Result<u32, Error> {
+        Ok(1)
+    }
+}
+
+#[wasm_export(js_name = "someFn", error_context)]
+pub async fn some_fn(
+    arg1: String,
+    #[wasm_export(js_name = "someAmount", from_js = parse_amount, js_type = "string")] amount: U256,
+) -> Result<u32, Error> {
+    Ok(1)
+}
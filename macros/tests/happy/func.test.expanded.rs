@@ -6,42 +6,78 @@ pub async fn some_fn(arg: String) -> Result<TestStruct, Error> {
     Ok(TestStruct)
 }
 #[allow(non_snake_case)]
-#[wasm_bindgen(
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
     js_name = "someSelfMethod",
     some_wbg_attr,
     some_other_wbg_attr = something,
     unchecked_return_type = "WasmEncodedResult<TestStruct>"
 )]
-pub async fn some_fn__wasm_export(arg: String) -> WasmEncodedResult<TestStruct> {
-    some_fn(arg).await.into()
+pub async fn some_fn__wasm_export(
+    arg: String,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<TestStruct> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "someSelfMethod",
+    );
+    some_fn(arg)
+        .await
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
 }
 pub fn some_other_fn() -> Result<Vec<u8>, Error> {
     Ok(::alloc::vec::Vec::new())
 }
 #[allow(non_snake_case)]
-#[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<number[]>")]
-pub fn some_other_fn__wasm_export() -> WasmEncodedResult<Vec<u8>> {
-    some_other_fn().into()
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    unchecked_return_type = "WasmEncodedResult<number[]>"
+)]
+pub fn some_other_fn__wasm_export() -> ::wasm_bindgen_utils::result::WasmEncodedResult<
+    Vec<u8>,
+> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "some_other_fn__wasm_export",
+    );
+    some_other_fn()
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
 }
 pub async fn some_fn_preserve_class_async(arg: String) -> Result<TestStruct, Error> {
     Ok(TestStruct)
 }
 #[allow(non_snake_case)]
-#[wasm_bindgen(
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
     js_name = "someFnPreserveClassAsync",
     unchecked_return_type = "WasmEncodedResult<TestStruct>"
 )]
-pub async fn some_fn_preserve_class_async__wasm_export(arg: String) -> JsValue {
-    use js_sys::{Reflect, Object};
+pub async fn some_fn_preserve_class_async__wasm_export(
+    arg: String,
+) -> ::wasm_bindgen_utils::prelude::JsValue {
+    use ::wasm_bindgen_utils::prelude::JsValue;
+    use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+    use ::wasm_bindgen_utils::result::WasmEncodedError;
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "someFnPreserveClassAsync",
+    );
     let obj = Object::new();
-    let result = some_fn_preserve_class_async(arg).await.into();
+    let result = some_fn_preserve_class_async(arg)
+        .await
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into();
     match result {
-        Ok(value) => {
+        ::core::result::Result::Ok(value) => {
             Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
             Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED)
                 .unwrap();
         }
-        Err(error) => {
+        ::core::result::Result::Err(error) => {
             let wasm_error: WasmEncodedError = error.into();
             Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED)
                 .unwrap();
@@ -54,21 +90,33 @@ pub fn some_fn_preserve_class_sync(arg: String) -> Result<TestStruct, Error> {
     Ok(TestStruct)
 }
 #[allow(non_snake_case)]
-#[wasm_bindgen(
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
     js_name = "someFnPreserveClassSync",
     unchecked_return_type = "WasmEncodedResult<TestStruct>"
 )]
-pub fn some_fn_preserve_class_sync__wasm_export(arg: String) -> JsValue {
-    use js_sys::{Reflect, Object};
+pub fn some_fn_preserve_class_sync__wasm_export(
+    arg: String,
+) -> ::wasm_bindgen_utils::prelude::JsValue {
+    use ::wasm_bindgen_utils::prelude::JsValue;
+    use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+    use ::wasm_bindgen_utils::result::WasmEncodedError;
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "someFnPreserveClassSync",
+    );
     let obj = Object::new();
-    let result = some_fn_preserve_class_sync(arg).into();
+    let result = some_fn_preserve_class_sync(arg)
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into();
     match result {
-        Ok(value) => {
+        ::core::result::Result::Ok(value) => {
             Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
             Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED)
                 .unwrap();
         }
-        Err(error) => {
+        ::core::result::Result::Err(error) => {
             let wasm_error: WasmEncodedError = error.into();
             Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED)
                 .unwrap();
@@ -81,18 +129,31 @@ pub fn add_with_description(a: u32, b: u32) -> Result<u32, Error> {
     Ok(a + b)
 }
 #[allow(non_snake_case)]
-#[wasm_bindgen(
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
     unchecked_return_type = "WasmEncodedResult<u32>",
     return_description = "returns the sum of two numbers"
 )]
-pub fn add_with_description__wasm_export(a: u32, b: u32) -> WasmEncodedResult<u32> {
-    add_with_description(a, b).into()
+pub fn add_with_description__wasm_export(
+    a: u32,
+    b: u32,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u32> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "add_with_description__wasm_export",
+    );
+    add_with_description(a, b)
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
 }
 pub async fn complex_calculation_with_desc(input: String) -> Result<i64, Error> {
     Ok(42)
 }
 #[allow(non_snake_case)]
-#[wasm_bindgen(
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
     js_name = "complexCalc",
     catch,
     unchecked_return_type = "WasmEncodedResult<i64>",
@@ -100,14 +161,23 @@ pub async fn complex_calculation_with_desc(input: String) -> Result<i64, Error>
 )]
 pub async fn complex_calculation_with_desc__wasm_export(
     input: String,
-) -> WasmEncodedResult<i64> {
-    complex_calculation_with_desc(input).await.into()
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<i64> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "complexCalc",
+    );
+    complex_calculation_with_desc(input)
+        .await
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
 }
 pub fn add(arg1: u32, arg2: u32) -> Result<u32, Error> {
     Ok(arg1 + arg2)
 }
 #[allow(non_snake_case)]
-#[wasm_bindgen(
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
     unchecked_return_type = "WasmEncodedResult<u32>",
     return_description = "the result of the addition of `arg1` and `arg2`"
 )]
@@ -116,20 +186,36 @@ pub fn add__wasm_export(
     arg1: u32,
     #[wasm_bindgen(param_description = "the second number")]
     arg2: u32,
-) -> WasmEncodedResult<u32> {
-    add(arg1, arg2).into()
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u32> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "add__wasm_export",
+    );
+    add(arg1, arg2)
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
 }
 pub fn mixed_params(input: String, count: u32) -> Result<String, Error> {
     Ok(input.repeat(count as usize))
 }
 #[allow(non_snake_case)]
-#[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<String>")]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    unchecked_return_type = "WasmEncodedResult<String>"
+)]
 pub fn mixed_params__wasm_export(
     #[wasm_bindgen(param_description = "the input string")]
     input: String,
     count: u32,
-) -> WasmEncodedResult<String> {
-    mixed_params(input, count).into()
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<String> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "mixed_params__wasm_export",
+    );
+    mixed_params(input, count)
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
 }
 pub fn with_unchecked_param_type(
     custom_param: wasm_bindgen::JsValue,
@@ -138,13 +224,23 @@ pub fn with_unchecked_param_type(
     Ok(normal_param)
 }
 #[allow(non_snake_case)]
-#[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<String>")]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    unchecked_return_type = "WasmEncodedResult<String>"
+)]
 pub fn with_unchecked_param_type__wasm_export(
     #[wasm_bindgen(unchecked_param_type = "CustomJSType")]
     custom_param: wasm_bindgen::JsValue,
     normal_param: String,
-) -> WasmEncodedResult<String> {
-    with_unchecked_param_type(custom_param, normal_param).into()
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<String> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "with_unchecked_param_type__wasm_export",
+    );
+    with_unchecked_param_type(custom_param, normal_param)
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
 }
 pub fn with_mixed_param_attrs(
     mixed_param: wasm_bindgen::JsValue,
@@ -153,7 +249,11 @@ pub fn with_mixed_param_attrs(
     Ok(regular_param)
 }
 #[allow(non_snake_case)]
-#[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<String>")]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    unchecked_return_type = "WasmEncodedResult<String>"
+)]
 pub fn with_mixed_param_attrs__wasm_export(
     #[wasm_bindgen(
         param_description = "a custom JS object",
@@ -162,41 +262,66 @@ pub fn with_mixed_param_attrs__wasm_export(
     mixed_param: wasm_bindgen::JsValue,
     #[wasm_bindgen(param_description = "a regular string")]
     regular_param: String,
-) -> WasmEncodedResult<String> {
-    with_mixed_param_attrs(mixed_param, regular_param).into()
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<String> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "with_mixed_param_attrs__wasm_export",
+    );
+    with_mixed_param_attrs(mixed_param, regular_param)
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
 }
 pub fn with_unchecked_and_js_name(element: wasm_bindgen::JsValue) -> Result<u32, Error> {
     Ok(42)
 }
 #[allow(non_snake_case)]
-#[wasm_bindgen(
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
     js_name = "customFunction",
     unchecked_return_type = "WasmEncodedResult<u32>"
 )]
 pub fn with_unchecked_and_js_name__wasm_export(
     #[wasm_bindgen(unchecked_param_type = "HTMLElement")]
     element: wasm_bindgen::JsValue,
-) -> WasmEncodedResult<u32> {
-    with_unchecked_and_js_name(element).into()
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u32> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "customFunction",
+    );
+    with_unchecked_and_js_name(element)
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
 }
 pub fn with_js_name_params(
     first_name: String,
     last_name: String,
 ) -> Result<String, Error> {
-    Ok({
-        let res = ::alloc::fmt::format(format_args!("{0} {1}", first_name, last_name));
-        res
-    })
+    Ok(
+        ::alloc::__export::must_use({
+            ::alloc::fmt::format(format_args!("{0} {1}", first_name, last_name))
+        }),
+    )
 }
 #[allow(non_snake_case)]
-#[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<String>")]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    unchecked_return_type = "WasmEncodedResult<String>"
+)]
 pub fn with_js_name_params__wasm_export(
     #[wasm_bindgen(js_name = "firstName")]
     first_name: String,
     #[wasm_bindgen(js_name = "lastName")]
     last_name: String,
-) -> WasmEncodedResult<String> {
-    with_js_name_params(first_name, last_name).into()
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<String> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "with_js_name_params__wasm_export",
+    );
+    with_js_name_params(first_name, last_name)
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
 }
 pub fn with_mixed_js_attributes(
     user_data: wasm_bindgen::JsValue,
@@ -205,7 +330,9 @@ pub fn with_mixed_js_attributes(
     Ok(true)
 }
 #[allow(non_snake_case)]
-#[wasm_bindgen(
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
     js_name = "processUserData",
     unchecked_return_type = "WasmEncodedResult<bool>"
 )]
@@ -218,23 +345,36 @@ pub fn with_mixed_js_attributes__wasm_export(
     user_data: wasm_bindgen::JsValue,
     #[wasm_bindgen(js_name = "processMode")]
     process_mode: String,
-) -> WasmEncodedResult<bool> {
-    with_mixed_js_attributes(user_data, process_mode).into()
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<bool> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "processUserData",
+    );
+    with_mixed_js_attributes(user_data, process_mode)
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
 }
 pub fn snake_to_camel_conversion(
     user_id: u32,
     is_active: bool,
     created_at: String,
 ) -> Result<String, Error> {
-    Ok({
-        let res = ::alloc::fmt::format(
-            format_args!("User {0} active: {1} at {2}", user_id, is_active, created_at),
-        );
-        res
-    })
+    Ok(
+        ::alloc::__export::must_use({
+            ::alloc::fmt::format(
+                format_args!(
+                    "User {0} active: {1} at {2}", user_id, is_active, created_at,
+                ),
+            )
+        }),
+    )
 }
 #[allow(non_snake_case)]
-#[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<String>")]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    unchecked_return_type = "WasmEncodedResult<String>"
+)]
 pub fn snake_to_camel_conversion__wasm_export(
     #[wasm_bindgen(js_name = "userId")]
     user_id: u32,
@@ -242,6 +382,12 @@ pub fn snake_to_camel_conversion__wasm_export(
     is_active: bool,
     #[wasm_bindgen(js_name = "createdAt")]
     created_at: String,
-) -> WasmEncodedResult<String> {
-    snake_to_camel_conversion(user_id, is_active, created_at).into()
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<String> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "snake_to_camel_conversion__wasm_export",
+    );
+    snake_to_camel_conversion(user_id, is_active, created_at)
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
 }
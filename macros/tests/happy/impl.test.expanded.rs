@@ -26,82 +26,125 @@ impl TestStruct {
         Ok(TestStruct)
     }
 }
-#[wasm_bindgen]
-impl TestStruct {
-    #[allow(non_snake_case)]
-    #[wasm_bindgen(
-        js_name = "someStaticMethod",
-        unchecked_return_type = "WasmEncodedResult<string>"
-    )]
-    pub async fn some_static_method__wasm_export(
-        (arg1, arg2): (String, u8),
-    ) -> WasmEncodedResult<String> {
-        Self::some_static_method((arg1, arg2)).await.into()
-    }
-    #[allow(non_snake_case)]
-    #[wasm_bindgen(
-        js_name = "someSelfMethod",
-        some_wbg_attr,
-        some_other_wbg_attr = something,
-        unchecked_return_type = "WasmEncodedResult<TestStruct>"
-    )]
-    pub async fn some_self_method__wasm_export(
-        &self,
-        arg: String,
-    ) -> WasmEncodedResult<TestStruct> {
-        self.some_self_method(arg).await.into()
-    }
-    #[allow(non_snake_case)]
-    #[wasm_bindgen(
-        js_name = "someMethodPreserveClassAsync",
-        unchecked_return_type = "WasmEncodedResult<TestStruct>"
-    )]
-    pub async fn some_method_preserve_class_async__wasm_export(arg: String) -> JsValue {
-        use js_sys::{Reflect, Object};
-        let obj = Object::new();
-        let result = Self::some_method_preserve_class_async(arg).await.into();
-        match result {
-            Ok(value) => {
-                Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
-                Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED)
-                    .unwrap();
-            }
-            Err(error) => {
-                let wasm_error: WasmEncodedError = error.into();
-                Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED)
-                    .unwrap();
-                Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into())
-                    .unwrap();
-            }
-        };
-        obj.into()
-    }
-    #[allow(non_snake_case)]
-    #[wasm_bindgen(
-        js_name = "someMethodPreserveClassSync",
-        unchecked_return_type = "WasmEncodedResult<TestStruct>"
-    )]
-    pub fn some_method_preserve_class_sync__wasm_export(&self, arg: String) -> JsValue {
-        use js_sys::{Reflect, Object};
-        let obj = Object::new();
-        let result = self.some_method_preserve_class_sync(arg).into();
-        match result {
-            Ok(value) => {
-                Reflect::set(&obj, &JsValue::from_str("value"), &value.into()).unwrap();
-                Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED)
-                    .unwrap();
-            }
-            Err(error) => {
-                let wasm_error: WasmEncodedError = error.into();
-                Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED)
-                    .unwrap();
-                Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into())
-                    .unwrap();
-            }
-        };
-        obj.into()
+const _: () = {
+    #[allow(unused_imports)]
+    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[wasm_bindgen]
+    impl TestStruct {
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "someStaticMethod",
+            unchecked_return_type = "WasmEncodedResult<string>"
+        )]
+        pub async fn some_static_method__wasm_export(
+            (arg1, arg2): (String, u8),
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<String> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "someStaticMethod",
+            );
+            Self::some_static_method((arg1, arg2))
+                .await
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "someSelfMethod",
+            some_wbg_attr,
+            some_other_wbg_attr = something,
+            unchecked_return_type = "WasmEncodedResult<TestStruct>"
+        )]
+        pub async fn some_self_method__wasm_export(
+            &self,
+            arg: String,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<TestStruct> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "someSelfMethod",
+            );
+            self.some_self_method(arg)
+                .await
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "someMethodPreserveClassAsync",
+            unchecked_return_type = "WasmEncodedResult<TestStruct>"
+        )]
+        pub async fn some_method_preserve_class_async__wasm_export(
+            arg: String,
+        ) -> ::wasm_bindgen_utils::prelude::JsValue {
+            use ::wasm_bindgen_utils::prelude::JsValue;
+            use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+            use ::wasm_bindgen_utils::result::WasmEncodedError;
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "someMethodPreserveClassAsync",
+            );
+            let obj = Object::new();
+            let result = Self::some_method_preserve_class_async(arg)
+                .await
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into();
+            match result {
+                ::core::result::Result::Ok(value) => {
+                    Reflect::set(&obj, &JsValue::from_str("value"), &value.into())
+                        .unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED)
+                        .unwrap();
+                }
+                ::core::result::Result::Err(error) => {
+                    let wasm_error: WasmEncodedError = error.into();
+                    Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED)
+                        .unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into())
+                        .unwrap();
+                }
+            };
+            obj.into()
+        }
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "someMethodPreserveClassSync",
+            unchecked_return_type = "WasmEncodedResult<TestStruct>"
+        )]
+        pub fn some_method_preserve_class_sync__wasm_export(
+            &self,
+            arg: String,
+        ) -> ::wasm_bindgen_utils::prelude::JsValue {
+            use ::wasm_bindgen_utils::prelude::JsValue;
+            use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+            use ::wasm_bindgen_utils::result::WasmEncodedError;
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "someMethodPreserveClassSync",
+            );
+            let obj = Object::new();
+            let result = self
+                .some_method_preserve_class_sync(arg)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into();
+            match result {
+                ::core::result::Result::Ok(value) => {
+                    Reflect::set(&obj, &JsValue::from_str("value"), &value.into())
+                        .unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED)
+                        .unwrap();
+                }
+                ::core::result::Result::Err(error) => {
+                    let wasm_error: WasmEncodedError = error.into();
+                    Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED)
+                        .unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into())
+                        .unwrap();
+                }
+            };
+            obj.into()
+        }
     }
-}
+};
 impl TestStruct {
     pub fn returns_num_array(&mut self) -> Result<Vec<u8>, Error> {
         Ok(Vec::new())
@@ -133,12 +176,13 @@ impl TestStruct {
         primary_key: u32,
         display_name: String,
     ) -> Result<String, Error> {
-        Ok({
-            let res = ::alloc::fmt::format(
-                format_args!("Item {0}: {1}", primary_key, display_name),
-            );
-            res
-        })
+        Ok(
+            ::alloc::__export::must_use({
+                ::alloc::fmt::format(
+                    format_args!("Item {0}: {1}", primary_key, display_name),
+                )
+            }),
+        )
     }
     pub fn update_record(
         &mut self,
@@ -149,100 +193,159 @@ impl TestStruct {
         Ok(true)
     }
 }
-#[wasm_bindgen(some_top_wbg_attr = "something", some_other_wbg_attr)]
-impl TestStruct {
-    #[allow(non_snake_case)]
-    #[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<Vec < u8 >>")]
-    pub fn returns_num_array__wasm_export(&mut self) -> WasmEncodedResult<Vec<u8>> {
-        self.returns_num_array().into()
-    }
-    #[allow(non_snake_case)]
-    #[wasm_bindgen(
-        unchecked_return_type = "WasmEncodedResult<String>",
-        return_description = "gets the user's name"
-    )]
-    pub fn get_name__wasm_export(&self) -> WasmEncodedResult<String> {
-        self.get_name().into()
-    }
-    #[allow(non_snake_case)]
-    #[wasm_bindgen(
-        js_name = "getUserAge",
-        catch,
-        unchecked_return_type = "WasmEncodedResult<u32>",
-        return_description = "returns user age in years"
-    )]
-    pub fn get_age__wasm_export(&self, user_id: u32) -> WasmEncodedResult<u32> {
-        self.get_age(user_id).into()
-    }
-    #[allow(non_snake_case)]
-    #[wasm_bindgen(
-        unchecked_return_type = "WasmEncodedResult<u32>",
-        return_description = "the number at the given index"
-    )]
-    pub fn number__wasm_export(
-        &self,
-        #[wasm_bindgen(param_description = "the index of the number to be returned")]
-        index: u32,
-    ) -> WasmEncodedResult<u32> {
-        self.number(index).into()
-    }
-    #[allow(non_snake_case)]
-    #[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<String>")]
-    pub fn with_unchecked_param__wasm_export(
-        &self,
-        #[wasm_bindgen(unchecked_param_type = "CustomJSType")]
-        custom_param: wasm_bindgen::JsValue,
-    ) -> WasmEncodedResult<String> {
-        self.with_unchecked_param(custom_param).into()
-    }
-    #[allow(non_snake_case)]
-    #[wasm_bindgen(
-        js_name = "processElement",
-        unchecked_return_type = "WasmEncodedResult<bool>"
-    )]
-    pub fn process_element__wasm_export(
-        &mut self,
+const _: () = {
+    #[allow(unused_imports)]
+    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[wasm_bindgen(some_top_wbg_attr = "something", some_other_wbg_attr)]
+    impl TestStruct {
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<Vec < u8 >>")]
+        pub fn returns_num_array__wasm_export(
+            &mut self,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<Vec<u8>> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "returns_num_array__wasm_export",
+            );
+            self.returns_num_array()
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+        #[allow(non_snake_case)]
         #[wasm_bindgen(
-            unchecked_param_type = "HTMLElement",
-            param_description = "the DOM element to process"
+            unchecked_return_type = "WasmEncodedResult<String>",
+            return_description = "gets the user's name"
         )]
-        element: wasm_bindgen::JsValue,
-        #[wasm_bindgen(param_description = "processing options")]
-        options: String,
-    ) -> WasmEncodedResult<bool> {
-        self.process_element(element, options).into()
-    }
-    #[allow(non_snake_case)]
-    #[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<String>")]
-    pub fn with_js_name_params__wasm_export(
-        &self,
-        #[wasm_bindgen(js_name = "primaryKey")]
-        primary_key: u32,
-        #[wasm_bindgen(js_name = "displayName")]
-        display_name: String,
-    ) -> WasmEncodedResult<String> {
-        self.with_js_name_params(primary_key, display_name).into()
-    }
-    #[allow(non_snake_case)]
-    #[wasm_bindgen(
-        js_name = "updateRecord",
-        unchecked_return_type = "WasmEncodedResult<bool>"
-    )]
-    pub fn update_record__wasm_export(
-        &mut self,
+        pub fn get_name__wasm_export(
+            &self,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<String> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "get_name__wasm_export",
+            );
+            self.get_name()
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+        #[allow(non_snake_case)]
         #[wasm_bindgen(
-            js_name = "recordId",
-            param_description = "unique identifier for the record"
+            js_name = "getUserAge",
+            catch,
+            unchecked_return_type = "WasmEncodedResult<u32>",
+            return_description = "returns user age in years"
         )]
-        record_id: u32,
-        #[wasm_bindgen(js_name = "newData", unchecked_param_type = "RecordData")]
-        new_data: wasm_bindgen::JsValue,
+        pub fn get_age__wasm_export(
+            &self,
+            user_id: u32,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u32> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "getUserAge",
+            );
+            self.get_age(user_id)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+        #[allow(non_snake_case)]
         #[wasm_bindgen(
-            js_name = "saveOptions",
-            param_description = "options for saving"
+            unchecked_return_type = "WasmEncodedResult<u32>",
+            return_description = "the number at the given index"
         )]
-        save_options: String,
-    ) -> WasmEncodedResult<bool> {
-        self.update_record(record_id, new_data, save_options).into()
+        pub fn number__wasm_export(
+            &self,
+            #[wasm_bindgen(param_description = "the index of the number to be returned")]
+            index: u32,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u32> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "number__wasm_export",
+            );
+            self.number(index)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<String>")]
+        pub fn with_unchecked_param__wasm_export(
+            &self,
+            #[wasm_bindgen(unchecked_param_type = "CustomJSType")]
+            custom_param: wasm_bindgen::JsValue,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<String> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "with_unchecked_param__wasm_export",
+            );
+            self.with_unchecked_param(custom_param)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "processElement",
+            unchecked_return_type = "WasmEncodedResult<bool>"
+        )]
+        pub fn process_element__wasm_export(
+            &mut self,
+            #[wasm_bindgen(
+                unchecked_param_type = "HTMLElement",
+                param_description = "the DOM element to process"
+            )]
+            element: wasm_bindgen::JsValue,
+            #[wasm_bindgen(param_description = "processing options")]
+            options: String,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<bool> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "processElement",
+            );
+            self.process_element(element, options)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(unchecked_return_type = "WasmEncodedResult<String>")]
+        pub fn with_js_name_params__wasm_export(
+            &self,
+            #[wasm_bindgen(js_name = "primaryKey")]
+            primary_key: u32,
+            #[wasm_bindgen(js_name = "displayName")]
+            display_name: String,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<String> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "with_js_name_params__wasm_export",
+            );
+            self.with_js_name_params(primary_key, display_name)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "updateRecord",
+            unchecked_return_type = "WasmEncodedResult<bool>"
+        )]
+        pub fn update_record__wasm_export(
+            &mut self,
+            #[wasm_bindgen(
+                js_name = "recordId",
+                param_description = "unique identifier for the record"
+            )]
+            record_id: u32,
+            #[wasm_bindgen(js_name = "newData", unchecked_param_type = "RecordData")]
+            new_data: wasm_bindgen::JsValue,
+            #[wasm_bindgen(
+                js_name = "saveOptions",
+                param_description = "options for saving"
+            )]
+            save_options: String,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<bool> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "updateRecord",
+            );
+            self.update_record(record_id, new_data, save_options)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
     }
-}
+};
@@ -0,0 +1,234 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+struct TestStruct;
+impl TestStruct {
+    pub fn decode<T: Format>(&self, data: Vec<u8>) -> Result<T::Output, Error> {
+        T::decode(&data)
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[wasm_bindgen]
+    impl TestStruct {
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "decodeJson",
+            unchecked_return_type = "WasmEncodedResult<Order>"
+        )]
+        pub fn decode__wasm_export_decodeJson(
+            &self,
+            data: Vec<u8>,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<<Json as Format>::Output> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "decodeJson",
+            );
+            self.decode::<Json>(data)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "decodeCbor",
+            unchecked_return_type = "WasmEncodedResult<Order>"
+        )]
+        pub fn decode__wasm_export_decodeCbor(
+            &self,
+            data: Vec<u8>,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<<Cbor as Format>::Output> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "decodeCbor",
+            );
+            self.decode::<Cbor>(data)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+    }
+};
+struct Codec<T>(T);
+impl<T: Format> Codec<T> {
+    pub fn name() -> Result<String, Error> {
+        Ok(T::NAME.to_string())
+    }
+    pub async fn encode<U: Serialize>(value: U) -> Result<Vec<u8>, Error> {
+        T::encode(&value)
+    }
+    pub fn inner(&self) -> &T {
+        &self.0
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[allow(non_camel_case_types)]
+    #[wasm_bindgen(js_name = "JsonCodec")]
+    pub struct JsonCodec__wasm_export;
+    #[wasm_bindgen(js_class = "JsonCodec")]
+    impl JsonCodec__wasm_export {
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "name",
+            unchecked_return_type = "WasmEncodedResult<String>"
+        )]
+        pub fn name__wasm_export() -> ::wasm_bindgen_utils::result::WasmEncodedResult<
+            String,
+        > {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "name",
+            );
+            <Codec<Json>>::name()
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "encodeOrder",
+            unchecked_return_type = "WasmEncodedResult<Vec < u8 >>"
+        )]
+        pub async fn encode__wasm_export_encodeOrder(
+            value: Order,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<Vec<u8>> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "encodeOrder",
+            );
+            <Codec<Json>>::encode::<Order>(value)
+                .await
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+    }
+    #[allow(non_camel_case_types)]
+    #[wasm_bindgen(js_name = "CborCodec")]
+    pub struct CborCodec__wasm_export;
+    #[wasm_bindgen(js_class = "CborCodec")]
+    impl CborCodec__wasm_export {
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "name",
+            unchecked_return_type = "WasmEncodedResult<String>"
+        )]
+        pub fn name__wasm_export() -> ::wasm_bindgen_utils::result::WasmEncodedResult<
+            String,
+        > {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "name",
+            );
+            <Codec<Cbor>>::name()
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "encodeOrder",
+            unchecked_return_type = "WasmEncodedResult<Vec < u8 >>"
+        )]
+        pub async fn encode__wasm_export_encodeOrder(
+            value: Order,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<Vec<u8>> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "encodeOrder",
+            );
+            <Codec<Cbor>>::encode::<Order>(value)
+                .await
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+    }
+};
+pub fn decode_as<T, U>(data: Vec<u8>) -> Result<U, Error>
+where
+    T: Format,
+    U: DeserializeOwned,
+{
+    T::decode_as(&data)
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "decodeJsonOrder",
+    unchecked_return_type = "WasmEncodedResult<Order>"
+)]
+pub fn decode_as__wasm_export_decodeJsonOrder(
+    data: Vec<u8>,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<Order> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "decodeJsonOrder",
+    );
+    decode_as::<Json, Order>(data)
+        .encode_errors(
+            |error| {
+                error.context = ::core::option::Option::Some(
+                    ::std::boxed::Box::new(
+                        ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                            ::std::any::type_name_of_val(&decode_as::<Json, Order>),
+                            "decodeJsonOrder",
+                            &["data: Vec<u8>"],
+                        ),
+                    ),
+                );
+                __wasm_export_call.on_error(error);
+            },
+            |warning| {
+                warning.context = ::core::option::Option::Some(
+                    ::std::boxed::Box::new(
+                        ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                            ::std::any::type_name_of_val(&decode_as::<Json, Order>),
+                            "decodeJsonOrder",
+                            &["data: Vec<u8>"],
+                        ),
+                    ),
+                );
+            },
+        )
+        .into()
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "decodeCborOrder",
+    unchecked_return_type = "WasmEncodedResult<Order>"
+)]
+pub fn decode_as__wasm_export_decodeCborOrder(
+    data: Vec<u8>,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<Order> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "decodeCborOrder",
+    );
+    decode_as::<Cbor, Order>(data)
+        .encode_errors(
+            |error| {
+                error.context = ::core::option::Option::Some(
+                    ::std::boxed::Box::new(
+                        ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                            ::std::any::type_name_of_val(&decode_as::<Cbor, Order>),
+                            "decodeCborOrder",
+                            &["data: Vec<u8>"],
+                        ),
+                    ),
+                );
+                __wasm_export_call.on_error(error);
+            },
+            |warning| {
+                warning.context = ::core::option::Option::Some(
+                    ::std::boxed::Box::new(
+                        ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                            ::std::any::type_name_of_val(&decode_as::<Cbor, Order>),
+                            "decodeCborOrder",
+                            &["data: Vec<u8>"],
+                        ),
+                    ),
+                );
+            },
+        )
+        .into()
+}
@@ -0,0 +1,50 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+struct TestStruct;
+
+#[wasm_export]
+impl TestStruct {
+    #[wasm_export(
+        instantiate(T = Json, js_name = "decodeJson"),
+        instantiate(T = Cbor, js_name = "decodeCbor"),
+        unchecked_return_type = "Order"
+    )]
+    pub fn decode<T: Format>(&self, data: Vec<u8>) -> Result<T::Output, Error> {
+        T::decode(&data)
+    }
+}
+
+struct Codec<T>(T);
+
+#[wasm_export(
+    instantiate(T = Json, js_class = "JsonCodec"),
+    instantiate(T = Cbor, js_class = "CborCodec")
+)]
+impl<T: Format> Codec<T> {
+    #[wasm_export(js_name = "name")]
+    pub fn name() -> Result<String, Error> {
+        Ok(T::NAME.to_string())
+    }
+    #[wasm_export(instantiate(U = Order, js_name = "encodeOrder"))]
+    pub async fn encode<U: Serialize>(value: U) -> Result<Vec<u8>, Error> {
+        T::encode(&value)
+    }
+    #[wasm_export(skip)]
+    pub fn inner(&self) -> &T {
+        &self.0
+    }
+}
+
+#[wasm_export(
+    instantiate(T = Json, U = Order, js_name = "decodeJsonOrder"),
+    instantiate(T = Cbor, U = Order, js_name = "decodeCborOrder"),
+    error_context
+)]
+pub fn decode_as<T, U>(data: Vec<u8>) -> Result<U, Error>
+where
+    T: Format,
+    U: DeserializeOwned,
+{
+    T::decode_as(&data)
+}
@@ -0,0 +1,66 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+pub fn tokenize(text: Text) -> impl Iterator<Item = Token> {
+    Tokenizer::new(text)
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "tokenize",
+    unchecked_return_type = "IterableIterator<Token>"
+)]
+pub fn tokenize__wasm_export(
+    #[wasm_bindgen(unchecked_param_type = "string")]
+    text: ::wasm_bindgen_utils::prelude::JsValue,
+) -> ::wasm_bindgen_utils::iter::JsIterator {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("tokenize");
+    let text = ::wasm_bindgen_utils::result::convert_from_js("text", text, Text::parse);
+    (match text {
+        ::core::result::Result::Ok(text) => {
+            ::wasm_bindgen_utils::iter::ExportIter::new(tokenize(text))
+                .encode_errors(move |error| __wasm_export_call.on_error(error), |_| {})
+        }
+        ::core::result::Result::Err(error) => {
+            __wasm_export_call.on_error(&error);
+            ::wasm_bindgen_utils::result::FromEncodedError::from_encoded_error(error)
+        }
+    })
+        .into()
+}
+struct EventLog;
+impl EventLog {
+    pub fn decoded_events(&self) -> Box<dyn Iterator<Item = Event>> {
+        Box::new(self.events.clone().into_iter())
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[wasm_bindgen]
+    impl EventLog {
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "decodedEvents",
+            unchecked_return_type = "IterableIterator<JsEvent>"
+        )]
+        pub fn decoded_events__wasm_export(
+            &self,
+        ) -> ::wasm_bindgen_utils::prelude::JsValue {
+            use ::wasm_bindgen_utils::result::{EncodeErrors as _, MapOk as _};
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "decodedEvents",
+            );
+            ::wasm_bindgen_utils::iter::JsIterator::from(
+                    ::wasm_bindgen_utils::iter::ExportIter::new(self.decoded_events())
+                        .map_ok(JsEvent::from)
+                        .encode_errors(
+                            move |error| __wasm_export_call.on_error(error),
+                            |_| {},
+                        ),
+                )
+                .into()
+        }
+    }
+};
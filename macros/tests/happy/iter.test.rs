@@ -0,0 +1,19 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+#[wasm_export(js_name = "tokenize", unchecked_return_type = "Token")]
+pub fn tokenize(
+    #[wasm_export(from_js = Text::parse, js_type = "string")] text: Text,
+) -> impl Iterator<Item = Token> {
+    Tokenizer::new(text)
+}
+
+struct EventLog;
+
+#[wasm_export]
+impl EventLog {
+    #[wasm_export(js_name = "decodedEvents", map_ok = JsEvent::from, unchecked_return_type = "JsEvent")]
+    pub fn decoded_events(&self) -> Box<dyn Iterator<Item = Event>> {
+        Box::new(self.events.clone().into_iter())
+    }
+}
@@ -0,0 +1,103 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+struct TestStruct;
+impl TestStruct {
+    pub fn some_method(&self) -> Result<InternalOrder, Error> {
+        Ok(InternalOrder::new())
+    }
+    pub async fn some_class_method(&self) -> Result<InternalOrder, Error> {
+        Ok(InternalOrder::new())
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[wasm_bindgen]
+    impl TestStruct {
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "someMethod",
+            unchecked_return_type = "WasmEncodedResult<Order>"
+        )]
+        pub fn some_method__wasm_export(
+            &self,
+        ) -> ::wasm_bindgen_utils::prelude::JsValue {
+            use ::wasm_bindgen_utils::result::{EncodeErrors as _, MapOk as _};
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "someMethod",
+            );
+            ::wasm_bindgen_utils::result::WasmEncodedResult::from(
+                    self
+                        .some_method()
+                        .map_ok(to_js_order)
+                        .encode_errors(
+                            |error| __wasm_export_call.on_error(error),
+                            |_| {},
+                        ),
+                )
+                .into()
+        }
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "someClassMethod",
+            unchecked_return_type = "WasmEncodedResult<SomeClass>"
+        )]
+        pub async fn some_class_method__wasm_export(
+            &self,
+        ) -> ::wasm_bindgen_utils::prelude::JsValue {
+            use ::wasm_bindgen_utils::prelude::JsValue;
+            use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+            use ::wasm_bindgen_utils::result::WasmEncodedError;
+            use ::wasm_bindgen_utils::result::{EncodeErrors as _, MapOk as _};
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "someClassMethod",
+            );
+            let obj = Object::new();
+            let result = self
+                .some_class_method()
+                .await
+                .map_ok(SomeClass::from)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into();
+            match result {
+                ::core::result::Result::Ok(value) => {
+                    Reflect::set(&obj, &JsValue::from_str("value"), &value.into())
+                        .unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED)
+                        .unwrap();
+                }
+                ::core::result::Result::Err(error) => {
+                    let wasm_error: WasmEncodedError = error.into();
+                    Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED)
+                        .unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into())
+                        .unwrap();
+                }
+            };
+            obj.into()
+        }
+    }
+};
+pub async fn some_fn(arg1: String) -> Diagnosed<Vec<InternalOrder>, Error> {
+    Diagnosed::ok(::alloc::vec::Vec::new())
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "someFn",
+    unchecked_return_type = "WasmEncodedDiagnosedResult<Order[]>"
+)]
+pub async fn some_fn__wasm_export(
+    arg1: String,
+) -> ::wasm_bindgen_utils::prelude::JsValue {
+    use ::wasm_bindgen_utils::result::{EncodeErrors as _, MapOk as _};
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("someFn");
+    ::wasm_bindgen_utils::result::WasmEncodedDiagnosedResult::from(
+            some_fn(arg1)
+                .await
+                .map_ok(convert::to_js_orders)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {}),
+        )
+        .into()
+}
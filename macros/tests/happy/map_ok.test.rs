@@ -0,0 +1,26 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+struct TestStruct;
+
+#[wasm_export]
+impl TestStruct {
+    #[wasm_export(js_name = "someMethod", map_ok = to_js_order, unchecked_return_type = "Order")]
+    pub fn some_method(&self) -> Result<InternalOrder, Error> {
+        Ok(InternalOrder::new())
+    }
+    #[wasm_export(
+        js_name = "someClassMethod",
+        map_ok = SomeClass::from,
+        unchecked_return_type = "SomeClass",
+        preserve_js_class
+    )]
+    pub async fn some_class_method(&self) -> Result<InternalOrder, Error> {
+        Ok(InternalOrder::new())
+    }
+}
+
+#[wasm_export(js_name = "someFn", map_ok = convert::to_js_orders, unchecked_return_type = "Order[]")]
+pub async fn some_fn(arg1: String) -> Diagnosed<Vec<InternalOrder>, Error> {
+    Diagnosed::ok(vec![])
+}
@@ -0,0 +1,62 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+struct TestStruct;
+impl TestStruct {
+    pub fn some_method(&self, arg1: String, arg2: Option<JsValue>) -> Result<u8, Error> {
+        Ok(1)
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[wasm_bindgen]
+    impl TestStruct {
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "someMethod",
+            unchecked_return_type = "WasmEncodedResult<u8>"
+        )]
+        pub fn some_method__wasm_export(
+            &self,
+            arg1: String,
+            #[wasm_bindgen(unchecked_param_type = "SomeType | undefined")]
+            arg2: Option<JsValue>,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u8> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "someMethod",
+            );
+            self.some_method(arg1, arg2)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+    }
+};
+pub fn some_fn(
+    arg1: String,
+    arg2: JsValue,
+    arg3: Option<u32>,
+    arg4: Option<u8>,
+) -> Result<u8, Error> {
+    Ok(1)
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "someFn",
+    unchecked_return_type = "WasmEncodedResult<u8>"
+)]
+pub fn some_fn__wasm_export(
+    arg1: String,
+    #[wasm_bindgen(unchecked_param_type = "SomeType | undefined")]
+    arg2: JsValue,
+    arg3: Option<u32>,
+    arg4: Option<u8>,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u8> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("someFn");
+    some_fn(arg1, arg2, arg3, arg4)
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
+}
@@ -0,0 +1,26 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+struct TestStruct;
+
+#[wasm_export]
+impl TestStruct {
+    #[wasm_export(js_name = "someMethod")]
+    pub fn some_method(
+        &self,
+        arg1: String,
+        #[wasm_export(optional, unchecked_param_type = "SomeType")] arg2: Option<JsValue>,
+    ) -> Result<u8, Error> {
+        Ok(1)
+    }
+}
+
+#[wasm_export(js_name = "someFn")]
+pub fn some_fn(
+    arg1: String,
+    #[wasm_export(optional, unchecked_param_type = "SomeType")] arg2: JsValue,
+    #[wasm_export(optional)] arg3: Option<u32>,
+    arg4: Option<u8>,
+) -> Result<u8, Error> {
+    Ok(1)
+}
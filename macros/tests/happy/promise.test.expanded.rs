@@ -0,0 +1,47 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+pub fn start_sync(count: u32) -> Result<TypedPromise<SomeType>, Error> {
+    Ok(TypedPromise::from_future(async move { sync(count).await }))
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "startSync",
+    unchecked_return_type = "WasmEncodedResult<Promise<SomeType>>"
+)]
+pub fn start_sync__wasm_export(
+    count: u32,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<TypedPromise<SomeType>> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("startSync");
+    start_sync(count)
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
+}
+pub async fn wait_for(
+    value: TypedPromise<String>,
+    order: TypedPromise<Order>,
+) -> Result<String, Error> {
+    Ok(value.await?)
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "waitFor",
+    unchecked_return_type = "WasmEncodedResult<String>"
+)]
+pub async fn wait_for__wasm_export(
+    #[wasm_bindgen(unchecked_param_type = "Promise<string>")]
+    value: TypedPromise<String>,
+    #[wasm_bindgen(unchecked_param_type = "Promise<Order>")]
+    order: TypedPromise<Order>,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<String> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("waitFor");
+    wait_for(value, order)
+        .await
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
+}
@@ -0,0 +1,15 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+#[wasm_export(js_name = "startSync")]
+pub fn start_sync(count: u32) -> Result<TypedPromise<SomeType>, Error> {
+    Ok(TypedPromise::from_future(async move { sync(count).await }))
+}
+
+#[wasm_export(js_name = "waitFor")]
+pub async fn wait_for(
+    value: TypedPromise<String>,
+    #[wasm_export(unchecked_param_type = "Promise<Order>")] order: TypedPromise<Order>,
+) -> Result<String, Error> {
+    Ok(value.await?)
+}
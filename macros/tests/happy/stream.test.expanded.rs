@@ -0,0 +1,85 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+pub fn scan_logs(from: u64) -> impl Stream<Item = Result<Log, Error>> {
+    LogScanner::new(from)
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "scanLogs",
+    unchecked_return_type = "AsyncIterableIterator<WasmEncodedResult<Log>>"
+)]
+pub fn scan_logs__wasm_export(
+    from: u64,
+) -> ::wasm_bindgen_utils::stream::JsAsyncIterator {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("scanLogs");
+    ::wasm_bindgen_utils::stream::ExportStream::new(scan_logs(from))
+        .encode_errors(
+            move |error| {
+                error.context = ::core::option::Option::Some(
+                    ::std::boxed::Box::new(
+                        ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                            ::std::any::type_name_of_val(&scan_logs),
+                            "scanLogs",
+                            &["from: u64"],
+                        ),
+                    ),
+                );
+                __wasm_export_call.on_error(error);
+            },
+            |warning| {
+                warning.context = ::core::option::Option::Some(
+                    ::std::boxed::Box::new(
+                        ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                            ::std::any::type_name_of_val(&scan_logs),
+                            "scanLogs",
+                            &["from: u64"],
+                        ),
+                    ),
+                );
+            },
+        )
+        .into()
+}
+struct Indexer;
+impl Indexer {
+    pub async fn fetch_pages(
+        &self,
+        size: u32,
+    ) -> impl Stream<Item = Result<Page, Error>> + 'static {
+        self.pages(size).await
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[wasm_bindgen]
+    impl Indexer {
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "fetchPages",
+            unchecked_return_type = "AsyncIterableIterator<WasmEncodedResult<Page>>"
+        )]
+        pub async fn fetch_pages__wasm_export(
+            &self,
+            size: u32,
+        ) -> ::wasm_bindgen_utils::prelude::JsValue {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "fetchPages",
+            );
+            ::wasm_bindgen_utils::stream::JsAsyncIterator::from_stream_preserved(
+                    ::wasm_bindgen_utils::stream::ExportStream::new(
+                            self.fetch_pages(size).await,
+                        )
+                        .encode_errors(
+                            move |error| __wasm_export_call.on_error(error),
+                            |_| {},
+                        ),
+                )
+                .into()
+        }
+    }
+};
@@ -0,0 +1,17 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+#[wasm_export(js_name = "scanLogs", error_context)]
+pub fn scan_logs(from: u64) -> impl Stream<Item = Result<Log, Error>> {
+    LogScanner::new(from)
+}
+
+struct Indexer;
+
+#[wasm_export]
+impl Indexer {
+    #[wasm_export(js_name = "fetchPages", preserve_js_class)]
+    pub async fn fetch_pages(&self, size: u32) -> impl Stream<Item = Result<Page, Error>> + 'static {
+        self.pages(size).await
+    }
+}
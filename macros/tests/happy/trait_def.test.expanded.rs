@@ -0,0 +1,114 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+/// A key value storage implemented in JS
+pub trait Storage {
+    /// Gets the item of the given key
+    ///
+    /// # Arguments
+    /// * `key` - the key of the item
+    ///
+    /// # Returns
+    /// the item if it exists
+    fn get(&self, key: &str) -> Result<Option<String>, Error>;
+    async fn set(&self, key: String, value: Vec<u8>) -> Result<Entry, Error>;
+    fn clear(&mut self) -> Result<(), Error>;
+    fn name(&self) -> String {
+        "storage".to_string()
+    }
+}
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen
+)]
+extern "C" {
+    /// A JS object that implements [`Storage`] trait as `KeyValueStorage` TS interface
+    #[wasm_bindgen(typescript_type = "KeyValueStorage")]
+    pub type JsStorage;
+    #[wasm_bindgen(method, catch, js_name = "getItem")]
+    fn get(
+        this: &JsStorage,
+        key: &::wasm_bindgen_utils::prelude::JsValue,
+    ) -> ::core::result::Result<
+        ::wasm_bindgen_utils::prelude::JsValue,
+        ::wasm_bindgen_utils::prelude::JsValue,
+    >;
+    #[wasm_bindgen(method, catch, js_name = "set")]
+    fn set(
+        this: &JsStorage,
+        key: &::wasm_bindgen_utils::prelude::JsValue,
+        value: &::wasm_bindgen_utils::prelude::JsValue,
+    ) -> ::core::result::Result<
+        ::wasm_bindgen_utils::prelude::JsValue,
+        ::wasm_bindgen_utils::prelude::JsValue,
+    >;
+    #[wasm_bindgen(method, catch, js_name = "clear")]
+    fn clear(
+        this: &JsStorage,
+    ) -> ::core::result::Result<
+        ::wasm_bindgen_utils::prelude::JsValue,
+        ::wasm_bindgen_utils::prelude::JsValue,
+    >;
+}
+const _: () = {
+    use ::wasm_bindgen_utils::prelude::wasm_bindgen;
+    #[wasm_bindgen(typescript_custom_section)]
+    const TS_INTERFACE: &'static str = "/**\n * A key value storage implemented in JS\n */\nexport interface KeyValueStorage {\n    /**\n     * Gets the item of the given key\n     * @param key - the key of the item\n     * @returns the item if it exists\n     */\n    getItem(key: string): string | undefined;\n    /**\n     * @param key - the key of the item\n     */\n    set(key: ItemKey, value: number[]): Entry | Promise<Entry>;\n    clear(): void;\n}";
+};
+/// Implements [`Storage`] trait by calling into the wrapped [`JsStorage`] JS object
+pub struct JsStorageAdapter(pub JsStorage);
+#[automatically_derived]
+impl ::core::fmt::Debug for JsStorageAdapter {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(
+            f,
+            "JsStorageAdapter",
+            &&self.0,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for JsStorageAdapter {
+    #[inline]
+    fn clone(&self) -> JsStorageAdapter {
+        JsStorageAdapter(::core::clone::Clone::clone(&self.0))
+    }
+}
+impl ::core::convert::From<JsStorage> for JsStorageAdapter {
+    fn from(value: JsStorage) -> Self {
+        Self(value)
+    }
+}
+impl Storage for JsStorageAdapter {
+    fn get(&self, key: &str) -> Result<Option<String>, Error> {
+        let key = ::wasm_bindgen_utils::import::encode_js_arg("key", &key)?;
+        let value = self
+            .0
+            .get(&key)
+            .map_err(::wasm_bindgen_utils::result::WasmEncodedError::from)?;
+        let value = ::wasm_bindgen_utils::import::decode_js_result::<
+            Option<String>,
+        >("KeyValueStorage.getItem", value)?;
+        ::core::result::Result::Ok(value)
+    }
+    async fn set(&self, key: String, value: Vec<u8>) -> Result<Entry, Error> {
+        let key = ::wasm_bindgen_utils::import::encode_js_arg("key", &key)?;
+        let value = ::wasm_bindgen_utils::import::encode_js_arg("value", &value)?;
+        let value = self
+            .0
+            .set(&key, &value)
+            .map_err(::wasm_bindgen_utils::result::WasmEncodedError::from)?;
+        let value = ::wasm_bindgen_utils::import::resolve_js_value(value).await?;
+        let value = ::wasm_bindgen_utils::import::decode_js_result::<
+            Entry,
+        >("KeyValueStorage.set", value)?;
+        ::core::result::Result::Ok(value)
+    }
+    fn clear(&mut self) -> Result<(), Error> {
+        let value = self
+            .0
+            .clear()
+            .map_err(::wasm_bindgen_utils::result::WasmEncodedError::from)?;
+        ::wasm_bindgen_utils::import::check_js_error(&value)?;
+        ::core::result::Result::Ok(())
+    }
+}
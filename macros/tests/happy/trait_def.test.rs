@@ -0,0 +1,30 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+/// A key value storage implemented in JS
+#[wasm_export(js_name = "KeyValueStorage")]
+pub trait Storage {
+    /// Gets the item of the given key
+    ///
+    /// # Arguments
+    /// * `key` - the key of the item
+    ///
+    /// # Returns
+    /// the item if it exists
+    #[wasm_export(js_name = "getItem")]
+    fn get(&self, key: &str) -> Result<Option<String>, Error>;
+
+    #[wasm_export(unchecked_return_type = "Entry")]
+    async fn set(
+        &self,
+        #[wasm_export(js_type = "ItemKey", param_description = "the key of the item")] key: String,
+        value: Vec<u8>,
+    ) -> Result<Entry, Error>;
+
+    fn clear(&mut self) -> Result<(), Error>;
+
+    #[wasm_export(skip)]
+    fn name(&self) -> String {
+        "storage".to_string()
+    }
+}
@@ -0,0 +1,106 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+struct TestStruct;
+trait Parser {
+    type Output;
+    fn parse(&self, input: String) -> Result<Self::Output, Error>;
+    async fn fetch(url: String) -> Result<Self::Output, Error>;
+}
+impl Parser for TestStruct {
+    type Output = u8;
+    /// Parses the given input
+    fn parse(&self, input: String) -> Result<Self::Output, Error> {
+        Ok(1)
+    }
+    async fn fetch(url: String) -> Result<Self::Output, Error> {
+        Ok(1)
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[wasm_bindgen(js_class = "TestStruct")]
+    impl TestStruct {
+        /// Parses the given input
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "parse",
+            unchecked_return_type = "WasmEncodedResult<u8>"
+        )]
+        pub fn parse__wasm_export(
+            &self,
+            #[wasm_bindgen(js_name = "rawInput")]
+            input: String,
+        ) -> ::wasm_bindgen_utils::prelude::JsValue {
+            use ::wasm_bindgen_utils::prelude::JsValue;
+            use ::wasm_bindgen_utils::prelude::js_sys::{Reflect, Object};
+            use ::wasm_bindgen_utils::result::WasmEncodedError;
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "parse",
+            );
+            let obj = Object::new();
+            let result = <Self as Parser>::parse(self, input)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into();
+            match result {
+                ::core::result::Result::Ok(value) => {
+                    Reflect::set(&obj, &JsValue::from_str("value"), &value.into())
+                        .unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::UNDEFINED)
+                        .unwrap();
+                }
+                ::core::result::Result::Err(error) => {
+                    let wasm_error: WasmEncodedError = error.into();
+                    Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::UNDEFINED)
+                        .unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &wasm_error.into())
+                        .unwrap();
+                }
+            };
+            obj.into()
+        }
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "fetch",
+            unchecked_return_type = "WasmEncodedResult<u8>"
+        )]
+        pub async fn fetch__wasm_export(
+            #[wasm_bindgen(param_description = "the url to fetch")]
+            url: String,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u8> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "fetch",
+            );
+            <Self as Parser>::fetch(url)
+                .await
+                .encode_errors(
+                    |error| {
+                        error.context = ::core::option::Option::Some(
+                            ::std::boxed::Box::new(
+                                ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                                    ::std::any::type_name_of_val(&<Self as Parser>::fetch),
+                                    "fetch",
+                                    &["url: String"],
+                                ),
+                            ),
+                        );
+                        __wasm_export_call.on_error(error);
+                    },
+                    |warning| {
+                        warning.context = ::core::option::Option::Some(
+                            ::std::boxed::Box::new(
+                                ::wasm_bindgen_utils::result::WasmEncodedErrorContext::new(
+                                    ::std::any::type_name_of_val(&<Self as Parser>::fetch),
+                                    "fetch",
+                                    &["url: String"],
+                                ),
+                            ),
+                        );
+                    },
+                )
+                .into()
+        }
+    }
+};
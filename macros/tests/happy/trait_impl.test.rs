@@ -0,0 +1,26 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+struct TestStruct;
+
+trait Parser {
+    type Output;
+    fn parse(&self, input: String) -> Result<Self::Output, Error>;
+    async fn fetch(url: String) -> Result<Self::Output, Error>;
+}
+
+#[wasm_export(js_class = "TestStruct")]
+impl Parser for TestStruct {
+    type Output = u8;
+
+    /// Parses the given input
+    #[wasm_export(js_name = "parse", preserve_js_class)]
+    fn parse(&self, #[wasm_export(js_name = "rawInput")] input: String) -> Result<Self::Output, Error> {
+        Ok(1)
+    }
+
+    #[wasm_export(js_name = "fetch", error_context)]
+    async fn fetch(#[wasm_export(param_description = "the url to fetch")] url: String) -> Result<Self::Output, Error> {
+        Ok(1)
+    }
+}
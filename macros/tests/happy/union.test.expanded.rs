@@ -0,0 +1,76 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+struct TestStruct;
+impl TestStruct {
+    pub fn some_method(
+        &self,
+        value: JsUnion2<String, u64>,
+    ) -> Result<JsUnion3<u32, bool, SomeType>, Error> {
+        Ok(JsUnion3::A(1))
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[wasm_bindgen]
+    impl TestStruct {
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "someMethod",
+            unchecked_return_type = "WasmEncodedResult<number | boolean | SomeType>"
+        )]
+        pub fn some_method__wasm_export(
+            &self,
+            #[wasm_bindgen(unchecked_param_type = "string | bigint")]
+            value: JsUnion2<String, u64>,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<
+            JsUnion3<u32, bool, SomeType>,
+        > {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "someMethod",
+            );
+            self.some_method(value)
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+                .into()
+        }
+    }
+};
+pub async fn some_fn(
+    bytes: JsUnion2<String, Vec<u8>>,
+    items: Vec<JsUnion2<String, u32>>,
+) -> Result<u32, Error> {
+    Ok(1)
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "someFn",
+    unchecked_return_type = "WasmEncodedResult<u32>",
+    variadic
+)]
+pub async fn some_fn__wasm_export(
+    #[wasm_bindgen(unchecked_param_type = "string | Uint8Array")]
+    bytes: JsUnion2<String, Vec<u8>>,
+    #[wasm_bindgen(unchecked_param_type = "(string | number)[]")]
+    items: ::std::vec::Vec<::wasm_bindgen_utils::prelude::JsValue>,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u32> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("someFn");
+    let items = ::wasm_bindgen_utils::result::decode_variadic::<
+        JsUnion2<String, u32>,
+    >("items", items);
+    (match items {
+        ::core::result::Result::Ok(items) => {
+            some_fn(bytes, items)
+                .await
+                .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        }
+        ::core::result::Result::Err(error) => {
+            __wasm_export_call.on_error(&error);
+            ::wasm_bindgen_utils::result::FromEncodedError::from_encoded_error(error)
+        }
+    })
+        .into()
+}
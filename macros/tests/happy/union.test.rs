@@ -0,0 +1,20 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+struct TestStruct;
+
+#[wasm_export]
+impl TestStruct {
+    #[wasm_export(js_name = "someMethod")]
+    pub fn some_method(&self, value: JsUnion2<String, u64>) -> Result<JsUnion3<u32, bool, SomeType>, Error> {
+        Ok(JsUnion3::A(1))
+    }
+}
+
+#[wasm_export(js_name = "someFn")]
+pub async fn some_fn(
+    #[wasm_export(unchecked_param_type = "string | Uint8Array")] bytes: JsUnion2<String, Vec<u8>>,
+    #[wasm_export(variadic)] items: Vec<JsUnion2<String, u32>>,
+) -> Result<u32, Error> {
+    Ok(1)
+}
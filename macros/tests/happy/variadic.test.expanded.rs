@@ -0,0 +1,73 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+struct TestStruct;
+impl TestStruct {
+    pub fn some_method(&self, arg1: String, items: Vec<u32>) -> Result<u32, Error> {
+        Ok(1)
+    }
+}
+const _: () = {
+    #[allow(unused_imports)]
+    use ::wasm_bindgen_utils::prelude::{wasm_bindgen, wasm_bindgen_futures};
+    #[wasm_bindgen]
+    impl TestStruct {
+        #[allow(non_snake_case)]
+        #[wasm_bindgen(
+            js_name = "someMethod",
+            unchecked_return_type = "WasmEncodedResult<u32>",
+            variadic
+        )]
+        pub fn some_method__wasm_export(
+            &self,
+            arg1: String,
+            #[wasm_bindgen(unchecked_param_type = "number[]")]
+            items: ::std::vec::Vec<::wasm_bindgen_utils::prelude::JsValue>,
+        ) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u32> {
+            use ::wasm_bindgen_utils::result::EncodeErrors as _;
+            let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+                "someMethod",
+            );
+            let items = ::wasm_bindgen_utils::result::decode_variadic::<
+                u32,
+            >("items", items);
+            (match items {
+                ::core::result::Result::Ok(items) => {
+                    self.some_method(arg1, items)
+                        .encode_errors(
+                            |error| __wasm_export_call.on_error(error),
+                            |_| {},
+                        )
+                }
+                ::core::result::Result::Err(error) => {
+                    __wasm_export_call.on_error(&error);
+                    ::wasm_bindgen_utils::result::FromEncodedError::from_encoded_error(
+                        error,
+                    )
+                }
+            })
+                .into()
+        }
+    }
+};
+pub async fn some_fn(items: Vec<JsValue>) -> Result<u32, Error> {
+    Ok(1)
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "someFn",
+    unchecked_return_type = "WasmEncodedResult<u32>",
+    variadic
+)]
+pub async fn some_fn__wasm_export(
+    #[wasm_bindgen(unchecked_param_type = "SomeType[]")]
+    items: Vec<JsValue>,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u32> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start("someFn");
+    some_fn(items)
+        .await
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
+}
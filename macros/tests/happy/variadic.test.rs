@@ -0,0 +1,23 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+struct TestStruct;
+
+#[wasm_export]
+impl TestStruct {
+    #[wasm_export(js_name = "someMethod")]
+    pub fn some_method(
+        &self,
+        arg1: String,
+        #[wasm_export(variadic)] items: Vec<u32>,
+    ) -> Result<u32, Error> {
+        Ok(1)
+    }
+}
+
+#[wasm_export(js_name = "someFn")]
+pub async fn some_fn(
+    #[wasm_export(unchecked_param_type = "SomeType[]", variadic)] items: Vec<JsValue>,
+) -> Result<u32, Error> {
+    Ok(1)
+}
@@ -0,0 +1,58 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    module = "/js/api.js"
+)]
+extern "C" {
+    pub type Client;
+    #[allow(non_snake_case)]
+    #[wasm_bindgen(catch, js_name = "fetchOrder")]
+    fn fetch_order__wasm_import(
+        id: &::wasm_bindgen_utils::prelude::JsValue,
+    ) -> ::core::result::Result<
+        ::wasm_bindgen_utils::prelude::JsValue,
+        ::wasm_bindgen_utils::prelude::JsValue,
+    >;
+    #[allow(non_snake_case)]
+    #[wasm_bindgen(catch, method, js_name = "sendRaw")]
+    fn send_raw__wasm_import(
+        this: &Client,
+        payload: &::wasm_bindgen_utils::prelude::JsValue,
+    ) -> ::core::result::Result<
+        ::wasm_bindgen_utils::prelude::JsValue,
+        ::wasm_bindgen_utils::prelude::JsValue,
+    >;
+    #[allow(non_snake_case)]
+    #[wasm_bindgen(catch, js_namespace = console, js_name = "log")]
+    fn log__wasm_import(
+        msg: &::wasm_bindgen_utils::prelude::JsValue,
+    ) -> ::core::result::Result<
+        ::wasm_bindgen_utils::prelude::JsValue,
+        ::wasm_bindgen_utils::prelude::JsValue,
+    >;
+}
+/// Fetches the order of the given id
+pub async fn fetch_order(id: u64) -> Result<Order, Error> {
+    let id = ::wasm_bindgen_utils::import::encode_js_arg("orderId", &id)?;
+    let value = fetch_order__wasm_import(&id)
+        .map_err(::wasm_bindgen_utils::result::WasmEncodedError::from)?;
+    let value = ::wasm_bindgen_utils::import::resolve_js_value(value).await?;
+    let value = ::wasm_bindgen_utils::import::decode_js_result::<
+        Order,
+    >("fetchOrder", value)?;
+    ::core::result::Result::Ok(value)
+}
+pub fn send_raw(this: &Client, payload: &JsValue) -> Result<JsValue, Error> {
+    let value = <Client>::send_raw__wasm_import(this, payload)
+        .map_err(::wasm_bindgen_utils::result::WasmEncodedError::from)?;
+    ::wasm_bindgen_utils::import::check_js_error(&value)?;
+    ::core::result::Result::Ok(value)
+}
+fn log(msg: &str) -> Result<(), Error> {
+    let msg = ::wasm_bindgen_utils::import::encode_js_arg("msg", &msg)?;
+    let value = log__wasm_import(&msg)
+        .map_err(::wasm_bindgen_utils::result::WasmEncodedError::from)?;
+    ::wasm_bindgen_utils::import::check_js_error(&value)?;
+    ::core::result::Result::Ok(())
+}
@@ -0,0 +1,17 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+#[wasm_import(module = "/js/api.js")]
+extern "C" {
+    pub type Client;
+
+    /// Fetches the order of the given id
+    #[wasm_import(js_name = "fetchOrder")]
+    pub async fn fetch_order(#[wasm_import(js_name = "orderId")] id: u64) -> Result<Order, Error>;
+
+    #[wasm_import(method, js_name = "sendRaw")]
+    pub fn send_raw(this: &Client, payload: &JsValue) -> Result<JsValue, Error>;
+
+    #[wasm_bindgen(js_namespace = console)]
+    fn log(msg: &str) -> Result<(), Error>;
+}
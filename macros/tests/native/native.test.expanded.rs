@@ -0,0 +1,39 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+struct TestStruct;
+impl TestStruct {
+    pub fn some_method(&self, some_arg: String, count: u8) -> Result<TestStruct, Error> {
+        Ok(TestStruct)
+    }
+    pub fn some_skip_fn() -> TestStruct {
+        TestStruct
+    }
+}
+pub async fn some_fn(items: Vec<String>) -> Result<u8, Error> {
+    Ok(1)
+}
+pub fn some_native_fn(arg: String) -> Result<u8, Error> {
+    Ok(1)
+}
+#[allow(non_snake_case)]
+#[::wasm_bindgen_utils::prelude::wasm_bindgen(
+    wasm_bindgen = ::wasm_bindgen_utils::prelude::wasm_bindgen,
+    wasm_bindgen_futures = ::wasm_bindgen_utils::prelude::wasm_bindgen_futures,
+    js_name = "someNativeFn",
+    unchecked_return_type = "WasmEncodedResult<u8>"
+)]
+pub fn some_native_fn__wasm_export(
+    #[wasm_bindgen(unchecked_param_type = "string")]
+    arg: String,
+) -> ::wasm_bindgen_utils::result::WasmEncodedResult<u8> {
+    use ::wasm_bindgen_utils::result::EncodeErrors as _;
+    let __wasm_export_call = ::wasm_bindgen_utils::hooks::ExportCall::start(
+        "someNativeFn",
+    );
+    some_native_fn(arg)
+        .encode_errors(|error| __wasm_export_call.on_error(error), |_| {})
+        .into()
+}
+pub trait SomeTrait {
+    fn some_trait_method(&self, some_arg: String) -> Result<u8, Error>;
+}
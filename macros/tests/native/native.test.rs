@@ -0,0 +1,40 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+struct TestStruct;
+
+#[wasm_export]
+impl TestStruct {
+    #[wasm_export(js_name = "someMethod", preserve_js_class)]
+    pub fn some_method(
+        &self,
+        #[wasm_export(js_name = "someArg", param_description = "some arg")] some_arg: String,
+        #[wasm_export(default = 1)] count: u8,
+    ) -> Result<TestStruct, Error> {
+        Ok(TestStruct)
+    }
+    #[wasm_export(skip)]
+    pub fn some_skip_fn() -> TestStruct {
+        TestStruct
+    }
+}
+
+#[wasm_export(js_name = "someFn")]
+pub async fn some_fn(#[wasm_export(variadic)] items: Vec<String>) -> Result<u8, Error> {
+    Ok(1)
+}
+
+#[wasm_export(js_name = "someNativeFn", cfg(all()))]
+pub fn some_native_fn(#[wasm_export(unchecked_param_type = "string")] arg: String) -> Result<u8, Error> {
+    Ok(1)
+}
+
+#[wasm_export]
+pub trait SomeTrait {
+    fn some_trait_method(&self, #[wasm_export(js_name = "someArg")] some_arg: String) -> Result<u8, Error>;
+}
+
+#[wasm_import]
+extern "C" {
+    pub fn some_import(#[wasm_import(js_name = "someArg")] some_arg: String) -> Result<u8, Error>;
+}
@@ -1,8 +1,20 @@
 #[test]
 pub fn happy() {
     // to update the tests "*.expanded.rs" fixtures, first
-    // delete them and then run cargo test to regenerate them
-    macrotest::expand("tests/happy/*.test.rs");
+    // delete them and then run cargo test to regenerate them,
+    // they are expanded for wasm target since the exporting
+    // items are only compiled for wasm targets by default
+    macrotest::expand_args(
+        "tests/happy/*.test.rs",
+        &["--target", "wasm32-unknown-unknown"],
+    );
+}
+
+#[test]
+pub fn native() {
+    // expanded for the host target, where the exporting items are
+    // omitted and only the cleaned original items are compiled
+    macrotest::expand("tests/native/*.test.rs");
 }
 
 #[test]
@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+#[wasm_export(js_name = "someFn", cancellable, timeout_ms = 1000)]
+pub fn some_fn(#[wasm_export(abort_signal)] signal: JsValue) -> Result<u32, Error> {
+    Ok(1)
+}
+
+#[wasm_export(js_name = "otherFn", cancellable)]
+pub async fn other_fn(signal: AbortSignal) -> Result<u32, Error> {
+    Ok(1)
+}
+
+fn main() {}
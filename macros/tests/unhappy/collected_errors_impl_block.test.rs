@@ -0,0 +1,26 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+struct TestStruct;
+
+#[wasm_export(map_ok = "to_js")]
+impl TestStruct {
+    #[wasm_export(js_name = "someMethod", skip = true)]
+    pub fn some_method(&self) -> Result<u32, Error> {
+        Ok(1)
+    }
+
+    #[wasm_export(return_description = 1)]
+    pub fn some_other_method(
+        #[wasm_export(js_nme = "arg")] arg: u32,
+        #[wasm_export(defualt = 1)] other_arg: u32,
+    ) -> Result<u32, Error> {
+        Ok(arg)
+    }
+
+    pub fn some_static_method(#[wasm_export(param_description = 1)] arg: u32) -> u32 {
+        arg
+    }
+}
+
+fn main() {}
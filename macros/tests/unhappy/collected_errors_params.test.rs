@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+#[wasm_export(js_name = "someFn", return_description = 1, map_ok = "to_js")]
+pub fn some_fn(
+    #[wasm_export(js_name = someArg, variadic(true))] arg: u32,
+    #[wasm_export(from_js = "parse", unchecked_param_typ = "string")] other_arg: u32,
+) -> Result<u32, Error> {
+    Ok(arg)
+}
+
+fn main() {}
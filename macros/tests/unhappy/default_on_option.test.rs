@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+struct TestStruct;
+
+#[wasm_export]
+impl TestStruct {
+    #[wasm_export(js_name = "someMethod")]
+    pub fn some_method(&self, #[wasm_export(default = Some(1))] arg: Option<u32>) -> Result<u32, Error> {
+        Ok(1)
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+struct TestStruct;
+
+#[wasm_export]
+impl TestStruct {
+    #[wasm_export(deprecated = "first note", deprecated = "second note")]
+    pub async fn test_method(arg: String) -> Result<String, Error> {
+        Ok(arg)
+    }
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+#[wasm_export(js_name = "decode")]
+pub fn decode<T: Format>(data: Vec<u8>) -> Result<T, Error> {
+    T::decode(&data)
+}
+
+fn main() {}
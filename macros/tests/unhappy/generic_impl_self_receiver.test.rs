@@ -0,0 +1,13 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+struct Codec<T>(T);
+
+#[wasm_export(instantiate(T = Json, js_class = "JsonCodec"))]
+impl<T: Format> Codec<T> {
+    pub fn name(&self) -> Result<String, Error> {
+        Ok(T::NAME.to_string())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+#[wasm_export(instantiate(T = Json, js_name = "decodeJson"))]
+pub fn decode<T: Format, U>(data: Vec<u8>) -> Result<U, Error> {
+    T::decode(&data)
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+#[wasm_export(deprecated(since = "0.1.0", replacement = "otherFn"))]
+pub fn some_fn() -> Result<String, Error> {
+    Ok(String::new())
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+#[wasm_export(js_name = "someFn")]
+pub fn some_fn(#[wasm_export(js_type = "string")] owner: String) -> Result<u32, Error> {
+    Ok(1)
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+#[wasm_export(js_name = "someFn", map_ok = to_js_order)]
+pub fn some_fn() -> Result<InternalOrder, Error> {
+    Ok(InternalOrder::new())
+}
+
+fn main() {}
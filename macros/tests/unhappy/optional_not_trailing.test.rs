@@ -0,0 +1,9 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+#[wasm_export]
+pub fn some_fn(#[wasm_export(optional)] arg1: Option<u32>, arg2: String) -> Result<String, Error> {
+    Ok(arg2)
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+#[wasm_export(js_name = "Store", preserve_js_class)]
+pub trait Storage<T>: Clone {
+    type Item;
+
+    #[wasm_export(error_context)]
+    fn get(&self, #[wasm_export(optional)] key: Option<String>) -> Result<T, Error>;
+
+    fn new() -> Result<Self, Error>;
+
+    fn len(&self) -> usize;
+
+    #[wasm_export(skip)]
+    fn clear(&self) -> Result<(), Error>;
+}
+
+fn main() {}
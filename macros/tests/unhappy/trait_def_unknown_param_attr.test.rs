@@ -0,0 +1,9 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+#[wasm_export]
+pub trait Storage {
+    fn get(&self, #[wasm_export(js_nam = "itemKey")] key: String) -> Result<String, Error>;
+}
+
+fn main() {}
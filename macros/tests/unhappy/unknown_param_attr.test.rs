@@ -0,0 +1,9 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+#[wasm_export]
+pub fn some_fn(#[wasm_export(description = "some arg")] arg: u32) -> Result<u32, Error> {
+    Ok(1)
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+#[macro_use]
+extern crate wasm_bindgen_utils_macros;
+
+#[wasm_import(js_namespace = api, preserve_js_class)]
+extern "C" {
+    #[wasm_import(error_context, deprecated = "use `get` instead", cfg(all()))]
+    fn fetch(#[wasm_import(optional)] key: Option<String>) -> Result<String, Error>;
+
+    fn parse<T>(value: T) -> Result<(), Error>;
+
+    fn len() -> usize;
+}
+
+#[wasm_import]
+pub fn not_extern_block() -> Result<(), Error> {
+    Ok(())
+}
+
+fn main() {}
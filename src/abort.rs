@@ -0,0 +1,353 @@
+use std::{
+    fmt,
+    pin::Pin,
+    future::Future,
+    task::{Context, Poll},
+};
+use js_sys::{Function, Promise};
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen::{prelude::*, JsCast};
+use crate::result::{EncodeErrors, FromEncodedError, MapOk, WasmEncodedError, WasmEncodedErrorKind};
+
+#[wasm_bindgen]
+extern "C" {
+    /// A JS `AbortSignal`, i.e. the `signal` of an `AbortController` or the one that is
+    /// returned by `AbortSignal.timeout()`, that is accepted by the async functions that
+    /// are exported by [wasm_bindgen_utils_macros::wasm_export!] to cancel their calls.
+    ///
+    /// Params of this type (or `Option<AbortSignal>`) of an async exported function are
+    /// detected as its abort signal, see [abortable].
+    #[wasm_bindgen(typescript_type = "AbortSignal")]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type AbortSignal;
+
+    /// Whether the signal has been aborted
+    #[wasm_bindgen(method, getter)]
+    pub fn aborted(this: &AbortSignal) -> bool;
+
+    /// The reason that the signal was aborted with, if it has been aborted
+    #[wasm_bindgen(method, getter)]
+    pub fn reason(this: &AbortSignal) -> JsValue;
+
+    #[wasm_bindgen(method, js_name = addEventListener)]
+    fn add_event_listener(this: &AbortSignal, kind: &str, listener: &Function);
+
+    #[wasm_bindgen(method, js_name = removeEventListener)]
+    fn remove_event_listener(this: &AbortSignal, kind: &str, listener: &Function);
+
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &Function, timeout: u32) -> JsValue;
+
+    #[wasm_bindgen(js_name = clearTimeout)]
+    fn clear_timeout(id: &JsValue);
+}
+
+/// Returns the given abort signal param of an exported function as [AbortSignal], it accepts
+/// any type that is a JS value, such as `web_sys::AbortSignal` or [JsValue], so the param can
+/// be of either of them, `undefined` and `null` are treated as no signal, and if the value is
+/// not an `AbortSignal` the returning error names the param.
+///
+/// Used in [wasm_bindgen_utils_macros::wasm_export!] generated functions.
+pub fn abort_signal_of<S: AsRef<JsValue>>(
+    name: &str,
+    signal: Option<&S>,
+) -> Result<Option<AbortSignal>, WasmEncodedError> {
+    let Some(value) = signal
+        .map(|signal| signal.as_ref())
+        .filter(|value| !value.is_undefined() && !value.is_null())
+    else {
+        return Ok(None);
+    };
+    match value.dyn_ref::<AbortSignal>() {
+        Some(signal) => Ok(Some(signal.clone())),
+        None => {
+            let msg = format!("invalid `{name}` argument: expected an AbortSignal");
+            Err(WasmEncodedError::new(msg.clone(), msg))
+        }
+    }
+}
+
+/// The outcome of an async exported function call that was raced against its
+/// abort signal and/or timeout by [abortable], it provides the same adapters that
+/// the returning types of exported functions provide, so the generated code handles
+/// it the same way, where an aborted call becomes the only error of the returning type.
+///
+/// Used in [wasm_bindgen_utils_macros::wasm_export!] generated functions.
+#[derive(Debug)]
+pub enum Abortable<R> {
+    /// The call was done before it was aborted
+    Done(R),
+    /// The call was aborted or timed out, its error has [WasmEncodedErrorKind::Aborted] kind
+    Aborted(WasmEncodedError),
+}
+
+impl<R: EncodeErrors> EncodeErrors for Abortable<R>
+where
+    R::Encoded: FromEncodedError,
+{
+    type Encoded = R::Encoded;
+    fn encode_errors<F, W>(self, mut on_error: F, on_warning: W) -> Self::Encoded
+    where
+        F: FnMut(&mut WasmEncodedError),
+        W: FnMut(&mut WasmEncodedError),
+    {
+        match self {
+            Abortable::Done(result) => result.encode_errors(on_error, on_warning),
+            Abortable::Aborted(mut err) => {
+                on_error(&mut err);
+                R::Encoded::from_encoded_error(err)
+            }
+        }
+    }
+}
+
+impl<T, U, R: MapOk<T, U>> MapOk<T, U> for Abortable<R> {
+    type Mapped = Abortable<R::Mapped>;
+    fn map_ok<F: FnMut(T) -> U>(self, f: F) -> Self::Mapped {
+        match self {
+            Abortable::Done(result) => Abortable::Done(result.map_ok(f)),
+            Abortable::Aborted(err) => Abortable::Aborted(err),
+        }
+    }
+}
+
+/// Builds the error of a call that was aborted by its signal with the given reason,
+/// `readable_msg` also includes the msg of the reason if it was given
+fn aborted_error(reason: JsValue) -> WasmEncodedError {
+    let msg = "operation was aborted".to_string();
+    let readable_msg = if reason.is_undefined() {
+        msg.clone()
+    } else {
+        format!("{msg}: {}", WasmEncodedError::from(reason).msg)
+    };
+    WasmEncodedError::new(msg, readable_msg).with_kind(WasmEncodedErrorKind::Aborted)
+}
+
+/// Builds the error of a call whose timeout of the given ms elapsed
+fn timed_out_error(timeout_ms: u32) -> WasmEncodedError {
+    let msg = format!("operation timed out after {timeout_ms}ms");
+    WasmEncodedError::new(msg.clone(), msg).with_kind(WasmEncodedErrorKind::Aborted)
+}
+
+/// The future returned by [abortable]
+pub struct AbortableFuture<F> {
+    future: Pin<Box<F>>,
+    signal: Option<AbortSignal>,
+    timeout_ms: Option<u32>,
+    // resolves once the signal is aborted or the timeout elapses
+    abort: JsFuture,
+    resolve: Function,
+    timer: Option<JsValue>,
+}
+
+impl<F: Future> Future for AbortableFuture<F> {
+    type Output = Abortable<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // the signal is checked first, so the call is never started if it is already aborted
+        if let Some(signal) = self.signal.as_ref().filter(|signal| signal.aborted()) {
+            return Poll::Ready(Abortable::Aborted(aborted_error(signal.reason())));
+        }
+        if Pin::new(&mut self.abort).poll(cx).is_ready() {
+            let timeout_ms = self.timeout_ms.unwrap_or_default();
+            return Poll::Ready(Abortable::Aborted(timed_out_error(timeout_ms)));
+        }
+        self.future.as_mut().poll(cx).map(Abortable::Done)
+    }
+}
+
+impl<F> Drop for AbortableFuture<F> {
+    fn drop(&mut self) {
+        if let Some(signal) = &self.signal {
+            signal.remove_event_listener("abort", &self.resolve);
+        }
+        if let Some(timer) = &self.timer {
+            clear_timeout(timer);
+        }
+    }
+}
+
+impl<F> fmt::Debug for AbortableFuture<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AbortableFuture")
+            .field("signal", &self.signal)
+            .field("timeout_ms", &self.timeout_ms)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Races the given future against the given abort signal and timeout, the returned future
+/// resolves to [Abortable::Aborted] with a [WasmEncodedErrorKind::Aborted] error as soon as
+/// the signal is aborted or the timeout elapses, in which case the given future is dropped,
+/// otherwise it resolves to [Abortable::Done] with the output of the given future. If the
+/// signal is already aborted, the given future is not polled at all.
+///
+/// Used in [wasm_bindgen_utils_macros::wasm_export!] generated functions for the async
+/// functions that have an abort signal param or `cancellable` or `timeout_ms` attributes.
+pub fn abortable<F: Future>(
+    future: F,
+    signal: Option<AbortSignal>,
+    timeout_ms: Option<u32>,
+) -> AbortableFuture<F> {
+    let mut resolve = None;
+    let promise = Promise::new(&mut |resolve_fn, _| resolve = Some(resolve_fn));
+    // the promise executor is called synchronously, so the resolve function is always set
+    let resolve = resolve.expect("promise executor is called synchronously");
+    if let Some(signal) = &signal {
+        signal.add_event_listener("abort", &resolve);
+    }
+    let timer = timeout_ms.map(|timeout_ms| set_timeout(&resolve, timeout_ms));
+    AbortableFuture {
+        future: Box::pin(future),
+        signal,
+        timeout_ms,
+        abort: JsFuture::from(promise),
+        resolve,
+        timer,
+    }
+}
+
+#[cfg(target_family = "wasm")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::Cell, rc::Rc};
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    /// Creates a new `AbortController` and returns it with its signal
+    fn abort_controller() -> (JsValue, AbortSignal) {
+        let controller = Function::new_no_args("return new AbortController()")
+            .call0(&JsValue::UNDEFINED)
+            .unwrap();
+        let signal = js_sys::Reflect::get(&controller, &JsValue::from_str("signal")).unwrap();
+        (controller, signal.unchecked_into())
+    }
+
+    /// Aborts the given `AbortController` with the given reason
+    fn abort(controller: &JsValue, reason: &JsValue) {
+        let abort: Function = js_sys::Reflect::get(controller, &JsValue::from_str("abort"))
+            .unwrap()
+            .unchecked_into();
+        abort.call1(controller, reason).unwrap();
+    }
+
+    /// A future that resolves after the given ms
+    async fn sleep(ms: u32) {
+        let promise = Promise::new(&mut |resolve, _| {
+            set_timeout(&resolve, ms);
+        });
+        JsFuture::from(promise).await.unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_abortable_done() {
+        let (_controller, signal) = abort_controller();
+        let result = abortable(
+            async { Ok::<_, WasmEncodedError>(1u8) },
+            Some(signal),
+            Some(50),
+        )
+        .await
+        .map_ok(|value| value * 2)
+        .encode_errors(|_| {}, |_| {});
+        assert_eq!(result, Ok(2));
+
+        let result = abortable(async { 1u8 }, None, None).await;
+        assert!(matches!(result, Abortable::Done(1)));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_abortable_aborted() {
+        // the future is dropped once the signal is aborted
+        let (controller, signal) = abort_controller();
+        let polled = Rc::new(Cell::new(false));
+        let polled_clone = polled.clone();
+        let future = abortable(
+            async move {
+                polled_clone.set(true);
+                sleep(1000).await;
+                Ok::<u8, WasmEncodedError>(1)
+            },
+            Some(signal),
+            None,
+        );
+        abort(&controller, &js_sys::Error::new("stop").into());
+        let reported = Rc::new(Cell::new(0));
+        let reported_clone = reported.clone();
+        let result = future
+            .await
+            .encode_errors(|_| reported_clone.set(reported_clone.get() + 1), |_| {});
+        let err = result.unwrap_err();
+        assert_eq!(err.msg, "operation was aborted");
+        assert_eq!(err.readable_msg, "operation was aborted: Error: stop");
+        assert_eq!(err.kind, Some(WasmEncodedErrorKind::Aborted));
+        assert_eq!(reported.get(), 1);
+
+        // the call is not started if the signal is already aborted
+        assert!(!polled.get());
+
+        // aborted while pending
+        let (controller, signal) = abort_controller();
+        let controller_clone = controller.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            sleep(10).await;
+            abort(&controller_clone, &JsValue::UNDEFINED);
+        });
+        let result = abortable(
+            async {
+                sleep(1000).await;
+                Ok::<u8, WasmEncodedError>(1)
+            },
+            Some(signal),
+            None,
+        )
+        .await
+        .encode_errors(|_| {}, |_| {});
+        let err = result.unwrap_err();
+        assert_eq!(err.msg, "operation was aborted");
+        assert_eq!(err.kind, Some(WasmEncodedErrorKind::Aborted));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_abortable_timeout() {
+        let result = abortable(
+            async {
+                sleep(1000).await;
+                Ok::<u8, WasmEncodedError>(1)
+            },
+            None,
+            Some(10),
+        )
+        .await
+        .encode_errors(|_| {}, |_| {});
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEncodedError::new(
+                "operation timed out after 10ms",
+                "operation timed out after 10ms"
+            )
+            .with_kind(WasmEncodedErrorKind::Aborted)
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_abort_signal_of() {
+        let (_controller, signal) = abort_controller();
+        assert_eq!(abort_signal_of("signal", Some(&signal)), Ok(Some(signal)));
+        assert_eq!(
+            abort_signal_of("signal", Some(&JsValue::UNDEFINED)),
+            Ok(None)
+        );
+        assert_eq!(abort_signal_of("signal", Some(&JsValue::NULL)), Ok(None));
+        assert_eq!(abort_signal_of::<JsValue>("signal", None), Ok(None));
+
+        // values that are not an AbortSignal are rejected
+        let err = abort_signal_of("signal", Some(&js_sys::Object::new())).unwrap_err();
+        assert_eq!(
+            err.msg,
+            "invalid `signal` argument: expected an AbortSignal"
+        );
+        assert_eq!(err.readable_msg, err.msg);
+        assert!(abort_signal_of("signal", Some(&JsValue::from(5))).is_err());
+    }
+}
@@ -0,0 +1,367 @@
+use std::{fmt, marker::PhantomData};
+use js_sys::{Array, Function};
+use crate::result::WasmEncodedError;
+use crate::import::{decode_js_result, encode_js_arg, resolve_js_value};
+use wasm_bindgen::{
+    convert::{FromWasmAbi, IntoWasmAbi, OptionFromWasmAbi, OptionIntoWasmAbi},
+    describe::WasmDescribe,
+    JsCast, JsValue,
+};
+use serde::{de, de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+use serde_wasm_bindgen::preserve;
+
+/// The args of a call into a JS callback, i.e. tuples of up to 6 items whose types are
+/// serializable, each of them is encoded into a JS value through the same serialization
+/// that [crate::impl_wasm_traits] uses, if it fails the returning error names its position
+/// in the tuple, e.g. `arg0`.
+///
+/// Used by [JsCallback] and [JsAsyncCallback] for their args.
+pub trait JsCallbackArgs {
+    /// Encodes the args into an array of JS values
+    fn into_js_args(self) -> Result<Array, WasmEncodedError>;
+}
+
+macro_rules! impl_js_callback_args {
+    ($($arg:ident $index:tt),*) => {
+        impl<$($arg: Serialize),*> JsCallbackArgs for ($($arg,)*) {
+            fn into_js_args(self) -> Result<Array, WasmEncodedError> {
+                let args = Array::new();
+                $(
+                    args.push(&encode_js_arg(concat!("arg", stringify!($index)), &self.$index)?);
+                )*
+                Ok(args)
+            }
+        }
+    };
+}
+
+impl_js_callback_args!();
+impl_js_callback_args!(A 0);
+impl_js_callback_args!(A 0, B 1);
+impl_js_callback_args!(A 0, B 1, C 2);
+impl_js_callback_args!(A 0, B 1, C 2, D 3);
+impl_js_callback_args!(A 0, B 1, C 2, D 3, E 4);
+impl_js_callback_args!(A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// Calls the given JS function with the given args and returns its returned value,
+/// thrown JS exceptions are returned as [WasmEncodedError]
+fn apply(function: &Function, args: impl JsCallbackArgs) -> Result<JsValue, WasmEncodedError> {
+    let args = args.into_js_args()?;
+    function
+        .apply(&JsValue::UNDEFINED, &args)
+        .map_err(WasmEncodedError::from)
+}
+
+/// Decodes the given returned value of a callback call, except for the callbacks that
+/// return `()` (`void` in TS) whose returned value is ignored, since JS functions such as
+/// `x => arr.push(x)` return values that are not meant to be used by their callers
+fn decode_callback_return<R: DeserializeOwned>(value: JsValue) -> Result<R, WasmEncodedError> {
+    match R::deserialize(UnitProbe) {
+        Ok(unit) => Ok(unit),
+        Err(_) => decode_js_result("callback", value),
+    }
+}
+
+/// A deserializer that can only deserialize `()`, used to detect the callbacks that return `()`
+struct UnitProbe;
+
+impl<'de> Deserializer<'de> for UnitProbe {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+        Err(de::Error::custom("expected unit"))
+    }
+
+    fn deserialize_unit<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit_struct newtype_struct seq tuple tuple_struct map struct enum identifier
+        ignored_any
+    }
+}
+
+/// Defines a typed JS callback type that wraps a JS function, it is passed through wasm
+/// bindgen and serialized (only supported by [serde_wasm_bindgen]) as the function itself
+macro_rules! impl_js_callback {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        pub struct $name<Args, R> {
+            function: Function,
+            _marker: PhantomData<fn(Args) -> R>,
+        }
+
+        impl<Args, R> $name<Args, R> {
+            /// Creates a new instance from the given JS function, it is assumed that the
+            /// function accepts the given args and returns a value of the given type, which
+            /// is checked when it is called
+            pub fn new(function: Function) -> Self {
+                Self {
+                    function,
+                    _marker: PhantomData,
+                }
+            }
+
+            /// Returns the underlying JS function
+            pub fn as_function(&self) -> &Function {
+                &self.function
+            }
+
+            /// Consumes this instance and returns the underlying JS function
+            pub fn into_function(self) -> Function {
+                self.function
+            }
+        }
+
+        impl<Args, R> Clone for $name<Args, R> {
+            fn clone(&self) -> Self {
+                Self::new(self.function.clone())
+            }
+        }
+
+        impl<Args, R> fmt::Debug for $name<Args, R> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.function).finish()
+            }
+        }
+
+        impl<Args, R> From<Function> for $name<Args, R> {
+            fn from(value: Function) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl<Args, R> From<$name<Args, R>> for Function {
+            fn from(value: $name<Args, R>) -> Self {
+                value.function
+            }
+        }
+
+        impl<Args, R> From<$name<Args, R>> for JsValue {
+            fn from(value: $name<Args, R>) -> Self {
+                value.function.into()
+            }
+        }
+
+        impl<Args, R> Serialize for $name<Args, R> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                preserve::serialize(&self.function, serializer)
+            }
+        }
+
+        impl<'de, Args, R> Deserialize<'de> for $name<Args, R> {
+            fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+                let value: JsValue = preserve::deserialize(deserializer)?;
+                value
+                    .dyn_into::<Function>()
+                    .map(Self::new)
+                    .map_err(|_| de::Error::custom("expected a function"))
+            }
+        }
+
+        impl<Args, R> WasmDescribe for $name<Args, R> {
+            #[inline]
+            fn describe() {
+                Function::describe()
+            }
+        }
+
+        impl<Args, R> IntoWasmAbi for $name<Args, R> {
+            type Abi = <Function as IntoWasmAbi>::Abi;
+
+            #[inline]
+            fn into_abi(self) -> Self::Abi {
+                self.function.into_abi()
+            }
+        }
+
+        impl<Args, R> OptionIntoWasmAbi for $name<Args, R> {
+            #[inline]
+            fn none() -> Self::Abi {
+                <Function as OptionIntoWasmAbi>::none()
+            }
+        }
+
+        impl<Args, R> FromWasmAbi for $name<Args, R> {
+            type Abi = <Function as FromWasmAbi>::Abi;
+
+            #[inline]
+            unsafe fn from_abi(js: Self::Abi) -> Self {
+                Self::new(Function::from_abi(js))
+            }
+        }
+
+        impl<Args, R> OptionFromWasmAbi for $name<Args, R> {
+            #[inline]
+            fn is_none(js: &Self::Abi) -> bool {
+                <Function as OptionFromWasmAbi>::is_none(js)
+            }
+        }
+    };
+}
+
+impl_js_callback!(
+    /// A typed JS callback, i.e. `(arg0: A, arg1: B) => R` in TS for `JsCallback<(A, B), R>`,
+    /// for the args that are JS functions, such as progress reporters or comparators, rather
+    /// than untyped [js_sys::Function].
+    ///
+    /// Calling it encodes the args (a tuple of up to 6 items) and decodes the returned value
+    /// the same way that [crate::impl_wasm_traits] does, where thrown JS exceptions, returned
+    /// [crate::result::WasmEncodedResult] errors and invalid returned values become the error,
+    /// except for the callbacks that return `()` whose returned value is ignored, [JsAsyncCallback]
+    /// should be used for the callbacks that return a promise.
+    ///
+    /// Example:
+    /// ```ignore
+    /// #[wasm_export(js_name = "sortOrders")]
+    /// pub fn sort_orders(
+    ///     mut orders: Vec<Order>,
+    ///     compare: JsCallback<(Order, Order), i32>,
+    /// ) -> Result<Vec<Order>, Error> {
+    ///     let mut result = Ok(());
+    ///     orders.sort_by(|a, b| match compare.call((a.clone(), b.clone())) {
+    ///         Ok(ordering) => ordering.cmp(&0),
+    ///         Err(err) => {
+    ///             result = Err(err);
+    ///             std::cmp::Ordering::Equal
+    ///         }
+    ///     });
+    ///     result?;
+    ///     Ok(orders)
+    /// }
+    /// ```
+    JsCallback
+);
+
+impl_js_callback!(
+    /// A typed async JS callback, i.e. `(arg0: A, arg1: B) => R | Promise<R>` in TS for
+    /// `JsAsyncCallback<(A, B), R>`, same as [JsCallback] except that calling it awaits the
+    /// returned promise, so a promise rejection becomes the error too.
+    ///
+    /// Example:
+    /// ```ignore
+    /// #[wasm_export(js_name = "resolveOrder")]
+    /// pub async fn resolve_order(
+    ///     id: String,
+    ///     resolver: JsAsyncCallback<(String,), Order>,
+    /// ) -> Result<Order, Error> {
+    ///     Ok(resolver.call((id,)).await?)
+    /// }
+    /// ```
+    JsAsyncCallback
+);
+
+impl<Args: JsCallbackArgs, R: DeserializeOwned> JsCallback<Args, R> {
+    /// Calls the JS function with the given args and returns its decoded returned value
+    pub fn call(&self, args: Args) -> Result<R, WasmEncodedError> {
+        let value = apply(&self.function, args)?;
+        decode_callback_return(value)
+    }
+}
+
+impl<Args: JsCallbackArgs, R: DeserializeOwned> JsAsyncCallback<Args, R> {
+    /// Calls the JS function with the given args and returns the decoded resolved value of
+    /// its returned promise, or its returned value as is if it does not return a promise
+    pub async fn call(&self, args: Args) -> Result<R, WasmEncodedError> {
+        let value = apply(&self.function, args)?;
+        let value = resolve_js_value(value).await?;
+        decode_callback_return(value)
+    }
+}
+
+#[cfg(target_family = "wasm")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_wasm_bindgen::{from_value as from_js_value, to_value as to_js_value};
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    fn test_js_callback_args() {
+        let args = ().into_js_args().unwrap();
+        assert_eq!(args.length(), 0);
+
+        let args = ("a".to_string(), 2u8, vec![1u8]).into_js_args().unwrap();
+        assert_eq!(args.length(), 3);
+        assert_eq!(args.get(0), JsValue::from_str("a"));
+        assert_eq!(args.get(1), JsValue::from(2));
+        assert!(Array::is_array(&args.get(2)));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_js_callback_call() {
+        let callback =
+            JsCallback::<(u8, u8), u8>::new(Function::new_with_args("a, b", "return a + b"));
+        assert_eq!(callback.call((1, 2)).unwrap(), 3);
+
+        let callback = JsCallback::<(), ()>::new(Function::new_no_args("throw new Error('boom')"));
+        let err = callback.call(()).unwrap_err();
+        assert_eq!(err.msg, "Error: boom");
+
+        // the returned value of the callbacks that return unit is ignored
+        let callback = JsCallback::<(u8,), ()>::new(Function::new_with_args("a", "return a + 1"));
+        assert_eq!(callback.call((1,)), Ok(()));
+        let callback = JsCallback::<(), Option<u8>>::new(Function::new_no_args("return 'a'"));
+        assert!(callback.call(()).is_err());
+
+        let callback = JsCallback::<(String,), u8>::new(Function::new_with_args("a", "return a"));
+        let err = callback.call(("a".to_string(),)).unwrap_err();
+        assert!(err
+            .msg
+            .starts_with("invalid value returned by `callback`: "));
+
+        let callback = JsCallback::<(), u8>::new(Function::new_no_args(
+            "return { error: { msg: 'some error', readableMsg: 'some readable error' } }",
+        ));
+        let err = callback.call(()).unwrap_err();
+        assert_eq!(
+            err,
+            WasmEncodedError::new("some error", "some readable error")
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_js_async_callback_call() {
+        let callback = JsAsyncCallback::<(u8,), u8>::new(Function::new_with_args(
+            "a",
+            "return Promise.resolve(a * 2)",
+        ));
+        assert_eq!(callback.call((2,)).await.unwrap(), 4);
+
+        // non promise returns are accepted as they are
+        let callback = JsAsyncCallback::<(u8,), u8>::new(Function::new_with_args("a", "return a"));
+        assert_eq!(callback.call((2,)).await.unwrap(), 2);
+
+        let callback = JsAsyncCallback::<(), u8>::new(Function::new_no_args(
+            "return Promise.reject(new Error('rejected'))",
+        ));
+        let err = callback.call(()).await.unwrap_err();
+        assert_eq!(err.msg, "Error: rejected");
+
+        // the resolved value of the callbacks that return unit is ignored, but not a rejection
+        let callback = JsAsyncCallback::<(u8,), ()>::new(Function::new_with_args(
+            "a",
+            "return Promise.resolve(a)",
+        ));
+        assert_eq!(callback.call((1,)).await, Ok(()));
+        let callback = JsAsyncCallback::<(), ()>::new(Function::new_no_args(
+            "return Promise.reject(new Error('rejected'))",
+        ));
+        assert!(callback.call(()).await.is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_js_callback_serde() {
+        let function = Function::new_with_args("a", "return a");
+        let callback = JsCallback::<(u8,), u8>::new(function.clone());
+        let value = to_js_value(&vec![callback]).unwrap();
+        let callbacks: Vec<JsCallback<(u8,), u8>> = from_js_value(value).unwrap();
+        assert_eq!(callbacks[0].as_function(), &function);
+        assert_eq!(callbacks[0].call((5,)).unwrap(), 5);
+
+        let err = from_js_value::<JsCallback<(), ()>>(JsValue::from(1)).unwrap_err();
+        assert_eq!(err.to_string(), "Error: expected a function");
+    }
+}
@@ -0,0 +1,20 @@
+#[cfg(target_family = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[cfg(target_family = "wasm")]
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = console, js_name = warn)]
+    fn console_warn(msg: &str);
+}
+
+/// Logs the given deprecation warning with `console.warn` on wasm and to stderr
+/// otherwise, used in [wasm_bindgen_utils_macros::wasm_export!] generated functions
+/// that are deprecated with `warn` key on their first call.
+pub fn warn_deprecated(msg: &str) {
+    #[cfg(target_family = "wasm")]
+    console_warn(msg);
+
+    #[cfg(not(target_family = "wasm"))]
+    eprintln!("{msg}");
+}
@@ -0,0 +1,203 @@
+use crate::result::WasmEncodedError;
+#[cfg(feature = "hooks")]
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, RwLock,
+};
+
+/// Hook that is called before an exported function is called with its js name
+#[cfg(feature = "hooks")]
+pub type BeforeExportHook = Box<dyn Fn(&str) + Send + Sync>;
+
+/// Hook that is called after an exported function is done with its js
+/// name and the elapsed time of the call in milliseconds
+#[cfg(feature = "hooks")]
+pub type AfterExportHook = Box<dyn Fn(&str, f64) + Send + Sync>;
+
+/// Hook that is called with the js name of an exported function and each
+/// of the errors it fails with, the warnings of [crate::result::Diagnosed]
+/// are not reported to it since the call has not failed because of them
+#[cfg(feature = "hooks")]
+pub type OnErrorExportHook = Box<dyn Fn(&str, &WasmEncodedError) + Send + Sync>;
+
+#[cfg(feature = "hooks")]
+struct ExportHooks {
+    before: BeforeExportHook,
+    after: AfterExportHook,
+    on_error: OnErrorExportHook,
+}
+
+/// Fast path flag, so exported functions skip the hooks entirely when none are registered
+#[cfg(feature = "hooks")]
+static HOOKS_ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[cfg(feature = "hooks")]
+static HOOKS: RwLock<Option<Arc<ExportHooks>>> = RwLock::new(None);
+
+/// Registers the given hooks that are called around every call of the functions
+/// exported by [wasm_bindgen_utils_macros::wasm_export!], replacing the previously
+/// registered ones, useful for uniform logging, timing and error reporting.
+///
+/// Example:
+/// ```ignore
+/// set_export_hooks(
+///     |name| log(&format!("calling {name}")),
+///     |name, elapsed_ms| log(&format!("{name} took {elapsed_ms}ms")),
+///     |name, error| log(&format!("{name} failed: {}", error.readable_msg)),
+/// );
+/// ```
+#[cfg(feature = "hooks")]
+pub fn set_export_hooks(
+    before: impl Fn(&str) + Send + Sync + 'static,
+    after: impl Fn(&str, f64) + Send + Sync + 'static,
+    on_error: impl Fn(&str, &WasmEncodedError) + Send + Sync + 'static,
+) {
+    let hooks = Arc::new(ExportHooks {
+        before: Box::new(before),
+        after: Box::new(after),
+        on_error: Box::new(on_error),
+    });
+    *HOOKS.write().unwrap_or_else(|e| e.into_inner()) = Some(hooks);
+    HOOKS_ENABLED.store(true, Ordering::Release);
+}
+
+/// Removes the registered export hooks if any
+#[cfg(feature = "hooks")]
+pub fn clear_export_hooks() {
+    HOOKS_ENABLED.store(false, Ordering::Release);
+    *HOOKS.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Calls the given fn with the registered hooks if there are any, the hooks are
+/// cloned out of the lock first, so they can register or clear the hooks themselves
+#[cfg(feature = "hooks")]
+fn with_hooks(f: impl FnOnce(&ExportHooks)) {
+    let hooks = HOOKS.read().unwrap_or_else(|e| e.into_inner()).clone();
+    if let Some(hooks) = hooks {
+        f(&hooks);
+    }
+}
+
+/// Returns the current time in milliseconds
+#[cfg(feature = "hooks")]
+fn now() -> f64 {
+    #[cfg(target_family = "wasm")]
+    return js_sys::Date::now();
+
+    #[cfg(not(target_family = "wasm"))]
+    return std::time::UNIX_EPOCH
+        .elapsed()
+        .map(|v| v.as_secs_f64() * 1000.0)
+        .unwrap_or_default();
+}
+
+/// A guard that tracks a single call of an exported function for the registered
+/// export hooks, it calls the `before` hook when started and the `after` hook when
+/// dropped, and it is a no-op if there are no hooks registered at the start of the
+/// call or if `hooks` feature is disabled.
+///
+/// Used in [wasm_bindgen_utils_macros::wasm_export!] generated functions.
+pub struct ExportCall {
+    #[cfg(feature = "hooks")]
+    tracked: Option<(&'static str, f64)>,
+}
+
+impl ExportCall {
+    /// Starts tracking a call of the exported function with the given js name
+    #[inline]
+    pub fn start(name: &'static str) -> Self {
+        #[cfg(feature = "hooks")]
+        {
+            if !HOOKS_ENABLED.load(Ordering::Acquire) {
+                return ExportCall { tracked: None };
+            }
+            with_hooks(|hooks| (hooks.before)(name));
+            ExportCall {
+                tracked: Some((name, now())),
+            }
+        }
+
+        #[cfg(not(feature = "hooks"))]
+        {
+            let _ = name;
+            ExportCall {}
+        }
+    }
+
+    /// Reports the given error of this call to the `on_error` hook
+    #[inline]
+    pub fn on_error(&self, error: &WasmEncodedError) {
+        #[cfg(feature = "hooks")]
+        if let Some((name, _)) = self.tracked {
+            with_hooks(|hooks| (hooks.on_error)(name, error));
+        }
+
+        #[cfg(not(feature = "hooks"))]
+        let _ = error;
+    }
+}
+
+#[cfg(feature = "hooks")]
+impl Drop for ExportCall {
+    fn drop(&mut self) {
+        if let Some((name, start)) = self.tracked {
+            with_hooks(|hooks| (hooks.after)(name, now() - start));
+        }
+    }
+}
+
+#[cfg(target_family = "wasm")]
+#[cfg(all(test, feature = "hooks"))]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    fn test_export_hooks() {
+        let calls = Arc::new(Mutex::new(vec![]));
+
+        // no hooks registered
+        let call = ExportCall::start("someFn");
+        assert!(call.tracked.is_none());
+        drop(call);
+
+        let (before_calls, after_calls, error_calls) =
+            (calls.clone(), calls.clone(), calls.clone());
+        set_export_hooks(
+            move |name| before_calls.lock().unwrap().push(format!("before {name}")),
+            move |name, elapsed| {
+                assert!(elapsed >= 0.0);
+                after_calls.lock().unwrap().push(format!("after {name}"));
+            },
+            move |name, error| {
+                error_calls
+                    .lock()
+                    .unwrap()
+                    .push(format!("error {name} {}", error.msg))
+            },
+        );
+        let call = ExportCall::start("someFn");
+        call.on_error(&WasmEncodedError::from("some msg"));
+        drop(call);
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec!["before someFn", "error someFn some msg", "after someFn"]
+        );
+
+        // cleared hooks
+        clear_export_hooks();
+        let call = ExportCall::start("someFn");
+        call.on_error(&WasmEncodedError::from("some msg"));
+        drop(call);
+        assert_eq!(calls.lock().unwrap().len(), 3);
+
+        // hooks that clear the hooks while being called
+        set_export_hooks(|_| {}, |_, _| {}, |_, _| clear_export_hooks());
+        let call = ExportCall::start("someFn");
+        call.on_error(&WasmEncodedError::from("some msg"));
+        drop(call);
+        assert!(!HOOKS_ENABLED.load(Ordering::Acquire));
+        assert!(HOOKS.read().unwrap().is_none());
+    }
+}
@@ -0,0 +1,222 @@
+use js_sys::{Promise, Reflect};
+use crate::result::WasmEncodedError;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Encodes the given argument of a call into JS code into a JS value, through
+/// the same serialization that [crate::impl_wasm_traits] uses for the type of
+/// the argument, if it fails the returning error names the argument.
+///
+/// Used in [wasm_bindgen_utils_macros::wasm_export!] generated adapters of the
+/// traits that are implemented by JS objects and [wasm_bindgen_utils_macros::wasm_import!]
+/// generated functions.
+pub fn encode_js_arg<T: Serialize + ?Sized>(
+    name: &str,
+    value: &T,
+) -> Result<JsValue, WasmEncodedError> {
+    serde_wasm_bindgen::to_value(value).map_err(|err| {
+        let mut err = WasmEncodedError::from(err);
+        err.msg = format!("failed to encode `{name}` argument: {}", err.msg);
+        err.readable_msg = format!("failed to encode `{name}` argument: {}", err.readable_msg);
+        err
+    })
+}
+
+/// Decodes the given returned value of a call into JS code into a rust value,
+/// through the same deserialization that [crate::impl_wasm_traits] uses for the
+/// returning type, if it fails the returning error names the called function.
+///
+/// Used by [decode_js_result] after the returned value is checked for errors.
+pub fn decode_js_return<T: DeserializeOwned>(
+    name: &str,
+    value: JsValue,
+) -> Result<T, WasmEncodedError> {
+    serde_wasm_bindgen::from_value(value).map_err(|err| {
+        let mut err = WasmEncodedError::from(err);
+        err.msg = format!("invalid value returned by `{name}`: {}", err.msg);
+        err.readable_msg = format!("invalid value returned by `{name}`: {}", err.readable_msg);
+        err
+    })
+}
+
+/// Checks if the given returned value of a call into JS code is the error variant of
+/// [crate::result::WasmEncodedResult], that is, an object with an `error` property that
+/// is a [WasmEncodedError], and returns that error if so, so JS code can report errors
+/// by either throwing or returning them the same way that the exported functions do.
+/// If the `error` property is not a [WasmEncodedError], e.g. a JS `Error` or a string,
+/// it is converted into one the same way that the thrown JS exceptions are.
+///
+/// Used in [wasm_bindgen_utils_macros::wasm_export!] generated adapters of the
+/// traits that are implemented by JS objects and [wasm_bindgen_utils_macros::wasm_import!]
+/// generated functions, for the returned values that are not decoded.
+pub fn check_js_error(value: &JsValue) -> Result<(), WasmEncodedError> {
+    if !value.is_object() {
+        return Ok(());
+    }
+    let error = Reflect::get(value, &JsValue::from_str("error")).unwrap_or_default();
+    if error.is_undefined() || error.is_null() {
+        return Ok(());
+    }
+    Err(
+        serde_wasm_bindgen::from_value::<WasmEncodedError>(error.clone())
+            .unwrap_or_else(|_| WasmEncodedError::from(error)),
+    )
+}
+
+/// Decodes the given returned value of a call into JS code into a rust value, the
+/// error variant of [crate::result::WasmEncodedResult] is returned as its error, and
+/// the success variant is unwrapped if the value itself cannot be decoded, so JS code
+/// can return either the value or a [crate::result::WasmEncodedResult] of it.
+///
+/// Used in [wasm_bindgen_utils_macros::wasm_export!] generated adapters of the
+/// traits that are implemented by JS objects and [wasm_bindgen_utils_macros::wasm_import!]
+/// generated functions.
+pub fn decode_js_result<T: DeserializeOwned>(
+    name: &str,
+    value: JsValue,
+) -> Result<T, WasmEncodedError> {
+    check_js_error(&value)?;
+    decode_js_return(name, value.clone()).or_else(|err| {
+        let key = JsValue::from_str("value");
+        if !value.is_object() || !Reflect::has(&value, &key).unwrap_or(false) {
+            return Err(err);
+        }
+        let inner = Reflect::get(&value, &key).unwrap_or_default();
+        decode_js_return(name, inner).map_err(|_| err)
+    })
+}
+
+/// Resolves the given returned value of a call into JS code, that is, if it is a
+/// promise (or a thenable) its resolved value, or a rejection as [WasmEncodedError],
+/// and the value itself otherwise, so JS code can return either of them.
+///
+/// Used in [wasm_bindgen_utils_macros::wasm_export!] generated adapters of the
+/// traits that are implemented by JS objects and [wasm_bindgen_utils_macros::wasm_import!]
+/// generated functions, for their async methods and functions.
+pub async fn resolve_js_value(value: JsValue) -> Result<JsValue, WasmEncodedError> {
+    JsFuture::from(Promise::resolve(&value))
+        .await
+        .map_err(WasmEncodedError::from)
+}
+
+#[cfg(target_family = "wasm")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::result::WasmEncodedResult;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    struct Unencodable;
+    impl Serialize for Unencodable {
+        fn serialize<S: serde::Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+            Err(serde::ser::Error::custom("unencodable"))
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encode_js_arg() {
+        let result = encode_js_arg("key", "some key").unwrap();
+        assert_eq!(result, JsValue::from_str("some key"));
+
+        let result = encode_js_arg("amounts", &vec![1u8, 2]).unwrap();
+        let value = Reflect::get_u32(&result, 1).unwrap();
+        assert_eq!(value, JsValue::from(2));
+
+        let err = encode_js_arg("value", &Unencodable).unwrap_err();
+        assert_eq!(
+            err.msg,
+            "failed to encode `value` argument: Error: unencodable"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_decode_js_return() {
+        let result: Option<String> = decode_js_return("get", JsValue::UNDEFINED).unwrap();
+        assert_eq!(result, None);
+
+        let result: u8 = decode_js_return("get", JsValue::from(1)).unwrap();
+        assert_eq!(result, 1);
+
+        let err = decode_js_return::<u8>("Storage.get", JsValue::from_str("1")).unwrap_err();
+        assert!(err
+            .msg
+            .starts_with("invalid value returned by `Storage.get`: "));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_check_js_error() {
+        assert!(check_js_error(&JsValue::from(1)).is_ok());
+        assert!(check_js_error(&JsValue::NULL).is_ok());
+
+        let result = WasmEncodedResult::success(1u8).try_into_js_value().unwrap();
+        assert!(check_js_error(&result).is_ok());
+
+        let obj = js_sys::Object::new();
+        Reflect::set(&obj, &"error".into(), &"not an encoded error".into()).unwrap();
+        let err = check_js_error(&obj).unwrap_err();
+        assert_eq!(err, WasmEncodedError::from("not an encoded error"));
+
+        let obj = js_sys::Object::new();
+        let js_error = js_sys::Error::new("some js error");
+        Reflect::set(&obj, &"error".into(), &js_error.clone().into()).unwrap();
+        let err = check_js_error(&obj).unwrap_err();
+        assert_eq!(err, WasmEncodedError::from(js_error));
+
+        let error = WasmEncodedError::from("some error");
+        let result = WasmEncodedResult::<u8>::Err {
+            value: None,
+            error: error.clone(),
+        };
+        let err = check_js_error(&result.try_into_js_value().unwrap()).unwrap_err();
+        assert_eq!(err, error);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_decode_js_result() {
+        let result: u8 = decode_js_result("get", JsValue::from(1)).unwrap();
+        assert_eq!(result, 1);
+
+        let value = WasmEncodedResult::success(1u8).try_into_js_value().unwrap();
+        let result: u8 = decode_js_result("get", value).unwrap();
+        assert_eq!(result, 1);
+
+        let value = WasmEncodedResult::success(None::<u8>)
+            .try_into_js_value()
+            .unwrap();
+        let result: Option<u8> = decode_js_result("get", value).unwrap();
+        assert_eq!(result, None);
+
+        // a value that has a `value` field is decoded as is
+        let value = WasmEncodedResult::success(2u8).try_into_js_value().unwrap();
+        let result: WasmEncodedResult<u8> = decode_js_result("get", value).unwrap();
+        assert_eq!(result, WasmEncodedResult::success(2));
+
+        let error = WasmEncodedError::from("some error");
+        let value = WasmEncodedResult::<u8>::Err {
+            value: None,
+            error: error.clone(),
+        };
+        let err = decode_js_result::<u8>("get", value.try_into_js_value().unwrap()).unwrap_err();
+        assert_eq!(err, error);
+
+        let err = decode_js_result::<u8>("Storage.get", JsValue::from_str("1")).unwrap_err();
+        assert!(err
+            .msg
+            .starts_with("invalid value returned by `Storage.get`: "));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_resolve_js_value() {
+        let result = resolve_js_value(JsValue::from(1)).await.unwrap();
+        assert_eq!(result, JsValue::from(1));
+
+        let promise = Promise::resolve(&JsValue::from_str("value"));
+        let result = resolve_js_value(promise.into()).await.unwrap();
+        assert_eq!(result, JsValue::from_str("value"));
+
+        let promise = Promise::reject(&js_sys::Error::new("some error").into());
+        let err = resolve_js_value(promise.into()).await.unwrap_err();
+        assert_eq!(err.msg, "Error: some error");
+    }
+}
@@ -0,0 +1,348 @@
+use std::{cell::RefCell, fmt, rc::Rc};
+use js_sys::{Function, Object, Reflect, Symbol};
+use crate::result::{FromEncodedError, WasmEncodedError};
+use wasm_bindgen::{
+    closure::Closure,
+    convert::{IntoWasmAbi, OptionIntoWasmAbi},
+    describe::WasmDescribe,
+    JsValue,
+};
+
+/// A boxed iterator that is returned by an exported function whose returning type is
+/// `impl Iterator<Item = T>` or `Box<dyn Iterator<Item = T>>`, its items are results so
+/// an error that occurs before the function is called, such as a failed param conversion,
+/// can be yielded as its only item, it provides the same adapters for its items that
+/// [crate::result::EncodeErrors] and [crate::result::MapOk] provide for other returning
+/// types, but they are applied lazily as the items are yielded, and it is converted into
+/// a [JsIterator] once it is returned.
+///
+/// Used in [wasm_bindgen_utils_macros::wasm_export!] generated functions.
+pub struct ExportIter<T> {
+    iter: Box<dyn Iterator<Item = Result<T, WasmEncodedError>>>,
+}
+
+impl<T: 'static> ExportIter<T> {
+    /// Creates a new instance from the given iterator
+    pub fn new<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: 'static,
+    {
+        Self {
+            iter: Box::new(iter.into_iter().map(Ok)),
+        }
+    }
+
+    /// Calls `on_error` for each of the errors of the items as they are yielded, the items
+    /// have no warnings so `on_warning` is never called, it is taken for the same signature
+    /// as [crate::result::EncodeErrors]
+    pub fn encode_errors<F, W>(self, mut on_error: F, _on_warning: W) -> Self
+    where
+        F: FnMut(&mut WasmEncodedError) + 'static,
+        W: FnMut(&mut WasmEncodedError),
+    {
+        Self {
+            iter: Box::new(self.iter.map(move |item| {
+                item.map_err(|mut err| {
+                    on_error(&mut err);
+                    err
+                })
+            })),
+        }
+    }
+
+    /// Maps the success values of the items by the given function as they are yielded
+    pub fn map_ok<U, F: FnMut(T) -> U + 'static>(self, mut f: F) -> ExportIter<U> {
+        ExportIter {
+            iter: Box::new(self.iter.map(move |item| item.map(&mut f))),
+        }
+    }
+}
+
+impl<T> Iterator for ExportIter<T> {
+    type Item = Result<T, WasmEncodedError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+impl<T: 'static> FromEncodedError for ExportIter<T> {
+    /// An iterator that only yields the given error
+    fn from_encoded_error(err: WasmEncodedError) -> Self {
+        Self {
+            iter: Box::new(std::iter::once(Err(err))),
+        }
+    }
+}
+
+impl<T> fmt::Debug for ExportIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExportIter").finish_non_exhaustive()
+    }
+}
+
+/// A JS object that implements the iterator protocol, i.e. `IterableIterator<T>` in TS, for
+/// the exported functions whose returning type is a rust iterator, so large results can be
+/// consumed lazily with `for...of` in JS rather than being collected and encoded up front.
+///
+/// It is backed by the rust iterator, each of its `next()` calls converts a single item into
+/// a JS value through its wasm traits (i.e. its [Into] [JsValue] impl), and the iterator is
+/// dropped once it is finished or the consumer stops early by calling `return()`, e.g. by
+/// breaking out of a `for...of` loop, otherwise it is dropped once the object is garbage
+/// collected. A [WasmEncodedError] that is yielded instead of an item, such as a failed param
+/// conversion of the exported function, is thrown by `next()`.
+///
+/// Functions exported by [wasm_bindgen_utils_macros::wasm_export!] return it for their
+/// `impl Iterator<Item = T>` and `Box<dyn Iterator<Item = T>>` returning types, declared
+/// as `IterableIterator<T>` in TS.
+///
+/// Example:
+/// ```ignore
+/// #[wasm_export(js_name = "tokenize", unchecked_return_type = "Token")]
+/// pub fn tokenize(text: String) -> impl Iterator<Item = Token> {
+///     Tokenizer::new(text)
+/// }
+/// ```
+/// ```js
+/// for (const token of tokenize(text)) {
+///     if (token.kind === "eof") break;
+/// }
+/// ```
+#[derive(Clone)]
+pub struct JsIterator {
+    obj: Object,
+}
+
+impl JsIterator {
+    /// Creates a new instance from the given iterator, where each of its items
+    /// is converted into a JS value through its [Into] [JsValue] impl
+    pub fn new<I>(iter: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<JsValue> + 'static,
+        I::IntoIter: 'static,
+    {
+        ExportIter::new(iter).into()
+    }
+
+    /// Returns the underlying JS object
+    pub fn as_object(&self) -> &Object {
+        &self.obj
+    }
+
+    /// Builds the result object of the iterator protocol for the given item,
+    /// that is, `{ value, done: false }` or `{ value: undefined, done: true }`
+    fn iterator_result(item: Option<JsValue>) -> JsValue {
+        let obj = Object::new();
+        let done = item.is_none();
+        let value = item.unwrap_or(JsValue::UNDEFINED);
+        Reflect::set(&obj, &JsValue::from_str("value"), &value).unwrap();
+        Reflect::set(&obj, &JsValue::from_str("done"), &JsValue::from_bool(done)).unwrap();
+        obj.into()
+    }
+}
+
+impl<T: Into<JsValue> + 'static> From<ExportIter<T>> for JsIterator {
+    fn from(value: ExportIter<T>) -> Self {
+        let state = Rc::new(RefCell::new(Some(value)));
+
+        let next_state = state.clone();
+        let next = Closure::<dyn FnMut() -> Result<JsValue, JsValue>>::new(move || {
+            let item = next_state.borrow_mut().as_mut().and_then(Iterator::next);
+            match item {
+                Some(Ok(value)) => Ok(Self::iterator_result(Some(value.into()))),
+                Some(Err(err)) => Err(err.into()),
+                None => {
+                    // take it out first, so the state is not borrowed while it is dropped
+                    let iter = next_state.borrow_mut().take();
+                    drop(iter);
+                    Ok(Self::iterator_result(None))
+                }
+            }
+        });
+
+        let return_ = Closure::<dyn FnMut(JsValue) -> JsValue>::new(move |value: JsValue| {
+            let iter = state.borrow_mut().take();
+            drop(iter);
+            let result = Self::iterator_result(None);
+            Reflect::set(&result, &JsValue::from_str("value"), &value).unwrap();
+            result
+        });
+
+        // "Reflect::set" can only fail if the obj is sealed or frozen which is not the case here
+        let obj = Object::new();
+        Reflect::set(&obj, &JsValue::from_str("next"), &next.into_js_value()).unwrap();
+        Reflect::set(&obj, &JsValue::from_str("return"), &return_.into_js_value()).unwrap();
+        Reflect::set(
+            &obj,
+            &Symbol::iterator(),
+            &Function::new_no_args("return this"),
+        )
+        .unwrap();
+        Self { obj }
+    }
+}
+
+impl fmt::Debug for JsIterator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("JsIterator").field(&self.obj).finish()
+    }
+}
+
+impl From<JsIterator> for Object {
+    fn from(value: JsIterator) -> Self {
+        value.obj
+    }
+}
+
+impl From<JsIterator> for JsValue {
+    fn from(value: JsIterator) -> Self {
+        value.obj.into()
+    }
+}
+
+impl WasmDescribe for JsIterator {
+    #[inline]
+    fn describe() {
+        Object::describe()
+    }
+}
+
+impl IntoWasmAbi for JsIterator {
+    type Abi = <Object as IntoWasmAbi>::Abi;
+
+    #[inline]
+    fn into_abi(self) -> Self::Abi {
+        self.obj.into_abi()
+    }
+}
+
+impl OptionIntoWasmAbi for JsIterator {
+    #[inline]
+    fn none() -> Self::Abi {
+        <Object as OptionIntoWasmAbi>::none()
+    }
+}
+
+#[cfg(target_family = "wasm")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    /// An iterator of the given items that sets the given flag once it is dropped
+    struct Items {
+        items: std::vec::IntoIter<u8>,
+        dropped: Rc<Cell<bool>>,
+    }
+
+    impl Iterator for Items {
+        type Item = u8;
+
+        fn next(&mut self) -> Option<u8> {
+            self.items.next()
+        }
+    }
+
+    impl Drop for Items {
+        fn drop(&mut self) {
+            self.dropped.set(true);
+        }
+    }
+
+    fn items(items: Vec<u8>) -> (Items, Rc<Cell<bool>>) {
+        let dropped = Rc::new(Cell::new(false));
+        let items = Items {
+            items: items.into_iter(),
+            dropped: dropped.clone(),
+        };
+        (items, dropped)
+    }
+
+    fn call_method(iter: &JsIterator, name: &str, arg: &JsValue) -> Result<JsValue, JsValue> {
+        let method: Function = Reflect::get(iter.as_object(), &JsValue::from_str(name))
+            .unwrap()
+            .unchecked_into();
+        method.call1(iter.as_object(), arg)
+    }
+
+    fn get(value: &JsValue, key: &str) -> JsValue {
+        Reflect::get(value, &JsValue::from_str(key)).unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_export_iter_adapters() {
+        let error = WasmEncodedError::from("some error");
+        let reported = Rc::new(RefCell::new(vec![]));
+        let reported_clone = reported.clone();
+        let results: Vec<_> = ExportIter::from_encoded_error(error.clone())
+            .map_ok(|value: u8| value * 10)
+            .encode_errors(
+                move |err| reported_clone.borrow_mut().push(err.msg.clone()),
+                |_| {},
+            )
+            .collect();
+        assert_eq!(results, vec![Err(error)]);
+        assert_eq!(*reported.borrow(), vec!["some error".to_string()]);
+
+        let results: Vec<_> = ExportIter::new(vec![1u8, 2]).map_ok(|v| v * 10).collect();
+        assert_eq!(results, vec![Ok(10), Ok(20)]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_js_iterator_next() {
+        let (iter, dropped) = items(vec![1, 2]);
+        let iter = JsIterator::new(iter);
+
+        let result = call_method(&iter, "next", &JsValue::UNDEFINED).unwrap();
+        assert_eq!(get(&result, "done"), JsValue::FALSE);
+        assert_eq!(get(&result, "value"), JsValue::from(1));
+        let result = call_method(&iter, "next", &JsValue::UNDEFINED).unwrap();
+        assert_eq!(get(&result, "value"), JsValue::from(2));
+
+        // the iterator is dropped once it is finished
+        assert!(!dropped.get());
+        let result = call_method(&iter, "next", &JsValue::UNDEFINED).unwrap();
+        assert_eq!(get(&result, "done"), JsValue::TRUE);
+        assert!(get(&result, "value").is_undefined());
+        assert!(dropped.get());
+
+        // it is iterable as itself
+        let method: Function = Reflect::get(iter.as_object(), &Symbol::iterator())
+            .unwrap()
+            .unchecked_into();
+        let this = method.call0(iter.as_object()).unwrap();
+        assert_eq!(this, JsValue::from(iter.as_object()));
+
+        // the yielded errors are thrown
+        let iter = JsIterator::from(ExportIter::<u8>::from_encoded_error(
+            WasmEncodedError::from("some error"),
+        ));
+        let err = call_method(&iter, "next", &JsValue::UNDEFINED).unwrap_err();
+        assert_eq!(get(&err, "msg"), JsValue::from_str("some error"));
+        let result = call_method(&iter, "next", &JsValue::UNDEFINED).unwrap();
+        assert_eq!(get(&result, "done"), JsValue::TRUE);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_js_iterator_return() {
+        let (iter, dropped) = items(vec![1, 2, 3]);
+        let iter = JsIterator::new(iter);
+
+        let result = call_method(&iter, "next", &JsValue::UNDEFINED).unwrap();
+        assert_eq!(get(&result, "value"), JsValue::from(1));
+        assert!(!dropped.get());
+
+        // the iterator is dropped once the consumer stops early
+        let result = call_method(&iter, "return", &JsValue::from(5)).unwrap();
+        assert_eq!(get(&result, "done"), JsValue::TRUE);
+        assert_eq!(get(&result, "value"), JsValue::from(5));
+        assert!(dropped.get());
+
+        let result = call_method(&iter, "next", &JsValue::UNDEFINED).unwrap();
+        assert_eq!(get(&result, "done"), JsValue::TRUE);
+    }
+}
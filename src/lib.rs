@@ -50,8 +50,17 @@
 //! ```
 
 mod ser;
+pub mod abort;
+pub mod hooks;
+pub mod callback;
+pub mod import;
+pub mod iter;
+pub mod deprecation;
 pub mod macros;
+pub mod promise;
 pub mod result;
+pub mod stream;
+pub mod union;
 
 pub use ser::*;
 pub use wasm_bindgen_utils_macros::*;
@@ -64,9 +73,19 @@ pub mod prelude {
     pub use wasm_bindgen;
     pub use serde_wasm_bindgen;
     pub use wasm_bindgen_futures;
+    pub use futures_core;
     pub use tsify::Tsify;
     pub use wasm_bindgen::prelude::*;
     pub use serde_wasm_bindgen::{to_value as to_js_value, from_value as from_js_value};
     pub use wasm_bindgen_utils_macros::*;
     pub use super::result::*;
+    pub use super::abort::*;
+    pub use super::hooks::*;
+    pub use super::callback::*;
+    pub use super::import::*;
+    pub use super::iter::*;
+    pub use super::promise::*;
+    pub use super::stream::*;
+    pub use super::deprecation::*;
+    pub use super::union::*;
 }
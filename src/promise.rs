@@ -0,0 +1,257 @@
+use js_sys::Promise;
+use std::{
+    fmt,
+    pin::Pin,
+    future::{Future, IntoFuture},
+    marker::PhantomData,
+    task::{Context, Poll},
+};
+use crate::result::WasmEncodedError;
+use wasm_bindgen_futures::{future_to_promise, JsFuture};
+use wasm_bindgen::{
+    convert::{FromWasmAbi, IntoWasmAbi, OptionFromWasmAbi, OptionIntoWasmAbi},
+    describe::WasmDescribe,
+    JsValue,
+};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+use serde_wasm_bindgen::{from_value as from_js_value, preserve, to_value as to_js_value};
+
+/// A JS promise that resolves to a value of the given type, i.e. `Promise<T>` in TS, for
+/// the args and returning values that are promises, such as the returning value of a non
+/// async function that starts an async operation, or a callback's result that rust awaits.
+///
+/// It is passed through wasm bindgen as the promise itself, it awaits as the decoded value
+/// of the given type, where a rejection becomes the error, and it can be created from a rust
+/// future. It is serialized as the promise itself too (only supported by [serde_wasm_bindgen]),
+/// so it can be nested in other types, such as [crate::result::WasmEncodedResult] of the
+/// functions exported by [wasm_bindgen_utils_macros::wasm_export!], that declares it as
+/// `Promise<T>` in TS if the TS type of `T` is known.
+///
+/// Example:
+/// ```ignore
+/// #[wasm_export(js_name = "startSync")]
+/// pub fn start_sync(client: Client) -> Result<TypedPromise<u32>, Error> {
+///     Ok(TypedPromise::from_future(async move { client.sync().await }))
+/// }
+///
+/// #[wasm_export(js_name = "waitFor")]
+/// pub async fn wait_for(order: TypedPromise<Order>) -> Result<String, Error> {
+///     let order = order.await?;
+///     Ok(order.id)
+/// }
+/// ```
+pub struct TypedPromise<T> {
+    promise: Promise,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> TypedPromise<T> {
+    /// Creates a new instance from the given promise, it is assumed that the
+    /// promise resolves to a value of the given type which is checked when awaited
+    pub fn new(promise: Promise) -> Self {
+        Self {
+            promise,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the underlying promise
+    pub fn as_promise(&self) -> &Promise {
+        &self.promise
+    }
+
+    /// Consumes this instance and returns the underlying promise
+    pub fn into_promise(self) -> Promise {
+        self.promise
+    }
+}
+
+impl<T: Serialize + 'static> TypedPromise<T> {
+    /// Creates a promise that resolves to the encoded value of the given future once it
+    /// is done, or rejects with its error as [WasmEncodedError], the future is spawned on
+    /// the current thread right away through [wasm_bindgen_futures]
+    pub fn from_future<F, E>(future: F) -> Self
+    where
+        F: Future<Output = Result<T, E>> + 'static,
+        E: Into<WasmEncodedError>,
+    {
+        let promise = future_to_promise(async move {
+            let value = future
+                .await
+                .map_err(|err| JsValue::from(Into::<WasmEncodedError>::into(err)))?;
+            to_js_value(&value).map_err(|err| JsValue::from(WasmEncodedError::from(err)))
+        });
+        Self::new(promise)
+    }
+}
+
+impl<T> Clone for TypedPromise<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.promise.clone())
+    }
+}
+
+impl<T> fmt::Debug for TypedPromise<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TypedPromise").field(&self.promise).finish()
+    }
+}
+
+impl<T> From<Promise> for TypedPromise<T> {
+    fn from(value: Promise) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> From<TypedPromise<T>> for Promise {
+    fn from(value: TypedPromise<T>) -> Self {
+        value.promise
+    }
+}
+
+impl<T> From<TypedPromise<T>> for JsValue {
+    fn from(value: TypedPromise<T>) -> Self {
+        value.promise.into()
+    }
+}
+
+/// The future of a [TypedPromise] that resolves to its decoded value
+pub struct TypedPromiseFuture<T> {
+    future: JsFuture,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: DeserializeOwned> Future for TypedPromiseFuture<T> {
+    type Output = Result<T, WasmEncodedError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.future)
+            .poll(cx)
+            .map(|result| match result {
+                Ok(value) => from_js_value(value).map_err(WasmEncodedError::from),
+                // promises created by TypedPromise::from_future reject with an encoded error
+                Err(err) => Err(from_js_value(err.clone()).unwrap_or_else(|_| err.into())),
+            })
+    }
+}
+
+impl<T: DeserializeOwned> IntoFuture for TypedPromise<T> {
+    type Output = Result<T, WasmEncodedError>;
+    type IntoFuture = TypedPromiseFuture<T>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        TypedPromiseFuture {
+            future: JsFuture::from(self.promise),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Serialize for TypedPromise<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        preserve::serialize(&self.promise, serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for TypedPromise<T> {
+    /// Any JS value is accepted, promises and thenables are adopted as they
+    /// are and other values become promises that resolve to them
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value: JsValue = preserve::deserialize(deserializer)?;
+        Ok(Self::new(Promise::resolve(&value)))
+    }
+}
+
+impl<T> WasmDescribe for TypedPromise<T> {
+    #[inline]
+    fn describe() {
+        Promise::describe()
+    }
+}
+
+impl<T> IntoWasmAbi for TypedPromise<T> {
+    type Abi = <Promise as IntoWasmAbi>::Abi;
+
+    #[inline]
+    fn into_abi(self) -> Self::Abi {
+        self.promise.into_abi()
+    }
+}
+
+impl<T> OptionIntoWasmAbi for TypedPromise<T> {
+    #[inline]
+    fn none() -> Self::Abi {
+        <Promise as OptionIntoWasmAbi>::none()
+    }
+}
+
+impl<T> FromWasmAbi for TypedPromise<T> {
+    type Abi = <Promise as FromWasmAbi>::Abi;
+
+    #[inline]
+    unsafe fn from_abi(js: Self::Abi) -> Self {
+        Self::new(Promise::from_abi(js))
+    }
+}
+
+impl<T> OptionFromWasmAbi for TypedPromise<T> {
+    #[inline]
+    fn is_none(js: &Self::Abi) -> bool {
+        <Promise as OptionFromWasmAbi>::is_none(js)
+    }
+}
+
+#[cfg(target_family = "wasm")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen::JsCast;
+    use crate::result::WasmEncodedResult;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    async fn test_typed_promise_await() {
+        let promise = TypedPromise::<u8>::new(Promise::resolve(&JsValue::from(1)));
+        assert_eq!(promise.await.unwrap(), 1);
+
+        let promise = TypedPromise::<String>::new(Promise::resolve(&JsValue::from(1)));
+        let err = promise.await.unwrap_err();
+        assert!(err.msg.contains("invalid type"));
+
+        let promise = Promise::reject(&js_sys::Error::new("some error").into());
+        let err = TypedPromise::<u8>::from(promise).await.unwrap_err();
+        assert_eq!(err.msg, "Error: some error");
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_typed_promise_from_future() {
+        let promise = TypedPromise::from_future(async { Ok::<_, WasmEncodedError>(vec![1u8, 2]) });
+        assert_eq!(promise.clone().await.unwrap(), vec![1, 2]);
+
+        // resolves to the encoded value in JS
+        let value = JsFuture::from(promise.into_promise()).await.unwrap();
+        assert!(js_sys::Array::is_array(&value));
+
+        let error = WasmEncodedError::from("some error");
+        let err_clone = error.clone();
+        let promise = TypedPromise::<u8>::from_future(async move { Err(err_clone) });
+        assert_eq!(promise.await.unwrap_err(), error);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_typed_promise_serde() {
+        let promise = TypedPromise::<u8>::new(Promise::resolve(&JsValue::from(2)));
+        let result = WasmEncodedResult::success(promise);
+        let value = result.try_into_js_value().unwrap();
+        let inner = js_sys::Reflect::get(&value, &"value".into()).unwrap();
+        assert!(inner.is_instance_of::<Promise>());
+
+        let array = js_sys::Array::of1(&inner);
+        let promises: Vec<TypedPromise<u8>> = from_js_value(array.into()).unwrap();
+        assert_eq!(promises[0].clone().await.unwrap(), 2);
+
+        // plain values are accepted as resolved promises
+        let promise: TypedPromise<u8> = from_js_value(JsValue::from(3)).unwrap();
+        assert_eq!(promise.await.unwrap(), 3);
+    }
+}
@@ -1,24 +1,217 @@
 use tsify::Tsify;
+use std::error::Error;
 use crate::impl_wasm_traits;
-use serde::{Serialize, Deserialize};
+use serde::{de::DeserializeOwned, Serialize, Deserialize};
+use wasm_bindgen::{JsCast, JsValue};
 
 /// A struct that holds info of a rust error that is serializable
 /// natively to JS/TS through wasm bindgen, so [Result::Err] variants
 /// of binding functions can return normally in JS/TS instead of throwing.
 ///
 /// Rust errors should impl [Into] trait to this struct, handling how the
-/// the rust error would translate into this struct.
+/// the rust error would translate into this struct, conversions for common
+/// error types such as [JsValue], [js_sys::Error], [std::io::Error] and
+/// boxed errors are already included, and `anyhow` and `serde_json`
+/// features enable conversions for the error types of those crates.
+///
+/// It is non exhaustive, so more optional fields can be added without breaking
+/// the dependent crates, instances should be created by [WasmEncodedError::new]
+/// or the conversions rather than struct literals.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct WasmEncodedError {
     /// A short msg of the error, which usually is a direct
     /// conversion from rust error by `Display` or `Debug` traits
     pub msg: String,
     /// Contains the detailed human readable msg of the error
     pub readable_msg: String,
+    /// Optional info about the call site that produced the error, populated
+    /// by [wasm_bindgen_utils_macros::wasm_export!] `error_context` attribute
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<Box<WasmEncodedErrorContext>>,
+    /// Optional kind of the error for the errors that are produced by the
+    /// generated code rather than the rust function, e.g. `aborted` for the
+    /// calls that were cancelled by an `AbortSignal` or timed out
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<WasmEncodedErrorKind>,
 }
 impl_wasm_traits!(WasmEncodedError);
 
+/// Specifies the kind of a [WasmEncodedError] that is produced by the
+/// generated code of [wasm_bindgen_utils_macros::wasm_export!] rather than
+/// by the exported rust function itself.
+///
+/// It is non exhaustive, so more kinds can be added without breaking the
+/// dependent crates.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Tsify)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum WasmEncodedErrorKind {
+    /// The call was cancelled by its `AbortSignal` or its timeout elapsed
+    /// before it was done, see [crate::abort]
+    Aborted,
+}
+impl_wasm_traits!(WasmEncodedErrorKind);
+
+/// A struct that holds info of the call site of an exported function that
+/// produced a [WasmEncodedError], such as its rust path, js name and a
+/// redacted summary of its arguments, that is, only their names and types
+/// and not their values.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct WasmEncodedErrorContext {
+    /// The rust path of the function that produced the error
+    pub rust_path: String,
+    /// The name of the function that is exported to JS/TS
+    pub js_name: String,
+    /// Redacted summary of the function arguments
+    pub args: Vec<String>,
+}
+impl_wasm_traits!(WasmEncodedErrorContext);
+
+impl WasmEncodedErrorContext {
+    /// Creates a new instance from the given details
+    pub fn new(rust_path: impl Into<String>, js_name: &str, args: &[&str]) -> Self {
+        WasmEncodedErrorContext {
+            rust_path: rust_path.into(),
+            js_name: js_name.to_string(),
+            args: args.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+impl WasmEncodedError {
+    /// Creates a new instance from the given msgs without context and kind
+    pub fn new(msg: impl Into<String>, readable_msg: impl Into<String>) -> Self {
+        WasmEncodedError {
+            msg: msg.into(),
+            readable_msg: readable_msg.into(),
+            context: None,
+            kind: None,
+        }
+    }
+
+    /// Sets the given call site context of the error
+    pub fn with_context(mut self, context: WasmEncodedErrorContext) -> Self {
+        self.context = Some(Box::new(context));
+        self
+    }
+
+    /// Sets the given kind of the error
+    pub fn with_kind(mut self, kind: WasmEncodedErrorKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    /// Creates a new instance from the given rust error, where `msg` is its
+    /// [Display](std::fmt::Display) msg and `readable_msg` also includes the
+    /// msgs of its chain of sources
+    pub fn from_error<E: Error + ?Sized>(err: &E) -> Self {
+        let msg = err.to_string();
+        let mut readable_msg = msg.clone();
+        let mut source = err.source();
+        while let Some(err) = source {
+            readable_msg.push_str(&format!(": {err}"));
+            source = err.source();
+        }
+        WasmEncodedError::new(msg, readable_msg)
+    }
+}
+
+impl From<String> for WasmEncodedError {
+    fn from(value: String) -> Self {
+        WasmEncodedError::new(value.clone(), value)
+    }
+}
+
+impl From<&str> for WasmEncodedError {
+    fn from(value: &str) -> Self {
+        value.to_string().into()
+    }
+}
+
+impl From<std::io::Error> for WasmEncodedError {
+    fn from(value: std::io::Error) -> Self {
+        WasmEncodedError::from_error(&value)
+    }
+}
+
+impl From<std::fmt::Error> for WasmEncodedError {
+    fn from(value: std::fmt::Error) -> Self {
+        WasmEncodedError::from_error(&value)
+    }
+}
+
+impl From<Box<dyn Error>> for WasmEncodedError {
+    fn from(value: Box<dyn Error>) -> Self {
+        WasmEncodedError::from_error(value.as_ref())
+    }
+}
+
+impl From<Box<dyn Error + Send + Sync>> for WasmEncodedError {
+    fn from(value: Box<dyn Error + Send + Sync>) -> Self {
+        WasmEncodedError::from_error(value.as_ref())
+    }
+}
+
+impl From<js_sys::Error> for WasmEncodedError {
+    /// The `msg` is built from the `name` and `message` of the js error and
+    /// `readable_msg` is its `stack` if available, otherwise same as `msg`
+    fn from(value: js_sys::Error) -> Self {
+        let name = String::from(value.name());
+        let message = String::from(value.message());
+        let msg = if name.is_empty() {
+            message
+        } else {
+            format!("{name}: {message}")
+        };
+        let readable_msg = js_sys::Reflect::get(&value, &JsValue::from_str("stack"))
+            .ok()
+            .and_then(|stack| stack.as_string())
+            .filter(|stack| !stack.is_empty())
+            .unwrap_or_else(|| msg.clone());
+        WasmEncodedError::new(msg, readable_msg)
+    }
+}
+
+impl From<JsValue> for WasmEncodedError {
+    /// Delegates to [js_sys::Error] conversion if the value is an instance of
+    /// js `Error`, uses the value as is if it is a string and falls back to its
+    /// [Debug] formatting otherwise
+    fn from(value: JsValue) -> Self {
+        if let Some(msg) = value.as_string() {
+            return msg.into();
+        }
+        match value.dyn_into::<js_sys::Error>() {
+            Ok(err) => err.into(),
+            Err(value) => format!("{value:?}").into(),
+        }
+    }
+}
+
+impl From<serde_wasm_bindgen::Error> for WasmEncodedError {
+    fn from(value: serde_wasm_bindgen::Error) -> Self {
+        JsValue::from(value).into()
+    }
+}
+
+#[cfg(feature = "anyhow")]
+impl From<anyhow::Error> for WasmEncodedError {
+    /// The `msg` is the top level msg of the error and `readable_msg`
+    /// also includes the msgs of its chain of contexts and sources
+    fn from(value: anyhow::Error) -> Self {
+        WasmEncodedError::new(value.to_string(), format!("{value:#}"))
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl From<serde_json::Error> for WasmEncodedError {
+    fn from(value: serde_json::Error) -> Self {
+        WasmEncodedError::from_error(&value)
+    }
+}
+
 /// A generic result enum that holds info of a rust [Result] that is
 /// serializable natively to JS/TS through wasm bindgen, so binding
 /// functions can return it normally in JS/TS instead of throwing.
@@ -67,3 +260,628 @@ impl<T, E: Into<WasmEncodedError>> From<Result<T, E>> for WasmEncodedResult<T> {
         }
     }
 }
+
+/// A generic struct that holds a batch of [WasmEncodedResult] items along
+/// with summary counters, so bulk operations can report success or failure
+/// of each item separately instead of failing the whole call on first error.
+///
+/// Used in [wasm_bindgen_utils_macros::wasm_export!] as the returning
+/// type of exporting wasm binding functions that return [Vec] of [Result].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct WasmEncodedResults<T> {
+    /// The encoded result of each item in the same order as the original list
+    pub items: Vec<WasmEncodedResult<T>>,
+    /// Number of items that are success
+    pub success_count: u32,
+    /// Number of items that are error
+    pub error_count: u32,
+    /// The error of the whole call if it failed before producing any items, such
+    /// as failing to decode its arguments or being aborted, in which case `items`
+    /// is empty, it is not counted in `error_count`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<WasmEncodedError>,
+}
+impl_wasm_traits!(WasmEncodedResults<T>);
+
+impl<T> FromIterator<WasmEncodedResult<T>> for WasmEncodedResults<T> {
+    fn from_iter<I: IntoIterator<Item = WasmEncodedResult<T>>>(iter: I) -> Self {
+        let items: Vec<_> = iter.into_iter().collect();
+        let success_count = items
+            .iter()
+            .filter(|item| matches!(item, WasmEncodedResult::Success { .. }))
+            .count() as u32;
+        let error_count = items.len() as u32 - success_count;
+        WasmEncodedResults {
+            items,
+            success_count,
+            error_count,
+            error: None,
+        }
+    }
+}
+
+impl<T, E: Into<WasmEncodedError>> From<Vec<Result<T, E>>> for WasmEncodedResults<T> {
+    fn from(results: Vec<Result<T, E>>) -> Self {
+        results.into_iter().map(WasmEncodedResult::from).collect()
+    }
+}
+
+impl<T> From<EncodedBatch<T>> for WasmEncodedResults<T> {
+    fn from(batch: EncodedBatch<T>) -> Self {
+        let mut results = Self::from(batch.items);
+        results.error = batch.error;
+        results
+    }
+}
+
+/// A batch of results of an exported function that returns [Vec] of [Result], with
+/// their errors converted to [WasmEncodedError], that also holds the error of the
+/// whole call if it failed before producing any items, so it can be told apart from
+/// the error of a single item, it is exported as [WasmEncodedResults].
+///
+/// Used in [wasm_bindgen_utils_macros::wasm_export!] generated functions as the
+/// [EncodeErrors::Encoded] type of [Vec] of [Result].
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncodedBatch<T> {
+    /// The results of the items in the same order as the original list
+    pub items: Vec<Result<T, WasmEncodedError>>,
+    /// The error of the whole call, if any
+    pub error: Option<WasmEncodedError>,
+}
+
+/// Trait for the returning types of exported functions that converts all of
+/// their errors into [WasmEncodedError] while allowing to modify each of them
+/// through the given callbacks, for example to attach a call site context, the
+/// errors that the call failed with are passed to `on_error` and the non-fatal
+/// ones, i.e. the warnings of [Diagnosed], are passed to `on_warning`.
+///
+/// Used in [wasm_bindgen_utils_macros::wasm_export!] generated functions.
+pub trait EncodeErrors {
+    /// The same type with its errors converted to [WasmEncodedError]
+    type Encoded;
+    /// Converts the errors into [WasmEncodedError] and calls `on_error` for each
+    /// of the errors and `on_warning` for each of the warnings
+    fn encode_errors<F, W>(self, on_error: F, on_warning: W) -> Self::Encoded
+    where
+        F: FnMut(&mut WasmEncodedError),
+        W: FnMut(&mut WasmEncodedError);
+}
+
+impl<T, E: Into<WasmEncodedError>> EncodeErrors for Result<T, E> {
+    type Encoded = Result<T, WasmEncodedError>;
+    fn encode_errors<F, W>(self, mut on_error: F, _on_warning: W) -> Self::Encoded
+    where
+        F: FnMut(&mut WasmEncodedError),
+        W: FnMut(&mut WasmEncodedError),
+    {
+        self.map_err(|err| {
+            let mut err = err.into();
+            on_error(&mut err);
+            err
+        })
+    }
+}
+
+impl<T, E: Into<WasmEncodedError>> EncodeErrors for Vec<Result<T, E>> {
+    type Encoded = EncodedBatch<T>;
+    fn encode_errors<F, W>(self, mut on_error: F, _on_warning: W) -> Self::Encoded
+    where
+        F: FnMut(&mut WasmEncodedError),
+        W: FnMut(&mut WasmEncodedError),
+    {
+        EncodedBatch {
+            items: self
+                .into_iter()
+                .map(|result| result.encode_errors(&mut on_error, |_| {}))
+                .collect(),
+            error: None,
+        }
+    }
+}
+
+/// Trait for the returning types of exported functions that maps their success
+/// values by the given function, for example to convert an internal type into a
+/// JS friendly one.
+///
+/// Used in [wasm_bindgen_utils_macros::wasm_export!] generated functions for
+/// the functions with `map_ok` attribute.
+pub trait MapOk<T, U> {
+    /// The same type with its success values mapped to `U`
+    type Mapped;
+    /// Maps the success values by the given function
+    fn map_ok<F: FnMut(T) -> U>(self, f: F) -> Self::Mapped;
+}
+
+impl<T, U, E> MapOk<T, U> for Result<T, E> {
+    type Mapped = Result<U, E>;
+    fn map_ok<F: FnMut(T) -> U>(self, f: F) -> Self::Mapped {
+        self.map(f)
+    }
+}
+
+impl<T, U, E> MapOk<T, U> for Vec<Result<T, E>> {
+    type Mapped = Vec<Result<U, E>>;
+    fn map_ok<F: FnMut(T) -> U>(self, mut f: F) -> Self::Mapped {
+        self.into_iter().map(|result| result.map(&mut f)).collect()
+    }
+}
+
+impl<T, U, E> MapOk<T, U> for Diagnosed<T, E> {
+    type Mapped = Diagnosed<U, E>;
+    fn map_ok<F: FnMut(T) -> U>(self, f: F) -> Self::Mapped {
+        Diagnosed {
+            result: self.result.map(f),
+            warnings: self.warnings,
+        }
+    }
+}
+
+/// Trait for the returning types of exported functions with their errors already
+/// converted into [WasmEncodedError], i.e. [EncodeErrors::Encoded], that can be
+/// built from a single error, for errors that occur before the function is called
+/// such as failing to decode its arguments.
+///
+/// Used in [wasm_bindgen_utils_macros::wasm_export!] generated functions.
+pub trait FromEncodedError {
+    /// Creates an instance that only holds the given error
+    fn from_encoded_error(err: WasmEncodedError) -> Self;
+}
+
+impl<T> FromEncodedError for Result<T, WasmEncodedError> {
+    fn from_encoded_error(err: WasmEncodedError) -> Self {
+        Err(err)
+    }
+}
+
+impl<T> FromEncodedError for EncodedBatch<T> {
+    /// An empty batch with the given error as the error of the whole call
+    fn from_encoded_error(err: WasmEncodedError) -> Self {
+        EncodedBatch {
+            items: vec![],
+            error: Some(err),
+        }
+    }
+}
+
+impl<T> FromEncodedError for Diagnosed<T, WasmEncodedError> {
+    fn from_encoded_error(err: WasmEncodedError) -> Self {
+        Diagnosed::err(err)
+    }
+}
+
+/// Decodes the given items of a variadic (rest) parameter of an exported function
+/// one by one, so that the returning error points to the index of each of the items
+/// that failed to decode, in which case `msg` lists the failed indexes and
+/// `readable_msg` also includes the decoding error of each of them.
+///
+/// Used in [wasm_bindgen_utils_macros::wasm_export!] generated functions.
+pub fn decode_variadic<T: DeserializeOwned>(
+    name: &str,
+    items: Vec<JsValue>,
+) -> Result<Vec<T>, WasmEncodedError> {
+    let mut decoded = Vec::with_capacity(items.len());
+    let mut indexes = vec![];
+    let mut errors = vec![];
+    for (index, item) in items.into_iter().enumerate() {
+        match serde_wasm_bindgen::from_value(item) {
+            Ok(value) => decoded.push(value),
+            Err(err) => {
+                indexes.push(index.to_string());
+                errors.push(format!("{name}[{index}]: {err}"));
+            }
+        }
+    }
+    if errors.is_empty() {
+        return Ok(decoded);
+    }
+    Err(WasmEncodedError::new(
+        format!(
+            "failed to decode `{name}` arguments at index {}",
+            indexes.join(", ")
+        ),
+        errors.join("; "),
+    ))
+}
+
+/// Converts the given JS value of a parameter of an exported function by the given
+/// conversion function, the value is first decoded into the input type of the function
+/// and if either of decoding or conversion fails, the returning error names the param.
+///
+/// Used in [wasm_bindgen_utils_macros::wasm_export!] generated functions for the
+/// params with `from_js` attribute.
+pub fn convert_from_js<I: DeserializeOwned, T, E: Into<WasmEncodedError>>(
+    name: &str,
+    value: JsValue,
+    convert: impl FnOnce(I) -> Result<T, E>,
+) -> Result<T, WasmEncodedError> {
+    let result = serde_wasm_bindgen::from_value(value)
+        .map_err(WasmEncodedError::from)
+        .and_then(|value| convert(value).map_err(Into::into));
+    result.map_err(|mut err| {
+        err.msg = format!("invalid `{name}` argument: {}", err.msg);
+        err.readable_msg = format!("invalid `{name}` argument: {}", err.readable_msg);
+        err
+    })
+}
+
+/// A rust type that holds the outcome of an operation together with the
+/// non-fatal diagnostics (warnings) that were collected while producing
+/// it, so an operation can succeed with warnings or fail with several
+/// diagnostics at once.
+///
+/// Used in [wasm_bindgen_utils_macros::wasm_export!] as an alternative
+/// to [Result] for the returning type of exporting functions, in which
+/// case it is exported as [WasmEncodedDiagnosedResult].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnosed<T, E> {
+    /// The outcome of the operation
+    pub result: Result<T, E>,
+    /// Non-fatal diagnostics collected along the way
+    pub warnings: Vec<E>,
+}
+
+impl<T, E> Diagnosed<T, E> {
+    /// Creates a new instance from the given result and warnings
+    pub fn new(result: Result<T, E>, warnings: Vec<E>) -> Self {
+        Diagnosed { result, warnings }
+    }
+    /// Creates a success instance from the given value without any warnings
+    pub fn ok(value: T) -> Self {
+        Diagnosed::new(Ok(value), vec![])
+    }
+    /// Creates an error instance from the given error without any warnings
+    pub fn err(error: E) -> Self {
+        Diagnosed::new(Err(error), vec![])
+    }
+    /// Appends the given warnings to this instance
+    pub fn with_warnings(mut self, warnings: impl IntoIterator<Item = E>) -> Self {
+        self.warnings.extend(warnings);
+        self
+    }
+}
+
+impl<T, E> From<Result<T, E>> for Diagnosed<T, E> {
+    fn from(result: Result<T, E>) -> Self {
+        Diagnosed::new(result, vec![])
+    }
+}
+
+/// Same as [WasmEncodedResult] but also carries the warnings of a
+/// [Diagnosed] result in its `warnings` field, serializable natively
+/// to JS/TS through wasm bindgen.
+///
+/// Used in [wasm_bindgen_utils_macros::wasm_export!] as the returning
+/// type of exporting wasm binding functions that return [Diagnosed].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(untagged)]
+pub enum WasmEncodedDiagnosedResult<T> {
+    /// Success variant that contains an instance of T in `value`
+    /// field with a [Option::None] `error` field
+    Success {
+        value: T,
+        #[tsify(type = "undefined")]
+        error: Option<WasmEncodedError>,
+        warnings: Vec<WasmEncodedError>,
+    },
+    /// Error variant that contains an instance of [WasmEncodedError]
+    /// in `error` field with a [Option::None] `value` field
+    Err {
+        #[tsify(type = "undefined")]
+        value: Option<T>,
+        error: WasmEncodedError,
+        warnings: Vec<WasmEncodedError>,
+    },
+}
+impl_wasm_traits!(WasmEncodedDiagnosedResult<T>);
+
+impl<T> WasmEncodedDiagnosedResult<T> {
+    /// Creates a success instance from the given value and warnings
+    pub fn success<W: Into<WasmEncodedError>>(value: T, warnings: Vec<W>) -> Self {
+        WasmEncodedDiagnosedResult::Success {
+            value,
+            error: None,
+            warnings: warnings.into_iter().map(Into::into).collect(),
+        }
+    }
+    /// Creates an error instance from the given error and warnings
+    pub fn error<E: Into<WasmEncodedError>, W: Into<WasmEncodedError>>(
+        err: E,
+        warnings: Vec<W>,
+    ) -> Self {
+        WasmEncodedDiagnosedResult::Err {
+            value: None,
+            error: err.into(),
+            warnings: warnings.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<T, E: Into<WasmEncodedError>> From<Diagnosed<T, E>> for WasmEncodedDiagnosedResult<T> {
+    fn from(diagnosed: Diagnosed<T, E>) -> Self {
+        match diagnosed.result {
+            Ok(value) => Self::success(value, diagnosed.warnings),
+            Err(err) => Self::error(err, diagnosed.warnings),
+        }
+    }
+}
+
+impl<T, E: Into<WasmEncodedError>> EncodeErrors for Diagnosed<T, E> {
+    type Encoded = Diagnosed<T, WasmEncodedError>;
+    fn encode_errors<F, W>(self, on_error: F, mut on_warning: W) -> Self::Encoded
+    where
+        F: FnMut(&mut WasmEncodedError),
+        W: FnMut(&mut WasmEncodedError),
+    {
+        Diagnosed {
+            result: self.result.encode_errors(on_error, |_| {}),
+            warnings: self
+                .warnings
+                .into_iter()
+                .map(|warning| {
+                    let mut warning = warning.into();
+                    on_warning(&mut warning);
+                    warning
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(target_family = "wasm")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[derive(Debug)]
+    struct SourceError;
+    impl std::fmt::Display for SourceError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "source error")
+        }
+    }
+    impl Error for SourceError {}
+
+    #[derive(Debug)]
+    struct TopError(SourceError);
+    impl std::fmt::Display for TopError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "top error")
+        }
+    }
+    impl Error for TopError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_new() {
+        let context = WasmEncodedErrorContext::new("some::path", "someFn", &["arg: u8"]);
+        let err = WasmEncodedError::new("msg", "readable msg");
+        assert_eq!(err.msg, "msg");
+        assert_eq!(err.readable_msg, "readable msg");
+        assert_eq!(err.context, None);
+        assert_eq!(err.kind, None);
+
+        let err = err
+            .with_context(context.clone())
+            .with_kind(WasmEncodedErrorKind::Aborted);
+        assert_eq!(err.context, Some(Box::new(context)));
+        assert_eq!(err.kind, Some(WasmEncodedErrorKind::Aborted));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_from_rust_errors() {
+        let result = WasmEncodedError::from("some msg");
+        assert_eq!(result.msg, "some msg");
+        assert_eq!(result.readable_msg, "some msg");
+
+        let result = WasmEncodedError::from("some msg".to_string());
+        assert_eq!(result.msg, "some msg");
+        assert_eq!(result.readable_msg, "some msg");
+
+        let result = WasmEncodedError::from(std::fmt::Error);
+        assert_eq!(result.msg, std::fmt::Error.to_string());
+        assert_eq!(result.readable_msg, std::fmt::Error.to_string());
+
+        let err = std::io::Error::other(TopError(SourceError));
+        let result = WasmEncodedError::from(err);
+        assert_eq!(result.msg, "top error");
+        assert_eq!(result.readable_msg, "top error: source error");
+
+        let err: Box<dyn Error> = Box::new(TopError(SourceError));
+        let result = WasmEncodedError::from(err);
+        assert_eq!(result.msg, "top error");
+        assert_eq!(result.readable_msg, "top error: source error");
+
+        let err: Box<dyn Error + Send + Sync> = "boxed msg".into();
+        let result = WasmEncodedError::from(err);
+        assert_eq!(result.msg, "boxed msg");
+        assert_eq!(result.readable_msg, "boxed msg");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_from_js_errors() {
+        let err = js_sys::Error::new("some msg");
+        let stack = js_sys::Reflect::get(&err, &JsValue::from_str("stack"))
+            .unwrap()
+            .as_string()
+            .unwrap_or("Error: some msg".to_string());
+        let result = WasmEncodedError::from(err.clone());
+        assert_eq!(result.msg, "Error: some msg");
+        assert_eq!(result.readable_msg, stack);
+
+        let result = WasmEncodedError::from(JsValue::from(err));
+        assert_eq!(result.msg, "Error: some msg");
+        assert_eq!(result.readable_msg, stack);
+
+        let result = WasmEncodedError::from(JsValue::from_str("some msg"));
+        assert_eq!(result.msg, "some msg");
+        assert_eq!(result.readable_msg, "some msg");
+
+        let result = WasmEncodedError::from(JsValue::from_f64(1.5));
+        assert_eq!(result.msg, "JsValue(1.5)");
+        assert_eq!(result.readable_msg, "JsValue(1.5)");
+
+        let err = serde_wasm_bindgen::from_value::<u8>(JsValue::from_str("abcd")).unwrap_err();
+        let result = WasmEncodedError::from(err);
+        assert!(result.msg.starts_with("Error: "));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encode_errors() {
+        let context = WasmEncodedErrorContext::new("some::path", "someFn", &["arg: u8"]);
+        let set_context =
+            |err: &mut WasmEncodedError| err.context = Some(Box::new(context.clone()));
+        let mut expected_err = WasmEncodedError::from("err");
+        expected_err.context = Some(Box::new(context.clone()));
+
+        let result: Result<u8, &str> = Err("err");
+        assert_eq!(
+            result.encode_errors(set_context, |_| {}),
+            Err(expected_err.clone())
+        );
+
+        let result: Result<u8, &str> = Ok(1);
+        assert_eq!(result.encode_errors(set_context, |_| {}), Ok(1));
+
+        let results: Vec<Result<u8, &str>> = vec![Ok(1), Err("err")];
+        assert_eq!(
+            results.encode_errors(set_context, |_| {}),
+            EncodedBatch {
+                items: vec![Ok(1), Err(expected_err.clone())],
+                error: None,
+            }
+        );
+
+        let diagnosed: Diagnosed<u8, &str> = Diagnosed::ok(1).with_warnings(["err"]);
+        let result = diagnosed.encode_errors(set_context, set_context);
+        assert_eq!(result.result, Ok(1));
+        assert_eq!(result.warnings, vec![expected_err.clone()]);
+
+        // the warnings are only passed to on_warning
+        let mut errors = vec![];
+        let mut warnings = vec![];
+        let diagnosed: Diagnosed<u8, &str> = Diagnosed::err("err").with_warnings(["warning"]);
+        let result = diagnosed.encode_errors(
+            |err| errors.push(err.msg.clone()),
+            |err| warnings.push(err.msg.clone()),
+        );
+        assert_eq!(result.result, Err(WasmEncodedError::from("err")));
+        assert_eq!(result.warnings, vec![WasmEncodedError::from("warning")]);
+        assert_eq!(errors, vec!["err"]);
+        assert_eq!(warnings, vec!["warning"]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_map_ok() {
+        let result: Result<u8, &str> = Ok(1);
+        assert_eq!(result.map_ok(|v| v.to_string()), Ok("1".to_string()));
+
+        let result: Result<u8, &str> = Err("err");
+        assert_eq!(result.map_ok(|v| v.to_string()), Err("err"));
+
+        let results: Vec<Result<u8, &str>> = vec![Ok(1), Err("err")];
+        assert_eq!(
+            results.map_ok(|v| v.to_string()),
+            vec![Ok("1".to_string()), Err("err")]
+        );
+
+        let diagnosed: Diagnosed<u8, &str> = Diagnosed::ok(1).with_warnings(["warn"]);
+        let result = diagnosed.map_ok(|v| v.to_string());
+        assert_eq!(result.result, Ok("1".to_string()));
+        assert_eq!(result.warnings, vec!["warn"]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_from_encoded_error() {
+        let err = WasmEncodedError::from("err");
+
+        let result: Result<u8, _> = FromEncodedError::from_encoded_error(err.clone());
+        assert_eq!(result, Err(err.clone()));
+
+        let batch: EncodedBatch<u8> = FromEncodedError::from_encoded_error(err.clone());
+        assert!(batch.items.is_empty());
+        assert_eq!(batch.error, Some(err.clone()));
+
+        let diagnosed: Diagnosed<u8, _> = FromEncodedError::from_encoded_error(err.clone());
+        assert_eq!(diagnosed, Diagnosed::err(err));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_decode_variadic() {
+        let items = vec![JsValue::from_f64(1.0), JsValue::from_f64(2.0)];
+        let result = decode_variadic::<u8>("items", items).unwrap();
+        assert_eq!(result, vec![1, 2]);
+
+        let result = decode_variadic::<u8>("items", vec![]).unwrap();
+        assert!(result.is_empty());
+
+        let items = vec![
+            JsValue::from_str("a"),
+            JsValue::from_f64(2.0),
+            JsValue::from_str("b"),
+        ];
+        let err = decode_variadic::<u8>("items", items).unwrap_err();
+        assert_eq!(err.msg, "failed to decode `items` arguments at index 0, 2");
+        let expected = |v: &str| {
+            let err = serde_wasm_bindgen::from_value::<u8>(JsValue::from_str(v)).unwrap_err();
+            err.to_string()
+        };
+        assert_eq!(
+            err.readable_msg,
+            format!("items[0]: {}; items[2]: {}", expected("a"), expected("b"))
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_convert_from_js() {
+        let parse = |value: String| value.parse::<u8>().map_err(|e| e.to_string());
+
+        let result = convert_from_js("someArg", JsValue::from_str("12"), parse);
+        assert_eq!(result, Ok(12));
+
+        let err = convert_from_js("someArg", JsValue::from_str("abcd"), parse).unwrap_err();
+        assert_eq!(
+            err.msg,
+            "invalid `someArg` argument: invalid digit found in string"
+        );
+        assert_eq!(err.readable_msg, err.msg);
+
+        let err = convert_from_js("someArg", JsValue::from_f64(1.0), parse).unwrap_err();
+        assert!(err.msg.starts_with("invalid `someArg` argument: Error: "));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encoded_results() {
+        let results: Vec<Result<u8, &str>> = vec![Ok(1), Err("err"), Ok(2)];
+        let result = WasmEncodedResults::from(results);
+        assert_eq!(result.success_count, 2);
+        assert_eq!(result.error_count, 1);
+        assert_eq!(
+            result.items,
+            vec![
+                WasmEncodedResult::success(1),
+                WasmEncodedResult::error("err"),
+                WasmEncodedResult::success(2),
+            ]
+        );
+        assert_eq!(result.error, None);
+
+        // the error of the whole call
+        let err = WasmEncodedError::from("err");
+        let result = WasmEncodedResults::from(EncodedBatch::<u8>::from_encoded_error(err.clone()));
+        assert!(result.items.is_empty());
+        assert_eq!(result.success_count, 0);
+        assert_eq!(result.error_count, 0);
+        assert_eq!(result.error, Some(err));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_from_js_error_to_encoded_result() {
+        let result: WasmEncodedResult<u8> = Err::<u8, _>(JsValue::from_str("some msg")).into();
+        assert_eq!(result, WasmEncodedResult::error("some msg"));
+    }
+}
@@ -0,0 +1,469 @@
+use futures_core::Stream;
+use js_sys::{Function, Object, Promise, Reflect, Symbol};
+use std::{
+    cell::RefCell,
+    fmt,
+    future::poll_fn,
+    pin::Pin,
+    rc::Rc,
+    task::{Context, Poll},
+};
+use crate::result::{FromEncodedError, WasmEncodedError};
+use wasm_bindgen_futures::{future_to_promise, JsFuture};
+use wasm_bindgen::{
+    closure::Closure,
+    convert::{IntoWasmAbi, OptionIntoWasmAbi},
+    describe::WasmDescribe,
+    JsValue,
+};
+use serde::Serialize;
+
+/// A stream that is polled by calling the given fn, used to build the adapters of
+/// [ExportStream], the fn is never pinned so it does not need to be [Unpin] itself
+struct PollFn<F>(F);
+
+impl<F> Unpin for PollFn<F> {}
+
+impl<I, F: FnMut(&mut Context<'_>) -> Poll<Option<I>>> Stream for PollFn<F> {
+    type Item = I;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<I>> {
+        (self.0)(cx)
+    }
+}
+
+/// A boxed stream of results that is returned by an exported function whose returning type
+/// is `impl Stream<Item = Result<T, E>>`, it provides the same adapters for its items that
+/// [crate::result::EncodeErrors] and [crate::result::MapOk] provide for other returning
+/// types, but they are applied lazily as the items are polled, and it is converted into a
+/// [JsAsyncIterator] once it is returned.
+///
+/// Used in [wasm_bindgen_utils_macros::wasm_export!] generated functions.
+pub struct ExportStream<T, E> {
+    stream: Pin<Box<dyn Stream<Item = Result<T, E>>>>,
+}
+
+impl<T, E> ExportStream<T, E> {
+    /// Creates a new instance from the given stream
+    pub fn new<S: Stream<Item = Result<T, E>> + 'static>(stream: S) -> Self {
+        Self {
+            stream: Box::pin(stream),
+        }
+    }
+}
+
+impl<T: 'static, E: 'static> ExportStream<T, E> {
+    /// Converts the errors of the items into [WasmEncodedError] and calls `on_error` for
+    /// each of them as they are polled, the items have no warnings so `on_warning` is never
+    /// called, it is taken for the same signature as [crate::result::EncodeErrors]
+    pub fn encode_errors<F, W>(
+        self,
+        mut on_error: F,
+        _on_warning: W,
+    ) -> ExportStream<T, WasmEncodedError>
+    where
+        E: Into<WasmEncodedError>,
+        F: FnMut(&mut WasmEncodedError) + 'static,
+        W: FnMut(&mut WasmEncodedError),
+    {
+        let mut stream = self.stream;
+        ExportStream::new(PollFn(move |cx: &mut Context<'_>| {
+            stream.as_mut().poll_next(cx).map(|item| {
+                item.map(|result| {
+                    result.map_err(|err| {
+                        let mut err = err.into();
+                        on_error(&mut err);
+                        err
+                    })
+                })
+            })
+        }))
+    }
+
+    /// Maps the success values of the items by the given function as they are polled
+    pub fn map_ok<U, F: FnMut(T) -> U + 'static>(self, mut f: F) -> ExportStream<U, E> {
+        let mut stream = self.stream;
+        ExportStream::new(PollFn(move |cx: &mut Context<'_>| {
+            stream
+                .as_mut()
+                .poll_next(cx)
+                .map(|item| item.map(|result| result.map(&mut f)))
+        }))
+    }
+}
+
+impl<T, E> Stream for ExportStream<T, E> {
+    type Item = Result<T, E>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.stream.as_mut().poll_next(cx)
+    }
+}
+
+impl<T: 'static> FromEncodedError for ExportStream<T, WasmEncodedError> {
+    /// A stream that only yields the given error
+    fn from_encoded_error(err: WasmEncodedError) -> Self {
+        let mut err = Some(err);
+        ExportStream::new(PollFn(move |_: &mut Context<'_>| {
+            Poll::Ready(err.take().map(Err))
+        }))
+    }
+}
+
+impl<T, E> fmt::Debug for ExportStream<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExportStream").finish_non_exhaustive()
+    }
+}
+
+/// The shared state of a [JsAsyncIterator] between its `next` and `return` methods
+struct IteratorState {
+    /// The stream of the encoded items, it is dropped once it ends or the consumer stops early
+    stream: Option<Pin<Box<dyn Stream<Item = JsValue>>>>,
+    /// The promise of the last `next` call, so the calls are queued and polled one at a time
+    pending: Option<Promise>,
+}
+
+/// A JS object that implements the async iterator protocol, i.e. `AsyncIterableIterator<T>`
+/// in TS, for the exported functions whose returning type is a rust stream, so it can be
+/// consumed with `for await...of` in JS.
+///
+/// Each of the items of the stream is encoded as [crate::result::WasmEncodedResult] once it
+/// is yielded, its `next()` calls are queued so the stream is polled by one of them at a time,
+/// and the stream is dropped once it ends or the consumer stops early by calling `return()`,
+/// e.g. by breaking out of a `for await...of` loop, so its resources are cleaned up right away
+/// rather than when the object is garbage collected.
+///
+/// Functions exported by [wasm_bindgen_utils_macros::wasm_export!] return it for their
+/// `impl Stream<Item = Result<T, E>>` returning types, declared as
+/// `AsyncIterableIterator<WasmEncodedResult<T>>` in TS.
+///
+/// Example:
+/// ```ignore
+/// #[wasm_export(js_name = "scanLogs", unchecked_return_type = "Log")]
+/// pub fn scan_logs(from: u64) -> impl Stream<Item = Result<Log, Error>> {
+///     LogScanner::new(from)
+/// }
+/// ```
+/// ```js
+/// for await (const { value, error } of scanLogs(0n)) {
+///     if (error) break;
+///     console.log(value);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct JsAsyncIterator {
+    obj: Object,
+}
+
+impl JsAsyncIterator {
+    /// Creates a new instance from the given stream, where each of its items is serialized
+    /// as [crate::result::WasmEncodedResult] through [serde_wasm_bindgen] as it is yielded
+    pub fn from_stream<S, T, E>(stream: S) -> Self
+    where
+        S: Stream<Item = Result<T, E>> + 'static,
+        T: Serialize + 'static,
+        E: Into<WasmEncodedError> + 'static,
+    {
+        Self::from_js_stream(
+            ExportStream::new(stream).map_ok(|value| {
+                serde_wasm_bindgen::to_value(&value).map_err(WasmEncodedError::from)
+            }),
+        )
+    }
+
+    /// Same as [JsAsyncIterator::from_stream] but the success values are converted into JS
+    /// values as they are rather than serialized, so JS class instances are preserved
+    pub fn from_stream_preserved<S, T, E>(stream: S) -> Self
+    where
+        S: Stream<Item = Result<T, E>> + 'static,
+        T: Into<JsValue> + 'static,
+        E: Into<WasmEncodedError> + 'static,
+    {
+        Self::from_js_stream(ExportStream::new(stream).map_ok(|value| Ok(value.into())))
+    }
+
+    /// Returns the underlying JS object
+    pub fn as_object(&self) -> &Object {
+        &self.obj
+    }
+
+    /// Builds the JS object from the given stream of values that are already converted
+    /// into JS values, or the errors of their conversion
+    fn from_js_stream<E: Into<WasmEncodedError> + 'static>(
+        stream: ExportStream<Result<JsValue, WasmEncodedError>, E>,
+    ) -> Self {
+        let mut stream = stream.stream;
+        let stream = PollFn(move |cx: &mut Context<'_>| {
+            stream.as_mut().poll_next(cx).map(|item| {
+                item.map(|result| {
+                    let result = result.map_err(Into::into).and_then(|value| value);
+                    let (value, error) = match result {
+                        Ok(value) => (value, JsValue::UNDEFINED),
+                        Err(error) => (JsValue::UNDEFINED, error.into()),
+                    };
+                    let obj = Object::new();
+                    Reflect::set(&obj, &JsValue::from_str("value"), &value).unwrap();
+                    Reflect::set(&obj, &JsValue::from_str("error"), &error).unwrap();
+                    JsValue::from(obj)
+                })
+            })
+        });
+        let state = Rc::new(RefCell::new(IteratorState {
+            stream: Some(Box::pin(stream)),
+            pending: None,
+        }));
+
+        let next_state = state.clone();
+        let next = Closure::<dyn FnMut() -> Promise>::new(move || {
+            let state = next_state.clone();
+            let previous = state.borrow_mut().pending.take();
+            let promise = future_to_promise(async move {
+                if let Some(previous) = previous {
+                    let _ = JsFuture::from(previous).await;
+                }
+                let item = poll_fn(|cx| match state.borrow_mut().stream.as_mut() {
+                    Some(stream) => stream.as_mut().poll_next(cx),
+                    None => Poll::Ready(None),
+                })
+                .await;
+                if item.is_none() {
+                    // take it out first, so the state is not borrowed while it is dropped
+                    let stream = state.borrow_mut().stream.take();
+                    drop(stream);
+                }
+                Ok(Self::iterator_result(item))
+            });
+            next_state.borrow_mut().pending = Some(promise.clone());
+            promise
+        });
+
+        let return_ = Closure::<dyn FnMut(JsValue) -> Promise>::new(move |value: JsValue| {
+            let stream = state.borrow_mut().stream.take();
+            drop(stream);
+            let result = Self::iterator_result(None);
+            Reflect::set(&result, &JsValue::from_str("value"), &value).unwrap();
+            Promise::resolve(&result)
+        });
+
+        // "Reflect::set" can only fail if the obj is sealed or frozen which is not the case here
+        let obj = Object::new();
+        Reflect::set(&obj, &JsValue::from_str("next"), &next.into_js_value()).unwrap();
+        Reflect::set(&obj, &JsValue::from_str("return"), &return_.into_js_value()).unwrap();
+        Reflect::set(
+            &obj,
+            &Symbol::async_iterator(),
+            &Function::new_no_args("return this"),
+        )
+        .unwrap();
+        Self { obj }
+    }
+
+    /// Builds the result object of the async iterator protocol for the given item,
+    /// that is, `{ value, done: false }` or `{ value: undefined, done: true }`
+    fn iterator_result(item: Option<JsValue>) -> JsValue {
+        let obj = Object::new();
+        let done = item.is_none();
+        let value = item.unwrap_or(JsValue::UNDEFINED);
+        Reflect::set(&obj, &JsValue::from_str("value"), &value).unwrap();
+        Reflect::set(&obj, &JsValue::from_str("done"), &JsValue::from_bool(done)).unwrap();
+        obj.into()
+    }
+}
+
+impl<T: Serialize + 'static> From<ExportStream<T, WasmEncodedError>> for JsAsyncIterator {
+    fn from(value: ExportStream<T, WasmEncodedError>) -> Self {
+        Self::from_stream(value)
+    }
+}
+
+impl fmt::Debug for JsAsyncIterator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("JsAsyncIterator").field(&self.obj).finish()
+    }
+}
+
+impl From<JsAsyncIterator> for Object {
+    fn from(value: JsAsyncIterator) -> Self {
+        value.obj
+    }
+}
+
+impl From<JsAsyncIterator> for JsValue {
+    fn from(value: JsAsyncIterator) -> Self {
+        value.obj.into()
+    }
+}
+
+impl WasmDescribe for JsAsyncIterator {
+    #[inline]
+    fn describe() {
+        Object::describe()
+    }
+}
+
+impl IntoWasmAbi for JsAsyncIterator {
+    type Abi = <Object as IntoWasmAbi>::Abi;
+
+    #[inline]
+    fn into_abi(self) -> Self::Abi {
+        self.obj.into_abi()
+    }
+}
+
+impl OptionIntoWasmAbi for JsAsyncIterator {
+    #[inline]
+    fn none() -> Self::Abi {
+        <Object as OptionIntoWasmAbi>::none()
+    }
+}
+
+#[cfg(target_family = "wasm")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::Cell, rc::Rc};
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    /// A stream of the given items that sets the given flag once it is dropped
+    struct Items {
+        items: std::vec::IntoIter<Result<u8, WasmEncodedError>>,
+        dropped: Rc<Cell<bool>>,
+    }
+
+    impl Stream for Items {
+        type Item = Result<u8, WasmEncodedError>;
+
+        fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            Poll::Ready(self.items.next())
+        }
+    }
+
+    impl Drop for Items {
+        fn drop(&mut self) {
+            self.dropped.set(true);
+        }
+    }
+
+    fn items(items: Vec<Result<u8, WasmEncodedError>>) -> (Items, Rc<Cell<bool>>) {
+        let dropped = Rc::new(Cell::new(false));
+        let items = Items {
+            items: items.into_iter(),
+            dropped: dropped.clone(),
+        };
+        (items, dropped)
+    }
+
+    async fn next_item<S: Stream + Unpin>(stream: &mut S) -> Option<S::Item> {
+        poll_fn(|cx| Pin::new(&mut *stream).poll_next(cx)).await
+    }
+
+    async fn call_method(iter: &JsAsyncIterator, name: &str, arg: &JsValue) -> JsValue {
+        let method: Function = Reflect::get(iter.as_object(), &JsValue::from_str(name))
+            .unwrap()
+            .unchecked_into();
+        let promise: Promise = method
+            .call1(iter.as_object(), arg)
+            .unwrap()
+            .unchecked_into();
+        JsFuture::from(promise).await.unwrap()
+    }
+
+    fn get(value: &JsValue, key: &str) -> JsValue {
+        Reflect::get(value, &JsValue::from_str(key)).unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_export_stream_adapters() {
+        let error = WasmEncodedError::from("some error");
+        let (stream, dropped) = items(vec![Ok(1), Err(error.clone()), Ok(2)]);
+        let reported = Rc::new(RefCell::new(vec![]));
+        let reported_clone = reported.clone();
+        let mut stream = ExportStream::new(stream)
+            .map_ok(|value| value * 10)
+            .encode_errors(
+                move |err| reported_clone.borrow_mut().push(err.msg.clone()),
+                |_| {},
+            );
+        let mut results = vec![];
+        while let Some(item) = next_item(&mut stream).await {
+            results.push(item);
+        }
+        assert_eq!(results, vec![Ok(10), Err(error), Ok(20)]);
+        assert_eq!(*reported.borrow(), vec!["some error".to_string()]);
+        assert!(!dropped.get());
+        drop(stream);
+        assert!(dropped.get());
+
+        let mut stream = ExportStream::<u8, _>::from_encoded_error(WasmEncodedError::from("err"));
+        let item = next_item(&mut stream).await.unwrap();
+        assert_eq!(item.unwrap_err().msg, "err");
+        assert!(next_item(&mut stream).await.is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_js_async_iterator_next() {
+        let (stream, dropped) = items(vec![Ok(1), Err(WasmEncodedError::from("some error"))]);
+        let iter = JsAsyncIterator::from_stream(stream);
+
+        let result = call_method(&iter, "next", &JsValue::UNDEFINED).await;
+        assert_eq!(get(&result, "done"), JsValue::FALSE);
+        assert_eq!(get(&get(&result, "value"), "value"), JsValue::from(1));
+        assert!(get(&get(&result, "value"), "error").is_undefined());
+
+        let result = call_method(&iter, "next", &JsValue::UNDEFINED).await;
+        assert_eq!(get(&result, "done"), JsValue::FALSE);
+        assert!(get(&get(&result, "value"), "value").is_undefined());
+        let error = get(&get(&result, "value"), "error");
+        assert_eq!(get(&error, "msg"), JsValue::from_str("some error"));
+
+        // the stream is dropped once it ends
+        assert!(!dropped.get());
+        let result = call_method(&iter, "next", &JsValue::UNDEFINED).await;
+        assert_eq!(get(&result, "done"), JsValue::TRUE);
+        assert!(get(&result, "value").is_undefined());
+        assert!(dropped.get());
+
+        // it is iterable as itself
+        let method: Function = Reflect::get(iter.as_object(), &Symbol::async_iterator())
+            .unwrap()
+            .unchecked_into();
+        let this = method.call0(iter.as_object()).unwrap();
+        assert_eq!(this, JsValue::from(iter.as_object()));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_js_async_iterator_return() {
+        let (stream, dropped) = items(vec![Ok(1), Ok(2), Ok(3)]);
+        let iter = JsAsyncIterator::from_stream(stream);
+
+        let result = call_method(&iter, "next", &JsValue::UNDEFINED).await;
+        assert_eq!(get(&get(&result, "value"), "value"), JsValue::from(1));
+        assert!(!dropped.get());
+
+        // the stream is dropped once the consumer stops early
+        let result = call_method(&iter, "return", &JsValue::from(5)).await;
+        assert_eq!(get(&result, "done"), JsValue::TRUE);
+        assert_eq!(get(&result, "value"), JsValue::from(5));
+        assert!(dropped.get());
+
+        let result = call_method(&iter, "next", &JsValue::UNDEFINED).await;
+        assert_eq!(get(&result, "done"), JsValue::TRUE);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_js_async_iterator_preserved() {
+        let value = js_sys::Date::new_0();
+        let items = vec![Ok::<_, WasmEncodedError>(value.clone())];
+        let stream = ExportStream::new(PollFn({
+            let mut items = items.into_iter();
+            move |_: &mut Context<'_>| Poll::Ready(items.next())
+        }));
+        let iter = JsAsyncIterator::from_stream_preserved(stream);
+        let result = call_method(&iter, "next", &JsValue::UNDEFINED).await;
+        let item = get(&get(&result, "value"), "value");
+        assert!(item.is_instance_of::<js_sys::Date>());
+        assert_eq!(item, JsValue::from(value));
+    }
+}
@@ -0,0 +1,309 @@
+use std::fmt;
+use tsify::Tsify;
+use crate::impl_wasm_traits;
+use js_sys::{Array, ArrayBuffer, Uint8Array};
+use wasm_bindgen::{JsCast, JsValue};
+use serde::{
+    de::{self, DeserializeOwned, Visitor},
+    forward_to_deserialize_any, Deserialize, Deserializer, Serialize,
+};
+use serde_wasm_bindgen::{from_value as from_js_value, preserve};
+
+/// The JS type that the values of a rust type are expected to be, which is determined
+/// by probing the [Deserialize] impl of that type, used for dispatching JS values to
+/// the branches of JS union types, i.e. [JsUnion2], [JsUnion3] and [JsUnion4].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsTypeHint {
+    String,
+    Number,
+    BigInt,
+    Boolean,
+    Bytes,
+    Array,
+    Object,
+    Unknown,
+}
+
+impl JsTypeHint {
+    /// Determines the JS type hint of the given type, types that deserialize from any value
+    /// or from several JS types, such as enums, result in [JsTypeHint::Unknown]
+    pub fn of<T: DeserializeOwned>() -> Self {
+        match T::deserialize(TypeProbe) {
+            Err(ProbeResult(hint)) => hint,
+            Ok(_) => JsTypeHint::Unknown,
+        }
+    }
+
+    /// Determines if the given JS value is of this JS type
+    pub fn matches(&self, value: &JsValue) -> bool {
+        match self {
+            JsTypeHint::String => value.is_string(),
+            JsTypeHint::Number => value.as_f64().is_some(),
+            JsTypeHint::BigInt => value.is_bigint(),
+            JsTypeHint::Boolean => value.as_bool().is_some(),
+            JsTypeHint::Bytes => value.is_instance_of::<Uint8Array>(),
+            JsTypeHint::Array => Array::is_array(value) || ArrayBuffer::is_view(value),
+            JsTypeHint::Object => value.is_object() && !Array::is_array(value),
+            JsTypeHint::Unknown => false,
+        }
+    }
+}
+
+/// A deserializer that records the first deserialize method that is called by a type
+/// and returns its corresponding JS type hint as error to abort the deserialization
+struct TypeProbe;
+
+#[derive(Debug)]
+struct ProbeResult(JsTypeHint);
+
+impl fmt::Display for ProbeResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "probed js type: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ProbeResult {}
+
+impl de::Error for ProbeResult {
+    fn custom<T: fmt::Display>(_msg: T) -> Self {
+        ProbeResult(JsTypeHint::Unknown)
+    }
+}
+
+macro_rules! probe_hint {
+    ($hint:ident => $($method:ident),+) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+                Err(ProbeResult(JsTypeHint::$hint))
+            }
+        )+
+    };
+}
+
+impl<'de> Deserializer<'de> for TypeProbe {
+    type Error = ProbeResult;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(ProbeResult(JsTypeHint::Unknown))
+    }
+
+    probe_hint!(Boolean => deserialize_bool);
+    probe_hint!(Number => deserialize_i8, deserialize_i16, deserialize_i32, deserialize_u8, deserialize_u16, deserialize_u32, deserialize_f32, deserialize_f64);
+    probe_hint!(BigInt => deserialize_i64, deserialize_i128, deserialize_u64, deserialize_u128);
+    probe_hint!(String => deserialize_char, deserialize_str, deserialize_string);
+    probe_hint!(Bytes => deserialize_bytes, deserialize_byte_buf);
+    probe_hint!(Array => deserialize_seq);
+    probe_hint!(Object => deserialize_map);
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(ProbeResult(JsTypeHint::Array))
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(ProbeResult(JsTypeHint::Array))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(ProbeResult(JsTypeHint::Object))
+    }
+
+    forward_to_deserialize_any! {
+        unit unit_struct enum identifier ignored_any
+    }
+}
+
+/// Defines a JS union type with the given branches, it is serialized as its inner value
+/// and declared as the union of its branches in TS, when deserialized from a JS value
+/// (only supported by [serde_wasm_bindgen]) the branches whose JS type matches the JS
+/// type of the value are tried first and then all of the branches in order
+macro_rules! impl_js_union {
+    ($(#[$attr:meta])* $name:ident<$($branch:ident),+>) => {
+        $(#[$attr])*
+        #[derive(Serialize, Debug, Clone, PartialEq, Tsify)]
+        #[serde(untagged)]
+        pub enum $name<$($branch),+> {
+            $($branch($branch)),+
+        }
+        impl_wasm_traits!($name<$($branch),+>);
+
+        impl<'de, $($branch: DeserializeOwned),+> Deserialize<'de> for $name<$($branch),+> {
+            fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+                let value: JsValue = preserve::deserialize(deserializer)?;
+                $(
+                    if JsTypeHint::of::<$branch>().matches(&value) {
+                        if let Ok(v) = from_js_value(value.clone()) {
+                            return Ok(Self::$branch(v));
+                        }
+                    }
+                )+
+                $(
+                    if let Ok(v) = from_js_value(value.clone()) {
+                        return Ok(Self::$branch(v));
+                    }
+                )+
+                Err(de::Error::custom(concat!(
+                    "data did not match any branch of ",
+                    stringify!($name)
+                )))
+            }
+        }
+    };
+}
+
+impl_js_union!(
+    /// A JS union of two types, i.e. `A | B` in TS, for the args and returning values
+    /// that can be either of the given types, such as a hex string or a `Uint8Array`.
+    ///
+    /// Example:
+    /// ```ignore
+    /// #[wasm_export(js_name = "toHex")]
+    /// pub fn to_hex(value: JsUnion2<String, Vec<u8>>) -> Result<String, Error> {
+    ///     match value {
+    ///         JsUnion2::A(hex) => Ok(hex),
+    ///         JsUnion2::B(bytes) => Ok(encode_hex(bytes)),
+    ///     }
+    /// }
+    /// ```
+    JsUnion2<A, B>
+);
+
+impl_js_union!(
+    /// A JS union of three types, i.e. `A | B | C` in TS, same as [JsUnion2]
+    JsUnion3<A, B, C>
+);
+
+impl_js_union!(
+    /// A JS union of four types, i.e. `A | B | C | D` in TS, same as [JsUnion2]
+    JsUnion4<A, B, C, D>
+);
+
+#[cfg(target_family = "wasm")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use wasm_bindgen_test::wasm_bindgen_test;
+    use serde_wasm_bindgen::to_value as to_js_value;
+
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct SomeType {
+        field: String,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct SomeNewType(u64);
+
+    #[wasm_bindgen_test]
+    fn test_js_type_hint() {
+        assert_eq!(JsTypeHint::of::<String>(), JsTypeHint::String);
+        assert_eq!(JsTypeHint::of::<u32>(), JsTypeHint::Number);
+        assert_eq!(JsTypeHint::of::<f64>(), JsTypeHint::Number);
+        assert_eq!(JsTypeHint::of::<u64>(), JsTypeHint::BigInt);
+        assert_eq!(JsTypeHint::of::<bool>(), JsTypeHint::Boolean);
+        assert_eq!(JsTypeHint::of::<Vec<u8>>(), JsTypeHint::Array);
+        assert_eq!(JsTypeHint::of::<(u8, u8)>(), JsTypeHint::Array);
+        assert_eq!(JsTypeHint::of::<SomeType>(), JsTypeHint::Object);
+        assert_eq!(JsTypeHint::of::<HashMap<String, u8>>(), JsTypeHint::Object);
+        assert_eq!(JsTypeHint::of::<SomeNewType>(), JsTypeHint::BigInt);
+        assert_eq!(JsTypeHint::of::<Option<String>>(), JsTypeHint::String);
+        assert_eq!(JsTypeHint::of::<()>(), JsTypeHint::Unknown);
+
+        assert!(JsTypeHint::String.matches(&JsValue::from_str("abcd")));
+        assert!(!JsTypeHint::String.matches(&JsValue::from(1)));
+        assert!(JsTypeHint::Number.matches(&JsValue::from(1)));
+        assert!(JsTypeHint::BigInt.matches(&JsValue::from(1u64)));
+        assert!(JsTypeHint::Boolean.matches(&JsValue::TRUE));
+        assert!(JsTypeHint::Bytes.matches(&Uint8Array::new_with_length(2).into()));
+        assert!(JsTypeHint::Array.matches(&Uint8Array::new_with_length(2).into()));
+        assert!(JsTypeHint::Array.matches(&Array::new().into()));
+        assert!(!JsTypeHint::Object.matches(&Array::new().into()));
+        assert!(JsTypeHint::Object.matches(&js_sys::Object::new().into()));
+        assert!(!JsTypeHint::Unknown.matches(&JsValue::NULL));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_js_union_dispatch() {
+        // by js type
+        let value = JsValue::from_str("0x1234");
+        let result: JsUnion2<Vec<u8>, String> = from_js_value(value).unwrap();
+        assert_eq!(result, JsUnion2::B("0x1234".to_string()));
+
+        let value: JsValue = Uint8Array::from([1u8, 2].as_slice()).into();
+        let result: JsUnion2<String, Vec<u8>> = from_js_value(value).unwrap();
+        assert_eq!(result, JsUnion2::B(vec![1, 2]));
+
+        let value = JsValue::from(5u64);
+        let result: JsUnion3<u32, String, u64> = from_js_value(value).unwrap();
+        assert_eq!(result, JsUnion3::C(5));
+
+        let value = to_js_value(&SomeType {
+            field: "abcd".to_string(),
+        })
+        .unwrap();
+        let result: JsUnion4<String, bool, SomeType, u8> = from_js_value(value).unwrap();
+        assert_eq!(
+            result,
+            JsUnion4::C(SomeType {
+                field: "abcd".to_string()
+            })
+        );
+
+        // falls back to serde when no branch matches the js type
+        let value = JsValue::from(5);
+        let result: JsUnion2<String, u64> = from_js_value(value).unwrap();
+        assert_eq!(result, JsUnion2::B(5));
+
+        // no branch matches
+        let value = JsValue::TRUE;
+        let err = from_js_value::<JsUnion2<String, u64>>(value).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error: data did not match any branch of JsUnion2"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_js_union_wasm_traits() {
+        let value = JsUnion2::<String, u32>::A("abcd".to_string());
+        let js_value: JsValue = value.clone().into();
+        assert_eq!(js_value.as_string(), Some("abcd".to_string()));
+        assert_eq!(JsUnion2::try_from_js_value(js_value).unwrap(), value);
+
+        let value = JsUnion2::<String, u32>::B(5);
+        let js_value: JsValue = value.clone().into();
+        assert_eq!(js_value.as_f64(), Some(5.0));
+        assert_eq!(JsUnion2::try_from_js_value(js_value).unwrap(), value);
+
+        // nested in other types
+        let value = vec![JsUnion2::<String, bool>::B(true), JsUnion2::A("a".into())];
+        let js_value = to_js_value(&value).unwrap();
+        let result: Vec<JsUnion2<String, bool>> = from_js_value(js_value).unwrap();
+        assert_eq!(result, value);
+    }
+}
@@ -0,0 +1,245 @@
+//! Type checks the code generated by `wasm_export` against this crate for each of the
+//! returning kinds, the exporting items are compiled for every target by `cfg(all())`
+//! and nothing is glob imported, e.g. `Diagnosed` is not in scope, so the generated code
+//! must only use qualified paths.
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use wasm_bindgen_utils::{prelude::wasm_bindgen, result, wasm_export, wasm_import};
+
+/// The error of the exported functions
+#[derive(Debug)]
+pub struct Error;
+
+impl From<Error> for result::WasmEncodedError {
+    fn from(_: Error) -> Self {
+        result::WasmEncodedError::new("error", "error")
+    }
+}
+
+impl From<result::WasmEncodedError> for Error {
+    fn from(_: result::WasmEncodedError) -> Self {
+        Error
+    }
+}
+
+/// A stream that yields the given items
+struct Items(std::vec::IntoIter<Result<u8, Error>>);
+
+impl futures_core::Stream for Items {
+    type Item = Result<u8, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.0.next())
+    }
+}
+
+#[wasm_export(js_name = "resultFn", cfg(all()))]
+pub fn result_fn(
+    value: u8,
+    #[wasm_export(optional, unchecked_param_type = "number")] extra: Option<u8>,
+) -> Result<u8, Error> {
+    Ok(value + extra.unwrap_or_default())
+}
+
+#[wasm_export(js_name = "diagnosedFn", cfg(all()))]
+pub fn diagnosed_fn(value: u8) -> result::Diagnosed<u8, Error> {
+    result::Diagnosed::ok(value)
+}
+
+#[wasm_export(js_name = "batchFn", cfg(all()))]
+pub async fn batch_fn(values: Vec<u8>) -> Vec<Result<u8, Error>> {
+    values.into_iter().map(Ok).collect()
+}
+
+#[wasm_export(js_name = "streamFn", cfg(all()))]
+pub fn stream_fn(values: Vec<u8>) -> impl futures_core::Stream<Item = Result<u8, Error>> {
+    Items(values.into_iter().map(Ok).collect::<Vec<_>>().into_iter())
+}
+
+#[wasm_export(js_name = "iterFn", cfg(all()))]
+pub fn iter_fn(values: Vec<u8>) -> impl Iterator<Item = u8> {
+    values.into_iter()
+}
+
+#[allow(async_fn_in_trait)]
+#[wasm_export(js_name = "Source", cfg(all()))]
+pub trait Source {
+    fn get(&self, key: String) -> Result<Option<u8>, Error>;
+    async fn clear(&self) -> Result<(), Error>;
+}
+
+#[wasm_import(js_namespace = console, cfg(all()))]
+extern "C" {
+    pub fn log(msg: &str) -> Result<(), Error>;
+    pub async fn fetch(url: String) -> Result<Vec<u8>, Error>;
+}
+
+#[wasm_export(js_name = "signalFn", cfg(all()))]
+pub async fn signal_fn(
+    value: u8,
+    #[wasm_export(abort_signal, js_name = "signal")] _signal: wasm_bindgen_utils::prelude::JsValue,
+) -> Result<u8, Error> {
+    Ok(value)
+}
+
+#[wasm_export(js_name = "cancellableFn", cancellable, timeout_ms = 100, cfg(all()))]
+pub async fn cancellable_fn(value: u8) -> Result<u8, Error> {
+    Ok(value)
+}
+
+#[wasm_bindgen]
+pub struct Counter {
+    count: u8,
+}
+
+#[wasm_export(cfg(all()))]
+impl Counter {
+    #[wasm_export(js_name = "new", preserve_js_class)]
+    pub fn new(count: u8) -> Result<Counter, Error> {
+        Ok(Counter { count })
+    }
+
+    #[wasm_export(js_name = "diagnosed", preserve_js_class)]
+    pub fn diagnosed(&self) -> result::Diagnosed<Counter, Error> {
+        result::Diagnosed::ok(Counter { count: self.count })
+    }
+
+    #[wasm_export(js_name = "batch", preserve_js_class)]
+    pub async fn batch(&self) -> Vec<Result<Counter, Error>> {
+        vec![Ok(Counter { count: self.count })]
+    }
+
+    #[wasm_export(js_name = "stream", preserve_js_class)]
+    pub fn stream(&self) -> impl futures_core::Stream<Item = Result<u8, Error>> + 'static {
+        Items(vec![Ok(self.count)].into_iter())
+    }
+
+    #[wasm_export(js_name = "iter", unchecked_return_type = "number")]
+    pub fn iter(&self) -> Box<dyn Iterator<Item = u8>> {
+        Box::new(0..self.count)
+    }
+}
+
+/// The same exports with the crate path override
+mod renamed {
+    extern crate wasm_bindgen_utils as utils;
+
+    use super::Error;
+    use utils::{prelude::wasm_bindgen, result, wasm_export};
+
+    #[wasm_export(js_name = "renamedResultFn", crate = "utils", cfg(all()))]
+    pub fn renamed_result_fn(value: u8) -> Result<u8, Error> {
+        Ok(value)
+    }
+
+    #[wasm_bindgen]
+    pub struct Renamed;
+
+    #[wasm_export(crate = "utils", cfg(all()))]
+    impl Renamed {
+        #[wasm_export(js_name = "diagnosed", preserve_js_class)]
+        pub fn diagnosed() -> result::Diagnosed<Renamed, Error> {
+            result::Diagnosed::ok(Renamed)
+        }
+
+        #[wasm_export(js_name = "batch", preserve_js_class)]
+        pub async fn batch() -> Vec<Result<Renamed, Error>> {
+            vec![Ok(Renamed)]
+        }
+    }
+}
+
+/// The exports in a scope where the prelude names are shadowed, so the generated code
+/// must not rely on them
+#[allow(dead_code)]
+mod shadowed {
+    use super::Error;
+    use wasm_bindgen_utils::{prelude::wasm_bindgen, result, wasm_export};
+
+    struct Option;
+    struct Some;
+    struct None;
+    struct Ok;
+    struct Err;
+    struct Vec;
+
+    fn parse(value: String) -> core::result::Result<u8, Error> {
+        value.parse().map_err(|_| Error)
+    }
+
+    #[wasm_export(js_name = "shadowedFn", error_context, cfg(all()))]
+    pub fn shadowed_fn(
+        #[wasm_export(from_js = parse, js_type = "string")] parsed: u8,
+        #[wasm_export(optional, unchecked_param_type = "number")] value: core::option::Option<u8>,
+        #[wasm_export(default = 1)] other: u8,
+        #[wasm_export(variadic)] items: std::vec::Vec<u8>,
+    ) -> core::result::Result<u8, Error> {
+        core::result::Result::Ok(parsed + value.unwrap_or_default() + other + items.len() as u8)
+    }
+
+    #[wasm_export(js_name = "shadowedStream", error_context, cfg(all()))]
+    pub fn shadowed_stream(
+        values: std::vec::Vec<u8>,
+    ) -> impl futures_core::Stream<Item = core::result::Result<u8, Error>> {
+        let items = values.into_iter().map(core::result::Result::Ok);
+        super::Items(items.collect::<std::vec::Vec<_>>().into_iter())
+    }
+
+    #[wasm_export(
+        js_name = "shadowedSignal",
+        timeout_ms = 100,
+        error_context,
+        cfg(all())
+    )]
+    pub async fn shadowed_signal(
+        value: u8,
+        _signal: core::option::Option<wasm_bindgen_utils::abort::AbortSignal>,
+    ) -> core::result::Result<u8, Error> {
+        core::result::Result::Ok(value)
+    }
+
+    #[wasm_export(
+        js_name = "shadowedCancellable",
+        cancellable,
+        timeout_ms = 100,
+        cfg(all())
+    )]
+    pub async fn shadowed_cancellable(value: u8) -> core::result::Result<u8, Error> {
+        core::result::Result::Ok(value)
+    }
+
+    #[wasm_export(js_name = "shadowedIter", error_context, cfg(all()))]
+    pub fn shadowed_iter(values: std::vec::Vec<u8>) -> impl Iterator<Item = u8> {
+        values.into_iter()
+    }
+
+    #[wasm_bindgen]
+    pub struct Shadowed;
+
+    #[wasm_export(cfg(all()))]
+    impl Shadowed {
+        #[wasm_export(js_name = "diagnosed", preserve_js_class, error_context)]
+        pub fn diagnosed() -> result::Diagnosed<Shadowed, Error> {
+            result::Diagnosed::ok(Shadowed)
+        }
+
+        #[wasm_export(js_name = "batch", preserve_js_class, error_context)]
+        pub async fn batch() -> std::vec::Vec<core::result::Result<Shadowed, Error>> {
+            vec![core::result::Result::Ok(Shadowed)]
+        }
+    }
+}
+
+#[test]
+fn test_exports() {
+    // the exported functions are still callable as the original rust functions
+    assert_eq!(result_fn(1, Some(2)).unwrap(), 3);
+    assert_eq!(diagnosed_fn(1).result.unwrap(), 1);
+    assert_eq!(iter_fn(vec![1, 2]).collect::<Vec<_>>(), vec![1, 2]);
+    assert_eq!(Counter::new(3).unwrap().iter().count(), 3);
+    assert!(renamed::renamed_result_fn(1).is_ok());
+    assert_eq!(shadowed::shadowed_fn(1, Some(2), 3, vec![4]).unwrap(), 7);
+}